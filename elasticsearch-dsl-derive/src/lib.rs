@@ -0,0 +1,523 @@
+//! Derive macros for [elasticsearch-dsl](https://docs.rs/elasticsearch-dsl).
+//!
+//! Enable them with the `derive` feature of `elasticsearch-dsl` rather than depending on this
+//! crate directly.
+#![deny(missing_docs, unreachable_pub, unused, unused_results, warnings)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Lit, Meta, Result, Token};
+
+/// Derives `EsMapping` for a struct with named fields.
+///
+/// Every field annotated with `#[es(...)]` becomes a property of the mapping. The first item of
+/// the attribute names the `Property` constructor, optionally with its arguments, and the
+/// remaining `key = value` items call the corresponding builder methods:
+///
+/// ```
+/// use elasticsearch_dsl::*;
+///
+/// #[derive(EsMapping)]
+/// #[es(dynamic = Dynamic::Strict)]
+/// struct Product {
+///     #[es(text, analyzer = "english")]
+///     title: String,
+///
+///     #[es(scaled_float(100.0))]
+///     price: f64,
+///
+///     #[es(nested)]
+///     sellers: Vec<Seller>,
+///
+///     description: String,
+/// }
+///
+/// #[derive(EsMapping)]
+/// struct Seller {
+///     #[es(keyword, rename = "id")]
+///     seller_id: String,
+/// }
+///
+/// let mappings = Product::es_mappings();
+/// ```
+///
+/// `object` and `nested` fields take their properties from the field type, which has to implement
+/// `EsMapping` as well. Fields are mapped under the name `serde` serializes them with, taking
+/// `#[serde(rename)]` and the container's `#[serde(rename_all)]` into account, unless renamed with
+/// `rename`. Fields without the attribute are left to dynamic mapping, `#[serde(flatten)]` fields
+/// are rejected.
+#[proc_macro_derive(EsMapping, attributes(es))]
+pub fn derive_es_mapping(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = named_fields(&input, "EsMapping")?;
+    let container = SerdeContainer::parse(&input.attrs)?;
+
+    let mut properties = Vec::new();
+
+    for field in fields {
+        let mut name = container.field(field)?.name;
+        let mut property = None;
+
+        for attr in field.attrs.iter().filter(|x| x.path().is_ident("es")) {
+            let mut items = parse_items(attr)?.into_iter();
+
+            let constructor = match items.next() {
+                Some(constructor) => constructor,
+                None => return Err(Error::new_spanned(attr, "expected a property type")),
+            };
+
+            let mut tokens = match constructor {
+                Meta::Path(path) if path.is_ident("object") || path.is_ident("nested") => {
+                    let ty = &field.ty;
+                    quote! {
+                        <#ty as ::elasticsearch_dsl::indices::EsMapping>::es_properties()
+                            .into_iter()
+                            .fold(
+                                ::elasticsearch_dsl::indices::Property::#path(),
+                                |property, (name, value)| property.property(name, value),
+                            )
+                    }
+                }
+                Meta::Path(path) => quote!(::elasticsearch_dsl::indices::Property::#path()),
+                Meta::List(list) => {
+                    let path = &list.path;
+                    let args = &list.tokens;
+                    quote!(::elasticsearch_dsl::indices::Property::#path(#args))
+                }
+                Meta::NameValue(meta) => {
+                    return Err(Error::new_spanned(meta, "expected a property type"))
+                }
+            };
+
+            for item in items {
+                match item {
+                    Meta::NameValue(meta) if meta.path.is_ident("rename") => {
+                        name = string_value(&meta.value)?;
+                    }
+                    Meta::NameValue(meta) => {
+                        let method = &meta.path;
+                        let value = &meta.value;
+                        tokens = quote!(#tokens.#method(#value));
+                    }
+                    Meta::List(list) => {
+                        let method = &list.path;
+                        let args = &list.tokens;
+                        tokens = quote!(#tokens.#method(#args));
+                    }
+                    Meta::Path(path) => {
+                        return Err(Error::new_spanned(
+                            path,
+                            "expected `key = value` or `key(args)`",
+                        ))
+                    }
+                }
+            }
+
+            if property.replace(tokens).is_some() {
+                return Err(Error::new_spanned(attr, "duplicate `es` attribute"));
+            }
+        }
+
+        if let Some(property) = property {
+            properties.push(quote! {
+                let _ = properties.insert(
+                    #name.to_string(),
+                    ::elasticsearch_dsl::indices::Property::from(#property),
+                );
+            });
+        }
+    }
+
+    let mut mappings = quote!(::elasticsearch_dsl::indices::Mappings::new());
+
+    for attr in input.attrs.iter().filter(|x| x.path().is_ident("es")) {
+        for item in parse_items(attr)? {
+            match item {
+                Meta::NameValue(meta) => {
+                    let method = &meta.path;
+                    let value = &meta.value;
+                    mappings = quote!(#mappings.#method(#value));
+                }
+                Meta::List(list) => {
+                    let method = &list.path;
+                    let args = &list.tokens;
+                    mappings = quote!(#mappings.#method(#args));
+                }
+                Meta::Path(path) => {
+                    return Err(Error::new_spanned(
+                        path,
+                        "expected `key = value` or `key(args)`",
+                    ))
+                }
+            }
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::elasticsearch_dsl::indices::EsMapping for #ident #ty_generics #where_clause {
+            fn es_properties() -> ::std::collections::BTreeMap<
+                ::std::string::String,
+                ::elasticsearch_dsl::indices::Property,
+            > {
+                #[allow(unused_mut)]
+                let mut properties = ::std::collections::BTreeMap::new();
+                #(#properties)*
+                properties
+            }
+
+            fn es_mappings() -> ::elasticsearch_dsl::indices::Mappings {
+                <Self as ::elasticsearch_dsl::indices::EsMapping>::es_properties()
+                    .into_iter()
+                    .fold(#mappings, |mappings, (name, property)| {
+                        mappings.property(name, property)
+                    })
+            }
+        }
+    })
+}
+
+/// Derives `EsDocument` for a struct with named fields.
+///
+/// Generates a `<Name>Fields` companion type with an accessor per field, returning the typed
+/// `Field` to build queries with:
+///
+/// ```
+/// use elasticsearch_dsl::*;
+///
+/// #[derive(EsDocument)]
+/// struct Product {
+///     title: String,
+///
+///     #[es(rename = "id")]
+///     product_id: String,
+///
+///     #[es(nested)]
+///     sellers: Vec<Seller>,
+/// }
+///
+/// #[derive(EsDocument)]
+/// struct Seller {
+///     name: String,
+/// }
+///
+/// let query = Query::bool()
+///     .must(Product::fields().title().r#match("shirt"))
+///     .filter(Product::fields().sellers().name().term("Acme"));
+/// ```
+///
+/// Fields are named like `EsMapping` names them, `#[serde(skip)]` and
+/// `#[serde(skip_serializing)]` fields are left out. Accessors
+/// of `object` and `nested` fields return the accessors of the field type, which has to implement
+/// `EsDocument` as well.
+#[proc_macro_derive(EsDocument, attributes(es))]
+pub fn derive_es_document(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_document(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_document(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = named_fields(&input, "EsDocument")?;
+    let container = SerdeContainer::parse(&input.attrs)?;
+    let mut accessors = Vec::new();
+
+    for field in fields {
+        let serde = container.field(field)?;
+
+        if serde.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let mut name = serde.name;
+        let mut object = false;
+
+        for attr in field.attrs.iter().filter(|x| x.path().is_ident("es")) {
+            for (index, item) in parse_items(attr)?.into_iter().enumerate() {
+                match item {
+                    Meta::Path(path)
+                        if index == 0 && (path.is_ident("object") || path.is_ident("nested")) =>
+                    {
+                        object = true;
+                    }
+                    Meta::NameValue(meta) if meta.path.is_ident("rename") => {
+                        name = string_value(&meta.value)?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let doc = format!("`{name}` field");
+        let field_path = quote! {
+            match &self.prefix {
+                ::std::option::Option::Some(prefix) => prefix.join(#name),
+                ::std::option::Option::None => ::elasticsearch_dsl::search::Field::new(#name),
+            }
+        };
+
+        accessors.push(if object {
+            let ty = &field.ty;
+            quote! {
+                #[doc = #doc]
+                pub fn #ident(&self) -> <#ty as ::elasticsearch_dsl::search::EsDocument>::Fields {
+                    <#ty as ::elasticsearch_dsl::search::EsDocument>::fields_at(
+                        ::std::option::Option::Some(#field_path),
+                    )
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                pub fn #ident(&self) -> ::elasticsearch_dsl::search::Field {
+                    #field_path
+                }
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let fields_ident = syn::Ident::new(&format!("{ident}Fields"), ident.span());
+    let fields_doc = format!("Field accessors of [`{ident}`]");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[doc = #fields_doc]
+        #[derive(Debug, Clone, PartialEq)]
+        #vis struct #fields_ident {
+            prefix: ::std::option::Option<::elasticsearch_dsl::search::Field>,
+        }
+
+        impl #fields_ident {
+            #(#accessors)*
+        }
+
+        impl #impl_generics ::elasticsearch_dsl::search::EsDocument for #ident #ty_generics #where_clause {
+            type Fields = #fields_ident;
+
+            fn fields_at(
+                prefix: ::std::option::Option<::elasticsearch_dsl::search::Field>,
+            ) -> Self::Fields {
+                #fields_ident { prefix }
+            }
+        }
+    })
+}
+
+fn named_fields<'a>(
+    input: &'a DeriveInput,
+    derive: &str,
+) -> Result<&'a Punctuated<syn::Field, Token![,]>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(&fields.named),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                format!("{derive} can only be derived for structs with named fields"),
+            )),
+        },
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            format!("{derive} can only be derived for structs"),
+        )),
+    }
+}
+
+fn parse_items(attr: &syn::Attribute) -> Result<Punctuated<Meta, Token![,]>> {
+    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+}
+
+fn string_value(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => Ok(value.value()),
+        _ => Err(Error::new_spanned(expr, "expected a string literal")),
+    }
+}
+
+/// `serde` options of a container that affect the names of its fields in the document
+#[derive(Default)]
+struct SerdeContainer {
+    rename_all: Option<RenameRule>,
+}
+
+/// Name of a field in the serialized document
+struct SerdeField {
+    name: String,
+    skip: bool,
+}
+
+impl SerdeContainer {
+    /// Reads `#[serde(rename_all = "...")]` of a container, other `serde` options are ignored
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut container = Self::default();
+
+        for attr in attrs.iter().filter(|x| x.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    if let Some(rule) = serialize_name(&meta)? {
+                        container.rename_all = Some(RenameRule::parse(&rule)?);
+                    }
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(container)
+    }
+
+    /// Resolves the name `serde` serializes the field under, from `#[serde(rename = "...")]`,
+    /// `#[serde(rename(serialize = "..."))]` and the `rename_all` rule of the container.
+    /// `#[serde(flatten)]` fields are rejected as their properties can't be named.
+    fn field(&self, field: &syn::Field) -> Result<SerdeField> {
+        let ident = field.ident.as_ref().expect("named field").unraw();
+        let mut rename = None;
+        let mut skip = false;
+
+        for attr in field.attrs.iter().filter(|x| x.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if let Some(name) = serialize_name(&meta)? {
+                        rename = Some(name);
+                    }
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    return Err(meta.error("`#[serde(flatten)]` fields aren't supported"));
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        let name = match (rename, &self.rename_all) {
+            (Some(name), _) => name,
+            (None, Some(rule)) => rule.apply(&ident.to_string()),
+            (None, None) => ident.to_string(),
+        };
+
+        Ok(SerdeField { name, skip })
+    }
+}
+
+/// Reads the serialization name of `key = "..."` or `key(serialize = "...")` options,
+/// `None` when only the deserialization name is given
+fn serialize_name(meta: &syn::meta::ParseNestedMeta<'_>) -> Result<Option<String>> {
+    if meta.input.peek(Token![=]) {
+        let value: syn::LitStr = meta.value()?.parse()?;
+        return Ok(Some(value.value()));
+    }
+
+    let mut name = None;
+
+    meta.parse_nested_meta(|nested| {
+        let value: syn::LitStr = nested.value()?.parse()?;
+
+        if nested.path.is_ident("serialize") {
+            name = Some(value.value());
+        }
+        Ok(())
+    })?;
+
+    Ok(name)
+}
+
+/// Consumes the value of a `serde` option that doesn't affect names
+fn skip_meta(meta: &syn::meta::ParseNestedMeta<'_>) -> Result<()> {
+    if meta.input.peek(Token![=]) {
+        let _: Expr = meta.value()?.parse()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+    Ok(())
+}
+
+/// Case conventions of `#[serde(rename_all = "...")]`
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &str) -> Result<Self> {
+        Ok(match rule {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => {
+                return Err(Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!("unknown rename rule `rename_all = {rule:?}`"),
+                ))
+            }
+        })
+    }
+
+    /// Renames a snake case field name the same way `serde` does
+    fn apply(&self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_string(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+
+                for char in field.chars() {
+                    if char == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(char.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(char);
+                    }
+                }
+
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply(field);
+                let mut chars = pascal.chars();
+
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
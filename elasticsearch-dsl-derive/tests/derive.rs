@@ -0,0 +1,194 @@
+use elasticsearch_dsl::*;
+use pretty_assertions::assert_eq;
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize, EsMapping, EsDocument)]
+#[es(dynamic = Dynamic::Strict, routing_required = true)]
+struct Product {
+    #[es(text, analyzer = "english", copy_to = ["all"], field("raw", Property::keyword()))]
+    title: String,
+
+    #[serde(rename = "tag")]
+    #[es(keyword)]
+    tags: Vec<String>,
+
+    #[es(scaled_float(100.0), index = false)]
+    price: f64,
+
+    #[es(keyword, rename = "id")]
+    product_id: String,
+
+    #[es(object)]
+    seller: Seller,
+
+    #[es(nested, include_in_parent = true)]
+    variants: Vec<Variant>,
+
+    description: String,
+}
+
+#[derive(Serialize, EsMapping, EsDocument)]
+struct Seller {
+    #[es(keyword)]
+    name: String,
+
+    #[es(boolean)]
+    verified: Option<bool>,
+}
+
+#[derive(Serialize, EsMapping, EsDocument)]
+struct Variant {
+    #[es(keyword)]
+    color: String,
+}
+
+#[derive(EsMapping)]
+struct Empty {}
+
+#[derive(Serialize, EsDocument)]
+struct Skipped {
+    kept: String,
+
+    #[serde(skip)]
+    #[allow(dead_code)]
+    skipped: String,
+}
+
+#[derive(Serialize, EsMapping, EsDocument)]
+#[serde(rename_all = "camelCase")]
+struct Listing {
+    #[es(keyword)]
+    listing_id: String,
+
+    #[serde(rename(serialize = "listedAt", deserialize = "listed_at"))]
+    #[es(date)]
+    created_at: String,
+
+    #[es(object)]
+    shipping_address: Address,
+}
+
+#[derive(Serialize, EsMapping, EsDocument)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+struct Address {
+    #[es(keyword)]
+    country_code: String,
+}
+
+#[test]
+fn derives_mappings() {
+    assert_eq!(
+        serde_json::to_value(Product::es_mappings()).unwrap(),
+        json!({
+            "dynamic": "strict",
+            "_routing": { "required": true },
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "copy_to": ["all"],
+                    "fields": { "raw": { "type": "keyword" } }
+                },
+                "tag": { "type": "keyword" },
+                "price": { "type": "scaled_float", "scaling_factor": 100.0, "index": false },
+                "id": { "type": "keyword" },
+                "seller": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "keyword" },
+                        "verified": { "type": "boolean" }
+                    }
+                },
+                "variants": {
+                    "type": "nested",
+                    "include_in_parent": true,
+                    "properties": {
+                        "color": { "type": "keyword" }
+                    }
+                }
+            }
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(Empty::es_mappings()).unwrap(),
+        json!({})
+    );
+}
+
+#[test]
+fn derives_document_fields() {
+    let fields = Product::fields();
+
+    assert_eq!(fields.title(), Field::new("title"));
+    assert_eq!(fields.tags(), Field::new("tag"));
+    assert_eq!(fields.product_id(), Field::new("id"));
+    assert_eq!(fields.seller().verified(), Field::new("seller.verified"));
+    assert_eq!(fields.variants().color(), Field::new("variants.color"));
+    assert_eq!(Skipped::fields().kept(), Field::new("kept"));
+
+    assert_eq!(
+        serde_json::to_value(
+            Query::bool()
+                .must(fields.title().r#match("shirt"))
+                .filter(fields.seller().name().term("Acme"))
+        )
+        .unwrap(),
+        json!({
+            "bool": {
+                "must": [
+                    { "match": { "title": { "query": "shirt" } } }
+                ],
+                "filter": [
+                    { "term": { "seller.name": { "value": "Acme" } } }
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn follows_serde_names() {
+    let listing = Listing {
+        listing_id: "1".into(),
+        created_at: "2024-01-01".into(),
+        shipping_address: Address {
+            country_code: "LT".into(),
+        },
+    };
+
+    assert_eq!(
+        serde_json::to_value(&listing).unwrap(),
+        json!({
+            "listingId": "1",
+            "listedAt": "2024-01-01",
+            "shippingAddress": { "COUNTRY-CODE": "LT" }
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(Listing::es_mappings()).unwrap(),
+        json!({
+            "properties": {
+                "listingId": { "type": "keyword" },
+                "listedAt": { "type": "date" },
+                "shippingAddress": {
+                    "type": "object",
+                    "properties": {
+                        "COUNTRY-CODE": { "type": "keyword" }
+                    }
+                }
+            }
+        })
+    );
+
+    let fields = Listing::fields();
+
+    assert_eq!(fields.listing_id(), Field::new("listingId"));
+    assert_eq!(fields.created_at(), Field::new("listedAt"));
+    assert_eq!(
+        fields.shipping_address().country_code(),
+        Field::new("shippingAddress.COUNTRY-CODE")
+    );
+}
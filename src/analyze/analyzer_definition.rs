@@ -0,0 +1,45 @@
+use crate::analyze::*;
+
+/// Analyzer defined in the analysis settings of an index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum AnalyzerDefinition {
+    /// Custom analyzer combining a tokenizer with character and token filters
+    Custom(CustomAnalyzer),
+
+    /// Built-in analyzer configured with custom options
+    Configured(ConfiguredAnalyzer),
+}
+
+impl From<CustomAnalyzer> for AnalyzerDefinition {
+    fn from(value: CustomAnalyzer) -> Self {
+        Self::Custom(value)
+    }
+}
+
+impl From<ConfiguredAnalyzer> for AnalyzerDefinition {
+    fn from(value: ConfiguredAnalyzer) -> Self {
+        Self::Configured(value)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AnalyzerDefinition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use crate::util::Lenient;
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        let analyzer = match value.get("type").and_then(serde_json::Value::as_str) {
+            Some("custom") | None => {
+                CustomAnalyzer::deserialize(Lenient::new(value)).map(Self::Custom)
+            }
+            Some(_) => ConfiguredAnalyzer::deserialize(Lenient::new(value)).map(Self::Configured),
+        };
+
+        analyzer.map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,499 @@
+use crate::util::*;
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Elasticsearch ships with a wide range of built-in analyzers, which can be used in any index
+/// without further configuration.
+///
+/// To analyze text with a built-in analyzer:
+/// ```
+/// # use elasticsearch_dsl::analyze::*;
+/// # let analyze =
+/// Analyze::new("The quick brown fox").analyzer(BuiltinAnalyzer::Language(AnalyzerLanguage::English));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-analyzers.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BuiltinAnalyzer {
+    /// Divides text into terms on word boundaries, as defined by the Unicode Text Segmentation
+    /// algorithm. It removes most punctuation, lowercases terms, and supports removing stop
+    /// words.
+    Standard,
+
+    /// Divides text into terms whenever it encounters a character which is not a letter. It
+    /// lowercases all terms.
+    Simple,
+
+    /// Divides text into terms whenever it encounters any whitespace character. It does not
+    /// lowercase terms.
+    Whitespace,
+
+    /// Like the simple analyzer, but also supports removal of stop words
+    Stop,
+
+    /// A “noop” analyzer that accepts whatever text it is given and outputs the exact same text
+    /// as a single term
+    Keyword,
+
+    /// Uses a regular expression to split the text into terms. It supports lower-casing and
+    /// stop words.
+    Pattern,
+
+    /// Creates a fingerprint which can be used for duplicate detection
+    Fingerprint,
+
+    /// Language specific analyzer
+    Language(AnalyzerLanguage),
+}
+
+/// Languages supported by the built-in language analyzers
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-lang-analyzer.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum AnalyzerLanguage {
+    /// `arabic` analyzer
+    Arabic,
+
+    /// `armenian` analyzer
+    Armenian,
+
+    /// `basque` analyzer
+    Basque,
+
+    /// `bengali` analyzer
+    Bengali,
+
+    /// `brazilian` analyzer
+    Brazilian,
+
+    /// `bulgarian` analyzer
+    Bulgarian,
+
+    /// `catalan` analyzer
+    Catalan,
+
+    /// `cjk` analyzer
+    Cjk,
+
+    /// `czech` analyzer
+    Czech,
+
+    /// `danish` analyzer
+    Danish,
+
+    /// `dutch` analyzer
+    Dutch,
+
+    /// `english` analyzer
+    English,
+
+    /// `estonian` analyzer
+    Estonian,
+
+    /// `finnish` analyzer
+    Finnish,
+
+    /// `french` analyzer
+    French,
+
+    /// `galician` analyzer
+    Galician,
+
+    /// `german` analyzer
+    German,
+
+    /// `greek` analyzer
+    Greek,
+
+    /// `hindi` analyzer
+    Hindi,
+
+    /// `hungarian` analyzer
+    Hungarian,
+
+    /// `indonesian` analyzer
+    Indonesian,
+
+    /// `irish` analyzer
+    Irish,
+
+    /// `italian` analyzer
+    Italian,
+
+    /// `latvian` analyzer
+    Latvian,
+
+    /// `lithuanian` analyzer
+    Lithuanian,
+
+    /// `norwegian` analyzer
+    Norwegian,
+
+    /// `persian` analyzer
+    Persian,
+
+    /// `portuguese` analyzer
+    Portuguese,
+
+    /// `romanian` analyzer
+    Romanian,
+
+    /// `russian` analyzer
+    Russian,
+
+    /// `serbian` analyzer
+    Serbian,
+
+    /// `sorani` analyzer
+    Sorani,
+
+    /// `spanish` analyzer
+    Spanish,
+
+    /// `swedish` analyzer
+    Swedish,
+
+    /// `thai` analyzer
+    Thai,
+
+    /// `turkish` analyzer
+    Turkish,
+}
+
+/// A built-in analyzer configured with custom options, to be defined in the
+/// [analysis settings](crate::AnalysisSettings) of an index. All the options are optional and
+/// only applicable to some of the analyzer types.
+///
+/// To configure a built-in analyzer:
+/// ```
+/// # use elasticsearch_dsl::analyze::*;
+/// # let analyzer =
+/// ConfiguredAnalyzer::new(BuiltinAnalyzer::Standard)
+///     .max_token_length(5)
+///     .stopwords(["_english_"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/configure-text-analysis.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfiguredAnalyzer {
+    r#type: BuiltinAnalyzer,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_token_length: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords_path: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pattern: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lowercase: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    separator: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_output_size: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    stem_exclusion: Vec<String>,
+}
+
+impl BuiltinAnalyzer {
+    /// Returns the name of the analyzer
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Simple => "simple",
+            Self::Whitespace => "whitespace",
+            Self::Stop => "stop",
+            Self::Keyword => "keyword",
+            Self::Pattern => "pattern",
+            Self::Fingerprint => "fingerprint",
+            Self::Language(language) => language.as_str(),
+        }
+    }
+}
+
+impl AnalyzerLanguage {
+    /// Returns the name of the language analyzer
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Arabic => "arabic",
+            Self::Armenian => "armenian",
+            Self::Basque => "basque",
+            Self::Bengali => "bengali",
+            Self::Brazilian => "brazilian",
+            Self::Bulgarian => "bulgarian",
+            Self::Catalan => "catalan",
+            Self::Cjk => "cjk",
+            Self::Czech => "czech",
+            Self::Danish => "danish",
+            Self::Dutch => "dutch",
+            Self::English => "english",
+            Self::Estonian => "estonian",
+            Self::Finnish => "finnish",
+            Self::French => "french",
+            Self::Galician => "galician",
+            Self::German => "german",
+            Self::Greek => "greek",
+            Self::Hindi => "hindi",
+            Self::Hungarian => "hungarian",
+            Self::Indonesian => "indonesian",
+            Self::Irish => "irish",
+            Self::Italian => "italian",
+            Self::Latvian => "latvian",
+            Self::Lithuanian => "lithuanian",
+            Self::Norwegian => "norwegian",
+            Self::Persian => "persian",
+            Self::Portuguese => "portuguese",
+            Self::Romanian => "romanian",
+            Self::Russian => "russian",
+            Self::Serbian => "serbian",
+            Self::Sorani => "sorani",
+            Self::Spanish => "spanish",
+            Self::Swedish => "swedish",
+            Self::Thai => "thai",
+            Self::Turkish => "turkish",
+        }
+    }
+}
+
+impl std::fmt::Display for BuiltinAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Serialize for BuiltinAnalyzer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for AnalyzerLanguage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BuiltinAnalyzer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(match name.as_str() {
+            "standard" => Self::Standard,
+            "simple" => Self::Simple,
+            "whitespace" => Self::Whitespace,
+            "stop" => Self::Stop,
+            "keyword" => Self::Keyword,
+            "pattern" => Self::Pattern,
+            "fingerprint" => Self::Fingerprint,
+            _ => Self::Language(AnalyzerLanguage::deserialize(name.into_deserializer())?),
+        })
+    }
+}
+
+impl From<AnalyzerLanguage> for BuiltinAnalyzer {
+    fn from(value: AnalyzerLanguage) -> Self {
+        Self::Language(value)
+    }
+}
+
+impl ConfiguredAnalyzer {
+    /// Creates an instance of [`ConfiguredAnalyzer`]
+    ///
+    /// - `analyzer` - Built-in analyzer type to configure
+    pub fn new<T>(analyzer: T) -> Self
+    where
+        T: Into<BuiltinAnalyzer>,
+    {
+        Self {
+            r#type: analyzer.into(),
+            max_token_length: None,
+            stopwords: Vec::new(),
+            stopwords_path: None,
+            pattern: None,
+            flags: None,
+            lowercase: None,
+            separator: None,
+            max_output_size: None,
+            stem_exclusion: Vec::new(),
+        }
+    }
+
+    /// The maximum token length. If a token is seen that exceeds this length then it is split at
+    /// `max_token_length` intervals. Defaults to `255`. Applicable to the `standard` analyzer.
+    pub fn max_token_length(mut self, max_token_length: u32) -> Self {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+
+    /// A list of stop words or a pre-defined stop words list like `_english_`. Applicable to
+    /// the `standard`, `stop`, `pattern`, `fingerprint` and language analyzers.
+    pub fn stopwords<T>(mut self, stopwords: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.stopwords
+            .extend(stopwords.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// The path to a file containing stop words, relative to the Elasticsearch `config`
+    /// directory
+    pub fn stopwords_path<T>(mut self, stopwords_path: T) -> Self
+    where
+        T: ToString,
+    {
+        self.stopwords_path = Some(stopwords_path.to_string());
+        self
+    }
+
+    /// A Java regular expression used to split text into terms. Defaults to `\W+`. Applicable
+    /// to the `pattern` analyzer.
+    pub fn pattern<T>(mut self, pattern: T) -> Self
+    where
+        T: ToString,
+    {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Java regular expression flags, pipe-separated, e.g. `CASE_INSENSITIVE|COMMENTS`.
+    /// Applicable to the `pattern` analyzer.
+    pub fn flags<T>(mut self, flags: T) -> Self
+    where
+        T: ToString,
+    {
+        self.flags = Some(flags.to_string());
+        self
+    }
+
+    /// Should terms be lowercased or not. Defaults to `true`. Applicable to the `pattern`
+    /// analyzer.
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = Some(lowercase);
+        self
+    }
+
+    /// The character to use to concatenate the terms. Defaults to a space. Applicable to the
+    /// `fingerprint` analyzer.
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: ToString,
+    {
+        self.separator = Some(separator.to_string());
+        self
+    }
+
+    /// The maximum token size to emit, larger tokens will be discarded. Defaults to `255`.
+    /// Applicable to the `fingerprint` analyzer.
+    pub fn max_output_size(mut self, max_output_size: u32) -> Self {
+        self.max_output_size = Some(max_output_size);
+        self
+    }
+
+    /// A list of words which should not be stemmed. Applicable to the language analyzers.
+    pub fn stem_exclusion<T>(mut self, stem_exclusion: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.stem_exclusion
+            .extend(stem_exclusion.into_iter().map(|x| x.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                BuiltinAnalyzer::Standard,
+                BuiltinAnalyzer::Simple,
+                BuiltinAnalyzer::Whitespace,
+                BuiltinAnalyzer::Stop,
+                BuiltinAnalyzer::Keyword,
+                BuiltinAnalyzer::Pattern,
+                BuiltinAnalyzer::Fingerprint,
+                BuiltinAnalyzer::Language(AnalyzerLanguage::English),
+            ],
+            json!([
+                "standard",
+                "simple",
+                "whitespace",
+                "stop",
+                "keyword",
+                "pattern",
+                "fingerprint",
+                "english"
+            ]),
+        );
+
+        assert_serialize(
+            ConfiguredAnalyzer::new(BuiltinAnalyzer::Standard),
+            json!({ "type": "standard" }),
+        );
+
+        assert_serialize(
+            ConfiguredAnalyzer::new(BuiltinAnalyzer::Pattern)
+                .pattern("\\W|_")
+                .flags("CASE_INSENSITIVE")
+                .lowercase(true)
+                .stopwords(["_english_"]),
+            json!({
+                "type": "pattern",
+                "pattern": "\\W|_",
+                "flags": "CASE_INSENSITIVE",
+                "lowercase": true,
+                "stopwords": ["_english_"]
+            }),
+        );
+
+        assert_serialize(
+            ConfiguredAnalyzer::new(AnalyzerLanguage::English)
+                .stopwords_path("analysis/english.txt")
+                .stem_exclusion(["organization"]),
+            json!({
+                "type": "english",
+                "stopwords_path": "analysis/english.txt",
+                "stem_exclusion": ["organization"]
+            }),
+        );
+
+        assert_serialize(
+            ConfiguredAnalyzer::new(BuiltinAnalyzer::Fingerprint)
+                .separator("+")
+                .max_output_size(100)
+                .max_token_length(10),
+            json!({
+                "type": "fingerprint",
+                "separator": "+",
+                "max_output_size": 100,
+                "max_token_length": 10
+            }),
+        );
+    }
+}
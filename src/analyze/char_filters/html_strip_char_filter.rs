@@ -0,0 +1,57 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Strips HTML elements from a text and replaces HTML entities with their decoded value (e.g.
+/// replaces `&amp;` with `&`).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-htmlstrip-charfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct HtmlStripCharFilter {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    escaped_tags: Vec<String>,
+}
+
+impl CharacterFilter {
+    /// Creates an instance of [`HtmlStripCharFilter`]
+    pub fn html_strip() -> HtmlStripCharFilter {
+        HtmlStripCharFilter {
+            escaped_tags: Vec::new(),
+        }
+    }
+}
+
+impl HtmlStripCharFilter {
+    /// HTML elements without enclosing angle brackets (`< >`). The filter skips these HTML
+    /// elements when stripping HTML from the text.
+    pub fn escaped_tags<T>(mut self, escaped_tags: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.escaped_tags
+            .extend(escaped_tags.into_iter().map(|x| x.to_string()));
+        self
+    }
+}
+
+serialize_with_type!("html_strip": HtmlStripCharFilter);
+deserialize_with_type!("html_strip": HtmlStripCharFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            CharacterFilter::html_strip(),
+            json!({ "type": "html_strip" }),
+        );
+
+        assert_serialize(
+            CharacterFilter::html_strip().escaped_tags(["b"]),
+            json!({ "type": "html_strip", "escaped_tags": ["b"] }),
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Accepts a map of keys and values. Whenever it encounters a string of characters that is the same
+/// as a key, it replaces them with the value associated with that key.
+///
+/// To create a mapping char filter:
+/// ```
+/// # use elasticsearch_dsl::analyze::*;
+/// # let char_filter =
+/// CharacterFilter::mapping().mappings(["٠ => 0", "١ => 1", "٢ => 2"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-mapping-charfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct MappingCharFilter {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings_path: Option<String>,
+}
+
+impl CharacterFilter {
+    /// Creates an instance of [`MappingCharFilter`]
+    pub fn mapping() -> MappingCharFilter {
+        MappingCharFilter {
+            mappings: Vec::new(),
+            mappings_path: None,
+        }
+    }
+}
+
+impl MappingCharFilter {
+    /// Array of mappings, with each element having the form `key => value`.
+    pub fn mappings<T>(mut self, mappings: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.mappings
+            .extend(mappings.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Path to a file containing `key => value` mappings, relative to the `config` directory.
+    pub fn mappings_path<T>(mut self, mappings_path: T) -> Self
+    where
+        T: ToString,
+    {
+        self.mappings_path = Some(mappings_path.to_string());
+        self
+    }
+}
+
+serialize_with_type!("mapping": MappingCharFilter);
+deserialize_with_type!("mapping": MappingCharFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(CharacterFilter::mapping(), json!({ "type": "mapping" }));
+
+        assert_serialize(
+            CharacterFilter::mapping()
+                .mappings([":) => _happy_", ":( => _sad_"])
+                .mappings_path("analysis/mappings.txt"),
+            json!({
+                "type": "mapping",
+                "mappings": [":) => _happy_", ":( => _sad_"],
+                "mappings_path": "analysis/mappings.txt"
+            }),
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Character filters are used to preprocess the stream of characters before it is passed to the
+//! tokenizer.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-charfilters.html>
+
+mod html_strip_char_filter;
+mod mapping_char_filter;
+mod pattern_replace_char_filter;
+
+pub use self::html_strip_char_filter::*;
+pub use self::mapping_char_filter::*;
+pub use self::pattern_replace_char_filter::*;
+
+use crate::analyze::*;
+
+macro_rules! character_filter {
+    ($($variant:ident($filter:ty)),+ $(,)?) => {
+        /// A container enum for supported Elasticsearch character filter definitions
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum CharacterFilter {
+            $(
+                $variant($filter),
+            )*
+
+            /// Any other character filter definition, e.g. one provided by a plugin
+            Custom(serde_json::Value),
+        }
+
+        $(
+            impl From<$filter> for CharacterFilter {
+                fn from(filter: $filter) -> Self {
+                    CharacterFilter::$variant(filter)
+                }
+            }
+
+            impl From<$filter> for StringOrObject {
+                fn from(filter: $filter) -> Self {
+                    CharacterFilter::$variant(filter).into()
+                }
+            }
+        )+
+    };
+}
+
+character_filter!(
+    HtmlStrip(HtmlStripCharFilter),
+    Mapping(MappingCharFilter),
+    PatternReplace(PatternReplaceCharFilter),
+);
+
+impl From<serde_json::Value> for CharacterFilter {
+    fn from(filter: serde_json::Value) -> Self {
+        CharacterFilter::Custom(filter)
+    }
+}
+
+impl From<CharacterFilter> for StringOrObject {
+    fn from(filter: CharacterFilter) -> Self {
+        match filter {
+            CharacterFilter::Custom(value) => Self::Object(value),
+            filter => Self::Object(serde_json::to_value(filter).unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                CharacterFilter::from(CharacterFilter::html_strip()),
+                CharacterFilter::from(json!({ "type": "icu_normalizer" })),
+            ],
+            json!([
+                { "type": "html_strip" },
+                { "type": "icu_normalizer" }
+            ]),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Uses a regular expression to match characters which should be replaced with the specified
+/// replacement string. The replacement string can refer to capture groups in the regular
+/// expression.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-pattern-replace-charfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct PatternReplaceCharFilter {
+    pattern: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: Option<String>,
+}
+
+impl CharacterFilter {
+    /// Creates an instance of [`PatternReplaceCharFilter`]
+    ///
+    /// - `pattern` - A Java regular expression
+    pub fn pattern_replace<T>(pattern: T) -> PatternReplaceCharFilter
+    where
+        T: ToString,
+    {
+        PatternReplaceCharFilter {
+            pattern: pattern.to_string(),
+            replacement: None,
+            flags: None,
+        }
+    }
+}
+
+impl PatternReplaceCharFilter {
+    /// The replacement string, which can reference capture groups using the `$1`..`$9` syntax.
+    /// Defaults to an empty string.
+    pub fn replacement<T>(mut self, replacement: T) -> Self
+    where
+        T: ToString,
+    {
+        self.replacement = Some(replacement.to_string());
+        self
+    }
+
+    /// Java regular expression flags, pipe-separated, e.g. `CASE_INSENSITIVE|COMMENTS`.
+    pub fn flags<T>(mut self, flags: T) -> Self
+    where
+        T: ToString,
+    {
+        self.flags = Some(flags.to_string());
+        self
+    }
+}
+
+serialize_with_type!("pattern_replace": PatternReplaceCharFilter);
+deserialize_with_type!("pattern_replace": PatternReplaceCharFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            CharacterFilter::pattern_replace("(\\d+)-(?=\\d)"),
+            json!({ "type": "pattern_replace", "pattern": "(\\d+)-(?=\\d)" }),
+        );
+
+        assert_serialize(
+            CharacterFilter::pattern_replace("(\\d+)-(?=\\d)")
+                .replacement("$1_")
+                .flags("CASE_INSENSITIVE"),
+            json!({
+                "type": "pattern_replace",
+                "pattern": "(\\d+)-(?=\\d)",
+                "replacement": "$1_",
+                "flags": "CASE_INSENSITIVE"
+            }),
+        );
+    }
+}
@@ -4,8 +4,19 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-analyze.html#analyze-api-query-params>
 
+mod analyzer_definition;
+mod builtin_analyzer;
 mod request;
 mod response;
 
+pub mod char_filters;
+pub mod token_filters;
+pub mod tokenizers;
+
+pub use self::analyzer_definition::*;
+pub use self::builtin_analyzer::*;
+pub use self::char_filters::*;
 pub use self::request::*;
 pub use self::response::*;
+pub use self::token_filters::*;
+pub use self::tokenizers::*;
@@ -1,4 +1,6 @@
+use crate::analyze::*;
 use crate::util::*;
+use crate::Map;
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// Performs analysis on a text string and returns the resulting tokens.
@@ -10,12 +12,9 @@ use serde::ser::{Serialize, SerializeStruct, Serializer};
 /// To `analyze` with custom analyzer:
 /// ```
 /// # use elasticsearch_dsl::analyze::*;
-/// # use serde_json::json;
 /// let custom_analyzer = CustomAnalyzer::new("whitespace")
-///    .filter([
-///        StringOrObject::String("lowercase".to_string()),
-///        StringOrObject::Object(json!({"type": "stop", "stopwords": ["a", "is", "this"]})),
-///    ]);
+///    .filter(["lowercase"])
+///    .filter([TokenFilter::stop().stopwords(["a", "is", "this"])]);
 /// let test = Analyze::new(["test this text", "and this one please"])
 ///    .analyzer(custom_analyzer)
 ///    .explain(true)
@@ -24,14 +23,11 @@ use serde::ser::{Serialize, SerializeStruct, Serializer};
 /// To `analyze` custom normalizer:
 /// ```
 /// # use elasticsearch_dsl::analyze::*;
-/// # use serde_json::json;
 /// let custom_normalizer = CustomNormalizer::new()
-///    .char_filter([
-///        json!({ "type": "mapping", "mappings": ["٠ => 0", "١ => 1", "٢ => 2"] }),
-///    ])
+///    .char_filter([CharacterFilter::mapping().mappings(["٠ => 0", "١ => 1", "٢ => 2"])])
 ///    .filter(["snowball"]);
 /// let test = Analyze::new(["test this text", "and this one please"])
-///    .analyzer(custom_normalizer)
+///    .normalizer(custom_normalizer)
 ///    .explain(true)
 ///    .attributes(["attributes"]);
 /// ```
@@ -50,24 +46,24 @@ pub struct Analyze {
 }
 
 /// Structure of custom analyzer.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct CustomAnalyzer {
-    tokenizer: String,
+    tokenizer: StringOrObject,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     char_filter: Vec<StringOrObject>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     filter: Vec<StringOrObject>,
 }
 
 /// Structure of custom normalizer
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct CustomNormalizer {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     char_filter: Vec<StringOrObject>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     filter: Vec<StringOrObject>,
 }
 
@@ -80,6 +76,8 @@ pub enum Analysis {
     /// If no field is specified, the analyze API uses the default analyzer for the index.
     /// If no index is specified, or the index does not have a default analyzer, the analyze API uses the `standard analyzer`.
     ///
+    /// Use [`BuiltinAnalyzer`] for a typed reference to one of the built-in analyzers.
+    ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-analyzers.html>
     BuiltInAnalyzer(String),
 
@@ -102,7 +100,7 @@ pub enum Analysis {
 }
 
 /// Structure of filters
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum StringOrObject {
     /// Built-in filters
@@ -123,6 +121,19 @@ pub enum StringOrVecString {
     VecString(Vec<String>),
 }
 
+/// Normalizer used by the analyze API to convert text into a single token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Normalizer {
+    /// The name of a built-in normalizer, e.g. `lowercase`, or a normalizer configured in the
+    /// index
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-normalizers.html>
+    Named(String),
+
+    /// Custom normalizer built from character filters and token filters
+    Custom(CustomNormalizer),
+}
+
 impl Analyze {
     /// Creates an instance of [Analyze]
     ///
@@ -149,6 +160,21 @@ impl Analyze {
         self
     }
 
+    /// Specify a normalizer to convert text into a single token, either a built-in or index
+    /// normalizer by name, or a custom normalizer with token and character filters.
+    ///
+    /// Overrides any analyzer or field set previously.
+    pub fn normalizer<S>(mut self, normalizer: S) -> Self
+    where
+        S: Into<Normalizer>,
+    {
+        self.analysis = Some(match normalizer.into() {
+            Normalizer::Named(name) => Analysis::BuiltInNormalizer(name),
+            Normalizer::Custom(normalizer) => Analysis::CustomNormalizer(normalizer),
+        });
+        self
+    }
+
     /// Array of token attributes used to filter the output of the explain parameter.
     pub fn attributes<I>(mut self, attributes: I) -> Self
     where
@@ -199,19 +225,33 @@ impl CustomNormalizer {
         self.filter.extend(filter.into_iter().map(Into::into));
         self
     }
+
+    /// Replaces references to named components with their definitions
+    pub(crate) fn inline(
+        &self,
+        char_filters: &Map<String, CharacterFilter>,
+        filters: &Map<String, TokenFilter>,
+    ) -> Self {
+        Self {
+            char_filter: StringOrObject::inline_all(&self.char_filter, char_filters),
+            filter: StringOrObject::inline_all(&self.filter, filters),
+        }
+    }
 }
 
 impl CustomAnalyzer {
     /// Create instance of custom analyzer and sets tokenizer
     /// Tokenizer to use to convert text into tokens. See `Tokenizer reference` for a list of tokenizers.
+    /// Either the name of a tokenizer or a [`Tokenizer`] definition, the latter is only supported
+    /// by the analyze API.
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenizers.html>
     pub fn new<S>(tokenizer: S) -> Self
     where
-        S: ToString,
+        S: Into<StringOrObject>,
     {
         Self {
-            tokenizer: tokenizer.to_string(),
+            tokenizer: tokenizer.into(),
             char_filter: vec![],
             filter: vec![],
         }
@@ -243,6 +283,45 @@ impl CustomAnalyzer {
         self.filter.extend(filter.into_iter().map(Into::into));
         self
     }
+
+    /// Replaces references to named components with their definitions
+    pub(crate) fn inline(
+        &self,
+        tokenizers: &Map<String, Tokenizer>,
+        char_filters: &Map<String, CharacterFilter>,
+        filters: &Map<String, TokenFilter>,
+    ) -> Self {
+        Self {
+            tokenizer: self.tokenizer.inline(tokenizers),
+            char_filter: StringOrObject::inline_all(&self.char_filter, char_filters),
+            filter: StringOrObject::inline_all(&self.filter, filters),
+        }
+    }
+}
+
+impl StringOrObject {
+    /// Replaces a reference to a named component with its definition, references to built-in
+    /// components are left as is
+    fn inline<T>(&self, definitions: &Map<String, T>) -> Self
+    where
+        T: Clone + Into<Self>,
+    {
+        match self {
+            Self::String(name) => definitions
+                .get(name)
+                .cloned()
+                .map(Into::into)
+                .unwrap_or_else(|| self.clone()),
+            Self::Object(_) => self.clone(),
+        }
+    }
+
+    fn inline_all<T>(values: &[Self], definitions: &Map<String, T>) -> Vec<Self>
+    where
+        T: Clone + Into<Self>,
+    {
+        values.iter().map(|x| x.inline(definitions)).collect()
+    }
 }
 
 impl Analysis {
@@ -289,6 +368,36 @@ impl From<serde_json::Value> for StringOrObject {
     }
 }
 
+impl From<BuiltinAnalyzer> for Analysis {
+    fn from(value: BuiltinAnalyzer) -> Self {
+        Self::BuiltInAnalyzer(value.to_string())
+    }
+}
+
+impl From<AnalyzerLanguage> for Analysis {
+    fn from(value: AnalyzerLanguage) -> Self {
+        BuiltinAnalyzer::from(value).into()
+    }
+}
+
+impl<'a> From<&'a str> for Normalizer {
+    fn from(value: &'a str) -> Self {
+        Self::Named(value.to_owned())
+    }
+}
+
+impl From<String> for Normalizer {
+    fn from(value: String) -> Self {
+        Self::Named(value)
+    }
+}
+
+impl From<CustomNormalizer> for Normalizer {
+    fn from(value: CustomNormalizer) -> Self {
+        Self::Custom(value)
+    }
+}
+
 impl From<CustomAnalyzer> for Analysis {
     fn from(value: CustomAnalyzer) -> Self {
         Self::CustomAnalyzer(value)
@@ -358,6 +467,12 @@ impl Serialize for Analysis {
     }
 }
 
+impl Default for StringOrObject {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
 impl Default for StringOrVecString {
     fn default() -> Self {
         Self::String(Default::default())
@@ -410,6 +525,22 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Analyze::new("analyze these pants").analyzer(BuiltinAnalyzer::Whitespace),
+            json!({
+                "text": "analyze these pants",
+                "analyzer": "whitespace"
+            }),
+        );
+
+        assert_serialize(
+            Analyze::new("analyze these pants").analyzer(AnalyzerLanguage::German),
+            json!({
+                "text": "analyze these pants",
+                "analyzer": "german"
+            }),
+        );
+
         assert_serialize(
             Analyze::new("analyze these pants").analyzer(Analysis::normalizer("asciifolding")),
             json!({
@@ -442,6 +573,42 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Analyze::new("analyze these pants").analyzer(
+                CustomAnalyzer::new(Tokenizer::edge_ngram().min_gram(2).max_gram(3))
+                    .char_filter([CharacterFilter::html_strip()])
+                    .filter([TokenFilter::shingle().max_shingle_size(3)])
+                    .filter(["lowercase"]),
+            ),
+            json!({
+                "text": "analyze these pants",
+                "tokenizer": { "type": "edge_ngram", "min_gram": 2, "max_gram": 3 },
+                "char_filter": [{ "type": "html_strip" }],
+                "filter": [{ "type": "shingle", "max_shingle_size": 3 }, "lowercase"]
+            }),
+        );
+
+        assert_serialize(
+            Analyze::new("BÀR").normalizer("lowercase"),
+            json!({
+                "text": "BÀR",
+                "normalizer": "lowercase"
+            }),
+        );
+
+        assert_serialize(
+            Analyze::new("BÀR").normalizer(
+                CustomNormalizer::new()
+                    .char_filter(["html_strip"])
+                    .filter(["lowercase", "asciifolding"]),
+            ),
+            json!({
+                "text": "BÀR",
+                "char_filter": ["html_strip"],
+                "filter": ["lowercase", "asciifolding"]
+            }),
+        );
+
         assert_serialize(
             Analyze::new("analyze these pants").analyzer(Analysis::field("title")),
             json!({
@@ -75,9 +75,10 @@ pub struct CharFilter {
 }
 
 /// Type of token
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum TokenType {
     /// Alphanumeric token
+    #[default]
     Alphanum,
 
     /// Synonym token
@@ -123,12 +124,6 @@ pub enum TokenType {
     Other(String),
 }
 
-impl Default for TokenType {
-    fn default() -> Self {
-        Self::Alphanum
-    }
-}
-
 impl Serialize for TokenType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,51 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Converts alphabetic, numeric, and symbolic characters that are not in the Basic Latin Unicode
+/// block (first 127 ASCII characters) to their ASCII equivalent, if one exists.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-asciifolding-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct AsciiFoldingTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_original: Option<bool>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`AsciiFoldingTokenFilter`]
+    pub fn ascii_folding() -> AsciiFoldingTokenFilter {
+        AsciiFoldingTokenFilter {
+            preserve_original: None,
+        }
+    }
+}
+
+impl AsciiFoldingTokenFilter {
+    /// If `true`, emit both original tokens and folded tokens. Defaults to `false`.
+    pub fn preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = Some(preserve_original);
+        self
+    }
+}
+
+serialize_with_type!("asciifolding": AsciiFoldingTokenFilter);
+deserialize_with_type!("asciifolding": AsciiFoldingTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilter::ascii_folding(),
+            json!({ "type": "asciifolding" }),
+        );
+
+        assert_serialize(
+            TokenFilter::ascii_folding().preserve_original(true),
+            json!({ "type": "asciifolding", "preserve_original": true }),
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::analyze::*;
+use crate::search::*;
+
+/// Applies a set of token filters to tokens that match conditions in a provided predicate
+/// script.
+///
+/// To create a condition token filter:
+/// ```
+/// # use elasticsearch_dsl::analyze::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let filter =
+/// TokenFilter::condition(["lowercase"], Script::source("token.getTerm().length() < 5"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-condition-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct ConditionTokenFilter {
+    filter: Vec<String>,
+
+    script: Script,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`ConditionTokenFilter`]
+    ///
+    /// - `filter` - Array of token filters. If a token matches the predicate script in the
+    ///   `script` parameter, these filters are applied to the token in the order provided.
+    /// - `script` - Predicate script used to apply token filters. If a token matches this
+    ///   script, the filters in the `filter` parameter are applied to the token.
+    pub fn condition<T>(filter: T, script: Script) -> ConditionTokenFilter
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        ConditionTokenFilter {
+            filter: filter.into_iter().map(|x| x.to_string()).collect(),
+            script,
+        }
+    }
+}
+
+serialize_with_type!("condition": ConditionTokenFilter);
+deserialize_with_type!("condition": ConditionTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilter::condition(
+                ["lowercase"],
+                Script::source("token.getTerm().length() < 5"),
+            ),
+            json!({
+                "type": "condition",
+                "filter": ["lowercase"],
+                "script": {
+                    "source": "token.getTerm().length() < 5"
+                }
+            }),
+        );
+    }
+}
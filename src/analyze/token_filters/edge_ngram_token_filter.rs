@@ -0,0 +1,75 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Forms an n-gram of a specified length from the beginning of a token.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-edgengram-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct EdgeNGramTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_gram: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_gram: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_original: Option<bool>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`EdgeNGramTokenFilter`]
+    pub fn edge_ngram() -> EdgeNGramTokenFilter {
+        EdgeNGramTokenFilter {
+            min_gram: None,
+            max_gram: None,
+            preserve_original: None,
+        }
+    }
+}
+
+impl EdgeNGramTokenFilter {
+    /// Minimum character length of a gram. Defaults to `1`.
+    pub fn min_gram(mut self, min_gram: u32) -> Self {
+        self.min_gram = Some(min_gram);
+        self
+    }
+
+    /// Maximum character length of a gram. Defaults to `2`.
+    pub fn max_gram(mut self, max_gram: u32) -> Self {
+        self.max_gram = Some(max_gram);
+        self
+    }
+
+    /// Emits original token when set to `true`. Defaults to `false`.
+    pub fn preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = Some(preserve_original);
+        self
+    }
+}
+
+serialize_with_type!("edge_ngram": EdgeNGramTokenFilter);
+deserialize_with_type!("edge_ngram": EdgeNGramTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::edge_ngram(), json!({ "type": "edge_ngram" }));
+
+        assert_serialize(
+            TokenFilter::edge_ngram()
+                .min_gram(1)
+                .max_gram(5)
+                .preserve_original(true),
+            json!({
+                "type": "edge_ngram",
+                "min_gram": 1,
+                "max_gram": 5,
+                "preserve_original": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Removes tokens shorter or longer than specified character lengths.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-length-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct LengthTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max: Option<u32>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`LengthTokenFilter`]
+    pub fn length() -> LengthTokenFilter {
+        LengthTokenFilter {
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl LengthTokenFilter {
+    /// Minimum character length of a token. Defaults to `0`.
+    pub fn min(mut self, min: u32) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Maximum character length of a token. Defaults to `Integer.MAX_VALUE`.
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+serialize_with_type!("length": LengthTokenFilter);
+deserialize_with_type!("length": LengthTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::length(), json!({ "type": "length" }));
+
+        assert_serialize(
+            TokenFilter::length().min(2).max(10),
+            json!({ "type": "length", "min": 2, "max": 10 }),
+        );
+    }
+}
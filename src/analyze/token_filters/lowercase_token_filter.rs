@@ -0,0 +1,49 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Changes token text to lowercase.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-lowercase-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct LowercaseTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    language: Option<String>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`LowercaseTokenFilter`]
+    pub fn lowercase() -> LowercaseTokenFilter {
+        LowercaseTokenFilter { language: None }
+    }
+}
+
+impl LowercaseTokenFilter {
+    /// Language-specific lowercase token filter to use, one of `greek`, `irish` or `turkish`.
+    /// Defaults to the `LowerCaseFilter`.
+    pub fn language<T>(mut self, language: T) -> Self
+    where
+        T: ToString,
+    {
+        self.language = Some(language.to_string());
+        self
+    }
+}
+
+serialize_with_type!("lowercase": LowercaseTokenFilter);
+deserialize_with_type!("lowercase": LowercaseTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::lowercase(), json!({ "type": "lowercase" }));
+
+        assert_serialize(
+            TokenFilter::lowercase().language("greek"),
+            json!({ "type": "lowercase", "language": "greek" }),
+        );
+    }
+}
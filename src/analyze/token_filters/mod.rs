@@ -0,0 +1,118 @@
+//! Token filters accept a stream of tokens from a tokenizer and can modify tokens (eg
+//! lowercasing), delete tokens (eg remove stopwords) or add tokens (eg synonyms).
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenfilters.html>
+
+mod ascii_folding_token_filter;
+mod condition_token_filter;
+mod edge_ngram_token_filter;
+mod length_token_filter;
+mod lowercase_token_filter;
+mod multiplexer_token_filter;
+mod shingle_token_filter;
+mod stemmer_token_filter;
+mod stop_token_filter;
+mod synonym_format;
+mod synonym_graph_token_filter;
+mod synonym_token_filter;
+mod unique_token_filter;
+mod word_delimiter_graph_token_filter;
+
+pub use self::ascii_folding_token_filter::*;
+pub use self::condition_token_filter::*;
+pub use self::edge_ngram_token_filter::*;
+pub use self::length_token_filter::*;
+pub use self::lowercase_token_filter::*;
+pub use self::multiplexer_token_filter::*;
+pub use self::shingle_token_filter::*;
+pub use self::stemmer_token_filter::*;
+pub use self::stop_token_filter::*;
+pub use self::synonym_format::*;
+pub use self::synonym_graph_token_filter::*;
+pub use self::synonym_token_filter::*;
+pub use self::unique_token_filter::*;
+pub use self::word_delimiter_graph_token_filter::*;
+
+use crate::analyze::*;
+
+macro_rules! token_filter {
+    ($($variant:ident($filter:ty)),+ $(,)?) => {
+        /// A container enum for supported Elasticsearch token filter definitions
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum TokenFilter {
+            $(
+                $variant($filter),
+            )*
+
+            /// Any other token filter definition, e.g. one provided by a plugin
+            Custom(serde_json::Value),
+        }
+
+        $(
+            impl From<$filter> for TokenFilter {
+                fn from(filter: $filter) -> Self {
+                    TokenFilter::$variant(filter)
+                }
+            }
+
+            impl From<$filter> for StringOrObject {
+                fn from(filter: $filter) -> Self {
+                    TokenFilter::$variant(filter).into()
+                }
+            }
+        )+
+    };
+}
+
+token_filter!(
+    Lowercase(LowercaseTokenFilter),
+    AsciiFolding(AsciiFoldingTokenFilter),
+    Stop(StopTokenFilter),
+    Synonym(SynonymTokenFilter),
+    SynonymGraph(SynonymGraphTokenFilter),
+    Shingle(ShingleTokenFilter),
+    Stemmer(StemmerTokenFilter),
+    EdgeNGram(EdgeNGramTokenFilter),
+    WordDelimiterGraph(WordDelimiterGraphTokenFilter),
+    Length(LengthTokenFilter),
+    Unique(UniqueTokenFilter),
+    Multiplexer(MultiplexerTokenFilter),
+    Condition(ConditionTokenFilter),
+);
+
+impl From<serde_json::Value> for TokenFilter {
+    fn from(filter: serde_json::Value) -> Self {
+        TokenFilter::Custom(filter)
+    }
+}
+
+impl From<TokenFilter> for StringOrObject {
+    fn from(filter: TokenFilter) -> Self {
+        match filter {
+            TokenFilter::Custom(value) => Self::Object(value),
+            filter => Self::Object(serde_json::to_value(filter).unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                TokenFilter::from(TokenFilter::stemmer("english")),
+                TokenFilter::from(json!({ "type": "icu_folding" })),
+            ],
+            json!([
+                { "type": "stemmer", "language": "english" },
+                { "type": "icu_folding" }
+            ]),
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// A filter of type `multiplexer` will emit multiple tokens at the same position, each version of
+/// the token having been run through a different filter.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-multiplexer-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct MultiplexerTokenFilter {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    filters: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_original: Option<bool>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`MultiplexerTokenFilter`]
+    pub fn multiplexer() -> MultiplexerTokenFilter {
+        MultiplexerTokenFilter {
+            filters: Vec::new(),
+            preserve_original: None,
+        }
+    }
+}
+
+impl MultiplexerTokenFilter {
+    /// A list of token filters to apply to incoming tokens. These can be any token filters
+    /// defined elsewhere in the index mappings. Filters can be chained using a comma-delimited
+    /// string.
+    pub fn filters<T>(mut self, filters: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.filters
+            .extend(filters.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Whether to emit the original token in addition to the filtered tokens. Defaults to
+    /// `true`.
+    pub fn preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = Some(preserve_original);
+        self
+    }
+}
+
+serialize_with_type!("multiplexer": MultiplexerTokenFilter);
+deserialize_with_type!("multiplexer": MultiplexerTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::multiplexer(), json!({ "type": "multiplexer" }));
+
+        assert_serialize(
+            TokenFilter::multiplexer()
+                .filters(["lowercase", "lowercase, porter_stem"])
+                .preserve_original(false),
+            json!({
+                "type": "multiplexer",
+                "filters": ["lowercase", "lowercase, porter_stem"],
+                "preserve_original": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Add shingles, or word n-grams, to a token stream by concatenating adjacent tokens.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-shingle-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct ShingleTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    output_unigrams: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    output_unigrams_if_no_shingles: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    token_separator: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filler_token: Option<String>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`ShingleTokenFilter`]
+    pub fn shingle() -> ShingleTokenFilter {
+        ShingleTokenFilter {
+            max_shingle_size: None,
+            min_shingle_size: None,
+            output_unigrams: None,
+            output_unigrams_if_no_shingles: None,
+            token_separator: None,
+            filler_token: None,
+        }
+    }
+}
+
+impl ShingleTokenFilter {
+    /// Maximum number of tokens to concatenate when creating shingles. Defaults to `2`.
+    pub fn max_shingle_size(mut self, max_shingle_size: u32) -> Self {
+        self.max_shingle_size = Some(max_shingle_size);
+        self
+    }
+
+    /// Minimum number of tokens to concatenate when creating shingles. Defaults to `2`.
+    pub fn min_shingle_size(mut self, min_shingle_size: u32) -> Self {
+        self.min_shingle_size = Some(min_shingle_size);
+        self
+    }
+
+    /// If `true`, the output includes the original input tokens. Defaults to `true`.
+    pub fn output_unigrams(mut self, output_unigrams: bool) -> Self {
+        self.output_unigrams = Some(output_unigrams);
+        self
+    }
+
+    /// If `true`, the output includes the original input tokens only if no shingles are
+    /// produced. Defaults to `false`.
+    pub fn output_unigrams_if_no_shingles(mut self, output_unigrams_if_no_shingles: bool) -> Self {
+        self.output_unigrams_if_no_shingles = Some(output_unigrams_if_no_shingles);
+        self
+    }
+
+    /// Separator used to concatenate adjacent tokens to form a shingle. Defaults to a space.
+    pub fn token_separator<T>(mut self, token_separator: T) -> Self
+    where
+        T: ToString,
+    {
+        self.token_separator = Some(token_separator.to_string());
+        self
+    }
+
+    /// String used in shingles as a replacement for empty positions that do not contain a
+    /// token. Defaults to an underscore (`_`).
+    pub fn filler_token<T>(mut self, filler_token: T) -> Self
+    where
+        T: ToString,
+    {
+        self.filler_token = Some(filler_token.to_string());
+        self
+    }
+}
+
+serialize_with_type!("shingle": ShingleTokenFilter);
+deserialize_with_type!("shingle": ShingleTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::shingle(), json!({ "type": "shingle" }));
+
+        assert_serialize(
+            TokenFilter::shingle()
+                .max_shingle_size(3)
+                .min_shingle_size(2)
+                .output_unigrams(false)
+                .output_unigrams_if_no_shingles(true)
+                .token_separator("+")
+                .filler_token("-"),
+            json!({
+                "type": "shingle",
+                "max_shingle_size": 3,
+                "min_shingle_size": 2,
+                "output_unigrams": false,
+                "output_unigrams_if_no_shingles": true,
+                "token_separator": "+",
+                "filler_token": "-"
+            }),
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::analyze::*;
+
+/// Provides algorithmic stemming for several languages, some with additional variants.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-stemmer-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct StemmerTokenFilter {
+    language: String,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`StemmerTokenFilter`]
+    ///
+    /// - `language` - Language-dependent stemming algorithm used to stem tokens, e.g.
+    ///   `english` or `light_german`
+    pub fn stemmer<T>(language: T) -> StemmerTokenFilter
+    where
+        T: ToString,
+    {
+        StemmerTokenFilter {
+            language: language.to_string(),
+        }
+    }
+}
+
+serialize_with_type!("stemmer": StemmerTokenFilter);
+deserialize_with_type!("stemmer": StemmerTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilter::stemmer("light_german"),
+            json!({ "type": "stemmer", "language": "light_german" }),
+        );
+    }
+}
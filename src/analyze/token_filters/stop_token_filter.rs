@@ -0,0 +1,97 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Removes stop words from a token stream.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-stop-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct StopTokenFilter {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stopwords_path: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_case: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    remove_trailing: Option<bool>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`StopTokenFilter`]
+    pub fn stop() -> StopTokenFilter {
+        StopTokenFilter {
+            stopwords: Vec::new(),
+            stopwords_path: None,
+            ignore_case: None,
+            remove_trailing: None,
+        }
+    }
+}
+
+impl StopTokenFilter {
+    /// Language value, such as `_arabic_` or `_thai_`, or a list of stop words. Defaults to
+    /// `_english_`.
+    pub fn stopwords<T>(mut self, stopwords: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.stopwords
+            .extend(stopwords.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Path to a file that contains a list of stop words to remove, relative to the `config`
+    /// directory.
+    pub fn stopwords_path<T>(mut self, stopwords_path: T) -> Self
+    where
+        T: ToString,
+    {
+        self.stopwords_path = Some(stopwords_path.to_string());
+        self
+    }
+
+    /// If `true`, stop word matching is case insensitive. Defaults to `false`.
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = Some(ignore_case);
+        self
+    }
+
+    /// If `true`, the last token of a stream is removed if it’s a stop word. Defaults to `true`.
+    pub fn remove_trailing(mut self, remove_trailing: bool) -> Self {
+        self.remove_trailing = Some(remove_trailing);
+        self
+    }
+}
+
+serialize_with_type!("stop": StopTokenFilter);
+deserialize_with_type!("stop": StopTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::stop(), json!({ "type": "stop" }));
+
+        assert_serialize(
+            TokenFilter::stop()
+                .stopwords(["and", "is", "the"])
+                .stopwords_path("stopwords.txt")
+                .ignore_case(true)
+                .remove_trailing(false),
+            json!({
+                "type": "stop",
+                "stopwords": ["and", "is", "the"],
+                "stopwords_path": "stopwords.txt",
+                "ignore_case": true,
+                "remove_trailing": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,12 @@
+/// The format used to define synonyms in the `synonym` and `synonym_graph` token filters
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-synonym-graph-tokenfilter.html#analysis-synonym-graph-define-synonyms>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SynonymFormat {
+    /// Solr synonyms format
+    Solr,
+
+    /// WordNet synonyms format
+    Wordnet,
+}
@@ -0,0 +1,140 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Allows to easily handle synonyms, including multi-word synonyms correctly during the analysis
+/// process. Only to be used as part of a search analyzer.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-synonym-graph-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct SynonymGraphTokenFilter {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms_path: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    synonyms_set: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    expand: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lenient: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    updateable: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<SynonymFormat>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`SynonymGraphTokenFilter`]
+    pub fn synonym_graph() -> SynonymGraphTokenFilter {
+        SynonymGraphTokenFilter {
+            synonyms: Vec::new(),
+            synonyms_path: None,
+            synonyms_set: None,
+            expand: None,
+            lenient: None,
+            updateable: None,
+            format: None,
+        }
+    }
+}
+
+impl SynonymGraphTokenFilter {
+    /// Inline list of synonym rules.
+    pub fn synonyms<T>(mut self, synonyms: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.synonyms
+            .extend(synonyms.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Path to a synonym file, relative to the `config` directory.
+    pub fn synonyms_path<T>(mut self, synonyms_path: T) -> Self
+    where
+        T: ToString,
+    {
+        self.synonyms_path = Some(synonyms_path.to_string());
+        self
+    }
+
+    /// Name of a synonyms set managed through the synonyms API.
+    pub fn synonyms_set<T>(mut self, synonyms_set: T) -> Self
+    where
+        T: ToString,
+    {
+        self.synonyms_set = Some(synonyms_set.to_string());
+        self
+    }
+
+    /// If `true`, all terms of an equivalence rule are mapped to each other. Defaults to `true`.
+    pub fn expand(mut self, expand: bool) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// If `true`, exceptions are ignored while parsing the synonym configuration. Defaults to
+    /// `false`.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = Some(lenient);
+        self
+    }
+
+    /// If `true`, allows reloading search analyzers to pick up changes to synonym files. Only
+    /// to be used for search analyzers. Defaults to `false`.
+    pub fn updateable(mut self, updateable: bool) -> Self {
+        self.updateable = Some(updateable);
+        self
+    }
+
+    /// The format used to define the synonyms. Defaults to `solr`.
+    pub fn format(mut self, format: SynonymFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+serialize_with_type!("synonym_graph": SynonymGraphTokenFilter);
+deserialize_with_type!("synonym_graph": SynonymGraphTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilter::synonym_graph(),
+            json!({ "type": "synonym_graph" }),
+        );
+
+        assert_serialize(
+            TokenFilter::synonym_graph()
+                .synonyms(["i-pod, i pod => ipod", "universe, cosmos"])
+                .synonyms_path("analysis/synonym.txt")
+                .synonyms_set("my-synonym-set")
+                .expand(false)
+                .lenient(true)
+                .updateable(true)
+                .format(SynonymFormat::Wordnet),
+            json!({
+                "type": "synonym_graph",
+                "synonyms": ["i-pod, i pod => ipod", "universe, cosmos"],
+                "synonyms_path": "analysis/synonym.txt",
+                "synonyms_set": "my-synonym-set",
+                "expand": false,
+                "lenient": true,
+                "updateable": true,
+                "format": "wordnet"
+            }),
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Removes duplicate tokens from a stream.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-unique-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct UniqueTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    only_on_same_position: Option<bool>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`UniqueTokenFilter`]
+    pub fn unique() -> UniqueTokenFilter {
+        UniqueTokenFilter {
+            only_on_same_position: None,
+        }
+    }
+}
+
+impl UniqueTokenFilter {
+    /// If `true`, only remove duplicate tokens in the same position. Defaults to `false`.
+    pub fn only_on_same_position(mut self, only_on_same_position: bool) -> Self {
+        self.only_on_same_position = Some(only_on_same_position);
+        self
+    }
+}
+
+serialize_with_type!("unique": UniqueTokenFilter);
+deserialize_with_type!("unique": UniqueTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenFilter::unique(), json!({ "type": "unique" }));
+
+        assert_serialize(
+            TokenFilter::unique().only_on_same_position(true),
+            json!({ "type": "unique", "only_on_same_position": true }),
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// Splits tokens at non-alphanumeric characters. The filter also performs optional token
+/// normalization based on a set of rules.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-word-delimiter-graph-tokenfilter.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct WordDelimiterGraphTokenFilter {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    adjust_offsets: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    catenate_all: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    catenate_numbers: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    catenate_words: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    generate_number_parts: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    generate_word_parts: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_keywords: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_original: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    protected_words: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    split_on_case_change: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    split_on_numerics: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stem_english_possessive: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    type_table: Vec<String>,
+}
+
+impl TokenFilter {
+    /// Creates an instance of [`WordDelimiterGraphTokenFilter`]
+    pub fn word_delimiter_graph() -> WordDelimiterGraphTokenFilter {
+        WordDelimiterGraphTokenFilter {
+            adjust_offsets: None,
+            catenate_all: None,
+            catenate_numbers: None,
+            catenate_words: None,
+            generate_number_parts: None,
+            generate_word_parts: None,
+            ignore_keywords: None,
+            preserve_original: None,
+            protected_words: Vec::new(),
+            split_on_case_change: None,
+            split_on_numerics: None,
+            stem_english_possessive: None,
+            type_table: Vec::new(),
+        }
+    }
+}
+
+impl WordDelimiterGraphTokenFilter {
+    /// If `true`, the filter adjusts the offsets of split or catenated tokens to better reflect
+    /// their actual position in the token stream. Defaults to `true`.
+    pub fn adjust_offsets(mut self, adjust_offsets: bool) -> Self {
+        self.adjust_offsets = Some(adjust_offsets);
+        self
+    }
+
+    /// If `true`, the filter produces catenated tokens for chains of alphanumeric characters
+    /// separated by non-alphabetic delimiters. Defaults to `false`.
+    pub fn catenate_all(mut self, catenate_all: bool) -> Self {
+        self.catenate_all = Some(catenate_all);
+        self
+    }
+
+    /// If `true`, the filter produces catenated tokens for chains of numeric characters
+    /// separated by non-alphabetic delimiters. Defaults to `false`.
+    pub fn catenate_numbers(mut self, catenate_numbers: bool) -> Self {
+        self.catenate_numbers = Some(catenate_numbers);
+        self
+    }
+
+    /// If `true`, the filter produces catenated tokens for chains of alphabetical characters
+    /// separated by non-alphabetic delimiters. Defaults to `false`.
+    pub fn catenate_words(mut self, catenate_words: bool) -> Self {
+        self.catenate_words = Some(catenate_words);
+        self
+    }
+
+    /// If `true`, the filter includes tokens consisting of only numeric characters in the
+    /// output. Defaults to `true`.
+    pub fn generate_number_parts(mut self, generate_number_parts: bool) -> Self {
+        self.generate_number_parts = Some(generate_number_parts);
+        self
+    }
+
+    /// If `true`, the filter includes tokens consisting of only alphabetical characters in the
+    /// output. Defaults to `true`.
+    pub fn generate_word_parts(mut self, generate_word_parts: bool) -> Self {
+        self.generate_word_parts = Some(generate_word_parts);
+        self
+    }
+
+    /// If `true`, the filter skips tokens with a keyword attribute of `true`. Defaults to
+    /// `false`.
+    pub fn ignore_keywords(mut self, ignore_keywords: bool) -> Self {
+        self.ignore_keywords = Some(ignore_keywords);
+        self
+    }
+
+    /// If `true`, the filter includes the original version of any split tokens in the output.
+    /// Defaults to `false`.
+    pub fn preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = Some(preserve_original);
+        self
+    }
+
+    /// Array of tokens the filter won’t split.
+    pub fn protected_words<T>(mut self, protected_words: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.protected_words
+            .extend(protected_words.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// If `true`, the filter splits tokens at letter case transitions. Defaults to `true`.
+    pub fn split_on_case_change(mut self, split_on_case_change: bool) -> Self {
+        self.split_on_case_change = Some(split_on_case_change);
+        self
+    }
+
+    /// If `true`, the filter splits tokens at letter-number transitions. Defaults to `true`.
+    pub fn split_on_numerics(mut self, split_on_numerics: bool) -> Self {
+        self.split_on_numerics = Some(split_on_numerics);
+        self
+    }
+
+    /// If `true`, the filter removes the English possessive (`'s`) from the end of each token.
+    /// Defaults to `true`.
+    pub fn stem_english_possessive(mut self, stem_english_possessive: bool) -> Self {
+        self.stem_english_possessive = Some(stem_english_possessive);
+        self
+    }
+
+    /// Array of custom type mappings for characters, e.g. `"+ => ALPHA"`.
+    pub fn type_table<T>(mut self, type_table: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.type_table
+            .extend(type_table.into_iter().map(|x| x.to_string()));
+        self
+    }
+}
+
+serialize_with_type!("word_delimiter_graph": WordDelimiterGraphTokenFilter);
+deserialize_with_type!("word_delimiter_graph": WordDelimiterGraphTokenFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            TokenFilter::word_delimiter_graph(),
+            json!({ "type": "word_delimiter_graph" }),
+        );
+
+        assert_serialize(
+            TokenFilter::word_delimiter_graph()
+                .adjust_offsets(false)
+                .catenate_all(true)
+                .catenate_numbers(true)
+                .catenate_words(true)
+                .generate_number_parts(false)
+                .generate_word_parts(false)
+                .ignore_keywords(true)
+                .preserve_original(true)
+                .protected_words(["wi-fi"])
+                .split_on_case_change(false)
+                .split_on_numerics(false)
+                .stem_english_possessive(false)
+                .type_table(["- => ALPHANUM"]),
+            json!({
+                "type": "word_delimiter_graph",
+                "adjust_offsets": false,
+                "catenate_all": true,
+                "catenate_numbers": true,
+                "catenate_words": true,
+                "generate_number_parts": false,
+                "generate_word_parts": false,
+                "ignore_keywords": true,
+                "preserve_original": true,
+                "protected_words": ["wi-fi"],
+                "split_on_case_change": false,
+                "split_on_numerics": false,
+                "stem_english_possessive": false,
+                "type_table": ["- => ALPHANUM"]
+            }),
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `char_group` tokenizer breaks text into terms whenever it encounters a character which is
+/// in a defined set. It is mostly useful for cases where a simple custom tokenization is desired,
+/// and the overhead of use of the `pattern` tokenizer is not acceptable.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-chargroup-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct CharGroupTokenizer {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    tokenize_on_chars: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_token_length: Option<u32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`CharGroupTokenizer`]
+    pub fn char_group() -> CharGroupTokenizer {
+        CharGroupTokenizer {
+            tokenize_on_chars: Vec::new(),
+            max_token_length: None,
+        }
+    }
+}
+
+impl CharGroupTokenizer {
+    /// A list containing a list of characters to tokenize the string on. Whenever a character
+    /// from this list is encountered, a new token is started. This accepts either single
+    /// characters like e.g. `-`, or character groups: `whitespace`, `letter`, `digit`,
+    /// `punctuation`, `symbol`.
+    pub fn tokenize_on_chars<T>(mut self, tokenize_on_chars: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.tokenize_on_chars
+            .extend(tokenize_on_chars.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// The maximum token length. If a token is seen that exceeds this length then it is split at
+    /// `max_token_length` intervals. Defaults to `255`.
+    pub fn max_token_length(mut self, max_token_length: u32) -> Self {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+}
+
+serialize_with_type!("char_group": CharGroupTokenizer);
+deserialize_with_type!("char_group": CharGroupTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Tokenizer::char_group(), json!({ "type": "char_group" }));
+
+        assert_serialize(
+            Tokenizer::char_group()
+                .tokenize_on_chars(["whitespace", "-", "\n"])
+                .max_token_length(10),
+            json!({
+                "type": "char_group",
+                "tokenize_on_chars": ["whitespace", "-", "\n"],
+                "max_token_length": 10
+            }),
+        );
+    }
+}
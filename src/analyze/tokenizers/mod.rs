@@ -0,0 +1,103 @@
+//! A tokenizer receives a stream of characters, breaks it up into individual tokens (usually
+//! individual words), and outputs a stream of tokens.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenizers.html>
+
+mod char_group_tokenizer;
+mod edge_ngram_tokenizer;
+mod ngram_tokenizer;
+mod path_hierarchy_tokenizer;
+mod pattern_tokenizer;
+mod standard_tokenizer;
+mod token_char;
+mod uax_url_email_tokenizer;
+mod whitespace_tokenizer;
+
+pub use self::char_group_tokenizer::*;
+pub use self::edge_ngram_tokenizer::*;
+pub use self::ngram_tokenizer::*;
+pub use self::path_hierarchy_tokenizer::*;
+pub use self::pattern_tokenizer::*;
+pub use self::standard_tokenizer::*;
+pub use self::token_char::*;
+pub use self::uax_url_email_tokenizer::*;
+pub use self::whitespace_tokenizer::*;
+
+use crate::analyze::*;
+
+macro_rules! tokenizer {
+    ($($variant:ident($tokenizer:ty)),+ $(,)?) => {
+        /// A container enum for supported Elasticsearch tokenizer definitions
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum Tokenizer {
+            $(
+                $variant($tokenizer),
+            )*
+
+            /// Any other tokenizer definition, e.g. one provided by a plugin
+            Custom(serde_json::Value),
+        }
+
+        $(
+            impl From<$tokenizer> for Tokenizer {
+                fn from(tokenizer: $tokenizer) -> Self {
+                    Tokenizer::$variant(tokenizer)
+                }
+            }
+
+            impl From<$tokenizer> for StringOrObject {
+                fn from(tokenizer: $tokenizer) -> Self {
+                    Tokenizer::$variant(tokenizer).into()
+                }
+            }
+        )+
+    };
+}
+
+tokenizer!(
+    Standard(StandardTokenizer),
+    Whitespace(WhitespaceTokenizer),
+    UaxUrlEmail(UaxUrlEmailTokenizer),
+    NGram(NGramTokenizer),
+    EdgeNGram(EdgeNGramTokenizer),
+    Pattern(PatternTokenizer),
+    CharGroup(CharGroupTokenizer),
+    PathHierarchy(PathHierarchyTokenizer),
+);
+
+impl From<serde_json::Value> for Tokenizer {
+    fn from(tokenizer: serde_json::Value) -> Self {
+        Tokenizer::Custom(tokenizer)
+    }
+}
+
+impl From<Tokenizer> for StringOrObject {
+    fn from(tokenizer: Tokenizer) -> Self {
+        match tokenizer {
+            Tokenizer::Custom(value) => Self::Object(value),
+            tokenizer => Self::Object(serde_json::to_value(tokenizer).unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Tokenizer::from(Tokenizer::ngram().min_gram(2)),
+                Tokenizer::from(json!({ "type": "icu_tokenizer" })),
+            ],
+            json!([
+                { "type": "ngram", "min_gram": 2 },
+                { "type": "icu_tokenizer" }
+            ]),
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `ngram` tokenizer first breaks text down into words whenever it encounters one of a list
+/// of specified characters, then it emits N-grams of each word of the specified length.
+///
+/// To create an N-gram tokenizer:
+/// ```
+/// # use elasticsearch_dsl::analyze::*;
+/// # let tokenizer =
+/// Tokenizer::ngram()
+///     .min_gram(3)
+///     .max_gram(3)
+///     .token_chars([TokenChar::Letter, TokenChar::Digit]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-ngram-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct NGramTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_gram: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_gram: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    token_chars: Vec<TokenChar>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    custom_token_chars: Option<String>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`NGramTokenizer`]
+    pub fn ngram() -> NGramTokenizer {
+        NGramTokenizer {
+            min_gram: None,
+            max_gram: None,
+            token_chars: Vec::new(),
+            custom_token_chars: None,
+        }
+    }
+}
+
+impl NGramTokenizer {
+    /// Minimum length of characters in a gram. Defaults to `1`.
+    pub fn min_gram(mut self, min_gram: u32) -> Self {
+        self.min_gram = Some(min_gram);
+        self
+    }
+
+    /// Maximum length of characters in a gram. Defaults to `2`.
+    pub fn max_gram(mut self, max_gram: u32) -> Self {
+        self.max_gram = Some(max_gram);
+        self
+    }
+
+    /// Character classes that should be included in a token. Elasticsearch will split on
+    /// characters that don’t belong to the classes specified. Defaults to `[]` (keep all
+    /// characters).
+    pub fn token_chars<T>(mut self, token_chars: T) -> Self
+    where
+        T: IntoIterator<Item = TokenChar>,
+    {
+        self.token_chars.extend(token_chars);
+        self
+    }
+
+    /// Custom characters that should be treated as part of a token. Requires
+    /// [`TokenChar::Custom`] in `token_chars`.
+    pub fn custom_token_chars<T>(mut self, custom_token_chars: T) -> Self
+    where
+        T: ToString,
+    {
+        self.custom_token_chars = Some(custom_token_chars.to_string());
+        self
+    }
+}
+
+serialize_with_type!("ngram": NGramTokenizer);
+deserialize_with_type!("ngram": NGramTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Tokenizer::ngram(), json!({ "type": "ngram" }));
+
+        assert_serialize(
+            Tokenizer::ngram()
+                .min_gram(3)
+                .max_gram(4)
+                .token_chars([TokenChar::Letter, TokenChar::Custom])
+                .custom_token_chars("+-_"),
+            json!({
+                "type": "ngram",
+                "min_gram": 3,
+                "max_gram": 4,
+                "token_chars": ["letter", "custom"],
+                "custom_token_chars": "+-_"
+            }),
+        );
+    }
+}
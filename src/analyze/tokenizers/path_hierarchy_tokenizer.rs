@@ -0,0 +1,111 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `path_hierarchy` tokenizer takes a hierarchical value like a filesystem path, splits on
+/// the path separator, and emits a term for each component in the tree.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-pathhierarchy-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct PathHierarchyTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delimiter: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    replacement: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    buffer_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    reverse: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    skip: Option<u32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`PathHierarchyTokenizer`]
+    pub fn path_hierarchy() -> PathHierarchyTokenizer {
+        PathHierarchyTokenizer {
+            delimiter: None,
+            replacement: None,
+            buffer_size: None,
+            reverse: None,
+            skip: None,
+        }
+    }
+}
+
+impl PathHierarchyTokenizer {
+    /// The character to use as the path separator. Defaults to `/`.
+    pub fn delimiter<T>(mut self, delimiter: T) -> Self
+    where
+        T: ToString,
+    {
+        self.delimiter = Some(delimiter.to_string());
+        self
+    }
+
+    /// An optional replacement character to use for the delimiter. Defaults to the
+    /// `delimiter`.
+    pub fn replacement<T>(mut self, replacement: T) -> Self
+    where
+        T: ToString,
+    {
+        self.replacement = Some(replacement.to_string());
+        self
+    }
+
+    /// The number of characters read into the term buffer in a single pass. Defaults to
+    /// `1024`.
+    pub fn buffer_size(mut self, buffer_size: u32) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// If set to `true`, emits the tokens in reverse order. Defaults to `false`.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = Some(reverse);
+        self
+    }
+
+    /// The number of initial tokens to skip. Defaults to `0`.
+    pub fn skip(mut self, skip: u32) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+}
+
+serialize_with_type!("path_hierarchy": PathHierarchyTokenizer);
+deserialize_with_type!("path_hierarchy": PathHierarchyTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Tokenizer::path_hierarchy(),
+            json!({ "type": "path_hierarchy" }),
+        );
+
+        assert_serialize(
+            Tokenizer::path_hierarchy()
+                .delimiter("-")
+                .replacement("/")
+                .buffer_size(2048)
+                .reverse(true)
+                .skip(2),
+            json!({
+                "type": "path_hierarchy",
+                "delimiter": "-",
+                "replacement": "/",
+                "buffer_size": 2048,
+                "reverse": true,
+                "skip": 2
+            }),
+        );
+    }
+}
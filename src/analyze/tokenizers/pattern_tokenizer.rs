@@ -0,0 +1,82 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `pattern` tokenizer uses a regular expression to either split text into terms whenever it
+/// matches a word separator, or to capture matching text as terms.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-pattern-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct PatternTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pattern: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    group: Option<i32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`PatternTokenizer`]
+    pub fn pattern() -> PatternTokenizer {
+        PatternTokenizer {
+            pattern: None,
+            flags: None,
+            group: None,
+        }
+    }
+}
+
+impl PatternTokenizer {
+    /// A Java regular expression. Defaults to `\\W+`.
+    pub fn pattern<T>(mut self, pattern: T) -> Self
+    where
+        T: ToString,
+    {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Java regular expression flags, pipe-separated, e.g. `CASE_INSENSITIVE|COMMENTS`.
+    pub fn flags<T>(mut self, flags: T) -> Self
+    where
+        T: ToString,
+    {
+        self.flags = Some(flags.to_string());
+        self
+    }
+
+    /// Which capture group to extract as tokens. Defaults to `-1` (split).
+    pub fn group(mut self, group: i32) -> Self {
+        self.group = Some(group);
+        self
+    }
+}
+
+serialize_with_type!("pattern": PatternTokenizer);
+deserialize_with_type!("pattern": PatternTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Tokenizer::pattern(), json!({ "type": "pattern" }));
+
+        assert_serialize(
+            Tokenizer::pattern()
+                .pattern("\"((?:\\\\\"|[^\"]|\\\\\")+)\"")
+                .flags("CASE_INSENSITIVE")
+                .group(1),
+            json!({
+                "type": "pattern",
+                "pattern": "\"((?:\\\\\"|[^\"]|\\\\\")+)\"",
+                "flags": "CASE_INSENSITIVE",
+                "group": 1
+            }),
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `standard` tokenizer provides grammar based tokenization (based on the Unicode Text
+/// Segmentation algorithm) and works well for most languages.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-standard-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct StandardTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_token_length: Option<u32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`StandardTokenizer`]
+    pub fn standard() -> StandardTokenizer {
+        StandardTokenizer {
+            max_token_length: None,
+        }
+    }
+}
+
+impl StandardTokenizer {
+    /// The maximum token length. If a token is seen that exceeds this length then it is split at
+    /// `max_token_length` intervals. Defaults to `255`.
+    pub fn max_token_length(mut self, max_token_length: u32) -> Self {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+}
+
+serialize_with_type!("standard": StandardTokenizer);
+deserialize_with_type!("standard": StandardTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Tokenizer::standard(), json!({ "type": "standard" }));
+
+        assert_serialize(
+            Tokenizer::standard().max_token_length(5),
+            json!({ "type": "standard", "max_token_length": 5 }),
+        );
+    }
+}
@@ -0,0 +1,53 @@
+/// Character classes that should be included in a token by the `ngram` and `edge_ngram`
+/// tokenizers
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-ngram-tokenizer.html#_configuration_16>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenChar {
+    /// For example `a`, `b`, `ï` or `京`
+    Letter,
+
+    /// For example `3` or `7`
+    Digit,
+
+    /// For example `" "` or `"\n"`
+    Whitespace,
+
+    /// For example `!` or `"`
+    Punctuation,
+
+    /// For example `$` or `√`
+    Symbol,
+
+    /// Custom characters which need to be set using the `custom_token_chars` setting
+    Custom,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                TokenChar::Letter,
+                TokenChar::Digit,
+                TokenChar::Whitespace,
+                TokenChar::Punctuation,
+                TokenChar::Symbol,
+                TokenChar::Custom,
+            ],
+            json!([
+                "letter",
+                "digit",
+                "whitespace",
+                "punctuation",
+                "symbol",
+                "custom"
+            ]),
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `uax_url_email` tokenizer is like the `standard` tokenizer except that it recognises
+/// URLs and email addresses as single tokens.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-uaxurlemail-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct UaxUrlEmailTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_token_length: Option<u32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`UaxUrlEmailTokenizer`]
+    pub fn uax_url_email() -> UaxUrlEmailTokenizer {
+        UaxUrlEmailTokenizer {
+            max_token_length: None,
+        }
+    }
+}
+
+impl UaxUrlEmailTokenizer {
+    /// The maximum token length. If a token is seen that exceeds this length then it is split at
+    /// `max_token_length` intervals. Defaults to `255`.
+    pub fn max_token_length(mut self, max_token_length: u32) -> Self {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+}
+
+serialize_with_type!("uax_url_email": UaxUrlEmailTokenizer);
+deserialize_with_type!("uax_url_email": UaxUrlEmailTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Tokenizer::uax_url_email(),
+            json!({ "type": "uax_url_email" }),
+        );
+
+        assert_serialize(
+            Tokenizer::uax_url_email().max_token_length(5),
+            json!({ "type": "uax_url_email", "max_token_length": 5 }),
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::analyze::*;
+use crate::util::*;
+
+/// The `whitespace` tokenizer breaks text into terms whenever it encounters a whitespace
+/// character.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-whitespace-tokenizer.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct WhitespaceTokenizer {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_token_length: Option<u32>,
+}
+
+impl Tokenizer {
+    /// Creates an instance of [`WhitespaceTokenizer`]
+    pub fn whitespace() -> WhitespaceTokenizer {
+        WhitespaceTokenizer {
+            max_token_length: None,
+        }
+    }
+}
+
+impl WhitespaceTokenizer {
+    /// The maximum token length. If a token is seen that exceeds this length then it is split at
+    /// `max_token_length` intervals. Defaults to `255`.
+    pub fn max_token_length(mut self, max_token_length: u32) -> Self {
+        self.max_token_length = Some(max_token_length);
+        self
+    }
+}
+
+serialize_with_type!("whitespace": WhitespaceTokenizer);
+deserialize_with_type!("whitespace": WhitespaceTokenizer);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Tokenizer::whitespace(), json!({ "type": "whitespace" }));
+
+        assert_serialize(
+            Tokenizer::whitespace().max_token_length(5),
+            json!({ "type": "whitespace", "max_token_length": 5 }),
+        );
+    }
+}
@@ -0,0 +1,530 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Body of a [bulk](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html)
+/// request, performing multiple index, create, update and delete operations in a single API call.
+///
+/// The body is newline delimited JSON and has to be sent with the `application/x-ndjson` content
+/// type, the response is parsed with [`BulkResponse`](super::BulkResponse).
+/// ```
+/// # use elasticsearch_dsl::document::*;
+/// # use serde_json::json;
+/// let bulk = Bulk::new()
+///     .operation(BulkOperation::index(json!({ "title": "Dune" })).id("1"))
+///     .operation(BulkOperation::delete("2").index_name("archive"));
+///
+/// assert_eq!(
+///     String::from_utf8(bulk.to_bytes().unwrap()).unwrap(),
+///     concat!(
+///         "{\"index\":{\"_id\":\"1\"}}\n",
+///         "{\"title\":\"Dune\"}\n",
+///         "{\"delete\":{\"_index\":\"archive\",\"_id\":\"2\"}}\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bulk {
+    operations: Vec<BulkOperation>,
+}
+
+/// A single operation of a [`Bulk`] request, made of the action with its metadata and the
+/// document or partial update, if any
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkOperation {
+    action: BulkAction,
+    body: Option<BulkOperationBody>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BulkAction {
+    Index(BulkMetadata),
+    Create(BulkMetadata),
+    Update(BulkMetadata),
+    Delete(BulkMetadata),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+struct BulkMetadata {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _index: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version_type: Option<VersionType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    if_seq_no: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    if_primary_term: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pipeline: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    require_alias: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    retry_on_conflict: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum BulkOperationBody {
+    Document(BulkDocument),
+    Update(BulkUpdate),
+}
+
+/// Document converted into JSON when the operation is built. A conversion error is kept and
+/// returned once the request is serialized, instead of sending a `null` document.
+#[derive(Debug, Clone, PartialEq)]
+struct BulkDocument(Result<Value, String>);
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+struct BulkUpdate {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc: Option<BulkDocument>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    upsert: Option<BulkDocument>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_as_upsert: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    scripted_upsert: Option<bool>,
+}
+
+/// Versioning used by [`BulkOperation::version`]
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-index_.html#index-version-types>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionType {
+    /// Only index the document if the version matches the version of the stored document
+    Internal,
+
+    /// Only index the document if the version is greater than the version of the stored
+    /// document, or if there is no existing document
+    External,
+
+    /// Only index the document if the version is equal or greater than the version of the
+    /// stored document, or if there is no existing document
+    ExternalGte,
+}
+
+impl Bulk {
+    /// Creates an empty bulk request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an operation to the request
+    pub fn operation(mut self, operation: BulkOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Adds multiple operations to the request
+    pub fn operations<T>(mut self, operations: T) -> Self
+    where
+        T: IntoIterator<Item = BulkOperation>,
+    {
+        self.operations.extend(operations);
+        self
+    }
+
+    /// Number of operations in the request, the response items come back in the same order
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Whether the request has no operations, which Elasticsearch rejects
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Serializes the request into a newline delimited JSON byte buffer, fails if any of the
+    /// documents couldn't be serialized
+    pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        let mut bytes = Vec::new();
+
+        self.write_to(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Serializes the request as newline delimited JSON straight into the `writer`, nothing is
+    /// written if any of the documents couldn't be serialized
+    pub fn write_to<W>(&self, mut writer: W) -> Result<(), serde_json::Error>
+    where
+        W: std::io::Write,
+    {
+        if let Some(error) = self.operations.iter().find_map(BulkOperation::error) {
+            return Err(serde::ser::Error::custom(error));
+        }
+
+        for operation in &self.operations {
+            serde_json::to_writer(&mut writer, &operation.action)?;
+            writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+
+            if let Some(body) = &operation.body {
+                serde_json::to_writer(&mut writer, body)?;
+                writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BulkOperation {
+    /// Indexes the document, replacing the existing document with the same id. The id is
+    /// generated by Elasticsearch unless set with [`id`](Self::id).
+    pub fn index<T>(document: T) -> Self
+    where
+        T: Serialize,
+    {
+        Self {
+            action: BulkAction::Index(Default::default()),
+            body: Some(BulkOperationBody::Document(BulkDocument::new(document))),
+        }
+    }
+
+    /// Indexes the document only if a document with the same id doesn't exist yet
+    pub fn create<T>(document: T) -> Self
+    where
+        T: Serialize,
+    {
+        Self {
+            action: BulkAction::Create(Default::default()),
+            body: Some(BulkOperationBody::Document(BulkDocument::new(document))),
+        }
+    }
+
+    /// Merges the partial document `doc` into the existing document with the given `id`
+    pub fn update<T, U>(id: T, doc: U) -> Self
+    where
+        T: ToString,
+        U: Serialize,
+    {
+        Self::update_with(
+            id,
+            BulkUpdate {
+                doc: Some(BulkDocument::new(doc)),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Updates the existing document with the given `id` by running the `script` on it
+    pub fn update_with_script<T, U>(id: T, script: U) -> Self
+    where
+        T: ToString,
+        U: Into<Script>,
+    {
+        Self::update_with(
+            id,
+            BulkUpdate {
+                script: Some(script.into()),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Deletes the document with the given `id`
+    pub fn delete<T>(id: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            action: BulkAction::Delete(BulkMetadata {
+                _id: Some(id.to_string()),
+                ..Default::default()
+            }),
+            body: None,
+        }
+    }
+
+    fn update_with<T>(id: T, update: BulkUpdate) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            action: BulkAction::Update(BulkMetadata {
+                _id: Some(id.to_string()),
+                ..Default::default()
+            }),
+            body: Some(BulkOperationBody::Update(update)),
+        }
+    }
+
+    fn metadata(&mut self) -> &mut BulkMetadata {
+        match &mut self.action {
+            BulkAction::Index(metadata)
+            | BulkAction::Create(metadata)
+            | BulkAction::Update(metadata)
+            | BulkAction::Delete(metadata) => metadata,
+        }
+    }
+
+    fn error(&self) -> Option<&str> {
+        let documents = match &self.body {
+            Some(BulkOperationBody::Document(document)) => [Some(document), None],
+            Some(BulkOperationBody::Update(update)) => {
+                [update.doc.as_ref(), update.upsert.as_ref()]
+            }
+            None => [None, None],
+        };
+
+        documents
+            .iter()
+            .flatten()
+            .find_map(|document| document.0.as_ref().err())
+            .map(String::as_str)
+    }
+
+    fn update_body(&mut self) -> Option<&mut BulkUpdate> {
+        match &mut self.body {
+            Some(BulkOperationBody::Update(update)) => Some(update),
+            _ => None,
+        }
+    }
+
+    /// The index to perform the operation on, defaults to the index of the request path
+    pub fn index_name<T>(mut self, index: T) -> Self
+    where
+        T: ToString,
+    {
+        self.metadata()._index = Some(index.to_string());
+        self
+    }
+
+    /// The document id
+    pub fn id<T>(mut self, id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.metadata()._id = Some(id.to_string());
+        self
+    }
+
+    /// Custom value used to route the operation to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.metadata().routing = Some(routing.to_string());
+        self
+    }
+
+    /// Explicit version number for concurrency control, checked according to the `version_type`
+    pub fn version(mut self, version: u64) -> Self {
+        self.metadata().version = Some(version);
+        self
+    }
+
+    /// How the [`version`](Self::version) is checked against the stored document
+    pub fn version_type(mut self, version_type: VersionType) -> Self {
+        self.metadata().version_type = Some(version_type);
+        self
+    }
+
+    /// Only performs the operation if the document has this sequence number
+    pub fn if_seq_no(mut self, if_seq_no: u64) -> Self {
+        self.metadata().if_seq_no = Some(if_seq_no);
+        self
+    }
+
+    /// Only performs the operation if the document has this primary term
+    pub fn if_primary_term(mut self, if_primary_term: u64) -> Self {
+        self.metadata().if_primary_term = Some(if_primary_term);
+        self
+    }
+
+    /// The ingest pipeline to preprocess the indexed document with
+    pub fn pipeline<T>(mut self, pipeline: T) -> Self
+    where
+        T: ToString,
+    {
+        self.metadata().pipeline = Some(pipeline.to_string());
+        self
+    }
+
+    /// Whether the [`index_name`](Self::index_name) must be an alias
+    pub fn require_alias(mut self, require_alias: bool) -> Self {
+        self.metadata().require_alias = Some(require_alias);
+        self
+    }
+
+    /// How many times an update should be retried in the case of a version conflict
+    pub fn retry_on_conflict(mut self, retry_on_conflict: u32) -> Self {
+        self.metadata().retry_on_conflict = Some(retry_on_conflict);
+        self
+    }
+
+    /// The document indexed by an update when it doesn't exist yet, ignored by the other
+    /// operations
+    pub fn upsert<T>(mut self, upsert: T) -> Self
+    where
+        T: Serialize,
+    {
+        if let Some(update) = self.update_body() {
+            update.upsert = Some(BulkDocument::new(upsert));
+        }
+        self
+    }
+
+    /// Whether the partial document of an update is indexed when the document doesn't exist yet,
+    /// ignored by the other operations
+    pub fn doc_as_upsert(mut self, doc_as_upsert: bool) -> Self {
+        if let Some(update) = self.update_body() {
+            update.doc_as_upsert = Some(doc_as_upsert);
+        }
+        self
+    }
+
+    /// Whether the script of an update runs when the document doesn't exist yet, ignored by the
+    /// other operations
+    pub fn scripted_upsert(mut self, scripted_upsert: bool) -> Self {
+        if let Some(update) = self.update_body() {
+            update.scripted_upsert = Some(scripted_upsert);
+        }
+        self
+    }
+}
+
+impl BulkDocument {
+    fn new<T>(document: T) -> Self
+    where
+        T: Serialize,
+    {
+        Self(serde_json::to_value(document).map_err(|error| error.to_string()))
+    }
+}
+
+impl ShouldSkip for BulkDocument {}
+
+impl Serialize for BulkDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.0 {
+            Ok(document) => document.serialize(serializer),
+            Err(error) => Err(serde::ser::Error::custom(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_to_ndjson() {
+        let bulk = Bulk::new()
+            .operation(
+                BulkOperation::index(json!({ "field1": "value1" }))
+                    .index_name("test")
+                    .id("1")
+                    .pipeline("my-pipeline"),
+            )
+            .operation(
+                BulkOperation::delete("2")
+                    .index_name("test")
+                    .routing("user-1"),
+            )
+            .operations([
+                BulkOperation::create(json!({ "field1": "value3" }))
+                    .index_name("test")
+                    .id("3")
+                    .require_alias(true),
+                BulkOperation::update("1", json!({ "field2": "value2" }))
+                    .index_name("test")
+                    .retry_on_conflict(3)
+                    .doc_as_upsert(true),
+                BulkOperation::update_with_script(
+                    "4",
+                    Script::source("ctx._source.counter += params.count").param("count", 4),
+                )
+                .upsert(json!({ "counter": 1 }))
+                .if_seq_no(10)
+                .if_primary_term(1),
+                BulkOperation::index(json!({ "field1": "value5" }))
+                    .id("5")
+                    .version(7)
+                    .version_type(VersionType::ExternalGte)
+                    .doc_as_upsert(true),
+            ]);
+
+        assert_eq!(bulk.len(), 6);
+        assert!(!bulk.is_empty());
+        assert!(Bulk::new().is_empty());
+
+        let lines = String::from_utf8(bulk.to_bytes().unwrap()).unwrap();
+        let lines = lines
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            [
+                json!({ "index": { "_index": "test", "_id": "1", "pipeline": "my-pipeline" } }),
+                json!({ "field1": "value1" }),
+                json!({ "delete": { "_index": "test", "_id": "2", "routing": "user-1" } }),
+                json!({ "create": { "_index": "test", "_id": "3", "require_alias": true } }),
+                json!({ "field1": "value3" }),
+                json!({ "update": { "_index": "test", "_id": "1", "retry_on_conflict": 3 } }),
+                json!({ "doc": { "field2": "value2" }, "doc_as_upsert": true }),
+                json!({ "update": { "_id": "4", "if_seq_no": 10, "if_primary_term": 1 } }),
+                json!({
+                    "script": {
+                        "source": "ctx._source.counter += params.count",
+                        "params": { "count": 4 }
+                    },
+                    "upsert": { "counter": 1 }
+                }),
+                json!({ "index": { "_id": "5", "version": 7, "version_type": "external_gte" } }),
+                json!({ "field1": "value5" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_on_unserializable_documents() {
+        let invalid = std::collections::BTreeMap::from([((1, 2), "value")]);
+
+        for operation in [
+            BulkOperation::index(&invalid),
+            BulkOperation::create(&invalid),
+            BulkOperation::update("1", &invalid),
+            BulkOperation::update_with_script("1", Script::source("ctx._source.counter++"))
+                .upsert(&invalid),
+        ] {
+            let bulk = Bulk::new()
+                .operation(BulkOperation::delete("2"))
+                .operation(operation);
+            let mut bytes = Vec::new();
+
+            assert!(bulk.to_bytes().is_err());
+            assert!(bulk.write_to(&mut bytes).is_err());
+            assert!(bytes.is_empty());
+        }
+    }
+}
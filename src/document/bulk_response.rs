@@ -0,0 +1,252 @@
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// Bulk response, with the items in the same order as the operations of the request
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html#bulk-api-response-body>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulkResponse {
+    /// The time that it took Elasticsearch to process the bulk request
+    pub took: u64,
+
+    /// Whether any of the operations failed
+    pub errors: bool,
+
+    /// Results of the individual operations
+    pub items: Vec<BulkResponseItem>,
+}
+
+/// Result of a single operation of a bulk request, keyed by the operation action
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkResponseItem {
+    /// Result of an index operation
+    Index(BulkItemResult),
+
+    /// Result of a create operation
+    Create(BulkItemResult),
+
+    /// Result of an update operation
+    Update(BulkItemResult),
+
+    /// Result of a delete operation
+    Delete(BulkItemResult),
+}
+
+/// Outcome of a single operation of a bulk request, which fails or succeeds independently of the
+/// others
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulkItemResult {
+    /// Index the operation was performed on
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document id
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_id")]
+    pub id: Option<String>,
+
+    /// Document version, incremented with each successful operation
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_version")]
+    pub version: Option<u64>,
+
+    /// Result of a successful operation
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub result: Option<BulkItemOutcome>,
+
+    /// Number of shards touched with their states
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_shards")]
+    pub shards: Option<ShardStatistics>,
+
+    /// Sequence number assigned to the document
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_seq_no")]
+    pub seq_no: Option<u64>,
+
+    /// Primary term assigned to the document
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "_primary_term"
+    )]
+    pub primary_term: Option<u64>,
+
+    /// HTTP status code the operation would have returned on its own
+    pub status: u16,
+
+    /// Reason the operation failed
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub error: Option<Box<ErrorCause>>,
+}
+
+/// Failed operation of a bulk request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulkItemFailure {
+    /// Index the operation was performed on
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document id
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_id")]
+    pub id: Option<String>,
+
+    /// HTTP status code the operation would have failed with on its own
+    pub status: u16,
+
+    /// Error cause
+    pub error: Box<ErrorCause>,
+}
+
+/// Result of a successful bulk operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemOutcome {
+    /// The document was created
+    Created,
+
+    /// The existing document was replaced or updated
+    Updated,
+
+    /// The document was deleted
+    Deleted,
+
+    /// The document to delete doesn't exist
+    NotFound,
+
+    /// The update didn't change the document
+    Noop,
+}
+
+impl BulkResponse {
+    /// Results of the failed operations
+    pub fn failures(&self) -> impl Iterator<Item = &BulkItemResult> {
+        self.items
+            .iter()
+            .map(BulkResponseItem::result)
+            .filter(|result| result.error.is_some())
+    }
+}
+
+impl BulkResponseItem {
+    /// Result of the operation, regardless of the action
+    pub fn result(&self) -> &BulkItemResult {
+        match self {
+            Self::Index(result)
+            | Self::Create(result)
+            | Self::Update(result)
+            | Self::Delete(result) => result,
+        }
+    }
+
+    /// Converts the item into a result, so that failures can be handled with `?`
+    pub fn into_result(self) -> Result<BulkItemResult, BulkItemFailure> {
+        let mut result = match self {
+            Self::Index(result)
+            | Self::Create(result)
+            | Self::Update(result)
+            | Self::Delete(result) => result,
+        };
+
+        match result.error.take() {
+            Some(error) => Err(BulkItemFailure {
+                index: result.index,
+                id: result.id,
+                status: result.status,
+                error,
+            }),
+            None => Ok(result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let subject: BulkResponse = serde_json::from_value(json!({
+            "took": 30,
+            "errors": true,
+            "items": [
+                {
+                    "index": {
+                        "_index": "test",
+                        "_id": "1",
+                        "_version": 1,
+                        "result": "created",
+                        "_shards": { "total": 2, "successful": 1, "failed": 0 },
+                        "status": 201,
+                        "_seq_no": 0,
+                        "_primary_term": 1
+                    }
+                },
+                {
+                    "delete": {
+                        "_index": "test",
+                        "_id": "2",
+                        "_version": 1,
+                        "result": "not_found",
+                        "_shards": { "total": 2, "successful": 1, "failed": 0 },
+                        "status": 404,
+                        "_seq_no": 1,
+                        "_primary_term": 2
+                    }
+                },
+                {
+                    "update": {
+                        "_index": "index1",
+                        "_id": "5",
+                        "status": 404,
+                        "error": {
+                            "type": "document_missing_exception",
+                            "reason": "[5]: document missing",
+                            "index_uuid": "aAsFqTI0Tc2W0LCWgPNrOA",
+                            "shard": "0",
+                            "index": "index1"
+                        }
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(subject.took, 30);
+        assert!(subject.errors);
+        assert_eq!(subject.items.len(), 3);
+
+        let BulkResponseItem::Index(created) = &subject.items[0] else {
+            panic!("expected an index item, got {:?}", subject.items[0]);
+        };
+
+        assert_eq!(created.result, Some(BulkItemOutcome::Created));
+        assert_eq!(created.shards.as_ref().map(|shards| shards.total), Some(2));
+        assert_eq!(created.seq_no, Some(0));
+        assert_eq!(
+            subject.items[1].result().result,
+            Some(BulkItemOutcome::NotFound)
+        );
+
+        let failures = subject.failures().collect::<Vec<_>>();
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].id.as_deref(), Some("5"));
+        assert_eq!(
+            failures[0]
+                .error
+                .as_ref()
+                .and_then(|error| error.ty.as_deref()),
+            Some("document_missing_exception")
+        );
+
+        let results = subject
+            .items
+            .into_iter()
+            .map(BulkResponseItem::into_result)
+            .collect::<Vec<_>>();
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(
+            &results[2],
+            Err(BulkItemFailure { status: 404, id: Some(id), .. }) if id == "5"
+        ));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::util::ShouldSkip;
+use serde_json::Value;
+
+/// Response of the update by query and delete by query APIs
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ByQueryResponse {
+    /// The time that it took Elasticsearch to process the whole operation
+    pub took: u64,
+
+    /// Whether any of the requests executed during the operation timed out
+    pub timed_out: bool,
+
+    /// Number of documents that were successfully processed
+    pub total: u64,
+
+    /// Number of documents that were successfully updated, update by query only
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub updated: Option<u64>,
+
+    /// Number of documents that were successfully deleted
+    #[serde(default)]
+    pub deleted: u64,
+
+    /// Number of scroll responses pulled back by the operation
+    pub batches: u64,
+
+    /// Number of version conflicts the operation hit
+    pub version_conflicts: u64,
+
+    /// Number of documents ignored because the update script set `ctx.op` to `noop`
+    #[serde(default)]
+    pub noops: u64,
+
+    /// Number of retries attempted by the operation
+    pub retries: ByQueryRetries,
+
+    /// Time the request slept to conform to `requests_per_second`
+    #[serde(default)]
+    pub throttled_millis: u64,
+
+    /// Number of requests per second effectively executed, `-1` when unthrottled
+    #[serde(default)]
+    pub requests_per_second: f32,
+
+    /// Time until the next throttled request is executed
+    #[serde(default)]
+    pub throttled_until_millis: u64,
+
+    /// Unrecoverable errors, the operation is aborted when there are any
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub failures: Vec<Value>,
+}
+
+/// Retries attempted by an update by query or delete by query operation
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ByQueryRetries {
+    /// Number of bulk actions retried
+    pub bulk: u64,
+
+    /// Number of search actions retried
+    pub search: u64,
+}
+
+/// Response of a request sent with `wait_for_completion=false`, the operation is performed as a
+/// task which can be followed with the tasks API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskResponse {
+    /// Task id, in the `<node_id>:<task_number>` format
+    pub task: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_update_by_query_response() {
+        let subject: ByQueryResponse = serde_json::from_value(json!({
+            "took": 147,
+            "timed_out": false,
+            "total": 120,
+            "updated": 119,
+            "deleted": 0,
+            "batches": 1,
+            "version_conflicts": 1,
+            "noops": 0,
+            "retries": { "bulk": 0, "search": 0 },
+            "throttled_millis": 0,
+            "requests_per_second": -1.0,
+            "throttled_until_millis": 0,
+            "failures": []
+        }))
+        .unwrap();
+
+        assert_eq!(
+            subject,
+            ByQueryResponse {
+                took: 147,
+                timed_out: false,
+                total: 120,
+                updated: Some(119),
+                deleted: 0,
+                batches: 1,
+                version_conflicts: 1,
+                noops: 0,
+                retries: ByQueryRetries { bulk: 0, search: 0 },
+                throttled_millis: 0,
+                requests_per_second: -1.0,
+                throttled_until_millis: 0,
+                failures: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn deserializes_delete_by_query_response() {
+        let subject: ByQueryResponse = serde_json::from_value(json!({
+            "took": 12,
+            "timed_out": false,
+            "total": 3,
+            "deleted": 3,
+            "batches": 1,
+            "version_conflicts": 0,
+            "noops": 0,
+            "retries": { "bulk": 0, "search": 0 },
+            "throttled_millis": 0,
+            "requests_per_second": -1.0,
+            "throttled_until_millis": 0,
+            "failures": []
+        }))
+        .unwrap();
+
+        assert_eq!(subject.updated, None);
+        assert_eq!(subject.deleted, 3);
+    }
+
+    #[test]
+    fn deserializes_task_response() {
+        let subject: TaskResponse =
+            serde_json::from_value(json!({ "task": "r1A2WoRbTwKZ516z6NEs5A:36619" })).unwrap();
+
+        assert_eq!(subject.task, "r1A2WoRbTwKZ516z6NEs5A:36619");
+    }
+}
@@ -0,0 +1,10 @@
+/// What to do when a by-query operation hits version conflicts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Conflicts {
+    /// Abort the operation on the first version conflict (default)
+    Abort,
+
+    /// Count the version conflicts and continue with the rest of the documents
+    Proceed,
+}
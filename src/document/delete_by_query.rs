@@ -0,0 +1,99 @@
+use super::{Conflicts, Slice};
+use crate::search::*;
+use crate::util::*;
+
+/// Request body of the
+/// [delete by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html)
+/// API, deleting the documents matching the query.
+///
+/// The response is parsed with [`ByQueryResponse`](super::ByQueryResponse), or
+/// [`TaskResponse`](super::TaskResponse) when the request is sent with
+/// `wait_for_completion=false`.
+/// ```
+/// # use elasticsearch_dsl::document::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let request =
+/// DeleteByQuery::new(Query::range("created_at").lt("now-1y"))
+///     .conflicts(Conflicts::Proceed)
+///     .max_docs(1000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteByQuery {
+    query: Query,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    conflicts: Option<Conflicts>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slice: Option<Slice>,
+}
+
+impl DeleteByQuery {
+    /// Creates a new instance of [`DeleteByQuery`]
+    ///
+    /// - `query` - query matching the documents to delete
+    pub fn new<Q>(query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        Self {
+            query: query.into(),
+            max_docs: None,
+            conflicts: None,
+            slice: None,
+        }
+    }
+
+    /// Maximum number of documents to delete, defaults to all the matching documents
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+
+    /// What to do when the delete by query hits version conflicts
+    pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.conflicts = Some(conflicts);
+        self
+    }
+
+    /// Processes only a slice of the matching documents
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            DeleteByQuery::new(Query::r#match("user.id", "elkbee")),
+            json!({
+                "query": {
+                    "match": { "user.id": { "query": "elkbee" } }
+                }
+            }),
+        );
+
+        assert_serialize(
+            DeleteByQuery::new(Query::term("user.id", "elkbee"))
+                .max_docs(1)
+                .conflicts(Conflicts::Proceed)
+                .slice(Slice::new(0, 2)),
+            json!({
+                "query": {
+                    "term": { "user.id": { "value": "elkbee" } }
+                },
+                "max_docs": 1,
+                "conflicts": "proceed",
+                "slice": { "id": 0, "max": 2 }
+            }),
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Document APIs index, update and delete documents, either one by one or in bulk, without
+//! depending on a specific HTTP client.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs.html>
+
+mod bulk;
+mod bulk_response;
+mod by_query_response;
+mod conflicts;
+mod delete_by_query;
+mod slice;
+mod update_by_query;
+
+pub use self::bulk::*;
+pub use self::bulk_response::*;
+pub use self::by_query_response::*;
+pub use self::conflicts::*;
+pub use self::delete_by_query::*;
+pub use self::slice::*;
+pub use self::update_by_query::*;
@@ -0,0 +1,20 @@
+/// Manual slicing of a by-query operation or a [`Search`](crate::Search), each slice is sent as a
+/// separate request and processes its share of the documents in parallel with the others
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html#docs-update-by-query-manual-slice>
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#slice-scroll>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Slice {
+    id: u32,
+    max: u32,
+}
+
+impl Slice {
+    /// Creates a new instance of [`Slice`]
+    ///
+    /// - `id` - zero based id of the slice processed by this request
+    /// - `max` - total number of slices
+    pub fn new(id: u32, max: u32) -> Self {
+        Self { id, max }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Conflicts, Slice};
+use crate::search::*;
+use crate::util::*;
+
+/// Request body of the
+/// [update by query](https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html)
+/// API, updating the documents matching the query with a script, or reindexing them in place to
+/// pick up mapping changes when no script is set.
+///
+/// The response is parsed with [`ByQueryResponse`](super::ByQueryResponse), or
+/// [`TaskResponse`](super::TaskResponse) when the request is sent with
+/// `wait_for_completion=false`.
+/// ```
+/// # use elasticsearch_dsl::document::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let request =
+/// UpdateByQuery::new()
+///     .query(Query::term("user.id", "kimchy"))
+///     .script(Script::source("ctx._source.count++").lang("painless"))
+///     .conflicts(Conflicts::Proceed);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct UpdateByQuery {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    conflicts: Option<Conflicts>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slice: Option<Slice>,
+}
+
+impl UpdateByQuery {
+    /// Creates a new instance of [`UpdateByQuery`], updating all the documents of the index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query matching the documents to update
+    pub fn query<Q>(mut self, query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Script run on every matching document, the document is reindexed unchanged when missing
+    pub fn script<S>(mut self, script: S) -> Self
+    where
+        S: Into<Script>,
+    {
+        self.script = Some(script.into());
+        self
+    }
+
+    /// Maximum number of documents to update, defaults to all the matching documents
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+
+    /// What to do when the update by query hits version conflicts
+    pub fn conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.conflicts = Some(conflicts);
+        self
+    }
+
+    /// Processes only a slice of the matching documents
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(UpdateByQuery::new(), json!({}));
+
+        assert_serialize(
+            UpdateByQuery::new()
+                .query(Query::term("user.id", "kimchy"))
+                .script(Script::source("ctx._source.count++").lang("painless"))
+                .max_docs(10)
+                .conflicts(Conflicts::Proceed)
+                .slice(Slice::new(1, 4)),
+            json!({
+                "query": {
+                    "term": { "user.id": { "value": "kimchy" } }
+                },
+                "script": {
+                    "source": "ctx._source.count++",
+                    "lang": "painless"
+                },
+                "max_docs": 10,
+                "conflicts": "proceed",
+                "slice": { "id": 1, "max": 4 }
+            }),
+        );
+    }
+}
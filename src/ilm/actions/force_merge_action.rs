@@ -0,0 +1,49 @@
+use crate::util::*;
+
+/// Force merges the index into the specified maximum number of segments
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-forcemerge.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ForceMergeAction {
+    max_num_segments: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_codec: Option<String>,
+}
+
+impl ForceMergeAction {
+    /// Creates an instance of [`ForceMergeAction`]
+    ///
+    /// - `max_num_segments` - Number of segments to merge to. To fully merge the index, set to
+    ///   `1`.
+    pub fn new(max_num_segments: u32) -> Self {
+        Self {
+            max_num_segments,
+            index_codec: None,
+        }
+    }
+
+    /// Codec used to compress the document store, e.g. `best_compression`
+    pub fn index_codec<T>(mut self, index_codec: T) -> Self
+    where
+        T: ToString,
+    {
+        self.index_codec = Some(index_codec.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(ForceMergeAction::new(1), json!({ "max_num_segments": 1 }));
+
+        assert_serialize(
+            ForceMergeAction::new(1).index_codec("best_compression"),
+            json!({ "max_num_segments": 1, "index_codec": "best_compression" }),
+        );
+    }
+}
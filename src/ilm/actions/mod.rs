@@ -0,0 +1,15 @@
+//! Actions performed by an index lifecycle policy when an index enters a phase
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-actions.html>
+
+mod delete_action;
+mod force_merge_action;
+mod rollover_action;
+mod set_priority_action;
+mod shrink_action;
+
+pub use self::delete_action::*;
+pub use self::force_merge_action::*;
+pub use self::rollover_action::*;
+pub use self::set_priority_action::*;
+pub use self::shrink_action::*;
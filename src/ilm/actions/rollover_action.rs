@@ -0,0 +1,130 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Rolls over a target to a new index when the existing index satisfies any of the specified
+/// rollover conditions.
+///
+/// To create a rollover action:
+/// ```
+/// # use elasticsearch_dsl::ilm::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let action =
+/// RolloverAction::new()
+///     .max_age(Time::Days(7))
+///     .max_primary_shard_size(Byte::Gigabytes(50));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-rollover.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct RolloverAction {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_age: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_size: Option<Byte>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_size: Option<Byte>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_age: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_docs: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_primary_shard_size: Option<Byte>,
+}
+
+impl RolloverAction {
+    /// Creates an instance of [`RolloverAction`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Triggers rollover after the maximum elapsed time from index creation is reached
+    pub fn max_age(mut self, max_age: Time) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Triggers rollover after the specified maximum number of documents is reached
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+
+    /// Triggers rollover when the index reaches a certain size, the sum of all primary shards
+    pub fn max_size(mut self, max_size: Byte) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Triggers rollover when the largest primary shard in the index reaches a certain size
+    pub fn max_primary_shard_size(mut self, max_primary_shard_size: Byte) -> Self {
+        self.max_primary_shard_size = Some(max_primary_shard_size);
+        self
+    }
+
+    /// Triggers rollover when the largest primary shard in the index reaches a certain number
+    /// of documents
+    pub fn max_primary_shard_docs(mut self, max_primary_shard_docs: u64) -> Self {
+        self.max_primary_shard_docs = Some(max_primary_shard_docs);
+        self
+    }
+
+    /// Prevents rollover until after the minimum elapsed time from index creation is reached
+    pub fn min_age(mut self, min_age: Time) -> Self {
+        self.min_age = Some(min_age);
+        self
+    }
+
+    /// Prevents rollover until after the specified minimum number of documents is reached
+    pub fn min_docs(mut self, min_docs: u64) -> Self {
+        self.min_docs = Some(min_docs);
+        self
+    }
+
+    /// Prevents rollover until the largest primary shard in the index reaches a certain size
+    pub fn min_primary_shard_size(mut self, min_primary_shard_size: Byte) -> Self {
+        self.min_primary_shard_size = Some(min_primary_shard_size);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(RolloverAction::new(), json!({}));
+
+        assert_serialize(
+            RolloverAction::new()
+                .max_age(Time::Days(30))
+                .max_docs(100000000)
+                .max_size(Byte::Gigabytes(100))
+                .max_primary_shard_size(Byte::Gigabytes(50))
+                .max_primary_shard_docs(200000000)
+                .min_age(Time::Days(1))
+                .min_docs(1000)
+                .min_primary_shard_size(Byte::Gigabytes(1)),
+            json!({
+                "max_age": "30d",
+                "max_docs": 100000000,
+                "max_size": "100gb",
+                "max_primary_shard_size": "50gb",
+                "max_primary_shard_docs": 200000000,
+                "min_age": "1d",
+                "min_docs": 1000,
+                "min_primary_shard_size": "1gb"
+            }),
+        );
+    }
+}
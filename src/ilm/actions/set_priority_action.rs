@@ -0,0 +1,28 @@
+/// Sets the priority of the index as soon as the policy enters the phase. Higher priority
+/// indices are recovered before indices with lower priorities following a node restart.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-set-priority.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SetPriorityAction {
+    priority: u32,
+}
+
+impl SetPriorityAction {
+    /// Creates an instance of [`SetPriorityAction`]
+    ///
+    /// - `priority` - The priority for the index, must be `0` or greater
+    pub fn new(priority: u32) -> Self {
+        Self { priority }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(SetPriorityAction::new(50), json!({ "priority": 50 }));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Sets a source index to read-only and shrinks it into a new index with fewer primary shards
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-shrink.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShrinkAction {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_primary_shard_size: Option<Byte>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    allow_write_after_shrink: Option<bool>,
+}
+
+impl ShrinkAction {
+    /// Creates an instance of [`ShrinkAction`] with a fixed number of shards
+    ///
+    /// - `number_of_shards` - Number of shards to shrink to, must be a factor of the number of
+    ///   shards in the source index
+    pub fn number_of_shards(number_of_shards: u32) -> Self {
+        Self {
+            number_of_shards: Some(number_of_shards),
+            max_primary_shard_size: None,
+            allow_write_after_shrink: None,
+        }
+    }
+
+    /// Creates an instance of [`ShrinkAction`] with a computed number of shards
+    ///
+    /// - `max_primary_shard_size` - The max primary shard size for the target index, used to
+    ///   find the optimum number of shards
+    pub fn max_primary_shard_size(max_primary_shard_size: Byte) -> Self {
+        Self {
+            number_of_shards: None,
+            max_primary_shard_size: Some(max_primary_shard_size),
+            allow_write_after_shrink: None,
+        }
+    }
+
+    /// If `true`, the shrunken index is made writable by removing the write block. Defaults to
+    /// `false`.
+    pub fn allow_write_after_shrink(mut self, allow_write_after_shrink: bool) -> Self {
+        self.allow_write_after_shrink = Some(allow_write_after_shrink);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            ShrinkAction::number_of_shards(1),
+            json!({ "number_of_shards": 1 }),
+        );
+
+        assert_serialize(
+            ShrinkAction::max_primary_shard_size(Byte::Gigabytes(50))
+                .allow_write_after_shrink(true),
+            json!({ "max_primary_shard_size": "50gb", "allow_write_after_shrink": true }),
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Index lifecycle management (ILM) policies automatically manage indices according to
+//! performance, resiliency, and retention requirements.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-lifecycle-management.html>
+
+// Private modules
+mod phase;
+mod policy;
+
+// Public modules
+pub mod actions;
+
+// Public re-exports
+pub use self::actions::*;
+pub use self::phase::*;
+pub use self::policy::*;
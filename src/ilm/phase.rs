@@ -0,0 +1,117 @@
+use crate::ilm::*;
+use crate::search::*;
+use crate::util::*;
+
+/// A lifecycle phase with the minimum age an index must reach to enter it and the actions to
+/// perform once it does
+///
+/// To create a phase:
+/// ```
+/// # use elasticsearch_dsl::ilm::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let phase =
+/// Phase::new()
+///     .min_age(Time::Days(30))
+///     .force_merge(ForceMergeAction::new(1))
+///     .set_priority(SetPriorityAction::new(50));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-index-lifecycle.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Phase {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_age: Option<Time>,
+
+    actions: PhaseActions,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+struct PhaseActions {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rollover: Option<RolloverAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    set_priority: Option<SetPriorityAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shrink: Option<ShrinkAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    forcemerge: Option<ForceMergeAction>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delete: Option<DeleteAction>,
+}
+
+impl Phase {
+    /// Creates an instance of [`Phase`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Minimum age of an index to enter this phase, calculated from the index creation time or
+    /// the rollover time. Defaults to `0ms`.
+    pub fn min_age(mut self, min_age: Time) -> Self {
+        self.min_age = Some(min_age);
+        self
+    }
+
+    /// Rolls over the index when any of the conditions are met, allowed in the hot phase only
+    pub fn rollover(mut self, rollover: RolloverAction) -> Self {
+        self.actions.rollover = Some(rollover);
+        self
+    }
+
+    /// Sets the recovery priority of the index
+    pub fn set_priority(mut self, set_priority: SetPriorityAction) -> Self {
+        self.actions.set_priority = Some(set_priority);
+        self
+    }
+
+    /// Shrinks the index into a new index with fewer primary shards
+    pub fn shrink(mut self, shrink: ShrinkAction) -> Self {
+        self.actions.shrink = Some(shrink);
+        self
+    }
+
+    /// Force merges the index into the specified maximum number of segments
+    pub fn force_merge(mut self, force_merge: ForceMergeAction) -> Self {
+        self.actions.forcemerge = Some(force_merge);
+        self
+    }
+
+    /// Permanently removes the index, allowed in the delete phase only
+    pub fn delete(mut self, delete: DeleteAction) -> Self {
+        self.actions.delete = Some(delete);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Phase::new(), json!({ "actions": {} }));
+
+        assert_serialize(
+            Phase::new()
+                .min_age(Time::Days(7))
+                .rollover(RolloverAction::new().max_docs(100))
+                .set_priority(SetPriorityAction::new(100))
+                .shrink(ShrinkAction::number_of_shards(1))
+                .force_merge(ForceMergeAction::new(1))
+                .delete(DeleteAction::new()),
+            json!({
+                "min_age": "7d",
+                "actions": {
+                    "rollover": { "max_docs": 100 },
+                    "set_priority": { "priority": 100 },
+                    "shrink": { "number_of_shards": 1 },
+                    "forcemerge": { "max_num_segments": 1 },
+                    "delete": {}
+                }
+            }),
+        );
+    }
+}
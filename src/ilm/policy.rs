@@ -0,0 +1,162 @@
+//! Creates or updates a lifecycle policy.
+use crate::ilm::*;
+use crate::util::*;
+use crate::Map;
+
+/// Request body of the create or update lifecycle policy API
+///
+/// To create a lifecycle policy:
+/// ```
+/// # use elasticsearch_dsl::ilm::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let policy =
+/// LifecyclePolicy::new()
+///     .hot(
+///         Phase::new().rollover(
+///             RolloverAction::new()
+///                 .max_age(Time::Days(7))
+///                 .max_primary_shard_size(Byte::Gigabytes(50)),
+///         ),
+///     )
+///     .warm(Phase::new().min_age(Time::Days(30)).force_merge(ForceMergeAction::new(1)))
+///     .delete(Phase::new().min_age(Time::Days(90)).delete(DeleteAction::new()));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ilm-put-lifecycle.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct LifecyclePolicy {
+    phases: Phases,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _meta: Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+struct Phases {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hot: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    warm: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    cold: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    frozen: Option<Phase>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    delete: Option<Phase>,
+}
+
+impl LifecyclePolicy {
+    /// Creates an instance of [`LifecyclePolicy`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The hot phase, the index is actively being updated and queried
+    pub fn hot(mut self, phase: Phase) -> Self {
+        self.phases.hot = Some(phase);
+        self
+    }
+
+    /// The warm phase, the index is no longer being updated but is still being queried
+    pub fn warm(mut self, phase: Phase) -> Self {
+        self.phases.warm = Some(phase);
+        self
+    }
+
+    /// The cold phase, the index is no longer being updated and is queried infrequently
+    pub fn cold(mut self, phase: Phase) -> Self {
+        self.phases.cold = Some(phase);
+        self
+    }
+
+    /// The frozen phase, the index is no longer being updated and is queried rarely
+    pub fn frozen(mut self, phase: Phase) -> Self {
+        self.phases.frozen = Some(phase);
+        self
+    }
+
+    /// The delete phase, the index is no longer needed and can safely be removed
+    pub fn delete(mut self, phase: Phase) -> Self {
+        self.phases.delete = Some(phase);
+        self
+    }
+
+    /// Adds optional user metadata about the policy
+    pub fn meta<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Into<serde_json::Value>,
+    {
+        let _ = self._meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+serialize_with_root!("policy": LifecyclePolicy);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            LifecyclePolicy::new(),
+            json!({ "policy": { "phases": {} } }),
+        );
+
+        assert_serialize(
+            LifecyclePolicy::new()
+                .hot(
+                    Phase::new()
+                        .rollover(RolloverAction::new().max_age(Time::Days(7)))
+                        .set_priority(SetPriorityAction::new(100)),
+                )
+                .warm(
+                    Phase::new()
+                        .min_age(Time::Days(30))
+                        .shrink(ShrinkAction::number_of_shards(1))
+                        .force_merge(ForceMergeAction::new(1)),
+                )
+                .cold(Phase::new().min_age(Time::Days(60)))
+                .frozen(Phase::new().min_age(Time::Days(75)))
+                .delete(
+                    Phase::new()
+                        .min_age(Time::Days(90))
+                        .delete(DeleteAction::new()),
+                )
+                .meta("description", "used for nginx log"),
+            json!({
+                "policy": {
+                    "phases": {
+                        "hot": {
+                            "actions": {
+                                "rollover": { "max_age": "7d" },
+                                "set_priority": { "priority": 100 }
+                            }
+                        },
+                        "warm": {
+                            "min_age": "30d",
+                            "actions": {
+                                "shrink": { "number_of_shards": 1 },
+                                "forcemerge": { "max_num_segments": 1 }
+                            }
+                        },
+                        "cold": { "min_age": "60d", "actions": {} },
+                        "frozen": { "min_age": "75d", "actions": {} },
+                        "delete": {
+                            "min_age": "90d",
+                            "actions": { "delete": {} }
+                        }
+                    },
+                    "_meta": { "description": "used for nginx log" }
+                }
+            }),
+        );
+    }
+}
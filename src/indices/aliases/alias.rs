@@ -0,0 +1,120 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Alias definition used when creating an index or an index template
+///
+/// To create an alias:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let alias =
+/// Alias::new()
+///     .filter(Query::term("user.id", "kimchy"))
+///     .routing("shard-1")
+///     .is_write_index(true);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html#create-index-api-request-body>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Alias {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    is_hidden: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    is_write_index: Option<bool>,
+}
+
+impl Alias {
+    /// Creates an instance of [`Alias`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Query used to limit documents the alias can access
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Value used to route indexing and search operations to a specific shard. Overridden by
+    /// `index_routing` and `search_routing` respectively.
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /// Value used to route indexing operations to a specific shard
+    pub fn index_routing<T>(mut self, index_routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.index_routing = Some(index_routing.to_string());
+        self
+    }
+
+    /// Value used to route search operations to a specific shard
+    pub fn search_routing<T>(mut self, search_routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.search_routing = Some(search_routing.to_string());
+        self
+    }
+
+    /// If `true`, the alias is hidden. Defaults to `false`.
+    pub fn is_hidden(mut self, is_hidden: bool) -> Self {
+        self.is_hidden = Some(is_hidden);
+        self
+    }
+
+    /// If `true`, the index is the write index for the alias. Defaults to `false`.
+    pub fn is_write_index(mut self, is_write_index: bool) -> Self {
+        self.is_write_index = Some(is_write_index);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Alias::new(), json!({}));
+
+        assert_serialize(
+            Alias::new()
+                .filter(Query::term("user.id", "kimchy"))
+                .routing("1")
+                .index_routing("2")
+                .search_routing("3")
+                .is_hidden(false)
+                .is_write_index(true),
+            json!({
+                "filter": { "term": { "user.id": { "value": "kimchy" } } },
+                "routing": "1",
+                "index_routing": "2",
+                "search_routing": "3",
+                "is_hidden": false,
+                "is_write_index": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Performs one or more alias actions in a single atomic operation.
+use crate::indices::*;
+
+/// Request body of the aliases API, all actions are applied atomically
+///
+/// To swap an alias from one index to another:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let actions =
+/// AliasActions::new()
+///     .remove("products-v1", "products")
+///     .add("products-v2", "products", Alias::new().is_write_index(true));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AliasActions {
+    actions: Vec<AliasAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AliasAction {
+    Add {
+        index: String,
+        alias: String,
+        #[serde(flatten)]
+        options: Box<Alias>,
+    },
+    Remove {
+        index: String,
+        alias: String,
+    },
+    RemoveIndex {
+        index: String,
+    },
+}
+
+impl AliasActions {
+    /// Creates an instance of [`AliasActions`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a data stream or index to an alias. If the alias doesn’t exist, the action creates
+    /// it.
+    pub fn add<T, U>(mut self, index: T, alias: U, options: Alias) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        self.actions.push(AliasAction::Add {
+            index: index.to_string(),
+            alias: alias.to_string(),
+            options: Box::new(options),
+        });
+        self
+    }
+
+    /// Removes a data stream or index from an alias
+    pub fn remove<T, U>(mut self, index: T, alias: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        self.actions.push(AliasAction::Remove {
+            index: index.to_string(),
+            alias: alias.to_string(),
+        });
+        self
+    }
+
+    /// Deletes an index. You cannot use this action on aliases or data streams.
+    pub fn remove_index<T>(mut self, index: T) -> Self
+    where
+        T: ToString,
+    {
+        self.actions.push(AliasAction::RemoveIndex {
+            index: index.to_string(),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(AliasActions::new(), json!({ "actions": [] }));
+
+        assert_serialize(
+            AliasActions::new()
+                .add(
+                    "my-index-2099.05.06-000001",
+                    "my-alias",
+                    Alias::new()
+                        .filter(Query::term("user.id", "kimchy"))
+                        .routing("1")
+                        .index_routing("2")
+                        .search_routing("3")
+                        .is_write_index(true),
+                )
+                .remove("logs-nginx.access-prod", "logs")
+                .remove_index("my-index-2099.05.06-000002"),
+            json!({
+                "actions": [
+                    {
+                        "add": {
+                            "index": "my-index-2099.05.06-000001",
+                            "alias": "my-alias",
+                            "filter": { "term": { "user.id": { "value": "kimchy" } } },
+                            "routing": "1",
+                            "index_routing": "2",
+                            "search_routing": "3",
+                            "is_write_index": true
+                        }
+                    },
+                    {
+                        "remove": {
+                            "index": "logs-nginx.access-prod",
+                            "alias": "logs"
+                        }
+                    },
+                    {
+                        "remove_index": {
+                            "index": "my-index-2099.05.06-000002"
+                        }
+                    }
+                ]
+            }),
+        );
+    }
+}
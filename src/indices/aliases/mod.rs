@@ -0,0 +1,10 @@
+//! An alias is a secondary name for a group of data streams or indices. Most Elasticsearch APIs
+//! accept an alias in place of a data stream or index name.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/aliases.html>
+
+mod alias;
+mod alias_actions;
+
+pub use self::alias::*;
+pub use self::alias_actions::*;
@@ -0,0 +1,95 @@
+//! Creates a new index with settings, mappings and aliases.
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// Request body of the create index API
+///
+/// To create an index:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let index =
+/// CreateIndex::new()
+///     .settings(IndexSettings::new().number_of_shards(3))
+///     .mappings(Mappings::new().property("title", Property::text()))
+///     .alias("products", Alias::new().is_write_index(true));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct CreateIndex {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    settings: IndexSettings,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings: Mappings,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aliases: Map<String, Alias>,
+}
+
+impl CreateIndex {
+    /// Creates an instance of [`CreateIndex`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configuration options for the index
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Mapping for fields in the index
+    pub fn mappings(mut self, mappings: Mappings) -> Self {
+        self.mappings = mappings;
+        self
+    }
+
+    /// Adds an alias for the index
+    pub fn alias<T>(mut self, name: T, alias: Alias) -> Self
+    where
+        T: ToString,
+    {
+        let _ = self.aliases.insert(name.to_string(), alias);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(CreateIndex::new(), json!({}));
+
+        assert_serialize(
+            CreateIndex::new()
+                .settings(IndexSettings::new().number_of_shards(3))
+                .mappings(Mappings::new().property("title", Property::text()))
+                .alias("all_products", Alias::new())
+                .alias(
+                    "active_products",
+                    Alias::new()
+                        .filter(Query::term("active", true))
+                        .is_write_index(false),
+                ),
+            json!({
+                "settings": { "number_of_shards": 3 },
+                "mappings": {
+                    "properties": {
+                        "title": { "type": "text" }
+                    }
+                },
+                "aliases": {
+                    "active_products": {
+                        "filter": { "term": { "active": { "value": true } } },
+                        "is_write_index": false
+                    },
+                    "all_products": {}
+                }
+            }),
+        );
+    }
+}
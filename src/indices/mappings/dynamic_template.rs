@@ -0,0 +1,146 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Dynamic templates allow you greater control of how Elasticsearch maps your data beyond the
+/// default dynamic field mapping rules. Templates are matched against the detected data type,
+/// the field name or the full dotted path of the field and are processed in order, the first
+/// matching template wins.
+///
+/// To create a dynamic template:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let template =
+/// DynamicTemplate::new()
+///     .match_mapping_type(MatchMappingType::String)
+///     .r#match("*_text")
+///     .mapping(Property::text().analyzer("english"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DynamicTemplate {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    match_mapping_type: Option<MatchMappingType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unmatch_mapping_type: Option<MatchMappingType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#match: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unmatch: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    match_pattern: Option<MatchPattern>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    path_match: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    path_unmatch: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mapping: Option<Property>,
+}
+
+impl DynamicTemplate {
+    /// Creates an instance of [`DynamicTemplate`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Matches fields by the data type detected by the JSON parser
+    pub fn match_mapping_type(mut self, match_mapping_type: MatchMappingType) -> Self {
+        self.match_mapping_type = Some(match_mapping_type);
+        self
+    }
+
+    /// Excludes fields by the data type detected by the JSON parser
+    pub fn unmatch_mapping_type(mut self, unmatch_mapping_type: MatchMappingType) -> Self {
+        self.unmatch_mapping_type = Some(unmatch_mapping_type);
+        self
+    }
+
+    /// A pattern to match on the field name
+    pub fn r#match<T>(mut self, r#match: T) -> Self
+    where
+        T: ToString,
+    {
+        self.r#match = Some(r#match.to_string());
+        self
+    }
+
+    /// A pattern to exclude fields matched by `match`
+    pub fn unmatch<T>(mut self, unmatch: T) -> Self
+    where
+        T: ToString,
+    {
+        self.unmatch = Some(unmatch.to_string());
+        self
+    }
+
+    /// How `match` and `unmatch` patterns are interpreted. Defaults to `simple`.
+    pub fn match_pattern(mut self, match_pattern: MatchPattern) -> Self {
+        self.match_pattern = Some(match_pattern);
+        self
+    }
+
+    /// A pattern to match on the full dotted path of the field, e.g. `name.*`
+    pub fn path_match<T>(mut self, path_match: T) -> Self
+    where
+        T: ToString,
+    {
+        self.path_match = Some(path_match.to_string());
+        self
+    }
+
+    /// A pattern to exclude fields matched by `path_match`
+    pub fn path_unmatch<T>(mut self, path_unmatch: T) -> Self
+    where
+        T: ToString,
+    {
+        self.path_unmatch = Some(path_unmatch.to_string());
+        self
+    }
+
+    /// The mapping that matched fields should use
+    pub fn mapping<T>(mut self, mapping: T) -> Self
+    where
+        T: Into<Property>,
+    {
+        self.mapping = Some(mapping.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DynamicTemplate::new(), json!({}));
+
+        assert_serialize(
+            DynamicTemplate::new()
+                .match_mapping_type(MatchMappingType::String)
+                .unmatch_mapping_type(MatchMappingType::Object)
+                .r#match("^long_.*")
+                .unmatch("*_text")
+                .match_pattern(MatchPattern::Regex)
+                .path_match("name.*")
+                .path_unmatch("*.middle")
+                .mapping(Property::keyword()),
+            json!({
+                "match_mapping_type": "string",
+                "unmatch_mapping_type": "object",
+                "match": "^long_.*",
+                "unmatch": "*_text",
+                "match_pattern": "regex",
+                "path_match": "name.*",
+                "path_unmatch": "*.middle",
+                "mapping": { "type": "keyword" }
+            }),
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::indices::*;
+use crate::Map;
+
+/// Types which know their own index mapping, usually documents stored in an index.
+///
+/// With the `derive` feature enabled the trait can be derived for structs, mapping each field
+/// annotated with the `#[es(...)]` attribute. The first item of the attribute names the
+/// [`Property`] constructor, the rest are passed to the property builder methods. Fields
+/// without the attribute are left to dynamic mapping.
+///
+/// - `#[es(text, analyzer = "english")]` - `Property::text().analyzer("english")`
+/// - `#[es(scaled_float(100.0))]` - `Property::scaled_float(100.0)`
+/// - `#[es(object)]` and `#[es(nested)]` - object properties taken from the field type, which
+///   must implement [`EsMapping`] itself
+/// - `#[es(keyword, rename = "id")]` - maps the field under a different name, `serde` field
+///   renames are respected as well
+///
+/// Attributes on the struct itself are passed to the [`Mappings`] builder methods, e.g.
+/// `#[es(dynamic = Dynamic::Strict)]`.
+pub trait EsMapping {
+    /// Returns the mapped fields of the type
+    fn es_properties() -> Map<String, Property>;
+
+    /// Returns the index mappings of the type
+    fn es_mappings() -> Mappings {
+        Self::es_properties()
+            .into_iter()
+            .fold(Mappings::new(), |mappings, (name, property)| {
+                mappings.property(name, property)
+            })
+    }
+}
+
+impl<T> EsMapping for Option<T>
+where
+    T: EsMapping,
+{
+    fn es_properties() -> Map<String, Property> {
+        T::es_properties()
+    }
+}
+
+impl<T> EsMapping for Vec<T>
+where
+    T: EsMapping,
+{
+    fn es_properties() -> Map<String, Property> {
+        T::es_properties()
+    }
+}
+
+impl<T> EsMapping for Box<T>
+where
+    T: EsMapping,
+{
+    fn es_properties() -> Map<String, Property> {
+        T::es_properties()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    struct Seller;
+
+    impl EsMapping for Seller {
+        fn es_properties() -> Map<String, Property> {
+            let mut properties = Map::new();
+            let _ = properties.insert("name".to_string(), Property::keyword().into());
+            properties
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Seller::es_mappings(),
+            json!({ "properties": { "name": { "type": "keyword" } } }),
+        );
+
+        assert_serialize(
+            <Vec<Seller>>::es_mappings(),
+            json!({ "properties": { "name": { "type": "keyword" } } }),
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::indices::*;
+use crate::Map;
+
+/// Elasticsearch get mapping API response, with the mappings of each requested index
+///
+/// Mappings are deserialized into the same types used to define them, so live mappings can be
+/// compared against the expected definitions:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use serde_json::json;
+/// let response: GetMappingResponse = serde_json::from_value(json!({
+///     "products": {
+///         "mappings": {
+///             "properties": {
+///                 "title": { "type": "text", "analyzer": "english" }
+///             }
+///         }
+///     }
+/// }))
+/// .unwrap();
+///
+/// let expected = Mappings::new().property("title", Property::text().analyzer("english"));
+///
+/// assert_eq!(response.index("products"), Some(&expected));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-mapping.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GetMappingResponse {
+    /// Mappings keyed by index name
+    pub indices: Map<String, IndexMappings>,
+}
+
+/// Mappings of a single index
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexMappings {
+    /// Explicit mapping definition of the index
+    #[serde(default)]
+    pub mappings: Mappings,
+}
+
+impl GetMappingResponse {
+    /// Returns the mappings of the given index
+    pub fn index(&self, name: &str) -> Option<&Mappings> {
+        self.indices.get(name).map(|index| &index.mappings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn deserialization() {
+        let response: GetMappingResponse = serde_json::from_value(json!({
+            "products": {
+                "mappings": {
+                    "dynamic": "strict",
+                    "dynamic_templates": [
+                        {
+                            "strings_as_keywords": {
+                                "match_mapping_type": "string",
+                                "mapping": { "type": "keyword" }
+                            }
+                        }
+                    ],
+                    "_source": { "excludes": ["embedding"] },
+                    "_meta": { "version": 2 },
+                    "runtime": {
+                        "day_of_week": {
+                            "type": "keyword",
+                            "script": { "source": "emit('monday')", "lang": "painless" }
+                        }
+                    },
+                    "properties": {
+                        "title": {
+                            "type": "text",
+                            "analyzer": "english",
+                            "fields": {
+                                "raw": { "type": "keyword", "ignore_above": 256 }
+                            }
+                        },
+                        "price": { "type": "scaled_float", "scaling_factor": 100.0 },
+                        "stock": { "type": "integer", "null_value": 0 },
+                        "created_at": { "type": "date_nanos", "format": "strict_date_optional_time" },
+                        "location": { "type": "geo_point" },
+                        "embedding": {
+                            "type": "dense_vector",
+                            "dims": 3,
+                            "index": true,
+                            "similarity": "cosine",
+                            "index_options": { "type": "int8_hnsw", "m": 16, "ef_construction": 100 }
+                        },
+                        "seller": {
+                            "dynamic": "false",
+                            "properties": {
+                                "name": { "type": "keyword", "normalizer": "lowercase" }
+                            }
+                        },
+                        "variants": {
+                            "type": "nested",
+                            "properties": {
+                                "color": { "type": "keyword" }
+                            }
+                        },
+                        "summary": { "type": "semantic_text", "inference_id": "my-elser" },
+                        "hash": { "type": "murmur3" }
+                    }
+                }
+            },
+            "empty": {
+                "mappings": {}
+            }
+        }))
+        .unwrap();
+
+        let expected = Mappings::new()
+            .dynamic(Dynamic::Strict)
+            .dynamic_template(
+                "strings_as_keywords",
+                DynamicTemplate::new()
+                    .match_mapping_type(MatchMappingType::String)
+                    .mapping(Property::keyword()),
+            )
+            .source(SourceField::new().excludes(["embedding"]))
+            .meta("version", 2)
+            .runtime("day_of_week", RuntimeMapping::keyword("emit('monday')"))
+            .property(
+                "title",
+                Property::text()
+                    .analyzer("english")
+                    .field("raw", Property::keyword().ignore_above(256)),
+            )
+            .property("price", Property::scaled_float(100.0))
+            .property("stock", Property::integer().null_value(0))
+            .property(
+                "created_at",
+                Property::date_nanos().format("strict_date_optional_time"),
+            )
+            .property("location", Property::geo_point())
+            .property(
+                "embedding",
+                Property::dense_vector()
+                    .dims(3)
+                    .index(true)
+                    .similarity(DenseVectorSimilarity::Cosine)
+                    .index_options(
+                        DenseVectorIndexOptions::int8_hnsw()
+                            .m(16)
+                            .ef_construction(100),
+                    ),
+            )
+            .property(
+                "seller",
+                Property::object()
+                    .dynamic(false)
+                    .property("name", Property::keyword().normalizer("lowercase")),
+            )
+            .property(
+                "variants",
+                Property::nested().property("color", Property::keyword()),
+            )
+            .property(
+                "summary",
+                Property::semantic_text().inference_id("my-elser"),
+            )
+            .property("hash", json!({ "type": "murmur3" }));
+
+        assert_eq!(response.index("products"), Some(&expected));
+        assert_eq!(response.index("empty"), Some(&Mappings::new()));
+        assert_eq!(response.index("missing"), None);
+    }
+
+    #[test]
+    fn rejects_mismatching_property_type() {
+        assert!(serde_json::from_value::<TextProperty>(json!({ "type": "keyword" })).is_err());
+        assert!(serde_json::from_value::<TextProperty>(json!({ "type": "text" })).is_ok());
+    }
+}
@@ -0,0 +1,341 @@
+//! Mapping is the process of defining how a document, and the fields it contains, are stored and
+//! indexed.
+//!
+//! Each document is a collection of fields, which each have their own
+//! [data type](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-types.html).
+//! When mapping your data, you create a mapping definition, which contains a list of fields that
+//! are pertinent to the document. A mapping definition also includes
+//! [metadata fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-fields.html),
+//! like the `_source` field, which customize how a document’s associated metadata is handled.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping.html>
+
+mod dynamic_template;
+mod es_mapping;
+mod get_mapping_response;
+
+pub mod params;
+pub mod properties;
+
+pub use self::dynamic_template::*;
+pub use self::es_mapping::*;
+pub use self::get_mapping_response::*;
+pub use self::params::*;
+pub use self::properties::*;
+
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// Explicit mapping definition of an index
+///
+/// To create mappings:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let mappings =
+/// Mappings::new()
+///     .dynamic(Dynamic::Strict)
+///     .property("title", Property::text().analyzer("english"))
+///     .property("tags", Property::keyword())
+///     .property("price", Property::scaled_float(100.0))
+///     .property("created_at", Property::date());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/explicit-mapping.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mappings {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    date_detection: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic_date_formats: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    numeric_detection: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic_templates: Vec<KeyValuePair<String, DynamicTemplate>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _source: Option<SourceField>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _routing: Option<RoutingField>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    _meta: Map<String, serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    runtime: Map<String, RuntimeMapping>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Map<String, Property>,
+}
+
+/// The `_source` field contains the original JSON document body that was passed at index time.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-source-field.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceField {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    includes: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    excludes: Vec<String>,
+}
+
+/// A document is routed to a particular shard in an index using the `_routing` value.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-routing-field.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoutingField {
+    required: bool,
+}
+
+impl Mappings {
+    /// Creates an instance of [`Mappings`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Controls whether new fields are added dynamically to the document
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic.html>
+    pub fn dynamic<T>(mut self, dynamic: T) -> Self
+    where
+        T: Into<Dynamic>,
+    {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// If date detection is enabled (default), then new string fields are checked to see
+    /// whether their contents match any of the date patterns specified in
+    /// `dynamic_date_formats`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-field-mapping.html#date-detection>
+    pub fn date_detection(mut self, date_detection: bool) -> Self {
+        self.date_detection = Some(date_detection);
+        self
+    }
+
+    /// Date patterns used by date detection. Defaults to
+    /// `["strict_date_optional_time","yyyy/MM/dd HH:mm:ss Z||yyyy/MM/dd Z"]`.
+    pub fn dynamic_date_formats<T>(mut self, dynamic_date_formats: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.dynamic_date_formats
+            .extend(dynamic_date_formats.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// If numeric detection is enabled, numbers in strings will be detected and mapped as
+    /// numeric fields. Defaults to `false`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-field-mapping.html#numeric-detection>
+    pub fn numeric_detection(mut self, numeric_detection: bool) -> Self {
+        self.numeric_detection = Some(numeric_detection);
+        self
+    }
+
+    /// Adds a named dynamic template, templates are matched in the order they are added
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html>
+    pub fn dynamic_template<T>(mut self, name: T, template: DynamicTemplate) -> Self
+    where
+        T: ToString,
+    {
+        self.dynamic_templates
+            .push(KeyValuePair::new(name.to_string(), template));
+        self
+    }
+
+    /// Configures the `_source` metadata field
+    pub fn source(mut self, source: SourceField) -> Self {
+        self._source = Some(source);
+        self
+    }
+
+    /// Makes a custom routing value required for all CRUD operations on documents
+    pub fn routing_required(mut self, required: bool) -> Self {
+        self._routing = Some(RoutingField { required });
+        self
+    }
+
+    /// Adds custom metadata associated with the mapping. This metadata is not used at all by
+    /// Elasticsearch, but can be used to store application-specific metadata.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-meta-field.html>
+    pub fn meta<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Into<serde_json::Value>,
+    {
+        let _ = self._meta.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Adds a runtime field to the mapping, runtime fields are evaluated at query time and use
+    /// the same definition as the [`runtime_mappings`](crate::Search::runtime_mapping) of a
+    /// search request
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime-mapping-fields.html>
+    pub fn runtime<T>(mut self, name: T, mapping: RuntimeMapping) -> Self
+    where
+        T: ToString,
+    {
+        let _ = self.runtime.insert(name.to_string(), mapping);
+        self
+    }
+
+    /// Adds a field to the mapping
+    pub fn property<T, U>(mut self, name: T, property: U) -> Self
+    where
+        T: ToString,
+        U: Into<Property>,
+    {
+        let _ = self.properties.insert(name.to_string(), property.into());
+        self
+    }
+}
+
+impl ShouldSkip for Mappings {
+    fn should_skip(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl SourceField {
+    /// Creates an instance of [`SourceField`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the `_source` field is stored. Defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Fields to be included in the stored `_source`
+    pub fn includes<T>(mut self, includes: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.includes
+            .extend(includes.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Fields to be pruned from the stored `_source`
+    pub fn excludes<T>(mut self, excludes: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.excludes
+            .extend(excludes.into_iter().map(|x| x.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Mappings::new(), json!({}));
+
+        assert_serialize(
+            Mappings::new()
+                .dynamic(false)
+                .date_detection(false)
+                .dynamic_date_formats(["MM/dd/yyyy"])
+                .numeric_detection(true)
+                .dynamic_template(
+                    "strings_as_keywords",
+                    DynamicTemplate::new()
+                        .match_mapping_type(MatchMappingType::String)
+                        .mapping(Property::keyword()),
+                )
+                .dynamic_template(
+                    "full_name",
+                    DynamicTemplate::new()
+                        .path_match("name.*")
+                        .mapping(Property::text()),
+                )
+                .source(
+                    SourceField::new()
+                        .includes(["*.count", "meta.*"])
+                        .excludes(["meta.description"]),
+                )
+                .routing_required(true)
+                .meta("version", 2)
+                .runtime(
+                    "day_of_week",
+                    RuntimeMapping::keyword(
+                        "emit(doc['created_at'].value.dayOfWeekEnum.toString())",
+                    ),
+                )
+                .property("title", Property::text())
+                .property("tags", Property::keyword())
+                .property(
+                    "user",
+                    Property::nested().property("name", Property::keyword()),
+                ),
+            json!({
+                "dynamic": false,
+                "date_detection": false,
+                "dynamic_date_formats": ["MM/dd/yyyy"],
+                "numeric_detection": true,
+                "dynamic_templates": [
+                    {
+                        "strings_as_keywords": {
+                            "match_mapping_type": "string",
+                            "mapping": { "type": "keyword" }
+                        }
+                    },
+                    {
+                        "full_name": {
+                            "path_match": "name.*",
+                            "mapping": { "type": "text" }
+                        }
+                    }
+                ],
+                "_source": {
+                    "includes": ["*.count", "meta.*"],
+                    "excludes": ["meta.description"]
+                },
+                "_routing": { "required": true },
+                "_meta": { "version": 2 },
+                "runtime": {
+                    "day_of_week": {
+                        "type": "keyword",
+                        "script": {
+                            "source": "emit(doc['created_at'].value.dayOfWeekEnum.toString())"
+                        }
+                    }
+                },
+                "properties": {
+                    "tags": { "type": "keyword" },
+                    "title": { "type": "text" },
+                    "user": {
+                        "type": "nested",
+                        "properties": {
+                            "name": { "type": "keyword" }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,12 @@
+/// Date field types
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateType {
+    /// Dates stored with millisecond resolution
+    Date,
+
+    /// Dates stored with nanosecond resolution
+    DateNanos,
+}
@@ -0,0 +1,15 @@
+/// The data type used to encode vectors
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html#dense-vector-params>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenseVectorElementType {
+    /// Indexes a 4-byte floating-point value per dimension (default)
+    Float,
+
+    /// Indexes a 1-byte integer value per dimension
+    Byte,
+
+    /// Indexes a single bit per dimension, dimensions must be a multiple of `8`
+    Bit,
+}
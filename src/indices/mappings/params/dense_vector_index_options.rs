@@ -0,0 +1,154 @@
+use crate::util::*;
+
+/// The type of kNN algorithm to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenseVectorIndexType {
+    /// HNSW algorithm for approximate kNN search
+    Hnsw,
+
+    /// HNSW algorithm with automatic scalar quantization to 1-byte integers
+    Int8Hnsw,
+
+    /// HNSW algorithm with automatic scalar quantization to half-byte integers
+    Int4Hnsw,
+
+    /// HNSW algorithm with automatic binary quantization
+    BbqHnsw,
+
+    /// Brute-force search algorithm for exact kNN search
+    Flat,
+
+    /// Brute-force search algorithm with automatic scalar quantization to 1-byte integers
+    Int8Flat,
+
+    /// Brute-force search algorithm with automatic scalar quantization to half-byte integers
+    Int4Flat,
+
+    /// Brute-force search algorithm with automatic binary quantization
+    BbqFlat,
+}
+
+/// An optional section that configures the kNN indexing algorithm. The HNSW algorithm has two
+/// internal parameters that influence how the data structure is built. These can be adjusted to
+/// improve the accuracy of results, at the expense of slower indexing speed.
+///
+/// To create index options:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let options =
+/// DenseVectorIndexOptions::int8_hnsw()
+///     .m(32)
+///     .ef_construction(200)
+///     .confidence_interval(0.95);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html#dense-vector-index-options>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DenseVectorIndexOptions {
+    r#type: DenseVectorIndexType,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    m: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ef_construction: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    confidence_interval: Option<f32>,
+}
+
+impl DenseVectorIndexOptions {
+    /// Creates an instance of [`DenseVectorIndexOptions`] with the given kNN algorithm
+    pub fn new(r#type: DenseVectorIndexType) -> Self {
+        Self {
+            r#type,
+            m: None,
+            ef_construction: None,
+            confidence_interval: None,
+        }
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `hnsw` algorithm
+    pub fn hnsw() -> Self {
+        Self::new(DenseVectorIndexType::Hnsw)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `int8_hnsw` algorithm
+    pub fn int8_hnsw() -> Self {
+        Self::new(DenseVectorIndexType::Int8Hnsw)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `int4_hnsw` algorithm
+    pub fn int4_hnsw() -> Self {
+        Self::new(DenseVectorIndexType::Int4Hnsw)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `bbq_hnsw` algorithm
+    pub fn bbq_hnsw() -> Self {
+        Self::new(DenseVectorIndexType::BbqHnsw)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `flat` algorithm
+    pub fn flat() -> Self {
+        Self::new(DenseVectorIndexType::Flat)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `int8_flat` algorithm
+    pub fn int8_flat() -> Self {
+        Self::new(DenseVectorIndexType::Int8Flat)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `int4_flat` algorithm
+    pub fn int4_flat() -> Self {
+        Self::new(DenseVectorIndexType::Int4Flat)
+    }
+
+    /// Creates an instance of [`DenseVectorIndexOptions`] using `bbq_flat` algorithm
+    pub fn bbq_flat() -> Self {
+        Self::new(DenseVectorIndexType::BbqFlat)
+    }
+
+    /// The number of neighbors each node will be connected to in the HNSW graph. Defaults to
+    /// `16`. Only applicable to HNSW based algorithms.
+    pub fn m(mut self, m: u32) -> Self {
+        self.m = Some(m);
+        self
+    }
+
+    /// The number of candidates to track while assembling the list of nearest neighbors for
+    /// each new node. Defaults to `100`. Only applicable to HNSW based algorithms.
+    pub fn ef_construction(mut self, ef_construction: u32) -> Self {
+        self.ef_construction = Some(ef_construction);
+        self
+    }
+
+    /// The confidence interval to use when quantizing the vectors. Can be any value between
+    /// and including `0.90` and `1.0` or exactly `0`. Only applicable to quantized algorithms.
+    pub fn confidence_interval(mut self, confidence_interval: f32) -> Self {
+        self.confidence_interval = Some(confidence_interval);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DenseVectorIndexOptions::flat(), json!({ "type": "flat" }));
+
+        assert_serialize(
+            DenseVectorIndexOptions::int8_hnsw()
+                .m(32)
+                .ef_construction(200)
+                .confidence_interval(0.5),
+            json!({
+                "type": "int8_hnsw",
+                "m": 32,
+                "ef_construction": 200,
+                "confidence_interval": 0.5
+            }),
+        );
+    }
+}
@@ -0,0 +1,20 @@
+/// The vector similarity metric to use in kNN search
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html#dense-vector-similarity>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenseVectorSimilarity {
+    /// Computes similarity based on the `L2` distance (also known as Euclidean distance) between
+    /// the vectors
+    L2Norm,
+
+    /// Computes the dot product of two unit vectors. This option provides an optimized way to
+    /// perform cosine similarity
+    DotProduct,
+
+    /// Computes the cosine similarity
+    Cosine,
+
+    /// Computes the maximum inner product of two vectors, vectors do not need to be normalized
+    MaxInnerProduct,
+}
@@ -0,0 +1,114 @@
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+/// Controls whether new fields are added dynamically to the document or object they are
+/// found in.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dynamic {
+    /// New fields are added to the mapping (default)
+    True,
+
+    /// New fields are ignored. These fields will not be indexed or searchable, but will still
+    /// appear in the `_source` field of returned hits. These fields will not be added to the
+    /// mapping, and new fields must be added explicitly.
+    False,
+
+    /// If new fields are detected, an exception is thrown and the document is rejected. New
+    /// fields must be explicitly added to the mapping.
+    Strict,
+
+    /// New fields are added to the mapping as
+    /// [runtime fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime.html).
+    /// These fields are not indexed, and are loaded from `_source` at query time.
+    Runtime,
+}
+
+impl Serialize for Dynamic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::True => serializer.serialize_bool(true),
+            Self::False => serializer.serialize_bool(false),
+            Self::Strict => serializer.serialize_str("strict"),
+            Self::Runtime => serializer.serialize_str("runtime"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Dynamic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum BoolOrString {
+            Bool(bool),
+            String(String),
+        }
+
+        // The get mapping API may return booleans as strings
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(value) => Ok(value.into()),
+            BoolOrString::String(value) => match value.as_str() {
+                "true" => Ok(Self::True),
+                "false" => Ok(Self::False),
+                "strict" => Ok(Self::Strict),
+                "runtime" => Ok(Self::Runtime),
+                _ => Err(D::Error::unknown_variant(
+                    &value,
+                    &["true", "false", "strict", "runtime"],
+                )),
+            },
+        }
+    }
+}
+
+impl From<bool> for Dynamic {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Dynamic::True,
+                Dynamic::False,
+                Dynamic::Strict,
+                Dynamic::Runtime,
+            ],
+            json!([true, false, "strict", "runtime"]),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        assert_eq!(
+            serde_json::from_value::<Vec<Dynamic>>(json!([true, "false", "strict", "runtime"]))
+                .unwrap(),
+            [
+                Dynamic::True,
+                Dynamic::False,
+                Dynamic::Strict,
+                Dynamic::Runtime
+            ],
+        );
+
+        assert!(serde_json::from_value::<Dynamic>(json!("yes")).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+/// Vertex order for a shape’s coordinates list
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html#geo-shape-mapping-options>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoShapeOrientation {
+    /// Counterclockwise vertex order (default), following the OGC and GeoJSON standards
+    Right,
+
+    /// Clockwise vertex order
+    Left,
+}
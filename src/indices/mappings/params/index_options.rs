@@ -0,0 +1,25 @@
+/// The `index_options` parameter controls what information is added to the inverted index for
+/// search and highlighting purposes. Only term-based field types like `text` and `keyword`
+/// support this configuration.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-options.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexOptions {
+    /// Only the doc number is indexed. Can answer the question _Does this term exist in this
+    /// field?_
+    Docs,
+
+    /// Doc number and term frequencies are indexed. Term frequencies are used to score repeated
+    /// terms higher than single terms.
+    Freqs,
+
+    /// Doc number, term frequencies, and term positions (or order) are indexed. Positions can be
+    /// used for proximity or phrase queries.
+    Positions,
+
+    /// Doc number, term frequencies, positions, and start and end character offsets (which map
+    /// the term back to the original string) are indexed. Offsets are used by the unified
+    /// highlighter to speed up highlighting.
+    Offsets,
+}
@@ -0,0 +1,67 @@
+/// The data type detected by the JSON parser, used to match dynamic templates
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html#match-mapping-type>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMappingType {
+    /// Matches any data type
+    #[serde(rename = "*")]
+    All,
+
+    /// `true` or `false`
+    Boolean,
+
+    /// Binary values, only detected for non-JSON content types
+    Binary,
+
+    /// Strings that match any of the date patterns of `dynamic_date_formats`
+    Date,
+
+    /// Floating point numbers
+    Double,
+
+    /// Integer numbers
+    Long,
+
+    /// Objects
+    Object,
+
+    /// Strings
+    String,
+}
+
+/// The way `match` and `unmatch` patterns of dynamic templates are interpreted
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dynamic-templates.html#match-unmatch>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchPattern {
+    /// Patterns support `*` wildcards (default)
+    Simple,
+
+    /// Patterns are Java regular expressions
+    Regex,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                MatchMappingType::All,
+                MatchMappingType::Boolean,
+                MatchMappingType::Binary,
+                MatchMappingType::Date,
+                MatchMappingType::Double,
+                MatchMappingType::Long,
+                MatchMappingType::Object,
+                MatchMappingType::String,
+            ],
+            json!(["*", "boolean", "binary", "date", "double", "long", "object", "string"]),
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Mapping parameters shared between multiple field data types and the mapping itself
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-params.html>
+
+mod date_type;
+mod dense_vector_element_type;
+mod dense_vector_index_options;
+mod dense_vector_similarity;
+mod dynamic;
+mod geo_shape_orientation;
+mod index_options;
+mod match_mapping_type;
+mod numeric_type;
+mod range_type;
+mod term_vector;
+mod token_pruning_config;
+
+pub use self::date_type::*;
+pub use self::dense_vector_element_type::*;
+pub use self::dense_vector_index_options::*;
+pub use self::dense_vector_similarity::*;
+pub use self::dynamic::*;
+pub use self::geo_shape_orientation::*;
+pub use self::index_options::*;
+pub use self::match_mapping_type::*;
+pub use self::numeric_type::*;
+pub use self::range_type::*;
+pub use self::term_vector::*;
+pub use self::token_pruning_config::*;
@@ -0,0 +1,30 @@
+/// Numeric field types
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumericType {
+    /// A signed 64-bit integer with a minimum value of `-2^63` and a maximum value of `2^63 - 1`
+    Long,
+
+    /// A signed 32-bit integer with a minimum value of `-2^31` and a maximum value of `2^31 - 1`
+    Integer,
+
+    /// A signed 16-bit integer with a minimum value of `-32,768` and a maximum value of `32,767`
+    Short,
+
+    /// A signed 8-bit integer with a minimum value of `-128` and a maximum value of `127`
+    Byte,
+
+    /// A double-precision 64-bit IEEE 754 floating point number, restricted to finite values
+    Double,
+
+    /// A single-precision 32-bit IEEE 754 floating point number, restricted to finite values
+    Float,
+
+    /// A half-precision 16-bit IEEE 754 floating point number, restricted to finite values
+    HalfFloat,
+
+    /// An unsigned 64-bit integer with a minimum value of `0` and a maximum value of `2^64 - 1`
+    UnsignedLong,
+}
@@ -0,0 +1,25 @@
+/// Range field types
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RangeType {
+    /// A range of signed 32-bit integers
+    IntegerRange,
+
+    /// A range of single-precision 32-bit IEEE 754 floating point values
+    FloatRange,
+
+    /// A range of signed 64-bit integers
+    LongRange,
+
+    /// A range of double-precision 64-bit IEEE 754 floating point values
+    DoubleRange,
+
+    /// A range of date values, represented as unsigned 64-bit integer milliseconds elapsed
+    /// since system epoch
+    DateRange,
+
+    /// A range of ip values supporting either IPv4 or IPv6 (or mixed) addresses
+    IpRange,
+}
@@ -0,0 +1,27 @@
+/// Term vectors contain information about the terms produced by the analysis process.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/term-vector.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermVector {
+    /// No term vectors are stored (default)
+    No,
+
+    /// Just the terms in the field are stored
+    Yes,
+
+    /// Terms and positions are stored
+    WithPositions,
+
+    /// Terms and character offsets are stored
+    WithOffsets,
+
+    /// Terms, positions, and character offsets are stored
+    WithPositionsOffsets,
+
+    /// Terms, positions, and payloads are stored
+    WithPositionsPayloads,
+
+    /// Terms, positions, offsets and payloads are stored
+    WithPositionsOffsetsPayloads,
+}
@@ -0,0 +1,67 @@
+use crate::util::*;
+
+/// Token pruning configuration, used to omit non-significant tokens from sparse vector queries
+/// in order to improve query performance
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sparse-vector.html#sparse-vectors-params>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenPruningConfig {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokens_freq_ratio_threshold: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokens_weight_threshold: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    only_score_pruned_tokens: Option<bool>,
+}
+
+impl TokenPruningConfig {
+    /// Creates an instance of [`TokenPruningConfig`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Tokens whose frequency is more than `tokens_freq_ratio_threshold` times the average
+    /// frequency of all tokens in the specified field are considered outliers and pruned.
+    /// Must be between `1` and `100`, defaults to `5`.
+    pub fn tokens_freq_ratio_threshold(mut self, tokens_freq_ratio_threshold: f32) -> Self {
+        self.tokens_freq_ratio_threshold = Some(tokens_freq_ratio_threshold);
+        self
+    }
+
+    /// Tokens whose weight is less than `tokens_weight_threshold` are considered insignificant
+    /// and pruned. Must be between `0` and `1`, defaults to `0.4`.
+    pub fn tokens_weight_threshold(mut self, tokens_weight_threshold: f32) -> Self {
+        self.tokens_weight_threshold = Some(tokens_weight_threshold);
+        self
+    }
+
+    /// Only score the pruned tokens instead of the significant ones, to inspect what is omitted.
+    /// Only supported by the [`sparse_vector`](crate::SparseVectorQuery) query, defaults to
+    /// `false`.
+    pub fn only_score_pruned_tokens(mut self, only_score_pruned_tokens: bool) -> Self {
+        self.only_score_pruned_tokens = Some(only_score_pruned_tokens);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(TokenPruningConfig::new(), json!({}));
+
+        assert_serialize(
+            TokenPruningConfig::new()
+                .tokens_freq_ratio_threshold(5.0)
+                .tokens_weight_threshold(0.5),
+            json!({
+                "tokens_freq_ratio_threshold": 5.0,
+                "tokens_weight_threshold": 0.5
+            }),
+        );
+    }
+}
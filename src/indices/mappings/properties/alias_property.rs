@@ -0,0 +1,46 @@
+use crate::indices::*;
+
+/// An `alias` mapping defines an alternate name for a field in the index. The alias can be used
+/// in place of the target field in search requests, and selected other APIs like field
+/// capabilities.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/field-alias.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct AliasProperty {
+    path: String,
+}
+
+impl Property {
+    /// Creates an instance of [`AliasProperty`]
+    ///
+    /// - `path` - The path to the target field. Note that this must be the full path, including any
+    ///   parent objects.
+    pub fn alias<T>(path: T) -> AliasProperty
+    where
+        T: ToString,
+    {
+        AliasProperty {
+            path: path.to_string(),
+        }
+    }
+}
+
+impl AliasProperty {}
+
+serialize_with_type!("alias": AliasProperty);
+deserialize_with_type!("alias": AliasProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::alias("user.name"),
+            json!({ "type": "alias", "path": "user.name" }),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `binary` type accepts a binary value as a Base64 encoded string. The field is not stored by
+/// default and is not searchable.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/binary.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct BinaryProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`BinaryProperty`]
+    pub fn binary() -> BinaryProperty {
+        BinaryProperty {
+            doc_values: None,
+            store: None,
+        }
+    }
+}
+
+impl BinaryProperty {
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `false`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("binary": BinaryProperty);
+deserialize_with_type!("binary": BinaryProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::binary(), json!({ "type": "binary" }));
+
+        assert_serialize(
+            Property::binary().doc_values(true).store(true),
+            json!({ "type": "binary", "doc_values": true, "store": true }),
+        );
+    }
+}
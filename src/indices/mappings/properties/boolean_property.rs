@@ -0,0 +1,91 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Boolean fields accept JSON `true` and `false` values, but can also accept strings which are
+/// interpreted as either true or false.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/boolean.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct BooleanProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`BooleanProperty`]
+    pub fn boolean() -> BooleanProperty {
+        BooleanProperty {
+            doc_values: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+}
+
+impl BooleanProperty {
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value(mut self, null_value: bool) -> Self {
+        self.null_value = Some(null_value);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("boolean": BooleanProperty);
+deserialize_with_type!("boolean": BooleanProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::boolean(), json!({ "type": "boolean" }));
+
+        assert_serialize(
+            Property::boolean()
+                .doc_values(true)
+                .index(false)
+                .null_value(false)
+                .store(true),
+            json!({
+                "type": "boolean",
+                "doc_values": true,
+                "index": false,
+                "null_value": false,
+                "store": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `completion` type is used by the
+/// [completion suggester](crate::CompletionSuggester) to provide auto-complete/search-as-you-type
+/// functionality.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html#completion-suggester>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct CompletionProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_separators: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preserve_position_increments: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_input_length: Option<u32>,
+}
+
+impl Property {
+    /// Creates an instance of [`CompletionProperty`]
+    pub fn completion() -> CompletionProperty {
+        CompletionProperty {
+            analyzer: None,
+            search_analyzer: None,
+            preserve_separators: None,
+            preserve_position_increments: None,
+            max_input_length: None,
+        }
+    }
+}
+
+impl CompletionProperty {
+    /// The index analyzer to use, defaults to `simple`.
+    pub fn analyzer<T>(mut self, analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.analyzer = Some(analyzer.to_string());
+        self
+    }
+
+    /// The search analyzer to use, defaults to value of `analyzer`.
+    pub fn search_analyzer<T>(mut self, search_analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.search_analyzer = Some(search_analyzer.to_string());
+        self
+    }
+
+    /// Preserves the separators, defaults to `true`. If disabled, you could find a field
+    /// starting with `Foo Fighters`, if you suggest for `foof`.
+    pub fn preserve_separators(mut self, preserve_separators: bool) -> Self {
+        self.preserve_separators = Some(preserve_separators);
+        self
+    }
+
+    /// Enables position increments, defaults to `true`. If disabled and using stopwords
+    /// analyzer, you could get a field starting with `The Beatles`, if you suggest for `b`.
+    pub fn preserve_position_increments(mut self, preserve_position_increments: bool) -> Self {
+        self.preserve_position_increments = Some(preserve_position_increments);
+        self
+    }
+
+    /// Limits the length of a single input, defaults to `50` UTF-16 code points.
+    pub fn max_input_length(mut self, max_input_length: u32) -> Self {
+        self.max_input_length = Some(max_input_length);
+        self
+    }
+}
+
+serialize_with_type!("completion": CompletionProperty);
+deserialize_with_type!("completion": CompletionProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::completion(), json!({ "type": "completion" }));
+
+        assert_serialize(
+            Property::completion()
+                .analyzer("simple")
+                .search_analyzer("standard")
+                .preserve_separators(false)
+                .preserve_position_increments(true)
+                .max_input_length(20),
+            json!({
+                "type": "completion",
+                "analyzer": "simple",
+                "search_analyzer": "standard",
+                "preserve_separators": false,
+                "preserve_position_increments": true,
+                "max_input_length": 20
+            }),
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Constant keyword is a specialization of the [`keyword`](KeywordProperty) field for the case
+/// that all documents in the index have the same value.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html#constant-keyword-field-type>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct ConstantKeywordProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    value: Option<String>,
+}
+
+impl Property {
+    /// Creates an instance of [`ConstantKeywordProperty`]
+    pub fn constant_keyword() -> ConstantKeywordProperty {
+        ConstantKeywordProperty { value: None }
+    }
+}
+
+impl ConstantKeywordProperty {
+    /// The value to associate with all documents in the index. If this parameter is not
+    /// provided, it is set based on the first document that gets indexed.
+    pub fn value<T>(mut self, value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.value = Some(value.to_string());
+        self
+    }
+}
+
+serialize_with_type!("constant_keyword": ConstantKeywordProperty);
+deserialize_with_type!("constant_keyword": ConstantKeywordProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::constant_keyword(),
+            json!({ "type": "constant_keyword" }),
+        );
+
+        assert_serialize(
+            Property::constant_keyword().value("debug"),
+            json!({ "type": "constant_keyword", "value": "debug" }),
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// JSON doesn’t have a date data type, so dates in Elasticsearch can either be strings containing
+/// formatted dates, numbers representing milliseconds since the epoch or numbers representing
+/// seconds since the epoch.
+///
+/// The `date_nanos` variant stores dates in nanosecond resolution, which limits its range of dates
+/// from roughly 1970 to 2262.
+///
+/// To create a date property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::date()
+///     .format("yyyy-MM-dd HH:mm:ss||yyyy-MM-dd||epoch_millis");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/date.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateProperty {
+    r#type: DateType,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    locale: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`DateProperty`] of `date` type
+    pub fn date() -> DateProperty {
+        DateProperty::new(DateType::Date)
+    }
+
+    /// Creates an instance of [`DateProperty`] of `date_nanos` type
+    pub fn date_nanos() -> DateProperty {
+        DateProperty::new(DateType::DateNanos)
+    }
+}
+
+impl DateProperty {
+    /// Creates an instance of [`DateProperty`] of the given type
+    pub fn new(r#type: DateType) -> Self {
+        Self {
+            r#type,
+            copy_to: Vec::new(),
+            doc_values: None,
+            format: None,
+            ignore_malformed: None,
+            index: None,
+            locale: None,
+            null_value: None,
+            store: None,
+        }
+    }
+
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// The date format(s) that can be parsed. Defaults to
+    /// `strict_date_optional_time||epoch_millis`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html>
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// If `true`, malformed values are ignored. If `false` (default), malformed values throw an
+    /// exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// The locale to use when parsing dates since months do not have the same names and/or
+    /// abbreviations in all languages. Defaults to the `ROOT` locale.
+    pub fn locale<T>(mut self, locale: T) -> Self
+    where
+        T: ToString,
+    {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.null_value = Some(null_value.to_string());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [Property::date(), Property::date_nanos()],
+            json!([{ "type": "date" }, { "type": "date_nanos" }]),
+        );
+
+        assert_serialize(
+            Property::date()
+                .copy_to(["all"])
+                .doc_values(false)
+                .format("yyyy-MM-dd")
+                .ignore_malformed(true)
+                .index(true)
+                .locale("en")
+                .null_value("1970-01-01")
+                .store(true),
+            json!({
+                "type": "date",
+                "copy_to": ["all"],
+                "doc_values": false,
+                "format": "yyyy-MM-dd",
+                "ignore_malformed": true,
+                "index": true,
+                "locale": "en",
+                "null_value": "1970-01-01",
+                "store": true
+            }),
+        );
+    }
+}
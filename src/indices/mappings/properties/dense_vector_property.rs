@@ -0,0 +1,115 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `dense_vector` field type stores dense vectors of numeric values. Dense vector fields are
+/// primarily used for [k-nearest neighbor (kNN) search](crate::Knn).
+///
+/// To create a dense vector property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::dense_vector()
+///     .dims(384)
+///     .similarity(DenseVectorSimilarity::DotProduct)
+///     .index_options(DenseVectorIndexOptions::int8_hnsw().m(16));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct DenseVectorProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dims: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    element_type: Option<DenseVectorElementType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<DenseVectorSimilarity>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_options: Option<DenseVectorIndexOptions>,
+}
+
+impl Property {
+    /// Creates an instance of [`DenseVectorProperty`]
+    pub fn dense_vector() -> DenseVectorProperty {
+        DenseVectorProperty {
+            dims: None,
+            element_type: None,
+            index: None,
+            similarity: None,
+            index_options: None,
+        }
+    }
+}
+
+impl DenseVectorProperty {
+    /// Number of vector dimensions. Can’t exceed `4096`. If `dims` is not specified, it will be
+    /// set to the length of the first vector added to the field.
+    pub fn dims(mut self, dims: u32) -> Self {
+        self.dims = Some(dims);
+        self
+    }
+
+    /// The data type used to encode vectors. Defaults to `float`.
+    pub fn element_type(mut self, element_type: DenseVectorElementType) -> Self {
+        self.element_type = Some(element_type);
+        self
+    }
+
+    /// If `true`, you can search this field using the kNN search API. Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// The vector similarity metric to use in kNN search. Defaults to `l2_norm` when
+    /// `element_type` is `bit`, otherwise defaults to `cosine`.
+    pub fn similarity(mut self, similarity: DenseVectorSimilarity) -> Self {
+        self.similarity = Some(similarity);
+        self
+    }
+
+    /// An optional section that configures the kNN indexing algorithm.
+    pub fn index_options(mut self, index_options: DenseVectorIndexOptions) -> Self {
+        self.index_options = Some(index_options);
+        self
+    }
+}
+
+serialize_with_type!("dense_vector": DenseVectorProperty);
+deserialize_with_type!("dense_vector": DenseVectorProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::dense_vector(), json!({ "type": "dense_vector" }));
+
+        assert_serialize(
+            Property::dense_vector()
+                .dims(3)
+                .element_type(DenseVectorElementType::Byte)
+                .index(true)
+                .similarity(DenseVectorSimilarity::DotProduct)
+                .index_options(DenseVectorIndexOptions::hnsw().m(16).ef_construction(100)),
+            json!({
+                "type": "dense_vector",
+                "dims": 3,
+                "element_type": "byte",
+                "index": true,
+                "similarity": "dot_product",
+                "index_options": {
+                    "type": "hnsw",
+                    "m": 16,
+                    "ef_construction": 100
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// By default, each subfield in an object is mapped and indexed separately. The `flattened` type
+/// provides an alternative approach, where the entire object is mapped as a single field. Given
+/// an object, the `flattened` mapping will parse out its leaf values and index them into one field
+/// as keywords.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/flattened.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct FlattenedProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    depth_limit: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    eager_global_ordinals: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_above: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    split_queries_on_whitespace: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`FlattenedProperty`]
+    pub fn flattened() -> FlattenedProperty {
+        FlattenedProperty {
+            depth_limit: None,
+            doc_values: None,
+            eager_global_ordinals: None,
+            ignore_above: None,
+            index: None,
+            null_value: None,
+            split_queries_on_whitespace: None,
+        }
+    }
+}
+
+impl FlattenedProperty {
+    /// The maximum allowed depth of the flattened object field, in terms of nested inner objects.
+    /// Defaults to `20`.
+    pub fn depth_limit(mut self, depth_limit: u32) -> Self {
+        self.depth_limit = Some(depth_limit);
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Should global ordinals be loaded eagerly on refresh? Defaults to `false`. Enabling this
+    /// is a good idea on fields that are frequently used for (significant) terms aggregations.
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+
+    /// Leaf values longer than this limit will not be indexed. By default, there is no limit and
+    /// all values will be indexed.
+    pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+        self.ignore_above = Some(ignore_above);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.null_value = Some(null_value.to_string());
+        self
+    }
+
+    /// Whether full text queries should split the input on whitespace when building a query for
+    /// this field. Defaults to `false`.
+    pub fn split_queries_on_whitespace(mut self, split_queries_on_whitespace: bool) -> Self {
+        self.split_queries_on_whitespace = Some(split_queries_on_whitespace);
+        self
+    }
+}
+
+serialize_with_type!("flattened": FlattenedProperty);
+deserialize_with_type!("flattened": FlattenedProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::flattened(), json!({ "type": "flattened" }));
+
+        assert_serialize(
+            Property::flattened()
+                .depth_limit(5)
+                .doc_values(true)
+                .eager_global_ordinals(false)
+                .ignore_above(100)
+                .index(true)
+                .null_value("NULL")
+                .split_queries_on_whitespace(false),
+            json!({
+                "type": "flattened",
+                "depth_limit": 5,
+                "doc_values": true,
+                "eager_global_ordinals": false,
+                "ignore_above": 100,
+                "index": true,
+                "null_value": "NULL",
+                "split_queries_on_whitespace": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// Fields of type `geo_point` accept latitude-longitude pairs, which can be used to find geo-points
+/// within a bounding box, within a certain distance of a central point, or within a polygon, to
+/// aggregate documents geographically and to sort documents by distance.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct GeoPointProperty {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_z_value: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<GeoLocation>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`GeoPointProperty`]
+    pub fn geo_point() -> GeoPointProperty {
+        GeoPointProperty {
+            copy_to: Vec::new(),
+            doc_values: None,
+            ignore_malformed: None,
+            ignore_z_value: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+}
+
+impl GeoPointProperty {
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed geo-points are ignored. If `false` (default), malformed geo-points
+    /// throw an exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// If `true` (default) three dimension points will be accepted (stored in source) but only
+    /// latitude and longitude values will be indexed; the third dimension is ignored.
+    pub fn ignore_z_value(mut self, ignore_z_value: bool) -> Self {
+        self.ignore_z_value = Some(ignore_z_value);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: Into<GeoLocation>,
+    {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("geo_point": GeoPointProperty);
+deserialize_with_type!("geo_point": GeoPointProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::geo_point(), json!({ "type": "geo_point" }));
+
+        assert_serialize(
+            Property::geo_point()
+                .copy_to(["all"])
+                .doc_values(false)
+                .ignore_malformed(true)
+                .ignore_z_value(false)
+                .index(true)
+                .null_value(GeoLocation::new(40.12, -71.34))
+                .store(true),
+            json!({
+                "type": "geo_point",
+                "copy_to": ["all"],
+                "doc_values": false,
+                "ignore_malformed": true,
+                "ignore_z_value": false,
+                "index": true,
+                "null_value": [-71.34, 40.12],
+                "store": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `geo_shape` data type facilitates the indexing of and searching with arbitrary geo shapes
+/// such as rectangles and polygons.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct GeoShapeProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_z_value: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    orientation: Option<GeoShapeOrientation>,
+}
+
+impl Property {
+    /// Creates an instance of [`GeoShapeProperty`]
+    pub fn geo_shape() -> GeoShapeProperty {
+        GeoShapeProperty {
+            coerce: None,
+            doc_values: None,
+            ignore_malformed: None,
+            ignore_z_value: None,
+            index: None,
+            orientation: None,
+        }
+    }
+}
+
+impl GeoShapeProperty {
+    /// If `true` unclosed linear rings in polygons will be automatically closed. Defaults to
+    /// `false`.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed GeoJSON or WKT shapes are ignored. If `false` (default), malformed
+    /// shapes throw an exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// If `true` (default) three dimension points will be accepted (stored in source) but only
+    /// latitude and longitude values will be indexed; the third dimension is ignored.
+    pub fn ignore_z_value(mut self, ignore_z_value: bool) -> Self {
+        self.ignore_z_value = Some(ignore_z_value);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Default vertex order for the field’s WKT polygons.
+    pub fn orientation(mut self, orientation: GeoShapeOrientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+}
+
+serialize_with_type!("geo_shape": GeoShapeProperty);
+deserialize_with_type!("geo_shape": GeoShapeProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::geo_shape(), json!({ "type": "geo_shape" }));
+
+        assert_serialize(
+            Property::geo_shape()
+                .coerce(true)
+                .doc_values(true)
+                .ignore_malformed(false)
+                .ignore_z_value(true)
+                .index(true)
+                .orientation(GeoShapeOrientation::Left),
+            json!({
+                "type": "geo_shape",
+                "coerce": true,
+                "doc_values": true,
+                "ignore_malformed": false,
+                "ignore_z_value": true,
+                "index": true,
+                "orientation": "left"
+            }),
+        );
+    }
+}
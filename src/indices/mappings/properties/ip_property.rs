@@ -0,0 +1,124 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// An `ip` field can index/store either IPv4 or IPv6 addresses.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/ip.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct IpProperty {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`IpProperty`]
+    pub fn ip() -> IpProperty {
+        IpProperty {
+            copy_to: Vec::new(),
+            doc_values: None,
+            ignore_malformed: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+}
+
+impl IpProperty {
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed values are ignored. If `false` (default), malformed values throw an
+    /// exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.null_value = Some(null_value.to_string());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("ip": IpProperty);
+deserialize_with_type!("ip": IpProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::ip(), json!({ "type": "ip" }));
+
+        assert_serialize(
+            Property::ip()
+                .copy_to(["all"])
+                .doc_values(true)
+                .ignore_malformed(true)
+                .index(true)
+                .null_value("127.0.0.1")
+                .store(false),
+            json!({
+                "type": "ip",
+                "copy_to": ["all"],
+                "doc_values": true,
+                "ignore_malformed": true,
+                "index": true,
+                "null_value": "127.0.0.1",
+                "store": false
+            }),
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// The `join` data type is a special field that creates parent/child relation within documents of
+/// the same index. The `relations` section defines a set of possible relations within the
+/// documents, each relation being a parent name and a child name.
+///
+/// To create a join property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::join()
+///     .relation("question", ["answer", "comment"])
+///     .relation("answer", ["vote"]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct JoinProperty {
+    relations: Map<String, Vec<String>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    eager_global_ordinals: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`JoinProperty`]
+    pub fn join() -> JoinProperty {
+        JoinProperty {
+            relations: Map::new(),
+            eager_global_ordinals: None,
+        }
+    }
+}
+
+impl JoinProperty {
+    /// The `join` field uses global ordinals to speed up joins. Global ordinals are loaded
+    /// eagerly by default, set to `false` if the join field is used infrequently.
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+
+    /// Defines a relation between a parent name and one or more child names
+    pub fn relation<T, U>(mut self, parent: T, children: U) -> Self
+    where
+        T: ToString,
+        U: IntoIterator,
+        U::Item: ToString,
+    {
+        self.relations
+            .entry(parent.to_string())
+            .or_default()
+            .extend(children.into_iter().map(|x| x.to_string()));
+        self
+    }
+}
+
+serialize_with_type!("join": JoinProperty);
+deserialize_with_type!("join": JoinProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::join()
+                .relation("question", ["answer", "comment"])
+                .relation("answer", ["vote"])
+                .eager_global_ordinals(false),
+            json!({
+                "type": "join",
+                "relations": {
+                    "answer": ["vote"],
+                    "question": ["answer", "comment"]
+                },
+                "eager_global_ordinals": false
+            }),
+        );
+    }
+}
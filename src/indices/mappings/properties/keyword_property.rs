@@ -0,0 +1,240 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// A field to index structured content such as IDs, email addresses, hostnames, status codes,
+/// zip codes or tags. Keyword fields are typically used in sorting, aggregations, and term-level
+/// queries, such as `term`.
+///
+/// To create a keyword property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::keyword()
+///     .ignore_above(256)
+///     .null_value("NULL");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct KeywordProperty {
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    eager_global_ordinals: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    fields: Map<String, Property>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_above: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_options: Option<IndexOptions>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    normalizer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    norms: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    split_queries_on_whitespace: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`KeywordProperty`]
+    pub fn keyword() -> KeywordProperty {
+        KeywordProperty {
+            copy_to: Vec::new(),
+            doc_values: None,
+            eager_global_ordinals: None,
+            fields: Map::new(),
+            ignore_above: None,
+            index: None,
+            index_options: None,
+            normalizer: None,
+            norms: None,
+            null_value: None,
+            similarity: None,
+            split_queries_on_whitespace: None,
+            store: None,
+        }
+    }
+}
+
+impl KeywordProperty {
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Should global ordinals be loaded eagerly on refresh? Defaults to `false`. Enabling this
+    /// is a good idea on fields that are frequently used for (significant) terms aggregations.
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+
+    /// Adds a multi-field, which indexes the same value in a different way for a different
+    /// purpose, e.g. a `text` sub-field of a `keyword` field for full-text search.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-fields.html>
+    pub fn field<T, U>(mut self, name: T, property: U) -> Self
+    where
+        T: ToString,
+        U: Into<Property>,
+    {
+        let _ = self.fields.insert(name.to_string(), property.into());
+        self
+    }
+
+    /// Do not index any string longer than this value. Defaults to `2147483647` so that all
+    /// values would be accepted.
+    pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+        self.ignore_above = Some(ignore_above);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// What information should be stored in the index, for search and highlighting purposes. Defaults to
+    /// `docs`.
+    pub fn index_options(mut self, index_options: IndexOptions) -> Self {
+        self.index_options = Some(index_options);
+        self
+    }
+
+    /// How to pre-process the keyword prior to indexing. Defaults to `null`, meaning the keyword
+    /// is kept as-is.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/normalizer.html>
+    pub fn normalizer<T>(mut self, normalizer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.normalizer = Some(normalizer.to_string());
+        self
+    }
+
+    /// Whether field-length should be taken into account when scoring queries. Defaults to `false`.
+    pub fn norms(mut self, norms: bool) -> Self {
+        self.norms = Some(norms);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.null_value = Some(null_value.to_string());
+        self
+    }
+
+    /// Which scoring algorithm or similarity should be used. Defaults to `BM25`.
+    pub fn similarity<T>(mut self, similarity: T) -> Self
+    where
+        T: ToString,
+    {
+        self.similarity = Some(similarity.to_string());
+        self
+    }
+
+    /// Whether full text queries should split the input on whitespace when building a query for
+    /// this field. Defaults to `false`.
+    pub fn split_queries_on_whitespace(mut self, split_queries_on_whitespace: bool) -> Self {
+        self.split_queries_on_whitespace = Some(split_queries_on_whitespace);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("keyword": KeywordProperty);
+deserialize_with_type!("keyword": KeywordProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::keyword(), json!({ "type": "keyword" }));
+
+        assert_serialize(
+            Property::keyword()
+                .copy_to(["all", "other"])
+                .doc_values(false)
+                .eager_global_ordinals(true)
+                .field("text", Property::text().analyzer("english"))
+                .ignore_above(256)
+                .index(false)
+                .index_options(IndexOptions::Freqs)
+                .normalizer("lowercase")
+                .norms(true)
+                .null_value("NULL")
+                .similarity("BM25")
+                .split_queries_on_whitespace(true)
+                .store(true),
+            json!({
+                "type": "keyword",
+                "copy_to": ["all", "other"],
+                "doc_values": false,
+                "eager_global_ordinals": true,
+                "fields": {
+                    "text": { "type": "text", "analyzer": "english" }
+                },
+                "ignore_above": 256,
+                "index": false,
+                "index_options": "freqs",
+                "normalizer": "lowercase",
+                "norms": true,
+                "null_value": "NULL",
+                "similarity": "BM25",
+                "split_queries_on_whitespace": true,
+                "store": true
+            }),
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Each field has a field data type, or field type. This type indicates the kind of data the
+//! field contains, such as strings or boolean values, and its intended use.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-types.html>
+
+mod alias_property;
+mod binary_property;
+mod boolean_property;
+mod completion_property;
+mod constant_keyword_property;
+mod date_property;
+mod dense_vector_property;
+mod flattened_property;
+mod geo_point_property;
+mod geo_shape_property;
+mod ip_property;
+mod join_property;
+mod keyword_property;
+mod nested_property;
+mod numeric_property;
+mod object_property;
+mod percolator_property;
+mod range_property;
+mod rank_feature_property;
+mod rank_features_property;
+mod scaled_float_property;
+mod search_as_you_type_property;
+mod semantic_text_property;
+mod sparse_vector_property;
+mod text_property;
+mod token_count_property;
+mod wildcard_property;
+
+pub use self::alias_property::*;
+pub use self::binary_property::*;
+pub use self::boolean_property::*;
+pub use self::completion_property::*;
+pub use self::constant_keyword_property::*;
+pub use self::date_property::*;
+pub use self::dense_vector_property::*;
+pub use self::flattened_property::*;
+pub use self::geo_point_property::*;
+pub use self::geo_shape_property::*;
+pub use self::ip_property::*;
+pub use self::join_property::*;
+pub use self::keyword_property::*;
+pub use self::nested_property::*;
+pub use self::numeric_property::*;
+pub use self::object_property::*;
+pub use self::percolator_property::*;
+pub use self::range_property::*;
+pub use self::rank_feature_property::*;
+pub use self::rank_features_property::*;
+pub use self::scaled_float_property::*;
+pub use self::search_as_you_type_property::*;
+pub use self::semantic_text_property::*;
+pub use self::sparse_vector_property::*;
+pub use self::text_property::*;
+pub use self::token_count_property::*;
+pub use self::wildcard_property::*;
+
+macro_rules! property {
+    ($($variant:ident($property:ty)),+ $(,)?) => {
+        /// A container enum for supported Elasticsearch field data types
+        #[derive(Debug, Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        #[allow(missing_docs)]
+        pub enum Property {
+            $(
+                $variant($property),
+            )*
+
+            /// Field data type not covered by the typed properties, e.g. one provided by a plugin
+            Custom(serde_json::Value),
+        }
+
+        $(
+            impl From<$property> for Property {
+                fn from(property: $property) -> Self {
+                    Property::$variant(property)
+                }
+            }
+        )+
+
+        impl From<serde_json::Value> for Property {
+            fn from(property: serde_json::Value) -> Self {
+                Property::Custom(property)
+            }
+        }
+    };
+}
+
+property!(
+    Text(TextProperty),
+    Keyword(KeywordProperty),
+    ConstantKeyword(ConstantKeywordProperty),
+    Wildcard(WildcardProperty),
+    Numeric(NumericProperty),
+    ScaledFloat(ScaledFloatProperty),
+    Range(RangeProperty),
+    Date(DateProperty),
+    Boolean(BooleanProperty),
+    Binary(BinaryProperty),
+    Ip(IpProperty),
+    Object(ObjectProperty),
+    Nested(NestedProperty),
+    Flattened(FlattenedProperty),
+    GeoPoint(GeoPointProperty),
+    GeoShape(GeoShapeProperty),
+    Join(JoinProperty),
+    Completion(CompletionProperty),
+    SearchAsYouType(SearchAsYouTypeProperty),
+    TokenCount(TokenCountProperty),
+    Alias(AliasProperty),
+    RankFeature(RankFeatureProperty),
+    RankFeatures(RankFeaturesProperty),
+    DenseVector(DenseVectorProperty),
+    SparseVector(SparseVectorProperty),
+    SemanticText(SemanticTextProperty),
+    Percolator(PercolatorProperty),
+);
+
+impl<'de> serde::Deserialize<'de> for Property {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use crate::util::Lenient;
+        use serde::de::Error;
+        use serde::Deserialize;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+
+        // Object fields are returned without a type by the get mapping API
+        let r#type = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("object");
+
+        let property = match r#type {
+            "text" => Deserialize::deserialize(Lenient::new(value)).map(Self::Text),
+            "keyword" => Deserialize::deserialize(Lenient::new(value)).map(Self::Keyword),
+            "constant_keyword" => {
+                Deserialize::deserialize(Lenient::new(value)).map(Self::ConstantKeyword)
+            }
+            "wildcard" => Deserialize::deserialize(Lenient::new(value)).map(Self::Wildcard),
+            "long" | "integer" | "short" | "byte" | "double" | "float" | "half_float"
+            | "unsigned_long" => Deserialize::deserialize(Lenient::new(value)).map(Self::Numeric),
+            "integer_range" | "float_range" | "long_range" | "double_range" | "date_range"
+            | "ip_range" => Deserialize::deserialize(Lenient::new(value)).map(Self::Range),
+            "scaled_float" => Deserialize::deserialize(Lenient::new(value)).map(Self::ScaledFloat),
+            "date" | "date_nanos" => Deserialize::deserialize(Lenient::new(value)).map(Self::Date),
+            "boolean" => Deserialize::deserialize(Lenient::new(value)).map(Self::Boolean),
+            "binary" => Deserialize::deserialize(Lenient::new(value)).map(Self::Binary),
+            "ip" => Deserialize::deserialize(Lenient::new(value)).map(Self::Ip),
+            "object" => Deserialize::deserialize(Lenient::new(value)).map(Self::Object),
+            "nested" => Deserialize::deserialize(Lenient::new(value)).map(Self::Nested),
+            "flattened" => Deserialize::deserialize(Lenient::new(value)).map(Self::Flattened),
+            "geo_point" => Deserialize::deserialize(Lenient::new(value)).map(Self::GeoPoint),
+            "geo_shape" => Deserialize::deserialize(Lenient::new(value)).map(Self::GeoShape),
+            "join" => Deserialize::deserialize(Lenient::new(value)).map(Self::Join),
+            "completion" => Deserialize::deserialize(Lenient::new(value)).map(Self::Completion),
+            "search_as_you_type" => {
+                Deserialize::deserialize(Lenient::new(value)).map(Self::SearchAsYouType)
+            }
+            "token_count" => Deserialize::deserialize(Lenient::new(value)).map(Self::TokenCount),
+            "alias" => Deserialize::deserialize(Lenient::new(value)).map(Self::Alias),
+            "rank_feature" => Deserialize::deserialize(Lenient::new(value)).map(Self::RankFeature),
+            "rank_features" => {
+                Deserialize::deserialize(Lenient::new(value)).map(Self::RankFeatures)
+            }
+            "dense_vector" => Deserialize::deserialize(Lenient::new(value)).map(Self::DenseVector),
+            "sparse_vector" => {
+                Deserialize::deserialize(Lenient::new(value)).map(Self::SparseVector)
+            }
+            "percolator" => Deserialize::deserialize(Lenient::new(value)).map(Self::Percolator),
+            "semantic_text" => {
+                Deserialize::deserialize(Lenient::new(value)).map(Self::SemanticText)
+            }
+            _ => Ok(Self::Custom(value)),
+        };
+
+        property.map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// The `nested` type is a specialised version of the [`object`](ObjectProperty) data type that
+/// allows arrays of objects to be indexed in a way that they can be queried independently of each
+/// other.
+///
+/// To create a nested property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::nested()
+///     .property("first", Property::text())
+///     .property("last", Property::text());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/nested.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct NestedProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_in_parent: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include_in_root: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Map<String, Property>,
+}
+
+impl Property {
+    /// Creates an instance of [`NestedProperty`]
+    pub fn nested() -> NestedProperty {
+        NestedProperty {
+            dynamic: None,
+            include_in_parent: None,
+            include_in_root: None,
+            properties: Map::new(),
+        }
+    }
+}
+
+impl NestedProperty {
+    /// Whether or not new `properties` should be added dynamically to an existing nested object.
+    pub fn dynamic<T>(mut self, dynamic: T) -> Self
+    where
+        T: Into<Dynamic>,
+    {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// If `true`, all fields in the nested object are also added to the parent document as
+    /// standard (flat) fields. Defaults to `false`.
+    pub fn include_in_parent(mut self, include_in_parent: bool) -> Self {
+        self.include_in_parent = Some(include_in_parent);
+        self
+    }
+
+    /// If `true`, all fields in the nested object are also added to the root document as standard
+    /// (flat) fields. Defaults to `false`.
+    pub fn include_in_root(mut self, include_in_root: bool) -> Self {
+        self.include_in_root = Some(include_in_root);
+        self
+    }
+
+    /// Adds a field within the nested object, which can be of any data type, including
+    /// `nested`.
+    pub fn property<T, U>(mut self, name: T, property: U) -> Self
+    where
+        T: ToString,
+        U: Into<Property>,
+    {
+        let _ = self.properties.insert(name.to_string(), property.into());
+        self
+    }
+}
+
+serialize_with_type!("nested": NestedProperty);
+deserialize_with_type!("nested": NestedProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::nested(), json!({ "type": "nested" }));
+
+        assert_serialize(
+            Property::nested()
+                .dynamic(Dynamic::Runtime)
+                .include_in_parent(true)
+                .include_in_root(false)
+                .property("first", Property::text())
+                .property("last", Property::keyword()),
+            json!({
+                "type": "nested",
+                "dynamic": "runtime",
+                "include_in_parent": true,
+                "include_in_root": false,
+                "properties": {
+                    "first": { "type": "text" },
+                    "last": { "type": "keyword" }
+                }
+            }),
+        );
+    }
+}
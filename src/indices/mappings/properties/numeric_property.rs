@@ -0,0 +1,211 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// Numeric field types: `long`, `integer`, `short`, `byte`, `double`, `float`, `half_float` and
+/// `unsigned_long`.
+///
+/// As far as integer types are concerned, picking the smallest type which is enough for the use
+/// case will help indexing and searching be more efficient. For floating-point types, it is often
+/// more efficient to store floating-point data into an integer using a scaling factor, which is
+/// what the [`scaled_float`](ScaledFloatProperty) type does under the hood.
+///
+/// To create a numeric property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::long()
+///     .coerce(false)
+///     .null_value(0);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumericProperty {
+    r#type: NumericType,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    coerce: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`NumericProperty`] of `long` type
+    pub fn long() -> NumericProperty {
+        NumericProperty::new(NumericType::Long)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `integer` type
+    pub fn integer() -> NumericProperty {
+        NumericProperty::new(NumericType::Integer)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `short` type
+    pub fn short() -> NumericProperty {
+        NumericProperty::new(NumericType::Short)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `byte` type
+    pub fn byte() -> NumericProperty {
+        NumericProperty::new(NumericType::Byte)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `double` type
+    pub fn double() -> NumericProperty {
+        NumericProperty::new(NumericType::Double)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `float` type
+    pub fn float() -> NumericProperty {
+        NumericProperty::new(NumericType::Float)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `half_float` type
+    pub fn half_float() -> NumericProperty {
+        NumericProperty::new(NumericType::HalfFloat)
+    }
+
+    /// Creates an instance of [`NumericProperty`] of `unsigned_long` type
+    pub fn unsigned_long() -> NumericProperty {
+        NumericProperty::new(NumericType::UnsignedLong)
+    }
+}
+
+impl NumericProperty {
+    /// Creates an instance of [`NumericProperty`] of the given type
+    pub fn new(r#type: NumericType) -> Self {
+        Self {
+            r#type,
+            coerce: None,
+            copy_to: Vec::new(),
+            doc_values: None,
+            ignore_malformed: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+
+    /// Try to convert strings to numbers and truncate fractions for integers. Defaults to `true`.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed values are ignored. If `false` (default), malformed values throw an
+    /// exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Property::long(),
+                Property::integer(),
+                Property::short(),
+                Property::byte(),
+                Property::double(),
+                Property::float(),
+                Property::half_float(),
+                Property::unsigned_long(),
+            ],
+            json!([
+                { "type": "long" },
+                { "type": "integer" },
+                { "type": "short" },
+                { "type": "byte" },
+                { "type": "double" },
+                { "type": "float" },
+                { "type": "half_float" },
+                { "type": "unsigned_long" },
+            ]),
+        );
+
+        assert_serialize(
+            Property::long()
+                .coerce(false)
+                .copy_to(["all"])
+                .doc_values(true)
+                .ignore_malformed(true)
+                .index(false)
+                .null_value(-1)
+                .store(true),
+            json!({
+                "type": "long",
+                "coerce": false,
+                "copy_to": ["all"],
+                "doc_values": true,
+                "ignore_malformed": true,
+                "index": false,
+                "null_value": -1,
+                "store": true
+            }),
+        );
+    }
+}
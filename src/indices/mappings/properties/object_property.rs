@@ -0,0 +1,111 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// JSON documents are hierarchical in nature: the document may contain inner objects which, in
+/// turn, may contain inner objects themselves. Internally, documents are indexed as a simple, flat
+/// list of key-value pairs.
+///
+/// To create an object property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::object()
+///     .property("first", Property::text())
+///     .property("last", Property::text());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/object.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct ObjectProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    dynamic: Option<Dynamic>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    enabled: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    properties: Map<String, Property>,
+}
+
+impl Property {
+    /// Creates an instance of [`ObjectProperty`]
+    pub fn object() -> ObjectProperty {
+        ObjectProperty {
+            dynamic: None,
+            enabled: None,
+            properties: Map::new(),
+        }
+    }
+}
+
+impl ObjectProperty {
+    /// Whether or not new `properties` should be added dynamically to an existing object.
+    pub fn dynamic<T>(mut self, dynamic: T) -> Self
+    where
+        T: Into<Dynamic>,
+    {
+        self.dynamic = Some(dynamic.into());
+        self
+    }
+
+    /// Whether the JSON value given for the object field should be parsed and indexed (`true`,
+    /// default) or completely ignored (`false`).
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Adds a field within the object, which can be of any data type, including `object`.
+    pub fn property<T, U>(mut self, name: T, property: U) -> Self
+    where
+        T: ToString,
+        U: Into<Property>,
+    {
+        let _ = self.properties.insert(name.to_string(), property.into());
+        self
+    }
+}
+
+serialize_with_type!("object": ObjectProperty);
+deserialize_with_type!("object": ObjectProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::object(), json!({ "type": "object" }));
+
+        assert_serialize(
+            Property::object()
+                .dynamic(Dynamic::Strict)
+                .enabled(true)
+                .property("age", Property::integer())
+                .property(
+                    "name",
+                    Property::object()
+                        .dynamic(false)
+                        .property("first", Property::text())
+                        .property("last", Property::text()),
+                ),
+            json!({
+                "type": "object",
+                "dynamic": "strict",
+                "enabled": true,
+                "properties": {
+                    "age": { "type": "integer" },
+                    "name": {
+                        "type": "object",
+                        "dynamic": false,
+                        "properties": {
+                            "first": { "type": "text" },
+                            "last": { "type": "text" }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::indices::*;
+
+/// The `percolator` field type parses a json structure into a native query and stores that query,
+/// so that the [`percolate`](crate::PercolateQuery) query can use it to match provided documents.
+///
+/// Use [`PercolatorDocument`] to index a typed query into a percolator field.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/percolator.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct PercolatorProperty {}
+
+impl Property {
+    /// Creates an instance of [`PercolatorProperty`]
+    pub fn percolator() -> PercolatorProperty {
+        PercolatorProperty {}
+    }
+}
+
+serialize_with_type!("percolator": PercolatorProperty);
+deserialize_with_type!("percolator": PercolatorProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::percolator(), json!({ "type": "percolator" }));
+    }
+}
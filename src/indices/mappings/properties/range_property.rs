@@ -0,0 +1,167 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// Range field types represent a continuous range of values between an upper and lower bound:
+/// `integer_range`, `float_range`, `long_range`, `double_range`, `date_range` and `ip_range`.
+/// Documents are matched by `term` and `range` queries intersecting the indexed ranges.
+///
+/// To create a range property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::date_range().format("yyyy-MM-dd||epoch_millis");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/range.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RangeProperty {
+    r#type: RangeType,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    coerce: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`RangeProperty`] of `integer_range` type
+    pub fn integer_range() -> RangeProperty {
+        RangeProperty::new(RangeType::IntegerRange)
+    }
+
+    /// Creates an instance of [`RangeProperty`] of `float_range` type
+    pub fn float_range() -> RangeProperty {
+        RangeProperty::new(RangeType::FloatRange)
+    }
+
+    /// Creates an instance of [`RangeProperty`] of `long_range` type
+    pub fn long_range() -> RangeProperty {
+        RangeProperty::new(RangeType::LongRange)
+    }
+
+    /// Creates an instance of [`RangeProperty`] of `double_range` type
+    pub fn double_range() -> RangeProperty {
+        RangeProperty::new(RangeType::DoubleRange)
+    }
+
+    /// Creates an instance of [`RangeProperty`] of `date_range` type
+    pub fn date_range() -> RangeProperty {
+        RangeProperty::new(RangeType::DateRange)
+    }
+
+    /// Creates an instance of [`RangeProperty`] of `ip_range` type
+    pub fn ip_range() -> RangeProperty {
+        RangeProperty::new(RangeType::IpRange)
+    }
+}
+
+impl RangeProperty {
+    /// Creates an instance of [`RangeProperty`] of the given type
+    pub fn new(r#type: RangeType) -> Self {
+        Self {
+            r#type,
+            coerce: None,
+            doc_values: None,
+            format: None,
+            index: None,
+            store: None,
+        }
+    }
+
+    /// Try to convert strings to numbers and truncate fractions for integers. Defaults to `true`.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// The date format(s) that can be parsed, `date_range` fields only. Defaults to
+    /// `strict_date_optional_time||epoch_millis`.
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Property::integer_range(),
+                Property::float_range(),
+                Property::long_range(),
+                Property::double_range(),
+                Property::date_range(),
+                Property::ip_range(),
+            ],
+            json!([
+                { "type": "integer_range" },
+                { "type": "float_range" },
+                { "type": "long_range" },
+                { "type": "double_range" },
+                { "type": "date_range" },
+                { "type": "ip_range" },
+            ]),
+        );
+
+        assert_serialize(
+            Property::date_range()
+                .coerce(false)
+                .doc_values(true)
+                .format("yyyy-MM-dd||epoch_millis")
+                .index(true)
+                .store(false),
+            json!({
+                "type": "date_range",
+                "coerce": false,
+                "doc_values": true,
+                "format": "yyyy-MM-dd||epoch_millis",
+                "index": true,
+                "store": false
+            }),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let property: Property =
+            serde_json::from_value(json!({ "type": "ip_range", "store": true })).unwrap();
+
+        assert_eq!(property, Property::ip_range().store(true).into());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// A `rank_feature` field can index numbers so that they can later be used to boost documents in
+/// queries with a [`rank_feature`](crate::RankFeatureQuery) query.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/rank-feature.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct RankFeatureProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    positive_score_impact: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`RankFeatureProperty`]
+    pub fn rank_feature() -> RankFeatureProperty {
+        RankFeatureProperty {
+            positive_score_impact: None,
+        }
+    }
+}
+
+impl RankFeatureProperty {
+    /// Rank features that correlate negatively with the score should set
+    /// `positive_score_impact` to `false` (defaults to `true`). This will be used by the
+    /// `rank_feature` query to modify the scoring formula in such a way that the score decreases
+    /// with the value of the feature instead of increasing.
+    pub fn positive_score_impact(mut self, positive_score_impact: bool) -> Self {
+        self.positive_score_impact = Some(positive_score_impact);
+        self
+    }
+}
+
+serialize_with_type!("rank_feature": RankFeatureProperty);
+deserialize_with_type!("rank_feature": RankFeatureProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::rank_feature(), json!({ "type": "rank_feature" }));
+
+        assert_serialize(
+            Property::rank_feature().positive_score_impact(false),
+            json!({ "type": "rank_feature", "positive_score_impact": false }),
+        );
+    }
+}
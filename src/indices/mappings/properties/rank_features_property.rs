@@ -0,0 +1,57 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// A `rank_features` field can index numeric feature vectors, so that they can later be used to
+/// boost documents in queries with a [`rank_feature`](crate::RankFeatureQuery) query. It is
+/// analogous to the [`rank_feature`](RankFeatureProperty) data type but is better suited when the
+/// list of features is sparse so that it wouldn't be reasonable to add one field to the mappings
+/// for each of them.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/rank-features.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct RankFeaturesProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    positive_score_impact: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`RankFeaturesProperty`]
+    pub fn rank_features() -> RankFeaturesProperty {
+        RankFeaturesProperty {
+            positive_score_impact: None,
+        }
+    }
+}
+
+impl RankFeaturesProperty {
+    /// Rank features that correlate negatively with the score should set
+    /// `positive_score_impact` to `false` (defaults to `true`). This will be used by the
+    /// `rank_feature` query to modify the scoring formula in such a way that the score decreases
+    /// with the value of the feature instead of increasing.
+    pub fn positive_score_impact(mut self, positive_score_impact: bool) -> Self {
+        self.positive_score_impact = Some(positive_score_impact);
+        self
+    }
+}
+
+serialize_with_type!("rank_features": RankFeaturesProperty);
+deserialize_with_type!("rank_features": RankFeaturesProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::rank_features(),
+            json!({ "type": "rank_features" }),
+        );
+
+        assert_serialize(
+            Property::rank_features().positive_score_impact(false),
+            json!({ "type": "rank_features", "positive_score_impact": false }),
+        );
+    }
+}
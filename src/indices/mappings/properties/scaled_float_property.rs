@@ -0,0 +1,145 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+
+/// A floating point number that is backed by a `long`, scaled by a fixed `double` scaling factor.
+///
+/// To create a scaled float property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::scaled_float(100.0);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html#scaled-float-params>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct ScaledFloatProperty {
+    scaling_factor: f64,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    coerce: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_malformed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`ScaledFloatProperty`]
+    ///
+    /// - `scaling_factor` - The scaling factor to use when encoding values. Values will be multiplied
+    ///   by this factor at index time and rounded to the closest long value.
+    pub fn scaled_float(scaling_factor: f64) -> ScaledFloatProperty {
+        ScaledFloatProperty {
+            scaling_factor,
+            coerce: None,
+            copy_to: Vec::new(),
+            doc_values: None,
+            ignore_malformed: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+}
+
+impl ScaledFloatProperty {
+    /// Try to convert strings to numbers and truncate fractions for integers. Defaults to `true`.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// If `true`, malformed values are ignored. If `false` (default), malformed values throw an
+    /// exception and reject the whole document.
+    pub fn ignore_malformed(mut self, ignore_malformed: bool) -> Self {
+        self.ignore_malformed = Some(ignore_malformed);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("scaled_float": ScaledFloatProperty);
+deserialize_with_type!("scaled_float": ScaledFloatProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::scaled_float(100.0),
+            json!({ "type": "scaled_float", "scaling_factor": 100.0 }),
+        );
+
+        assert_serialize(
+            Property::scaled_float(10.0)
+                .coerce(true)
+                .null_value(1.5)
+                .store(false),
+            json!({
+                "type": "scaled_float",
+                "scaling_factor": 10.0,
+                "coerce": true,
+                "null_value": 1.5,
+                "store": false
+            }),
+        );
+    }
+}
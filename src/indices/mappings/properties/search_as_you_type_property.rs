@@ -0,0 +1,151 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `search_as_you_type` field type is a text-like field that is optimized to provide
+/// out-of-the-box support for queries that serve an as-you-type completion use case. It creates a
+/// series of subfields that are analyzed to index terms that can be efficiently matched by a query
+/// that partially matches the entire indexed text value.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-as-you-type.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct SearchAsYouTypeProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_options: Option<IndexOptions>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_shingle_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    norms: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    term_vector: Option<TermVector>,
+}
+
+impl Property {
+    /// Creates an instance of [`SearchAsYouTypeProperty`]
+    pub fn search_as_you_type() -> SearchAsYouTypeProperty {
+        SearchAsYouTypeProperty {
+            analyzer: None,
+            index: None,
+            index_options: None,
+            max_shingle_size: None,
+            norms: None,
+            similarity: None,
+            store: None,
+            term_vector: None,
+        }
+    }
+}
+
+impl SearchAsYouTypeProperty {
+    /// The analyzer which should be used for the field, both at index-time and at search-time
+    /// (unless overridden by the `search_analyzer`). Defaults to the default index analyzer, or the
+    /// `standard` analyzer.
+    pub fn analyzer<T>(mut self, analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.analyzer = Some(analyzer.to_string());
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// What information should be stored in the index, for search and highlighting purposes.
+    pub fn index_options(mut self, index_options: IndexOptions) -> Self {
+        self.index_options = Some(index_options);
+        self
+    }
+
+    /// The largest shingle size to create. Valid values are `2` (inclusive) to `4` (inclusive).
+    /// Defaults to `3`.
+    pub fn max_shingle_size(mut self, max_shingle_size: u32) -> Self {
+        self.max_shingle_size = Some(max_shingle_size);
+        self
+    }
+
+    /// Whether field-length should be taken into account when scoring queries.
+    pub fn norms(mut self, norms: bool) -> Self {
+        self.norms = Some(norms);
+        self
+    }
+
+    /// Which scoring algorithm or similarity should be used. Defaults to `BM25`.
+    pub fn similarity<T>(mut self, similarity: T) -> Self
+    where
+        T: ToString,
+    {
+        self.similarity = Some(similarity.to_string());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Whether term vectors should be stored for the field. Defaults to `no`.
+    pub fn term_vector(mut self, term_vector: TermVector) -> Self {
+        self.term_vector = Some(term_vector);
+        self
+    }
+}
+
+serialize_with_type!("search_as_you_type": SearchAsYouTypeProperty);
+deserialize_with_type!("search_as_you_type": SearchAsYouTypeProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::search_as_you_type(),
+            json!({ "type": "search_as_you_type" }),
+        );
+
+        assert_serialize(
+            Property::search_as_you_type()
+                .analyzer("standard")
+                .index(true)
+                .index_options(IndexOptions::Positions)
+                .max_shingle_size(4)
+                .norms(true)
+                .similarity("BM25")
+                .store(false)
+                .term_vector(TermVector::Yes),
+            json!({
+                "type": "search_as_you_type",
+                "analyzer": "standard",
+                "index": true,
+                "index_options": "positions",
+                "max_shingle_size": 4,
+                "norms": true,
+                "similarity": "BM25",
+                "store": false,
+                "term_vector": "yes"
+            }),
+        );
+    }
+}
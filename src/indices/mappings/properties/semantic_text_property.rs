@@ -0,0 +1,84 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `semantic_text` field type automatically generates embeddings for text content using an
+/// inference endpoint. Long passages are automatically chunked to smaller sections to enable the
+/// processing of larger corpuses of text.
+///
+/// To create a semantic text property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::semantic_text()
+///     .inference_id("my-elser-endpoint")
+///     .search_inference_id("my-elser-search-endpoint");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/semantic-text.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct SemanticTextProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inference_id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_inference_id: Option<String>,
+}
+
+impl Property {
+    /// Creates an instance of [`SemanticTextProperty`]
+    pub fn semantic_text() -> SemanticTextProperty {
+        SemanticTextProperty {
+            inference_id: None,
+            search_inference_id: None,
+        }
+    }
+}
+
+impl SemanticTextProperty {
+    /// Inference endpoint that will be used to generate embeddings for the field. Defaults to the
+    /// preconfigured ELSER endpoint.
+    pub fn inference_id<T>(mut self, inference_id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.inference_id = Some(inference_id.to_string());
+        self
+    }
+
+    /// Inference endpoint that will be used to generate embeddings at query time. Defaults to the
+    /// `inference_id`.
+    pub fn search_inference_id<T>(mut self, search_inference_id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.search_inference_id = Some(search_inference_id.to_string());
+        self
+    }
+}
+
+serialize_with_type!("semantic_text": SemanticTextProperty);
+deserialize_with_type!("semantic_text": SemanticTextProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::semantic_text(),
+            json!({ "type": "semantic_text" }),
+        );
+
+        assert_serialize(
+            Property::semantic_text()
+                .inference_id("my-elser-endpoint")
+                .search_inference_id("my-elser-search-endpoint"),
+            json!({
+                "type": "semantic_text",
+                "inference_id": "my-elser-endpoint",
+                "search_inference_id": "my-elser-search-endpoint"
+            }),
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// A `sparse_vector` field can index features and weights so that they can later be used to
+/// query documents in queries with a `sparse_vector` query. This field can also be used with a
+/// legacy `text_expansion` query.
+///
+/// To create a sparse vector property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::sparse_vector()
+///     .prune(true)
+///     .pruning_config(TokenPruningConfig::new().tokens_weight_threshold(0.4));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sparse-vector.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct SparseVectorProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_options: Option<SparseVectorIndexOptions>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct SparseVectorIndexOptions {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prune: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pruning_config: Option<TokenPruningConfig>,
+}
+
+impl Property {
+    /// Creates an instance of [`SparseVectorProperty`]
+    pub fn sparse_vector() -> SparseVectorProperty {
+        SparseVectorProperty {
+            store: None,
+            index_options: None,
+        }
+    }
+}
+
+impl SparseVectorProperty {
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Whether to perform pruning, omitting the non-significant tokens from the query to improve
+    /// query performance. Defaults to `false`.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.index_options
+            .get_or_insert_with(Default::default)
+            .prune = Some(prune);
+        self
+    }
+
+    /// Token pruning configuration, only used if `prune` is set to `true`
+    pub fn pruning_config(mut self, pruning_config: TokenPruningConfig) -> Self {
+        self.index_options
+            .get_or_insert_with(Default::default)
+            .pruning_config = Some(pruning_config);
+        self
+    }
+}
+
+serialize_with_type!("sparse_vector": SparseVectorProperty);
+deserialize_with_type!("sparse_vector": SparseVectorProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::sparse_vector(),
+            json!({ "type": "sparse_vector" }),
+        );
+
+        assert_serialize(
+            Property::sparse_vector()
+                .store(true)
+                .prune(true)
+                .pruning_config(
+                    TokenPruningConfig::new()
+                        .tokens_freq_ratio_threshold(5.0)
+                        .tokens_weight_threshold(0.4),
+                ),
+            json!({
+                "type": "sparse_vector",
+                "store": true,
+                "index_options": {
+                    "prune": true,
+                    "pruning_config": {
+                        "tokens_freq_ratio_threshold": 5.0,
+                        "tokens_weight_threshold": 0.4
+                    }
+                }
+            }),
+        );
+    }
+}
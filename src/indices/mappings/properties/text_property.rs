@@ -0,0 +1,271 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// A field to index full-text values, such as the body of an email or the description of a
+/// product. These fields are analyzed, that is they are passed through an analyzer to convert the
+/// string into a list of individual terms before being indexed.
+///
+/// To create a text property:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let property =
+/// Property::text()
+///     .analyzer("english")
+///     .index_options(IndexOptions::Offsets)
+///     .field("raw", Property::keyword());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/text.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct TextProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    copy_to: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    eager_global_ordinals: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fielddata: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    fields: Map<String, Property>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_options: Option<IndexOptions>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_phrases: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    norms: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    position_increment_gap: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_quote_analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    term_vector: Option<TermVector>,
+}
+
+impl Property {
+    /// Creates an instance of [`TextProperty`]
+    pub fn text() -> TextProperty {
+        TextProperty {
+            analyzer: None,
+            copy_to: Vec::new(),
+            eager_global_ordinals: None,
+            fielddata: None,
+            fields: Map::new(),
+            index: None,
+            index_options: None,
+            index_phrases: None,
+            norms: None,
+            position_increment_gap: None,
+            search_analyzer: None,
+            search_quote_analyzer: None,
+            similarity: None,
+            store: None,
+            term_vector: None,
+        }
+    }
+}
+
+impl TextProperty {
+    /// The analyzer which should be used for the field, both at index-time and at search-time
+    /// (unless overridden by the `search_analyzer`). Defaults to the default index analyzer, or the
+    /// `standard` analyzer.
+    pub fn analyzer<T>(mut self, analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.analyzer = Some(analyzer.to_string());
+        self
+    }
+
+    /// Allows copying the values of multiple fields into a group field, which can then be
+    /// queried as a single field.
+    pub fn copy_to<T>(mut self, copy_to: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.copy_to
+            .extend(copy_to.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Should global ordinals be loaded eagerly on refresh? Defaults to `false`. Enabling this
+    /// is a good idea on fields that are frequently used for (significant) terms aggregations.
+    pub fn eager_global_ordinals(mut self, eager_global_ordinals: bool) -> Self {
+        self.eager_global_ordinals = Some(eager_global_ordinals);
+        self
+    }
+
+    /// Can the field use in-memory fielddata for sorting, aggregations, or scripting? Defaults to
+    /// `false`.
+    pub fn fielddata(mut self, fielddata: bool) -> Self {
+        self.fielddata = Some(fielddata);
+        self
+    }
+
+    /// Adds a multi-field, which indexes the same value in a different way for a different
+    /// purpose, e.g. a `keyword` sub-field of a `text` field for sorting and aggregations.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/multi-fields.html>
+    pub fn field<T, U>(mut self, name: T, property: U) -> Self
+    where
+        T: ToString,
+        U: Into<Property>,
+    {
+        let _ = self.fields.insert(name.to_string(), property.into());
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// What information should be stored in the index, for search and highlighting purposes. Defaults to
+    /// `positions`.
+    pub fn index_options(mut self, index_options: IndexOptions) -> Self {
+        self.index_options = Some(index_options);
+        self
+    }
+
+    /// If enabled, two-term word combinations (shingles) are indexed into a separate field. This
+    /// allows exact phrase queries (no slop) to run more efficiently, at the expense of a larger
+    /// index. Defaults to `false`.
+    pub fn index_phrases(mut self, index_phrases: bool) -> Self {
+        self.index_phrases = Some(index_phrases);
+        self
+    }
+
+    /// Whether field-length should be taken into account when scoring queries. Defaults to `true`.
+    pub fn norms(mut self, norms: bool) -> Self {
+        self.norms = Some(norms);
+        self
+    }
+
+    /// The number of fake term position which should be inserted between each element of an
+    /// array of strings. Defaults to `100`.
+    pub fn position_increment_gap(mut self, position_increment_gap: u32) -> Self {
+        self.position_increment_gap = Some(position_increment_gap);
+        self
+    }
+
+    /// The analyzer that should be used at search time on the field. Defaults to the `analyzer`
+    /// setting.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-analyzer.html>
+    pub fn search_analyzer<T>(mut self, search_analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.search_analyzer = Some(search_analyzer.to_string());
+        self
+    }
+
+    /// The analyzer that should be used at search time when a phrase is encountered. Defaults to
+    /// the `search_analyzer` setting.
+    pub fn search_quote_analyzer<T>(mut self, search_quote_analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.search_quote_analyzer = Some(search_quote_analyzer.to_string());
+        self
+    }
+
+    /// Which scoring algorithm or similarity should be used. Defaults to `BM25`.
+    pub fn similarity<T>(mut self, similarity: T) -> Self
+    where
+        T: ToString,
+    {
+        self.similarity = Some(similarity.to_string());
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Whether term vectors should be stored for the field. Defaults to `no`.
+    pub fn term_vector(mut self, term_vector: TermVector) -> Self {
+        self.term_vector = Some(term_vector);
+        self
+    }
+}
+
+serialize_with_type!("text": TextProperty);
+deserialize_with_type!("text": TextProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::text(), json!({ "type": "text" }));
+
+        assert_serialize(
+            Property::text()
+                .analyzer("english")
+                .copy_to(["all"])
+                .eager_global_ordinals(false)
+                .fielddata(true)
+                .field("raw", Property::keyword().ignore_above(256))
+                .index(true)
+                .index_options(IndexOptions::Offsets)
+                .index_phrases(true)
+                .norms(false)
+                .position_increment_gap(10)
+                .search_analyzer("standard")
+                .search_quote_analyzer("whitespace")
+                .similarity("boolean")
+                .store(true)
+                .term_vector(TermVector::WithPositionsOffsets),
+            json!({
+                "type": "text",
+                "analyzer": "english",
+                "copy_to": ["all"],
+                "eager_global_ordinals": false,
+                "fielddata": true,
+                "fields": {
+                    "raw": { "type": "keyword", "ignore_above": 256 }
+                },
+                "index": true,
+                "index_options": "offsets",
+                "index_phrases": true,
+                "norms": false,
+                "position_increment_gap": 10,
+                "search_analyzer": "standard",
+                "search_quote_analyzer": "whitespace",
+                "similarity": "boolean",
+                "store": true,
+                "term_vector": "with_positions_offsets"
+            }),
+        );
+    }
+}
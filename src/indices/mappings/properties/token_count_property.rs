@@ -0,0 +1,116 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// A field of type `token_count` is really an integer field which accepts string values, analyzes
+/// them, then indexes the number of tokens in the string.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/token-count.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct TokenCountProperty {
+    analyzer: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    enable_position_increments: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_values: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    store: Option<bool>,
+}
+
+impl Property {
+    /// Creates an instance of [`TokenCountProperty`]
+    ///
+    /// - `analyzer` - The analyzer which should be used to analyze the string value.
+    pub fn token_count<T>(analyzer: T) -> TokenCountProperty
+    where
+        T: ToString,
+    {
+        TokenCountProperty {
+            analyzer: analyzer.to_string(),
+            enable_position_increments: None,
+            doc_values: None,
+            index: None,
+            null_value: None,
+            store: None,
+        }
+    }
+}
+
+impl TokenCountProperty {
+    /// Indicates if position increments should be counted. Set to `false` if you don’t want to
+    /// count tokens removed by analyzer filters (like `stop`). Defaults to `true`.
+    pub fn enable_position_increments(mut self, enable_position_increments: bool) -> Self {
+        self.enable_position_increments = Some(enable_position_increments);
+        self
+    }
+
+    /// Should the field be stored on disk in a column-stride fashion, so that it can later be
+    /// used for sorting, aggregations, or scripting? Defaults to `true`.
+    pub fn doc_values(mut self, doc_values: bool) -> Self {
+        self.doc_values = Some(doc_values);
+        self
+    }
+
+    /// Should the field be searchable? Defaults to `true`.
+    pub fn index(mut self, index: bool) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value(mut self, null_value: u64) -> Self {
+        self.null_value = Some(null_value);
+        self
+    }
+
+    /// Whether the field value should be stored and retrievable separately from the `_source`
+    /// field. Defaults to `false`.
+    pub fn store(mut self, store: bool) -> Self {
+        self.store = Some(store);
+        self
+    }
+}
+
+serialize_with_type!("token_count": TokenCountProperty);
+deserialize_with_type!("token_count": TokenCountProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Property::token_count("standard"),
+            json!({ "type": "token_count", "analyzer": "standard" }),
+        );
+
+        assert_serialize(
+            Property::token_count("standard")
+                .enable_position_increments(false)
+                .doc_values(true)
+                .index(true)
+                .null_value(0)
+                .store(true),
+            json!({
+                "type": "token_count",
+                "analyzer": "standard",
+                "enable_position_increments": false,
+                "doc_values": true,
+                "index": true,
+                "null_value": 0,
+                "store": true
+            }),
+        );
+    }
+}
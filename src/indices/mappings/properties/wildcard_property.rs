@@ -0,0 +1,63 @@
+use crate::indices::*;
+use crate::util::*;
+
+/// The `wildcard` field type is a specialized keyword field for unstructured machine-generated
+/// content you plan to search using grep-like `wildcard` and `regexp` queries.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html#wildcard-field-type>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct WildcardProperty {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_above: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    null_value: Option<String>,
+}
+
+impl Property {
+    /// Creates an instance of [`WildcardProperty`]
+    pub fn wildcard() -> WildcardProperty {
+        WildcardProperty {
+            ignore_above: None,
+            null_value: None,
+        }
+    }
+}
+
+impl WildcardProperty {
+    /// Do not index any string longer than this value. Defaults to `2147483647` so that all
+    /// values would be accepted.
+    pub fn ignore_above(mut self, ignore_above: u32) -> Self {
+        self.ignore_above = Some(ignore_above);
+        self
+    }
+
+    /// Accepts a value which is substituted for any explicit `null` values. Defaults to `null`,
+    /// which means the field is treated as missing.
+    pub fn null_value<T>(mut self, null_value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.null_value = Some(null_value.to_string());
+        self
+    }
+}
+
+serialize_with_type!("wildcard": WildcardProperty);
+deserialize_with_type!("wildcard": WildcardProperty);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Property::wildcard(), json!({ "type": "wildcard" }));
+
+        assert_serialize(
+            Property::wildcard().ignore_above(1024).null_value("NULL"),
+            json!({ "type": "wildcard", "ignore_above": 1024, "null_value": "NULL" }),
+        );
+    }
+}
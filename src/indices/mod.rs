@@ -0,0 +1,22 @@
+//! Index APIs are used to manage individual indices, index settings, aliases, mappings, and index
+//! templates.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices.html>
+
+// Private modules
+mod create_index;
+mod percolator_document;
+
+// Public modules
+pub mod aliases;
+pub mod mappings;
+pub mod settings;
+pub mod templates;
+
+// Public re-exports
+pub use self::aliases::*;
+pub use self::create_index::*;
+pub use self::mappings::*;
+pub use self::percolator_document::*;
+pub use self::settings::*;
+pub use self::templates::*;
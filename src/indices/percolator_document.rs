@@ -0,0 +1,97 @@
+//! Document holding a query to be indexed into a `percolator` field.
+use crate::search::*;
+use crate::Map;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Document body for indexing a typed [`Query`] into a [`percolator`](crate::PercolatorProperty)
+/// field, together with any other fields of the document. Stored queries can then be matched
+/// against documents with the [`percolate`](crate::PercolateQuery) query.
+///
+/// To create a percolator document:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let document =
+/// PercolatorDocument::new("query", Query::r#match("message", "bonsai tree"))
+///     .field("alert_id", 42)
+///     .field("owner", "alice");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/percolator.html>
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercolatorDocument {
+    query_field: String,
+    query: Query,
+    fields: Map<String, serde_json::Value>,
+}
+
+impl PercolatorDocument {
+    /// Creates an instance of [`PercolatorDocument`]
+    ///
+    /// - `query_field` - The field of type `percolator` that holds the query
+    /// - `query` - The query to store
+    pub fn new<T, U>(query_field: T, query: U) -> Self
+    where
+        T: ToString,
+        U: Into<Query>,
+    {
+        Self {
+            query_field: query_field.to_string(),
+            query: query.into(),
+            fields: Map::new(),
+        }
+    }
+
+    /// Adds another field to the document, e.g. metadata used to filter the stored queries
+    pub fn field<T, U>(mut self, name: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Serialize,
+    {
+        let _ = self.fields.insert(
+            name.to_string(),
+            serde_json::to_value(value).unwrap_or_default(),
+        );
+        self
+    }
+}
+
+impl Serialize for PercolatorDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.fields.len() + 1))?;
+        map.serialize_entry(&self.query_field, &self.query)?;
+        for (name, value) in &self.fields {
+            if name != &self.query_field {
+                map.serialize_entry(name, value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            PercolatorDocument::new("query", Query::term("status", "failed")),
+            json!({ "query": { "term": { "status": { "value": "failed" } } } }),
+        );
+
+        assert_serialize(
+            PercolatorDocument::new("query", Query::r#match("message", "bonsai tree"))
+                .field("alert_id", 42)
+                .field("tags", ["a", "b"]),
+            json!({
+                "query": { "match": { "message": { "query": "bonsai tree" } } },
+                "alert_id": 42,
+                "tags": ["a", "b"]
+            }),
+        );
+    }
+}
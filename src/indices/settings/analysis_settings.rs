@@ -0,0 +1,288 @@
+use crate::analyze::*;
+use crate::util::*;
+use crate::Map;
+
+/// The analysis section of index settings, used to define custom analyzers, normalizers,
+/// tokenizers, token filters and character filters which can then be referenced by name from
+/// the mappings.
+///
+/// Analyzers, normalizers and their components are the same types accepted by the
+/// [analyze API](crate::Analyze), so an analyzer can be defined once and tested with
+/// [`AnalysisSettings::inline_analyzer`] before creating the index.
+///
+/// To create an analysis section:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use elasticsearch_dsl::analyze::*;
+/// # use serde_json::json;
+/// # let analysis =
+/// AnalysisSettings::new()
+///     .analyzer(
+///         "my_analyzer",
+///         CustomAnalyzer::new("standard").filter(["lowercase", "my_stemmer"]),
+///     )
+///     .filter("my_stemmer", TokenFilter::stemmer("light_german"))
+///     .normalizer("lowercase", CustomNormalizer::new().filter(["lowercase"]));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnalysisSettings {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Map<String, AnalyzerDefinition>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    normalizer: Map<String, CustomNormalizer>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tokenizer: Map<String, Tokenizer>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Map<String, TokenFilter>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    char_filter: Map<String, CharacterFilter>,
+}
+
+impl AnalysisSettings {
+    /// Creates an instance of [`AnalysisSettings`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Defines a custom analyzer or a configured built-in analyzer
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-custom-analyzer.html>
+    pub fn analyzer<T, U>(mut self, name: T, analyzer: U) -> Self
+    where
+        T: ToString,
+        U: Into<AnalyzerDefinition>,
+    {
+        let _ = self.analyzer.insert(name.to_string(), analyzer.into());
+        self
+    }
+
+    /// Defines a custom normalizer
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-normalizers.html>
+    pub fn normalizer<T>(mut self, name: T, normalizer: CustomNormalizer) -> Self
+    where
+        T: ToString,
+    {
+        let _ = self.normalizer.insert(name.to_string(), normalizer);
+        self
+    }
+
+    /// Defines a configured tokenizer
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenizers.html>
+    pub fn tokenizer<T, U>(mut self, name: T, tokenizer: U) -> Self
+    where
+        T: ToString,
+        U: Into<Tokenizer>,
+    {
+        let _ = self.tokenizer.insert(name.to_string(), tokenizer.into());
+        self
+    }
+
+    /// Defines a configured token filter
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-tokenfilters.html>
+    pub fn filter<T, U>(mut self, name: T, filter: U) -> Self
+    where
+        T: ToString,
+        U: Into<TokenFilter>,
+    {
+        let _ = self.filter.insert(name.to_string(), filter.into());
+        self
+    }
+
+    /// Defines a configured character filter
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-charfilters.html>
+    pub fn char_filter<T, U>(mut self, name: T, char_filter: U) -> Self
+    where
+        T: ToString,
+        U: Into<CharacterFilter>,
+    {
+        let _ = self
+            .char_filter
+            .insert(name.to_string(), char_filter.into());
+        self
+    }
+
+    /// Returns the custom analyzer with the given name, with references to tokenizers, token
+    /// filters and character filters defined in these settings replaced by their definitions.
+    /// The result can be passed to the [analyze API](crate::Analyze) to test the analyzer
+    /// without creating the index first.
+    ///
+    /// Returns `None` if no custom analyzer with the given name is defined.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::indices::*;
+    /// # use elasticsearch_dsl::analyze::*;
+    /// let analysis = AnalysisSettings::new()
+    ///     .analyzer("my_analyzer", CustomAnalyzer::new("standard").filter(["my_stemmer"]))
+    ///     .filter("my_stemmer", TokenFilter::stemmer("light_german"));
+    ///
+    /// let analyze = Analyze::new("Häuser").analyzer(analysis.inline_analyzer("my_analyzer").unwrap());
+    /// ```
+    pub fn inline_analyzer(&self, name: &str) -> Option<CustomAnalyzer> {
+        match self.analyzer.get(name)? {
+            AnalyzerDefinition::Custom(analyzer) => {
+                Some(analyzer.inline(&self.tokenizer, &self.char_filter, &self.filter))
+            }
+            AnalyzerDefinition::Configured(_) => None,
+        }
+    }
+
+    /// Returns the custom normalizer with the given name, with references to token filters and
+    /// character filters defined in these settings replaced by their definitions.
+    /// The result can be passed to the [analyze API](crate::Analyze) to test the normalizer
+    /// without creating the index first.
+    ///
+    /// Returns `None` if no normalizer with the given name is defined.
+    pub fn inline_normalizer(&self, name: &str) -> Option<CustomNormalizer> {
+        self.normalizer
+            .get(name)
+            .map(|normalizer| normalizer.inline(&self.char_filter, &self.filter))
+    }
+}
+
+impl ShouldSkip for AnalysisSettings {
+    fn should_skip(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(AnalysisSettings::new(), json!({}));
+
+        assert_serialize(
+            AnalysisSettings::new()
+                .analyzer(
+                    "my_analyzer",
+                    CustomAnalyzer::new("my_tokenizer")
+                        .char_filter(["my_char_filter"])
+                        .filter(["lowercase", "my_filter"]),
+                )
+                .analyzer(
+                    "std_english",
+                    ConfiguredAnalyzer::new(BuiltinAnalyzer::Standard).stopwords(["_english_"]),
+                )
+                .normalizer(
+                    "my_normalizer",
+                    CustomNormalizer::new().filter(["lowercase", "asciifolding"]),
+                )
+                .tokenizer(
+                    "my_tokenizer",
+                    Tokenizer::ngram()
+                        .min_gram(3)
+                        .max_gram(3)
+                        .token_chars([TokenChar::Letter]),
+                )
+                .tokenizer("my_icu_tokenizer", json!({ "type": "icu_tokenizer" }))
+                .filter("my_filter", TokenFilter::stop().stopwords(["a"]))
+                .filter("my_icu_filter", json!({ "type": "icu_folding" }))
+                .char_filter(
+                    "my_char_filter",
+                    CharacterFilter::html_strip().escaped_tags(["b"]),
+                ),
+            json!({
+                "analyzer": {
+                    "my_analyzer": {
+                        "tokenizer": "my_tokenizer",
+                        "char_filter": ["my_char_filter"],
+                        "filter": ["lowercase", "my_filter"]
+                    },
+                    "std_english": {
+                        "type": "standard",
+                        "stopwords": ["_english_"]
+                    }
+                },
+                "normalizer": {
+                    "my_normalizer": {
+                        "filter": ["lowercase", "asciifolding"]
+                    }
+                },
+                "tokenizer": {
+                    "my_icu_tokenizer": { "type": "icu_tokenizer" },
+                    "my_tokenizer": {
+                        "type": "ngram",
+                        "min_gram": 3,
+                        "max_gram": 3,
+                        "token_chars": ["letter"]
+                    }
+                },
+                "filter": {
+                    "my_filter": { "type": "stop", "stopwords": ["a"] },
+                    "my_icu_filter": { "type": "icu_folding" }
+                },
+                "char_filter": {
+                    "my_char_filter": { "type": "html_strip", "escaped_tags": ["b"] }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn inlines_analysis_components() {
+        let analysis = AnalysisSettings::new()
+            .analyzer(
+                "my_analyzer",
+                CustomAnalyzer::new("my_tokenizer")
+                    .char_filter(["my_char_filter", "html_strip"])
+                    .filter(["lowercase", "my_filter"]),
+            )
+            .analyzer(
+                "std_english",
+                ConfiguredAnalyzer::new(BuiltinAnalyzer::Standard).stopwords(["_english_"]),
+            )
+            .normalizer(
+                "my_normalizer",
+                CustomNormalizer::new().filter(["my_filter", "asciifolding"]),
+            )
+            .tokenizer("my_tokenizer", Tokenizer::ngram().min_gram(3).max_gram(3))
+            .filter("my_filter", TokenFilter::stop().stopwords(["a"]))
+            .char_filter(
+                "my_char_filter",
+                CharacterFilter::mapping().mappings(["ä => ae"]),
+            );
+
+        assert_eq!(analysis.inline_analyzer("std_english"), None);
+        assert_eq!(analysis.inline_analyzer("missing"), None);
+        assert_eq!(analysis.inline_normalizer("missing"), None);
+
+        assert_serialize(
+            Analyze::new("test").analyzer(analysis.inline_analyzer("my_analyzer").unwrap()),
+            json!({
+                "text": "test",
+                "tokenizer": { "type": "ngram", "min_gram": 3, "max_gram": 3 },
+                "char_filter": [
+                    { "type": "mapping", "mappings": ["ä => ae"] },
+                    "html_strip"
+                ],
+                "filter": [
+                    "lowercase",
+                    { "type": "stop", "stopwords": ["a"] }
+                ]
+            }),
+        );
+
+        assert_serialize(
+            Analyze::new("test").normalizer(analysis.inline_normalizer("my_normalizer").unwrap()),
+            json!({
+                "text": "test",
+                "filter": [
+                    { "type": "stop", "stopwords": ["a"] },
+                    "asciifolding"
+                ]
+            }),
+        );
+    }
+}
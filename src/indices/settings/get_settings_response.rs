@@ -0,0 +1,88 @@
+use crate::indices::*;
+use crate::Map;
+
+/// Elasticsearch get settings API response, with the settings of each requested index
+///
+/// Settings are deserialized into the same types used to define them, both in the nested and in
+/// the flat (`flat_settings=true`) form, so live settings can be compared against the expected
+/// definitions:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use serde_json::json;
+/// let response: GetSettingsResponse = serde_json::from_value(json!({
+///     "products": {
+///         "settings": {
+///             "index.number_of_shards": "3",
+///             "index.number_of_replicas": "1"
+///         }
+///     }
+/// }))
+/// .unwrap();
+///
+/// let expected = IndexSettings::new().number_of_shards(3).number_of_replicas(1);
+///
+/// assert_eq!(response.index("products"), Some(&expected));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-get-settings.html>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GetSettingsResponse {
+    /// Settings keyed by index name
+    pub indices: Map<String, IndexSettingsResponse>,
+}
+
+/// Settings of a single index
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSettingsResponse {
+    /// Index settings explicitly set on the index
+    #[serde(default)]
+    pub settings: IndexSettings,
+}
+
+impl GetSettingsResponse {
+    /// Returns the settings of the given index
+    pub fn index(&self, name: &str) -> Option<&IndexSettings> {
+        self.indices.get(name).map(|index| &index.settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn deserialization() {
+        let response: GetSettingsResponse = serde_json::from_value(json!({
+            "products": {
+                "settings": {
+                    "index": {
+                        "number_of_shards": "1",
+                        "refresh_interval": "1m",
+                        "uuid": "mCWvcTBoQpGOn2YvE1nRpA"
+                    }
+                }
+            },
+            "logs": {
+                "settings": {
+                    "index.number_of_replicas": "0"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.index("products"),
+            Some(
+                &IndexSettings::new()
+                    .number_of_shards(1)
+                    .refresh_interval(Time::Minutes(1))
+            ),
+        );
+        assert_eq!(
+            response.index("logs"),
+            Some(&IndexSettings::new().number_of_replicas(0)),
+        );
+        assert_eq!(response.index("missing"), None);
+    }
+}
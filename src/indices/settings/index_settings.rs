@@ -0,0 +1,448 @@
+use crate::indices::*;
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// Index level settings applied when creating an index
+///
+/// To create index settings:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use elasticsearch_dsl::analyze::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let settings =
+/// IndexSettings::new()
+///     .number_of_shards(3)
+///     .number_of_replicas(1)
+///     .refresh_interval(Time::Seconds(30))
+///     .sort(IndexSort::new(["date"]).order([SortOrder::Desc]))
+///     .analysis(
+///         AnalysisSettings::new().analyzer("my_analyzer", CustomAnalyzer::new("standard")),
+///     );
+/// ```
+/// Settings returned by the [get settings API](GetSettingsResponse) deserialize into the same
+/// type, both in the nested and in the flat (`flat_settings=true`) form. Elasticsearch returns
+/// all setting values as strings, which are parsed back for the typed settings above, while any
+/// other setting is kept as a string under its dotted key, e.g. `mapping.total_fields.limit`.
+/// Read-only settings managed by Elasticsearch, such as `uuid` or `creation_date`, are dropped.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-modules-settings>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct IndexSettings {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_shards: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_replicas: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    refresh_interval: Option<RefreshInterval>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_result_window: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: Option<IndexSort>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analysis: AnalysisSettings,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Map<String, Similarity>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    other: Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefreshInterval {
+    Time(Time),
+    Disabled,
+}
+
+impl serde::Serialize for RefreshInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Time(time) => time.serialize(serializer),
+            Self::Disabled => "-1".serialize(serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RefreshInterval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::IntoDeserializer;
+
+        let value = String::deserialize(deserializer)?;
+
+        if value == "-1" {
+            Ok(Self::Disabled)
+        } else {
+            Time::deserialize(value.into_deserializer()).map(Self::Time)
+        }
+    }
+}
+
+/// Settings generated by Elasticsearch which can't be set when creating an index
+const READ_ONLY_SETTINGS: [&str; 4] = ["creation_date", "provided_name", "uuid", "version"];
+
+impl<'de> serde::Deserialize<'de> for IndexSettings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{DeserializeOwned, Error};
+        use serde_json::Value;
+
+        fn take<T>(
+            settings: &mut serde_json::Map<String, Value>,
+            key: &str,
+        ) -> Result<Option<T>, serde_json::Error>
+        where
+            T: DeserializeOwned,
+        {
+            settings
+                .remove(key)
+                .map(|value| T::deserialize(Lenient::new(value)))
+                .transpose()
+        }
+
+        let mut settings = match expand_dotted_keys(Value::deserialize(deserializer)?) {
+            Value::Object(settings) => settings,
+            _ => return Err(D::Error::custom("expected index settings to be a map")),
+        };
+
+        // The get settings API returns index settings under the `index` key
+        if let Some(Value::Object(index)) = settings.remove("index") {
+            settings.extend(index);
+        }
+
+        for key in READ_ONLY_SETTINGS {
+            let _ = settings.remove(key);
+        }
+
+        let mut index_settings = Self {
+            number_of_shards: take(&mut settings, "number_of_shards").map_err(D::Error::custom)?,
+            number_of_replicas: take(&mut settings, "number_of_replicas")
+                .map_err(D::Error::custom)?,
+            refresh_interval: take(&mut settings, "refresh_interval").map_err(D::Error::custom)?,
+            max_result_window: take(&mut settings, "max_result_window")
+                .map_err(D::Error::custom)?,
+            sort: take(&mut settings, "sort").map_err(D::Error::custom)?,
+            analysis: take(&mut settings, "analysis")
+                .map_err(D::Error::custom)?
+                .unwrap_or_default(),
+            similarity: take(&mut settings, "similarity")
+                .map_err(D::Error::custom)?
+                .unwrap_or_default(),
+            other: Map::new(),
+        };
+
+        for (key, value) in settings {
+            flatten_into(&mut index_settings.other, key, value);
+        }
+
+        Ok(index_settings)
+    }
+}
+
+/// Expands flat settings, e.g. `{"index.number_of_shards": "1"}`, into nested objects
+fn expand_dotted_keys(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    let object = match value {
+        Value::Object(object) => object,
+        value => return value,
+    };
+
+    let mut expanded = serde_json::Map::new();
+
+    for (key, value) in object {
+        let value = expand_dotted_keys(value);
+        let mut path = key.split('.').peekable();
+        let mut target = &mut expanded;
+
+        while let Some(segment) = path.next() {
+            if path.peek().is_none() {
+                match (target.get_mut(segment), value) {
+                    (Some(Value::Object(existing)), Value::Object(value)) => existing.extend(value),
+                    (_, value) => {
+                        let _ = target.insert(segment.to_string(), value);
+                    }
+                }
+                break;
+            }
+
+            let entry = target
+                .entry(segment)
+                .or_insert_with(|| Value::Object(Default::default()));
+
+            if !entry.is_object() {
+                *entry = Value::Object(Default::default());
+            }
+
+            target = entry.as_object_mut().expect("entry is an object");
+        }
+    }
+
+    Value::Object(expanded)
+}
+
+/// Flattens nested settings into dotted keys, the form used by [`IndexSettings::setting`]
+fn flatten_into(
+    settings: &mut Map<String, serde_json::Value>,
+    key: String,
+    value: serde_json::Value,
+) {
+    match value {
+        serde_json::Value::Object(object) if !object.is_empty() => {
+            for (nested_key, value) in object {
+                flatten_into(settings, format!("{key}.{nested_key}"), value);
+            }
+        }
+        value => {
+            let _ = settings.insert(key, value);
+        }
+    }
+}
+
+impl IndexSettings {
+    /// Creates an instance of [`IndexSettings`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The number of primary shards that an index should have. Defaults to `1`. This setting
+    /// can only be set at index creation time.
+    pub fn number_of_shards(mut self, number_of_shards: u32) -> Self {
+        self.number_of_shards = Some(number_of_shards);
+        self
+    }
+
+    /// The number of replicas each primary shard has. Defaults to `1`.
+    pub fn number_of_replicas(mut self, number_of_replicas: u32) -> Self {
+        self.number_of_replicas = Some(number_of_replicas);
+        self
+    }
+
+    /// How often to perform a refresh operation, which makes recent changes to the index
+    /// visible to search. Defaults to `1s`.
+    pub fn refresh_interval(mut self, refresh_interval: Time) -> Self {
+        self.refresh_interval = Some(RefreshInterval::Time(refresh_interval));
+        self
+    }
+
+    /// Disables periodic refresh of the index, e.g. for the duration of a bulk load
+    pub fn disable_refresh(mut self) -> Self {
+        self.refresh_interval = Some(RefreshInterval::Disabled);
+        self
+    }
+
+    /// The maximum value of `from + size` for searches to this index. Defaults to `10000`.
+    pub fn max_result_window(mut self, max_result_window: u32) -> Self {
+        self.max_result_window = Some(max_result_window);
+        self
+    }
+
+    /// Configures how the segments inside each shard will be sorted
+    pub fn sort(mut self, sort: IndexSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Configures custom analyzers, normalizers, tokenizers, token filters and character filters
+    pub fn analysis(mut self, analysis: AnalysisSettings) -> Self {
+        self.analysis = analysis;
+        self
+    }
+
+    /// Configures a custom similarity, referenced by name from the `similarity` parameter of
+    /// `text` and `keyword` fields
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules-similarity.html>
+    pub fn similarity<T, U>(mut self, name: T, similarity: U) -> Self
+    where
+        T: ToString,
+        U: Into<Similarity>,
+    {
+        let _ = self.similarity.insert(name.to_string(), similarity.into());
+        self
+    }
+
+    /// Sets any other index setting not covered by the typed builder methods, e.g.
+    /// `codec` or `mapping.total_fields.limit`
+    pub fn setting<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Into<serde_json::Value>,
+    {
+        let _ = self.other.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+impl ShouldSkip for IndexSettings {
+    fn should_skip(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyze::*;
+
+    #[test]
+    fn deserialization() {
+        let expected = IndexSettings::new()
+            .number_of_shards(3)
+            .number_of_replicas(1)
+            .refresh_interval(Time::Seconds(30))
+            .sort(IndexSort::new(["date"]).order([SortOrder::Desc]))
+            .analysis(
+                AnalysisSettings::new()
+                    .analyzer(
+                        "my_analyzer",
+                        CustomAnalyzer::new("my_tokenizer").filter(["lowercase", "my_stop"]),
+                    )
+                    .tokenizer("my_tokenizer", Tokenizer::ngram().min_gram(3).max_gram(4))
+                    .filter("my_stop", TokenFilter::stop().ignore_case(true)),
+            )
+            .similarity("my_bm25", Similarity::bm25().k1(1.5))
+            .setting("codec", "best_compression")
+            .setting("mapping.total_fields.limit", "2000");
+
+        let nested: IndexSettings = serde_json::from_value(json!({
+            "index": {
+                "number_of_shards": "3",
+                "number_of_replicas": "1",
+                "refresh_interval": "30s",
+                "sort": { "field": "date", "order": "desc" },
+                "analysis": {
+                    "analyzer": {
+                        "my_analyzer": {
+                            "type": "custom",
+                            "tokenizer": "my_tokenizer",
+                            "filter": ["lowercase", "my_stop"]
+                        }
+                    },
+                    "tokenizer": {
+                        "my_tokenizer": { "type": "ngram", "min_gram": "3", "max_gram": "4" }
+                    },
+                    "filter": {
+                        "my_stop": { "type": "stop", "ignore_case": "true" }
+                    }
+                },
+                "similarity": {
+                    "my_bm25": { "type": "BM25", "k1": "1.5" }
+                },
+                "codec": "best_compression",
+                "mapping": { "total_fields": { "limit": "2000" } },
+                "creation_date": "1700000000000",
+                "provided_name": "products",
+                "uuid": "mCWvcTBoQpGOn2YvE1nRpA",
+                "version": { "created": "8500003" }
+            }
+        }))
+        .unwrap();
+
+        let flat: IndexSettings = serde_json::from_value(json!({
+            "index.number_of_shards": "3",
+            "index.number_of_replicas": "1",
+            "index.refresh_interval": "30s",
+            "index.sort.field": ["date"],
+            "index.sort.order": ["desc"],
+            "index.analysis.analyzer.my_analyzer.type": "custom",
+            "index.analysis.analyzer.my_analyzer.tokenizer": "my_tokenizer",
+            "index.analysis.analyzer.my_analyzer.filter": ["lowercase", "my_stop"],
+            "index.analysis.tokenizer.my_tokenizer.type": "ngram",
+            "index.analysis.tokenizer.my_tokenizer.min_gram": "3",
+            "index.analysis.tokenizer.my_tokenizer.max_gram": "4",
+            "index.analysis.filter.my_stop.type": "stop",
+            "index.analysis.filter.my_stop.ignore_case": "true",
+            "index.similarity.my_bm25.type": "BM25",
+            "index.similarity.my_bm25.k1": "1.5",
+            "index.codec": "best_compression",
+            "index.mapping.total_fields.limit": "2000",
+            "index.creation_date": "1700000000000",
+            "index.uuid": "mCWvcTBoQpGOn2YvE1nRpA",
+            "index.version.created": "8500003"
+        }))
+        .unwrap();
+
+        assert_eq!(nested, expected);
+        assert_eq!(flat, expected);
+
+        assert_eq!(
+            serde_json::from_value::<IndexSettings>(json!({ "refresh_interval": "-1" })).unwrap(),
+            IndexSettings::new().disable_refresh(),
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        assert_serialize(IndexSettings::new(), json!({}));
+
+        assert_serialize(
+            IndexSettings::new().disable_refresh(),
+            json!({ "refresh_interval": "-1" }),
+        );
+
+        assert_serialize(
+            IndexSettings::new()
+                .number_of_shards(3)
+                .number_of_replicas(2)
+                .refresh_interval(Time::Seconds(30))
+                .max_result_window(50000)
+                .sort(IndexSort::new(["date"]).order([SortOrder::Desc]))
+                .analysis(AnalysisSettings::new().analyzer(
+                    "my_analyzer",
+                    CustomAnalyzer::new("standard").filter(["lowercase"]),
+                ))
+                .similarity(
+                    "my_dfr",
+                    Similarity::dfr(
+                        DfrBasicModel::G,
+                        DfrAfterEffect::L,
+                        SimilarityNormalization::H2,
+                    ),
+                )
+                .setting("codec", "best_compression")
+                .setting("mapping.total_fields.limit", 2000),
+            json!({
+                "number_of_shards": 3,
+                "number_of_replicas": 2,
+                "refresh_interval": "30s",
+                "max_result_window": 50000,
+                "sort": {
+                    "field": ["date"],
+                    "order": ["desc"]
+                },
+                "analysis": {
+                    "analyzer": {
+                        "my_analyzer": {
+                            "tokenizer": "standard",
+                            "filter": ["lowercase"]
+                        }
+                    }
+                },
+                "similarity": {
+                    "my_dfr": {
+                        "type": "DFR",
+                        "basic_model": "g",
+                        "after_effect": "l",
+                        "normalization": "h2"
+                    }
+                },
+                "codec": "best_compression",
+                "mapping.total_fields.limit": 2000
+            }),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::search::*;
+use crate::util::*;
+
+/// When creating a new index, it is possible to configure how the segments inside each shard
+/// will be sorted. Index sorting can only be defined once at index creation.
+///
+/// To create index sorting:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let sort =
+/// IndexSort::new(["username", "date"]).order([SortOrder::Asc, SortOrder::Desc]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules-index-sorting.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexSort {
+    field: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Vec<SortOrder>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    mode: Vec<SortMode>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Vec<SortMissing>,
+}
+
+impl IndexSort {
+    /// Creates an instance of [`IndexSort`]
+    ///
+    /// - `field` - The list of fields used to sort the index. Only `boolean`, `numeric`, `date`
+    ///   and `keyword` fields with `doc_values` are allowed here.
+    pub fn new<T>(field: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        Self {
+            field: field.into_iter().map(|x| x.to_string()).collect(),
+            order: Vec::new(),
+            mode: Vec::new(),
+            missing: Vec::new(),
+        }
+    }
+
+    /// The sort order to use for each field
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: IntoIterator<Item = SortOrder>,
+    {
+        self.order.extend(order);
+        self
+    }
+
+    /// Which value of a multi-valued field to pick for each field, only `min` and `max` are
+    /// allowed
+    pub fn mode<T>(mut self, mode: T) -> Self
+    where
+        T: IntoIterator<Item = SortMode>,
+    {
+        self.mode.extend(mode);
+        self
+    }
+
+    /// How to treat documents which are missing each field
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: IntoIterator<Item = SortMissing>,
+    {
+        self.missing.extend(missing);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(IndexSort::new(["date"]), json!({ "field": ["date"] }));
+
+        assert_serialize(
+            IndexSort::new(["username", "date"])
+                .order([SortOrder::Asc, SortOrder::Desc])
+                .mode([SortMode::Min, SortMode::Max])
+                .missing([SortMissing::Last, SortMissing::First]),
+            json!({
+                "field": ["username", "date"],
+                "order": ["asc", "desc"],
+                "mode": ["min", "max"],
+                "missing": ["_last", "_first"]
+            }),
+        );
+    }
+}
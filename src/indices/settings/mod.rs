@@ -0,0 +1,16 @@
+//! Index level settings can be set per-index. Settings may be static (they can only be set at
+//! index creation time or on a closed index) or dynamic (they can be changed on a live index).
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-modules-settings>
+
+mod analysis_settings;
+mod get_settings_response;
+mod index_settings;
+mod index_sort;
+mod similarity;
+
+pub use self::analysis_settings::*;
+pub use self::get_settings_response::*;
+pub use self::index_settings::*;
+pub use self::index_sort::*;
+pub use self::similarity::*;
@@ -0,0 +1,363 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A similarity (scoring / ranking model) defines how matching documents are scored. Custom
+/// similarities are configured in the index settings and referenced by name from the
+/// `similarity` parameter of `text` and `keyword` fields.
+///
+/// To configure a custom similarity:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let settings =
+/// IndexSettings::new().similarity("my_bm25", Similarity::bm25().k1(1.5).b(0.5));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules-similarity.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(missing_docs)]
+pub enum Similarity {
+    Bm25(Bm25Similarity),
+    Dfr(DfrSimilarity),
+    Dfi(DfiSimilarity),
+    Ib(IbSimilarity),
+    LmDirichlet(LmDirichletSimilarity),
+    LmJelinekMercer(LmJelinekMercerSimilarity),
+
+    /// Any other similarity definition, e.g. a `scripted` similarity or a DFR similarity with
+    /// normalization parameters
+    Custom(serde_json::Value),
+}
+
+/// TF/IDF based similarity that has built-in tf normalization and is supposed to work better for
+/// short fields (like names). This is the default similarity.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct Bm25Similarity {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    k1: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    b: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    discount_overlaps: Option<bool>,
+}
+
+/// Similarity that implements the divergence from randomness framework
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct DfrSimilarity {
+    basic_model: DfrBasicModel,
+    after_effect: DfrAfterEffect,
+    normalization: SimilarityNormalization,
+}
+
+/// Similarity that implements the divergence from independence model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct DfiSimilarity {
+    independence_measure: DfiIndependenceMeasure,
+}
+
+/// Information based model, the algorithm is based on the concept that the information content
+/// in any symbolic distribution sequence is primarily determined by the repetitive usage of its
+/// basic elements
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct IbSimilarity {
+    distribution: IbDistribution,
+    lambda: IbLambda,
+    normalization: SimilarityNormalization,
+}
+
+/// Bayesian smoothing using Dirichlet priors language model similarity
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct LmDirichletSimilarity {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mu: Option<f32>,
+}
+
+/// Jelinek-Mercer smoothing language model similarity, the algorithm attempts to capture
+/// important patterns in the text, while leaving out noise
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+pub struct LmJelinekMercerSimilarity {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lambda: Option<f32>,
+}
+
+/// Basic model of a [`DfrSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum DfrBasicModel {
+    G,
+    If,
+    In,
+    Ine,
+}
+
+/// After effect of a [`DfrSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum DfrAfterEffect {
+    B,
+    L,
+}
+
+/// Term frequency normalization of a [`DfrSimilarity`] or an [`IbSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum SimilarityNormalization {
+    No,
+    H1,
+    H2,
+    H3,
+    Z,
+}
+
+/// Independence measure of a [`DfiSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum DfiIndependenceMeasure {
+    Standardized,
+    Saturated,
+    ChiSquared,
+}
+
+/// Distribution of an [`IbSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum IbDistribution {
+    Ll,
+    Spl,
+}
+
+/// Lambda of an [`IbSimilarity`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[allow(missing_docs)]
+pub enum IbLambda {
+    Df,
+    Ttf,
+}
+
+impl Similarity {
+    /// Creates an instance of [`Bm25Similarity`]
+    pub fn bm25() -> Bm25Similarity {
+        Bm25Similarity::default()
+    }
+
+    /// Creates an instance of [`DfrSimilarity`]
+    pub fn dfr(
+        basic_model: DfrBasicModel,
+        after_effect: DfrAfterEffect,
+        normalization: SimilarityNormalization,
+    ) -> DfrSimilarity {
+        DfrSimilarity {
+            basic_model,
+            after_effect,
+            normalization,
+        }
+    }
+
+    /// Creates an instance of [`DfiSimilarity`]
+    pub fn dfi(independence_measure: DfiIndependenceMeasure) -> DfiSimilarity {
+        DfiSimilarity {
+            independence_measure,
+        }
+    }
+
+    /// Creates an instance of [`IbSimilarity`]
+    pub fn ib(
+        distribution: IbDistribution,
+        lambda: IbLambda,
+        normalization: SimilarityNormalization,
+    ) -> IbSimilarity {
+        IbSimilarity {
+            distribution,
+            lambda,
+            normalization,
+        }
+    }
+
+    /// Creates an instance of [`LmDirichletSimilarity`]
+    pub fn lm_dirichlet() -> LmDirichletSimilarity {
+        LmDirichletSimilarity::default()
+    }
+
+    /// Creates an instance of [`LmJelinekMercerSimilarity`]
+    pub fn lm_jelinek_mercer() -> LmJelinekMercerSimilarity {
+        LmJelinekMercerSimilarity::default()
+    }
+}
+
+impl Bm25Similarity {
+    /// Controls non-linear term frequency normalization (saturation). Defaults to `1.2`.
+    pub fn k1<T>(mut self, k1: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.k1 = Some(k1.as_float());
+        self
+    }
+
+    /// Controls to what degree document length normalizes tf values. Defaults to `0.75`.
+    pub fn b<T>(mut self, b: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.b = Some(b.as_float());
+        self
+    }
+
+    /// Whether overlap tokens (tokens with zero position increment) are ignored when computing
+    /// norm. Defaults to `true`.
+    pub fn discount_overlaps(mut self, discount_overlaps: bool) -> Self {
+        self.discount_overlaps = Some(discount_overlaps);
+        self
+    }
+}
+
+impl LmDirichletSimilarity {
+    /// The smoothing parameter. Defaults to `2000`.
+    pub fn mu<T>(mut self, mu: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.mu = Some(mu.as_float());
+        self
+    }
+}
+
+impl LmJelinekMercerSimilarity {
+    /// The optimal value depends on both the collection and the query, around `0.1` for title
+    /// queries and `0.7` for long queries. Defaults to `0.1`.
+    pub fn lambda<T>(mut self, lambda: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.lambda = Some(lambda.as_float());
+        self
+    }
+}
+
+serialize_with_type!("BM25": Bm25Similarity);
+deserialize_with_type!("BM25": Bm25Similarity);
+serialize_with_type!("DFR": DfrSimilarity);
+deserialize_with_type!("DFR": DfrSimilarity);
+serialize_with_type!("DFI": DfiSimilarity);
+deserialize_with_type!("DFI": DfiSimilarity);
+serialize_with_type!("IB": IbSimilarity);
+deserialize_with_type!("IB": IbSimilarity);
+serialize_with_type!("LMDirichlet": LmDirichletSimilarity);
+deserialize_with_type!("LMDirichlet": LmDirichletSimilarity);
+serialize_with_type!("LMJelinekMercer": LmJelinekMercerSimilarity);
+deserialize_with_type!("LMJelinekMercer": LmJelinekMercerSimilarity);
+
+impl From<Bm25Similarity> for Similarity {
+    fn from(similarity: Bm25Similarity) -> Self {
+        Self::Bm25(similarity)
+    }
+}
+
+impl From<DfrSimilarity> for Similarity {
+    fn from(similarity: DfrSimilarity) -> Self {
+        Self::Dfr(similarity)
+    }
+}
+
+impl From<DfiSimilarity> for Similarity {
+    fn from(similarity: DfiSimilarity) -> Self {
+        Self::Dfi(similarity)
+    }
+}
+
+impl From<IbSimilarity> for Similarity {
+    fn from(similarity: IbSimilarity) -> Self {
+        Self::Ib(similarity)
+    }
+}
+
+impl From<LmDirichletSimilarity> for Similarity {
+    fn from(similarity: LmDirichletSimilarity) -> Self {
+        Self::LmDirichlet(similarity)
+    }
+}
+
+impl From<LmJelinekMercerSimilarity> for Similarity {
+    fn from(similarity: LmJelinekMercerSimilarity) -> Self {
+        Self::LmJelinekMercer(similarity)
+    }
+}
+
+impl From<serde_json::Value> for Similarity {
+    fn from(similarity: serde_json::Value) -> Self {
+        Self::Custom(similarity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                Similarity::from(Similarity::bm25()),
+                Similarity::from(Similarity::bm25().k1(1.5).b(0.5).discount_overlaps(false)),
+                Similarity::from(Similarity::dfr(
+                    DfrBasicModel::G,
+                    DfrAfterEffect::L,
+                    SimilarityNormalization::H2,
+                )),
+                Similarity::from(Similarity::dfi(DfiIndependenceMeasure::ChiSquared)),
+                Similarity::from(Similarity::ib(
+                    IbDistribution::Ll,
+                    IbLambda::Df,
+                    SimilarityNormalization::Z,
+                )),
+                Similarity::from(Similarity::lm_dirichlet().mu(1500)),
+                Similarity::from(Similarity::lm_jelinek_mercer().lambda(0.7)),
+            ],
+            json!([
+                { "type": "BM25" },
+                { "type": "BM25", "k1": 1.5, "b": 0.5, "discount_overlaps": false },
+                { "type": "DFR", "basic_model": "g", "after_effect": "l", "normalization": "h2" },
+                { "type": "DFI", "independence_measure": "chisquared" },
+                { "type": "IB", "distribution": "ll", "lambda": "df", "normalization": "z" },
+                { "type": "LMDirichlet", "mu": 1500.0 },
+                { "type": "LMJelinekMercer", "lambda": 0.7 },
+            ]),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let subject: Vec<Similarity> = serde_json::from_value(json!([
+            { "type": "BM25", "k1": "1.5", "b": "0.5" },
+            { "type": "DFI", "independence_measure": "saturated" },
+            { "type": "LMJelinekMercer", "lambda": "0.7" },
+            { "type": "scripted", "script": { "source": "return 1.0;" } },
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            subject,
+            [
+                Similarity::from(Similarity::bm25().k1(1.5).b(0.5)),
+                Similarity::from(Similarity::dfi(DfiIndependenceMeasure::Saturated)),
+                Similarity::from(Similarity::lm_jelinek_mercer().lambda(0.7)),
+                Similarity::from(
+                    json!({ "type": "scripted", "script": { "source": "return 1.0;" } })
+                ),
+            ]
+        );
+    }
+}
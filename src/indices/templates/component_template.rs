@@ -0,0 +1,88 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// Request body of the create or update component template API
+///
+/// To create a component template:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let template =
+/// ComponentTemplate::new(
+///     Template::new().mappings(Mappings::new().property("@timestamp", Property::date())),
+/// )
+/// .version(1);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-component-template.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentTemplate {
+    template: Template,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _meta: Map<String, serde_json::Value>,
+}
+
+impl ComponentTemplate {
+    /// Creates an instance of [`ComponentTemplate`]
+    ///
+    /// - `template` - The template to be applied which includes `mappings`, `settings` or
+    ///   `aliases` configuration
+    pub fn new(template: Template) -> Self {
+        Self {
+            template,
+            version: None,
+            _meta: Default::default(),
+        }
+    }
+
+    /// Version number used to manage component templates externally
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Adds optional user metadata about the component template
+    pub fn meta<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Into<serde_json::Value>,
+    {
+        let _ = self._meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            ComponentTemplate::new(Template::new()),
+            json!({ "template": {} }),
+        );
+
+        assert_serialize(
+            ComponentTemplate::new(
+                Template::new().mappings(Mappings::new().property("@timestamp", Property::date())),
+            )
+            .version(1)
+            .meta("managed", true),
+            json!({
+                "template": {
+                    "mappings": {
+                        "properties": {
+                            "@timestamp": { "type": "date" }
+                        }
+                    }
+                },
+                "version": 1,
+                "_meta": { "managed": true }
+            }),
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// Request body of the create or update index template API
+///
+/// To create an index template:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let template =
+/// IndexTemplate::new(["logs-*"])
+///     .composed_of(["logs-mappings", "logs-settings"])
+///     .template(Template::new().settings(IndexSettings::new().number_of_replicas(1)))
+///     .priority(500);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-template.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IndexTemplate {
+    index_patterns: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    template: Template,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    composed_of: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    priority: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _meta: Map<String, serde_json::Value>,
+}
+
+impl IndexTemplate {
+    /// Creates an instance of [`IndexTemplate`]
+    ///
+    /// - `index_patterns` - Array of wildcard expressions used to match the names of data
+    ///   streams and indices during creation
+    pub fn new<T>(index_patterns: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        Self {
+            index_patterns: index_patterns.into_iter().map(|x| x.to_string()).collect(),
+            template: Default::default(),
+            composed_of: Vec::new(),
+            priority: None,
+            version: None,
+            _meta: Default::default(),
+        }
+    }
+
+    /// Template to be applied, may optionally include `aliases`, `mappings` and `settings`
+    pub fn template(mut self, template: Template) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// An ordered list of component template names. Component templates are merged in the
+    /// order specified, meaning that the last component template specified has the highest
+    /// precedence.
+    pub fn composed_of<T>(mut self, composed_of: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.composed_of
+            .extend(composed_of.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Priority to determine index template precedence when a new data stream or index is
+    /// created. The index template with the highest priority is chosen.
+    pub fn priority(mut self, priority: u64) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Version number used to manage index templates externally
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Adds optional user metadata about the index template
+    pub fn meta<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Into<serde_json::Value>,
+    {
+        let _ = self._meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            IndexTemplate::new(["logs-*"]),
+            json!({ "index_patterns": ["logs-*"] }),
+        );
+
+        assert_serialize(
+            IndexTemplate::new(["logs-*", "metrics-*"])
+                .template(Template::new().settings(IndexSettings::new().number_of_shards(2)))
+                .composed_of(["component_template1", "runtime_component_template"])
+                .priority(500)
+                .version(3)
+                .meta("description", "my custom"),
+            json!({
+                "index_patterns": ["logs-*", "metrics-*"],
+                "template": {
+                    "settings": { "number_of_shards": 2 }
+                },
+                "composed_of": ["component_template1", "runtime_component_template"],
+                "priority": 500,
+                "version": 3,
+                "_meta": { "description": "my custom" }
+            }),
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Index templates define settings, mappings, and aliases that can be applied automatically to
+//! new indices. Component templates are reusable building blocks that can be composed into
+//! index templates.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/index-templates.html>
+
+mod component_template;
+mod index_template;
+mod template;
+
+pub use self::component_template::*;
+pub use self::index_template::*;
+pub use self::template::*;
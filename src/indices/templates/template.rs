@@ -0,0 +1,87 @@
+use crate::indices::*;
+use crate::util::*;
+use crate::Map;
+
+/// Settings, mappings and aliases applied by an index or component template
+///
+/// To create a template:
+/// ```
+/// # use elasticsearch_dsl::indices::*;
+/// # let template =
+/// Template::new()
+///     .settings(IndexSettings::new().number_of_shards(1))
+///     .mappings(Mappings::new().property("@timestamp", Property::date()))
+///     .alias("logs", Alias::new());
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-template.html#put-index-template-api-request-body>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Template {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    settings: IndexSettings,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mappings: Mappings,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aliases: Map<String, Alias>,
+}
+
+impl Template {
+    /// Creates an instance of [`Template`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configuration options for the index
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Mapping for fields in the index
+    pub fn mappings(mut self, mappings: Mappings) -> Self {
+        self.mappings = mappings;
+        self
+    }
+
+    /// Adds an alias for the index
+    pub fn alias<T>(mut self, name: T, alias: Alias) -> Self
+    where
+        T: ToString,
+    {
+        let _ = self.aliases.insert(name.to_string(), alias);
+        self
+    }
+}
+
+impl ShouldSkip for Template {
+    fn should_skip(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Template::new(), json!({}));
+
+        assert_serialize(
+            Template::new()
+                .settings(IndexSettings::new().number_of_shards(1))
+                .mappings(Mappings::new().property("@timestamp", Property::date()))
+                .alias("logs", Alias::new()),
+            json!({
+                "settings": { "number_of_shards": 1 },
+                "mappings": {
+                    "properties": {
+                        "@timestamp": { "type": "date" }
+                    }
+                },
+                "aliases": { "logs": {} }
+            }),
+        );
+    }
+}
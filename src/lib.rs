@@ -6,6 +6,8 @@
 //!
 //! - Strongly typed queries
 //! - Strongly typed aggregations
+//! - Strongly typed index mappings, optionally derived from document structs with the `derive` feature
+//! - Typed document field accessors for building queries, derived with the `derive` feature
 //! - Automatically skips empty queries making DSL pleasant to use
 //! - Crate doesn't depend on [elasticsearch-rs](https://github.com/elastic/elasticsearch-rs) and can be used as a standalone library with any HTTP client to call Elasticsearch
 //!
@@ -25,7 +27,7 @@
 //! ## Quick start
 //!
 //! ```rust
-//! use elasticsearch_dsl::*;
+//! use elasticsearch_dsl::prelude::*;
 //!
 //! let query = Search::new()
 //!     .source(false)
@@ -55,6 +57,11 @@
 //!     ).rescore(Rescore::new(Query::term("field", 1)).query_weight(1.2));
 //! ```
 //!
+//! The crate root re-exports every type, which may collide with types of your own (e.g. `Term`,
+//! `Script` or `Sort`). Import the [`prelude`] and use the namespaced modules, like
+//! [`queries`], [`aggs`] and [`response`], instead to avoid that. The root re-exports are
+//! enabled by the default `root-reexports` feature.
+//!
 //! See examples for more.
 //!
 //! #### License
@@ -121,8 +128,37 @@ pub(crate) use self::types::*;
 
 // Public modules
 pub mod analyze;
+pub mod document;
+pub mod ilm;
+pub mod indices;
+pub mod scripts;
 pub mod search;
+pub mod synonyms;
+
+pub mod prelude;
+
+/// Queries and their parameters
+pub mod queries {
+    pub use crate::search::queries::params::*;
+    pub use crate::search::queries::*;
+}
+
+/// Aggregations and their parameters
+pub mod aggs {
+    pub use crate::search::aggregations::*;
+}
+
+/// Search response types
+pub mod response {
+    pub use crate::search::response::*;
+}
+
+// Public re-exports, kept at the crate root for backward compatibility
+#[cfg(feature = "root-reexports")]
+pub use self::{analyze::*, document::*, ilm::*, indices::*, scripts::*, search::*, synonyms::*};
+
+#[cfg(not(feature = "root-reexports"))]
+pub(crate) use self::search::*;
 
-// Public re-exports
-pub use self::analyze::*;
-pub use self::search::*;
+#[cfg(feature = "derive")]
+pub use elasticsearch_dsl_derive::{EsDocument, EsMapping};
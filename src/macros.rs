@@ -14,12 +14,31 @@ macro_rules! add_boost_and_name {
         /// A value greater than `1.0` increases the relevance score.
         pub fn boost<T>(mut self, boost: T) -> Self
         where
-            T: num_traits::AsPrimitive<f32>,
+            T: $crate::AsFloat,
         {
-            self.boost = Some(boost.as_());
+            self.boost = Some(boost.as_float());
             self
         }
 
+        /// Same as `boost`, but fails if `boost` is negative or not a finite number, which
+        /// Elasticsearch would reject.
+        pub fn try_boost<T>(
+            mut self,
+            boost: T,
+        ) -> ::std::result::Result<Self, $crate::ValidationError>
+        where
+            T: $crate::AsFloat,
+        {
+            let boost = boost.as_float();
+
+            if !boost.is_finite() || boost < 0.0 {
+                return Err($crate::ValidationError::InvalidBoost(boost));
+            }
+
+            self.boost = Some(boost);
+            Ok(self)
+        }
+
         /// You can use named queries to track which queries matched
         /// returned documents. If named queries are used, the response
         /// includes a `matched_queries` property for each hit.
@@ -45,10 +64,92 @@ macro_rules! add_aggregate {
             N: Into<AggregationName>,
             A: Into<Aggregation>,
         {
-            let a = aggregation.into();
-            let _ = self.aggs.entry(aggregation_name.into()).or_insert(a);
+            let aggregation_name = aggregation_name.into();
+
+            if !self.aggs().contains_key(&aggregation_name) {
+                let _ = self.aggs_mut().insert(aggregation_name, aggregation.into());
+            }
+
             self
         }
+
+        /// Pushes a linear chain of aggregations, each one nested in the previous one, instead
+        /// of building a pyramid of `.aggregate(...)` calls. The last aggregation of the chain is
+        /// usually a metric, fails if any of the other ones isn't a bucket aggregation.
+        pub fn try_aggregate_chain<I, N, A>(
+            self,
+            chain: I,
+        ) -> Result<Self, $crate::search::ValidationError>
+        where
+            I: IntoIterator<Item = (N, A)>,
+            N: Into<AggregationName>,
+            A: Into<Aggregation>,
+        {
+            let chain = chain
+                .into_iter()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .try_fold(None, |child, (name, aggregation)| {
+                    let aggregation: Aggregation = aggregation.into();
+
+                    Ok::<_, $crate::search::ValidationError>(Some(match child {
+                        Some((child_name, child)) => (
+                            name,
+                            aggregation.try_aggregate::<N, Aggregation>(child_name, child)?,
+                        ),
+                        None => (name, aggregation),
+                    }))
+                })?;
+
+            Ok(match chain {
+                Some((name, aggregation)) => self.aggregate(name, aggregation),
+                None => self,
+            })
+        }
+
+        /// Returns the sub-aggregation with the `aggregation_name`
+        pub fn sub_aggregation<N>(&self, aggregation_name: N) -> Option<&Aggregation>
+        where
+            N: Into<AggregationName>,
+        {
+            self.aggs().get(&aggregation_name.into())
+        }
+
+        /// Returns a mutable reference to the sub-aggregation with the `aggregation_name`, so that
+        /// a reusable aggregation can be adjusted after it has been built
+        pub fn sub_aggregation_mut<N>(&mut self, aggregation_name: N) -> Option<&mut Aggregation>
+        where
+            N: Into<AggregationName>,
+        {
+            self.aggs_mut().get_mut(&aggregation_name.into())
+        }
+
+        /// Removes the sub-aggregation with the `aggregation_name` and returns it
+        pub fn remove_aggregation<N>(&mut self, aggregation_name: N) -> Option<Aggregation>
+        where
+            N: Into<AggregationName>,
+        {
+            self.aggs_mut().remove(&aggregation_name.into())
+        }
+
+        /// Moves the sub-aggregation named `from` to the name `to`, replacing any aggregation
+        /// already stored under `to`. Returns `false` if there is no aggregation named `from`.
+        pub fn rename_aggregation<F, T>(&mut self, from: F, to: T) -> bool
+        where
+            F: Into<AggregationName>,
+            T: Into<AggregationName>,
+        {
+            self.aggs_mut().rename(&from.into(), to.into())
+        }
+
+        pub(crate) fn aggs(&self) -> &Aggregations {
+            &self.aggs
+        }
+
+        pub(crate) fn aggs_mut(&mut self) -> &mut Aggregations {
+            &mut self.aggs
+        }
     };
 }
 
@@ -197,3 +298,70 @@ macro_rules! serialize_keyed {
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serialize_with_type {
+    ($type:tt : $inner:ty) => {
+        impl $crate::serde::Serialize for $inner {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::ser::Serializer,
+            {
+                struct Wrapper<'a> {
+                    root: &'a $inner,
+                }
+
+                impl<'a> $crate::serde::Serialize for Wrapper<'a> {
+                    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                    where
+                        S: $crate::serde::Serializer,
+                    {
+                        <$inner>::serialize(&self.root, serializer)
+                    }
+                }
+
+                #[derive(Serialize)]
+                struct TypeWrapper<'a> {
+                    r#type: &'static str,
+
+                    #[serde(flatten)]
+                    wrapper: Wrapper<'a>,
+                }
+
+                TypeWrapper {
+                    r#type: $type,
+                    wrapper: Wrapper { root: self },
+                }
+                .serialize(serializer)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_with_type {
+    ($type:tt : $inner:ty) => {
+        impl<'de> $crate::serde::Deserialize<'de> for $inner {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::serde::de::Deserializer<'de>,
+            {
+                use $crate::serde::de::Error;
+
+                let value =
+                    <::serde_json::Value as $crate::serde::Deserialize>::deserialize(deserializer)?;
+
+                match value.get("type").and_then(::serde_json::Value::as_str) {
+                    Some($type) | None => <$inner>::deserialize($crate::util::Lenient::new(value))
+                        .map_err(D::Error::custom),
+                    Some(other) => Err(D::Error::custom(format!(
+                        "expected type `{}`, found `{}`",
+                        $type, other
+                    ))),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,27 @@
+//! Commonly used types for building requests and reading responses, without glob importing
+//! hundreds of types into the scope.
+//!
+//! ```
+//! use elasticsearch_dsl::prelude::*;
+//!
+//! # let search =
+//! Search::new()
+//!     .query(Query::term("user_id", 1))
+//!     .aggregate("brands", Aggregation::terms("brand_id"))
+//!     .sort(vec![FieldSort::descending("created_at")]);
+//! ```
+//!
+//! The rest of the types are available through the [`queries`](crate::queries),
+//! [`aggs`](crate::aggs), [`response`](crate::response) and other public modules.
+
+pub use crate::indices::{EsMapping, Mappings, Property};
+pub use crate::search::{
+    Aggregation, EsDocument, Field, FieldSort, Highlight, Hit, IntoQuery, IntoSearch, Knn, Query,
+    Rescore, Search, SearchResponse, SortOrder,
+};
+
+#[cfg(feature = "suggesters")]
+pub use crate::search::Suggester;
+
+#[cfg(feature = "derive")]
+pub use elasticsearch_dsl_derive::{EsDocument, EsMapping};
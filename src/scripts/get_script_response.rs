@@ -0,0 +1,89 @@
+use crate::search::*;
+use crate::util::ShouldSkip;
+use crate::Map;
+
+/// Elasticsearch get stored script API response
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/get-stored-script-api.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GetScriptResponse {
+    /// Stored script identifier
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the stored script exists
+    pub found: bool,
+
+    /// Stored script, present when the script exists
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub script: Option<StoredScript>,
+}
+
+/// Stored script or search template
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredScript {
+    /// Script language
+    pub lang: ScriptLang,
+
+    /// Script or search template source
+    pub source: String,
+
+    /// Script options
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub options: Map<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_found_script() {
+        let json = serde_json::json!({
+            "_id": "my-search-template",
+            "found": true,
+            "script": {
+                "lang": "mustache",
+                "source": "{\"query\":{\"match\":{\"message\":\"{{query_string}}\"}}}",
+                "options": {
+                    "content_type": "application/json;charset=utf-8"
+                }
+            }
+        });
+
+        let expected = GetScriptResponse {
+            id: "my-search-template".to_string(),
+            found: true,
+            script: Some(StoredScript {
+                lang: ScriptLang::Mustache,
+                source: "{\"query\":{\"match\":{\"message\":\"{{query_string}}\"}}}".to_string(),
+                options: Map::from([(
+                    "content_type".to_string(),
+                    "application/json;charset=utf-8".to_string(),
+                )]),
+            }),
+        };
+
+        let actual: GetScriptResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn deserializes_missing_script() {
+        let json = serde_json::json!({
+            "_id": "unknown",
+            "found": false
+        });
+
+        let expected = GetScriptResponse {
+            id: "unknown".to_string(),
+            found: false,
+            script: None,
+        };
+
+        let actual: GetScriptResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+}
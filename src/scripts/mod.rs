@@ -0,0 +1,10 @@
+//! Use the script APIs to create, retrieve and delete stored scripts and search templates which
+//! can then be referenced by `id` from queries, aggregations and other search features.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/script-apis.html>
+
+mod get_script_response;
+mod put_script;
+
+pub use self::get_script_response::*;
+pub use self::put_script::*;
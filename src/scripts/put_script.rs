@@ -0,0 +1,102 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// Request body of the create or update stored script API
+///
+/// The optional script `context` is not part of the body, it is passed as a path parameter, i.e.
+/// `PUT _scripts/<script-id>/<context>`.
+///
+/// To create a stored script:
+/// ```
+/// # use elasticsearch_dsl::scripts::*;
+/// # use elasticsearch_dsl::search::*;
+/// # let script =
+/// PutScript::new(ScriptLang::Painless, "Math.log(_score * 2) + params['my_modifier']");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/create-stored-script-api.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(remote = "Self")]
+pub struct PutScript {
+    lang: ScriptLang,
+
+    source: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    options: Map<String, String>,
+}
+
+impl PutScript {
+    /// Creates an instance of [`PutScript`]
+    ///
+    /// - `lang` - Script language, for search templates use `mustache`
+    /// - `source` - The script or search template source
+    pub fn new<T, U>(lang: T, source: U) -> Self
+    where
+        T: Into<ScriptLang>,
+        U: ToString,
+    {
+        Self {
+            lang: lang.into(),
+            source: source.to_string(),
+            options: Default::default(),
+        }
+    }
+
+    /// Creates an instance of [`PutScript`] for a search template
+    ///
+    /// - `source` - The search template, it may be either a JSON object or a string
+    pub fn search_template<T>(source: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::new(ScriptLang::Mustache, source)
+    }
+
+    /// Adds a script option, e.g. `content_type` for search templates
+    pub fn option<T, U>(mut self, key: T, value: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        let _ = self.options.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+serialize_with_root!("script": PutScript);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            PutScript::new(
+                ScriptLang::Painless,
+                "Math.log(_score * 2) + params['my_modifier']",
+            ),
+            json!({
+                "script": {
+                    "lang": "painless",
+                    "source": "Math.log(_score * 2) + params['my_modifier']"
+                }
+            }),
+        );
+
+        assert_serialize(
+            PutScript::search_template(r#"{"query":{"match":{"message":"{{query_string}}"}}}"#)
+                .option("content_type", "application/json;charset=utf-8"),
+            json!({
+                "script": {
+                    "lang": "mustache",
+                    "source": r#"{"query":{"match":{"message":"{{query_string}}"}}}"#,
+                    "options": {
+                        "content_type": "application/json;charset=utf-8"
+                    }
+                }
+            }),
+        );
+    }
+}
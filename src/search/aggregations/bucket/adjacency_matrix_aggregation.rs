@@ -0,0 +1,106 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A bucket aggregation returning a form of adjacency matrix. The request provides a collection of named filter
+/// expressions, similar to the `filters` aggregation request. Each bucket in the response represents a non-empty
+/// cell in the matrix of intersecting filters.
+///
+/// To create an adjacency matrix aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::adjacency_matrix([
+///     ("grpA", Query::terms("accounts", ["hillary", "sidney"])),
+///     ("grpB", Query::terms("accounts", ["donald", "mitt"])),
+/// ]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-adjacency-matrix-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AdjacencyMatrixAggregation {
+    adjacency_matrix: AdjacencyMatrixAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AdjacencyMatrixAggregationInner {
+    filters: Map<String, Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    separator: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AdjacencyMatrixAggregation`]
+    ///
+    /// - `filters` - named filters to intersect, can't exceed the `indices.query.bool.max_clause_count`
+    ///   cluster setting
+    pub fn adjacency_matrix<I, K, Q>(filters: I) -> AdjacencyMatrixAggregation
+    where
+        I: IntoIterator<Item = (K, Q)>,
+        K: ToString,
+        Q: Into<Query>,
+    {
+        AdjacencyMatrixAggregation {
+            adjacency_matrix: AdjacencyMatrixAggregationInner {
+                filters: filters
+                    .into_iter()
+                    .map(|(name, filter)| (name.to_string(), filter.into()))
+                    .collect(),
+                separator: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl AdjacencyMatrixAggregation {
+    /// The separator used to concatenate the filter names of the intersection buckets
+    ///
+    /// Default value is `&`
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: ToString,
+    {
+        self.adjacency_matrix.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Mutable access to the filters
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.adjacency_matrix.filters.values_mut().collect()
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::adjacency_matrix([
+                ("grpA", Query::term("accounts", "hillary")),
+                ("grpB", Query::term("accounts", "donald")),
+            ])
+            .separator("|")
+            .aggregate("dates", Aggregation::date_histogram("date")),
+            json!({
+                "adjacency_matrix": {
+                    "filters": {
+                        "grpA": { "term": { "accounts": { "value": "hillary" } } },
+                        "grpB": { "term": { "accounts": { "value": "donald" } } }
+                    },
+                    "separator": "|"
+                },
+                "aggs": {
+                    "dates": { "date_histogram": { "field": "date" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A multi-bucket aggregation similar to the date histogram except instead of providing an interval to use as the
+/// width of each bucket, a target number of buckets is provided indicating the number of buckets needed and the
+/// interval of the buckets is automatically chosen to best achieve that target. The number of buckets returned will
+/// always be less than or equal to this target number.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-autodatehistogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AutoDateHistogramAggregation {
+    auto_date_histogram: AutoDateHistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AutoDateHistogramAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    buckets: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    minimum_interval: Option<CalendarInterval>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AutoDateHistogramAggregation`]
+    ///
+    /// - `field` - field to group by
+    pub fn auto_date_histogram<T>(field: T) -> AutoDateHistogramAggregation
+    where
+        T: ToString,
+    {
+        AutoDateHistogramAggregation {
+            auto_date_histogram: AutoDateHistogramAggregationInner {
+                field: field.to_string(),
+                buckets: None,
+                format: None,
+                time_zone: None,
+                minimum_interval: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl AutoDateHistogramAggregation {
+    /// The target number of buckets
+    ///
+    /// Default value is `10`
+    pub fn buckets(mut self, buckets: u32) -> Self {
+        self.auto_date_histogram.buckets = Some(buckets);
+        self
+    }
+
+    /// Date format used to render the `key_as_string` of the buckets
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.auto_date_histogram.format = Some(format.to_string());
+        self
+    }
+
+    /// Elasticsearch stores date-times in Coordinated Universal Time (UTC). By default, all bucketing and rounding is
+    /// also done in UTC. Use the time_zone parameter to indicate that bucketing should use a different time zone.
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.auto_date_histogram.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// The smallest interval that can be chosen, one of `year`, `month`, `day`, `hour`, `minute`
+    /// or `second`
+    pub fn minimum_interval(mut self, minimum_interval: CalendarInterval) -> Self {
+        self.auto_date_histogram.minimum_interval = Some(minimum_interval);
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.auto_date_histogram.missing = Term::new(missing);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::auto_date_histogram("date"),
+            json!({ "auto_date_histogram": { "field": "date" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::auto_date_histogram("date")
+                .buckets(5)
+                .format("yyyy-MM-dd")
+                .time_zone("-01:00")
+                .minimum_interval(CalendarInterval::Minute)
+                .missing("2000/01/01")
+                .aggregate("sales", Aggregation::sum("price")),
+            json!({
+                "auto_date_histogram": {
+                    "field": "date",
+                    "buckets": 5,
+                    "format": "yyyy-MM-dd",
+                    "time_zone": "-01:00",
+                    "minimum_interval": "minute",
+                    "missing": "2000/01/01"
+                },
+                "aggs": {
+                    "sales": { "sum": { "field": "price" } }
+                }
+            }),
+        );
+    }
+}
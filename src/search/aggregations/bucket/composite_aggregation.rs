@@ -1,10 +1,39 @@
 use crate::search::*;
 use crate::util::*;
+use crate::Map;
 use serde::{Serialize, Serializer};
 use serde_json::Value;
 
 /// A multi-bucket aggregation that creates composite buckets from different sources.
 ///
+/// To paginate through all the buckets:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let response: SearchResponse = serde_json::from_value(serde_json::json!({
+/// #     "took": 1,
+/// #     "timed_out": false,
+/// #     "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+/// #     "hits": { "hits": [] },
+/// #     "aggregations": {
+/// #         "export": {
+/// #             "after_key": { "brand": "b" },
+/// #             "buckets": [{ "key": { "brand": "b" }, "doc_count": 1 }]
+/// #         }
+/// #     }
+/// # })).unwrap();
+/// let aggregation = Aggregation::composite([
+///     CompositeSource::terms("brand", "brand").missing_bucket(true),
+///     CompositeSource::date_histogram("day", "timestamp", "1d").order(SortOrder::Desc),
+/// ])
+/// .size(1000);
+///
+/// let result: CompositeAggregationResult = response.aggregation("export").unwrap().unwrap();
+///
+/// if let Some(after_key) = result.after_key {
+///     let next_page = aggregation.after(after_key);
+/// #   let _ = next_page;
+/// }
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-composite-aggregation.html>
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct CompositeAggregation {
@@ -28,11 +57,14 @@ struct CompositeAggregationInner {
 impl Aggregation {
     /// Creates an instance of [`CompositeAggregation`]
     ///
-    /// - `sources` - A vector of `CompositeSource` which defines the sources for the composite aggregation.
-    pub fn composite(sources: Vec<CompositeSource>) -> CompositeAggregation {
+    /// - `sources` - The sources of the composite buckets, the order defines the order of the keys
+    pub fn composite<T>(sources: T) -> CompositeAggregation
+    where
+        T: IntoIterator<Item = CompositeSource>,
+    {
         CompositeAggregation {
             composite: CompositeAggregationInner {
-                sources,
+                sources: sources.into_iter().collect(),
                 size: None,
                 after: None,
             },
@@ -67,8 +99,9 @@ impl CompositeAggregation {
 /// Represents the `after` key for pagination in composite aggregations.
 ///
 /// The `AfterKey` is used to paginate through the composite aggregation results.
-/// It is typically a JSON object containing the values of the composite keys.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+/// It is typically a JSON object containing the values of the composite keys, as returned in
+/// the [`after_key`](crate::CompositeAggregationResult::after_key) of the response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AfterKey(Value);
 
 impl From<Value> for AfterKey {
@@ -77,6 +110,12 @@ impl From<Value> for AfterKey {
     }
 }
 
+impl From<Map<String, Value>> for AfterKey {
+    fn from(value: Map<String, Value>) -> Self {
+        AfterKey(Value::Object(value.into_iter().collect()))
+    }
+}
+
 impl AfterKey {
     /// Creates a new `AfterKey` instance from a JSON value.
     ///
@@ -84,6 +123,11 @@ impl AfterKey {
     pub fn new(value: Value) -> Self {
         AfterKey(value)
     }
+
+    /// The value of the composite key of the given source
+    pub fn get(&self, source: &str) -> Option<&Value> {
+        self.0.get(source)
+    }
 }
 
 /// Represents different types of sources for a composite aggregation.
@@ -110,6 +154,27 @@ pub enum CompositeSource {
         /// The date histogram composite source.
         date_histogram: DateHistogramCompositeSource,
     },
+    /// Geotile grid source for the composite aggregation.
+    GeotileGrid {
+        /// The unique identifier for the geotile grid source.
+        name: String,
+        /// The geotile grid composite source.
+        geotile_grid: GeotileGridCompositeSource,
+    },
+}
+
+/// Position of the bucket of the documents without a value, when `missing_bucket` is enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingOrder {
+    /// Missing bucket comes first
+    First,
+
+    /// Missing bucket comes last
+    Last,
+
+    /// Missing bucket comes first for ascending and last for descending order
+    Default,
 }
 
 impl Serialize for CompositeSource {
@@ -117,25 +182,24 @@ impl Serialize for CompositeSource {
     where
         S: Serializer,
     {
-        let mut map = serde_json::Map::new();
         match self {
             CompositeSource::Terms { name, terms } => {
-                let _ = map.insert(name.clone(), serde_json::json!({ "terms": terms }));
+                KeyValuePair::new(name, KeyValuePair::new("terms", terms)).serialize(serializer)
             }
             CompositeSource::Histogram { name, histogram } => {
-                let _ = map.insert(name.clone(), serde_json::json!({ "histogram": histogram }));
+                KeyValuePair::new(name, KeyValuePair::new("histogram", histogram))
+                    .serialize(serializer)
             }
             CompositeSource::DateHistogram {
                 name,
                 date_histogram,
-            } => {
-                let _ = map.insert(
-                    name.clone(),
-                    serde_json::json!({ "date_histogram": date_histogram }),
-                );
+            } => KeyValuePair::new(name, KeyValuePair::new("date_histogram", date_histogram))
+                .serialize(serializer),
+            CompositeSource::GeotileGrid { name, geotile_grid } => {
+                KeyValuePair::new(name, KeyValuePair::new("geotile_grid", geotile_grid))
+                    .serialize(serializer)
             }
         }
-        map.serialize(serializer)
     }
 }
 
@@ -146,6 +210,8 @@ pub struct TermsCompositeSource {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing_bucket: Option<bool>,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     order: Option<SortOrder>,
 }
 
@@ -157,6 +223,8 @@ pub struct HistogramCompositeSource {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing_bucket: Option<bool>,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     order: Option<SortOrder>,
 }
 
@@ -168,6 +236,21 @@ pub struct DateHistogramCompositeSource {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing_bucket: Option<bool>,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<SortOrder>,
+}
+
+/// Represents a geotile grid source in a composite aggregation.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeotileGridCompositeSource {
+    field: String,
+    precision: u8,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     order: Option<SortOrder>,
 }
 
@@ -176,12 +259,17 @@ impl CompositeSource {
     ///
     /// - `name` - The unique identifier for the terms source.
     /// - `field` - The field to perform the terms aggregation on.
-    pub fn terms(name: &str, field: &str) -> CompositeSource {
+    pub fn terms<T, U>(name: T, field: U) -> CompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
         CompositeSource::Terms {
             name: name.to_string(),
             terms: TermsCompositeSource {
                 field: field.to_string(),
                 missing_bucket: None,
+                missing_order: None,
                 order: None,
             },
         }
@@ -192,13 +280,18 @@ impl CompositeSource {
     /// - `name` - The unique identifier for the histogram source.
     /// - `field` - The field to perform the histogram aggregation on.
     /// - `interval` - The interval for the histogram buckets.
-    pub fn histogram(name: &str, field: &str, interval: f64) -> CompositeSource {
+    pub fn histogram<T, U>(name: T, field: U, interval: f64) -> CompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
         CompositeSource::Histogram {
             name: name.to_string(),
             histogram: HistogramCompositeSource {
                 field: field.to_string(),
                 interval,
                 missing_bucket: None,
+                missing_order: None,
                 order: None,
             },
         }
@@ -209,17 +302,99 @@ impl CompositeSource {
     /// - `name` - The unique identifier for the date histogram source.
     /// - `field` - The field to perform the date histogram aggregation on.
     /// - `calendar_interval` - The calendar interval for the date histogram buckets.
-    pub fn date_histogram(name: &str, field: &str, calendar_interval: &str) -> CompositeSource {
+    pub fn date_histogram<T, U, V>(name: T, field: U, calendar_interval: V) -> CompositeSource
+    where
+        T: ToString,
+        U: ToString,
+        V: ToString,
+    {
         CompositeSource::DateHistogram {
             name: name.to_string(),
             date_histogram: DateHistogramCompositeSource {
                 field: field.to_string(),
                 calendar_interval: calendar_interval.to_string(),
                 missing_bucket: None,
+                missing_order: None,
+                order: None,
+            },
+        }
+    }
+
+    /// Creates a geotile grid source for the composite aggregation, the keys are the tiles in the
+    /// `{zoom}/{x}/{y}` format.
+    ///
+    /// - `name` - The unique identifier for the geotile grid source.
+    /// - `field` - The `geo_point` field to group by.
+    /// - `precision` - The zoom level of the tiles, between `0` and `29`.
+    pub fn geotile_grid<T, U>(name: T, field: U, precision: u8) -> CompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
+        CompositeSource::GeotileGrid {
+            name: name.to_string(),
+            geotile_grid: GeotileGridCompositeSource {
+                field: field.to_string(),
+                precision,
+                missing_bucket: None,
+                missing_order: None,
                 order: None,
             },
         }
     }
+
+    /// Sets the `missing_bucket` parameter of the source.
+    ///
+    /// - `missing_bucket` - Whether to include documents with missing values in the bucket.
+    pub fn missing_bucket(mut self, missing_bucket: bool) -> Self {
+        match &mut self {
+            CompositeSource::Terms { terms, .. } => terms.missing_bucket = Some(missing_bucket),
+            CompositeSource::Histogram { histogram, .. } => {
+                histogram.missing_bucket = Some(missing_bucket)
+            }
+            CompositeSource::DateHistogram { date_histogram, .. } => {
+                date_histogram.missing_bucket = Some(missing_bucket)
+            }
+            CompositeSource::GeotileGrid { geotile_grid, .. } => {
+                geotile_grid.missing_bucket = Some(missing_bucket)
+            }
+        }
+        self
+    }
+
+    /// Sets the `missing_order` parameter of the source.
+    ///
+    /// - `missing_order` - The position of the missing bucket.
+    pub fn missing_order(mut self, missing_order: MissingOrder) -> Self {
+        match &mut self {
+            CompositeSource::Terms { terms, .. } => terms.missing_order = Some(missing_order),
+            CompositeSource::Histogram { histogram, .. } => {
+                histogram.missing_order = Some(missing_order)
+            }
+            CompositeSource::DateHistogram { date_histogram, .. } => {
+                date_histogram.missing_order = Some(missing_order)
+            }
+            CompositeSource::GeotileGrid { geotile_grid, .. } => {
+                geotile_grid.missing_order = Some(missing_order)
+            }
+        }
+        self
+    }
+
+    /// Sets the `order` parameter of the source.
+    ///
+    /// - `order` - The order of the source values.
+    pub fn order(mut self, order: SortOrder) -> Self {
+        match &mut self {
+            CompositeSource::Terms { terms, .. } => terms.order = Some(order),
+            CompositeSource::Histogram { histogram, .. } => histogram.order = Some(order),
+            CompositeSource::DateHistogram { date_histogram, .. } => {
+                date_histogram.order = Some(order)
+            }
+            CompositeSource::GeotileGrid { geotile_grid, .. } => geotile_grid.order = Some(order),
+        }
+        self
+    }
 }
 
 impl TermsCompositeSource {
@@ -231,6 +406,14 @@ impl TermsCompositeSource {
         self
     }
 
+    /// Sets the `missing_order` parameter for the terms source.
+    ///
+    /// - `missing_order` - The position of the missing bucket.
+    pub fn missing_order(mut self, missing_order: MissingOrder) -> Self {
+        self.missing_order = Some(missing_order);
+        self
+    }
+
     /// Sets the `order` parameter for the terms source.
     ///
     /// - `order` - The order of the terms in the bucket.
@@ -249,6 +432,14 @@ impl HistogramCompositeSource {
         self
     }
 
+    /// Sets the `missing_order` parameter for the histogram source.
+    ///
+    /// - `missing_order` - The position of the missing bucket.
+    pub fn missing_order(mut self, missing_order: MissingOrder) -> Self {
+        self.missing_order = Some(missing_order);
+        self
+    }
+
     /// Sets the `order` parameter for the histogram source.
     ///
     /// - `order` - The order of the histogram buckets.
@@ -267,6 +458,14 @@ impl DateHistogramCompositeSource {
         self
     }
 
+    /// Sets the `missing_order` parameter for the date histogram source.
+    ///
+    /// - `missing_order` - The position of the missing bucket.
+    pub fn missing_order(mut self, missing_order: MissingOrder) -> Self {
+        self.missing_order = Some(missing_order);
+        self
+    }
+
     /// Sets the `order` parameter for the date histogram source.
     ///
     /// - `order` - The order of the date histogram buckets.
@@ -308,5 +507,64 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::composite([
+                CompositeSource::terms("brand", "brand")
+                    .missing_bucket(true)
+                    .missing_order(MissingOrder::Last)
+                    .order(SortOrder::Desc),
+                CompositeSource::histogram("price", "price", 5.0).order(SortOrder::Asc),
+                CompositeSource::date_histogram("day", "timestamp", "1d")
+                    .missing_bucket(true)
+                    .missing_order(MissingOrder::First),
+                CompositeSource::geotile_grid("tile", "location", 8).missing_bucket(false),
+            ])
+            .after(AfterKey::from(Map::from([
+                ("brand".to_string(), json!("a")),
+                ("tile".to_string(), json!("8/131/84")),
+            ])))
+            .aggregate("sales", Aggregation::sum("price")),
+            json!({
+                "composite": {
+                    "sources": [
+                        {
+                            "brand": {
+                                "terms": {
+                                    "field": "brand",
+                                    "missing_bucket": true,
+                                    "missing_order": "last",
+                                    "order": "desc"
+                                }
+                            }
+                        },
+                        { "price": { "histogram": { "field": "price", "interval": 5.0, "order": "asc" } } },
+                        {
+                            "day": {
+                                "date_histogram": {
+                                    "field": "timestamp",
+                                    "calendar_interval": "1d",
+                                    "missing_bucket": true,
+                                    "missing_order": "first"
+                                }
+                            }
+                        },
+                        {
+                            "tile": {
+                                "geotile_grid": {
+                                    "field": "location",
+                                    "precision": 8,
+                                    "missing_bucket": false
+                                }
+                            }
+                        }
+                    ],
+                    "after": { "brand": "a", "tile": "8/131/84" }
+                },
+                "aggs": {
+                    "sales": { "sum": { "field": "price" } }
+                }
+            }),
+        );
     }
 }
@@ -1,6 +1,6 @@
 use crate::search::*;
 use crate::util::*;
-use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 /// This multi-bucket aggregation is similar to the normal histogram, but it can only be used with date or date range
 /// values. Because dates are represented internally in Elasticsearch as long values, it is possible, but not as
@@ -31,7 +31,7 @@ struct DateHistogramAggregationInner {
     min_doc_count: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    missing: Option<DateTime<Utc>>,
+    missing: Option<Term>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     offset: Option<String>,
@@ -92,8 +92,11 @@ impl DateHistogramAggregation {
 
     /// The missing parameter defines how documents that are missing a value should be treated.
     /// By default they will be ignored but it is also possible to treat them as if they had a value.
-    pub fn missing(mut self, missing: DateTime<Utc>) -> Self {
-        self.date_histogram.missing = Some(missing);
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.date_histogram.missing = Term::new(missing);
         self
     }
 
@@ -145,7 +148,6 @@ impl DateHistogramAggregation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::prelude::*;
 
     #[test]
     fn serialization() {
@@ -159,11 +161,7 @@ mod tests {
                 .calendar_interval(CalendarInterval::Day)
                 .fixed_interval(Time::Hours(1))
                 .min_doc_count(2)
-                .missing(
-                    Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 4)
-                        .single()
-                        .unwrap(),
-                )
+                .missing("2014-11-28T12:00:04Z")
                 .order(TermsOrder::new("test_order", SortOrder::Asc))
                 .offset("+6h")
                 .time_zone("-01:00"),
@@ -0,0 +1,162 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A range aggregation that is dedicated for date values. The main difference between this aggregation and the
+/// normal range aggregation is that the `from` and `to` values can be expressed in
+/// [Date Math](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#date-math)
+/// expressions, and it is also possible to specify a date format by which the `from` and `to` response fields will
+/// be returned.
+///
+/// To create a date range aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::date_range(
+///     "created_at",
+///     [
+///         AggregationRange::new().to("now-10M/M"),
+///         AggregationRange::new().from("now-10M/M"),
+///     ],
+/// )
+/// .format("MM-yyyy");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-daterange-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DateRangeAggregation {
+    date_range: DateRangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct DateRangeAggregationInner {
+    field: String,
+
+    ranges: Vec<AggregationRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`DateRangeAggregation`]
+    ///
+    /// - `field` - field to group by
+    /// - `ranges` - ranges of the buckets, the bounds are dates or date math expressions
+    pub fn date_range<T, U>(field: T, ranges: U) -> DateRangeAggregation
+    where
+        T: ToString,
+        U: IntoIterator<Item = AggregationRange>,
+    {
+        DateRangeAggregation {
+            date_range: DateRangeAggregationInner {
+                field: field.to_string(),
+                ranges: ranges.into_iter().collect(),
+                format: None,
+                time_zone: None,
+                missing: None,
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl DateRangeAggregation {
+    /// Date format used to parse the range bounds and to render the `from_as_string` and
+    /// `to_as_string` response fields
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_range.format = Some(format.to_string());
+        self
+    }
+
+    /// Time zone used to convert the date math range bounds, UTC by default
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_range.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.date_range.missing = Term::new(missing);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by the bucket key, instead of an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.date_range.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::date_range(
+                "created_at",
+                [
+                    AggregationRange::new().to("now-10M/M"),
+                    AggregationRange::new().from("now-10M/M").key("recent"),
+                ],
+            ),
+            json!({
+                "date_range": {
+                    "field": "created_at",
+                    "ranges": [
+                        { "to": "now-10M/M" },
+                        { "key": "recent", "from": "now-10M/M" }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::date_range("created_at", [AggregationRange::new().from("2020-01")])
+                .format("yyyy-MM")
+                .time_zone("CET")
+                .missing("1970-01")
+                .keyed(true)
+                .aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "date_range": {
+                    "field": "created_at",
+                    "ranges": [{ "from": "2020-01" }],
+                    "format": "yyyy-MM",
+                    "time_zone": "CET",
+                    "missing": "1970-01",
+                    "keyed": true
+                },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+}
@@ -28,6 +28,11 @@ impl Aggregation {
 }
 
 impl FilterAggregation {
+    /// Mutable access to the filter
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.filter]
+    }
+
     add_aggregate!();
 }
 
@@ -0,0 +1,183 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A multi-bucket aggregation where each bucket contains the documents that match a query.
+///
+/// To create a filters aggregation with named buckets:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::filters([
+///     ("errors", Query::r#match("body", "error")),
+///     ("warnings", Query::r#match("body", "warning")),
+/// ])
+/// .other_bucket_key("other_messages");
+/// ```
+/// To create a filters aggregation with anonymous buckets, returned in the order of the filters:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::anonymous_filters([
+///     Query::r#match("body", "error"),
+///     Query::r#match("body", "warning"),
+/// ]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-filters-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FiltersAggregation {
+    filters: FiltersAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct FiltersAggregationInner {
+    filters: FiltersAggregationFilters,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    other_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    other_bucket_key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+enum FiltersAggregationFilters {
+    Anonymous(Vec<Query>),
+    Named(Map<String, Query>),
+}
+
+impl Aggregation {
+    /// Creates an instance of [`FiltersAggregation`] with named buckets
+    ///
+    /// - `filters` - named queries of the buckets
+    pub fn filters<I, K, Q>(filters: I) -> FiltersAggregation
+    where
+        I: IntoIterator<Item = (K, Q)>,
+        K: ToString,
+        Q: Into<Query>,
+    {
+        FiltersAggregation::new(FiltersAggregationFilters::Named(
+            filters
+                .into_iter()
+                .map(|(name, filter)| (name.to_string(), filter.into()))
+                .collect(),
+        ))
+    }
+
+    /// Creates an instance of [`FiltersAggregation`] with anonymous buckets
+    ///
+    /// - `filters` - queries of the buckets, the buckets are returned in the same order
+    pub fn anonymous_filters<I>(filters: I) -> FiltersAggregation
+    where
+        I: IntoIterator,
+        I::Item: Into<Query>,
+    {
+        FiltersAggregation::new(FiltersAggregationFilters::Anonymous(
+            filters.into_iter().map(Into::into).collect(),
+        ))
+    }
+}
+
+impl FiltersAggregation {
+    fn new(filters: FiltersAggregationFilters) -> Self {
+        Self {
+            filters: FiltersAggregationInner {
+                filters,
+                other_bucket: None,
+                other_bucket_key: None,
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    /// Adds a bucket with the documents that don't match any of the filters
+    pub fn other_bucket(mut self, other_bucket: bool) -> Self {
+        self.filters.other_bucket = Some(other_bucket);
+        self
+    }
+
+    /// The key of the bucket with the documents that don't match any of the filters, setting it
+    /// enables the `other_bucket`
+    ///
+    /// Default value is `_other_`
+    pub fn other_bucket_key<T>(mut self, other_bucket_key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.filters.other_bucket_key = Some(other_bucket_key.to_string());
+        self
+    }
+
+    /// Returns the buckets of named filters as a hash keyed by the filter names, set to `false`
+    /// to return them as an array instead
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.filters.keyed = Some(keyed);
+        self
+    }
+
+    /// Mutable access to the filters
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        match &mut self.filters.filters {
+            FiltersAggregationFilters::Anonymous(filters) => filters.iter_mut().collect(),
+            FiltersAggregationFilters::Named(filters) => filters.values_mut().collect(),
+        }
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::filters([
+                ("errors", Query::term("level", "error")),
+                ("warnings", Query::term("level", "warning")),
+            ])
+            .other_bucket(true)
+            .other_bucket_key("other_messages")
+            .keyed(false)
+            .aggregate("hosts", Aggregation::terms("host")),
+            json!({
+                "filters": {
+                    "filters": {
+                        "errors": { "term": { "level": { "value": "error" } } },
+                        "warnings": { "term": { "level": { "value": "warning" } } }
+                    },
+                    "other_bucket": true,
+                    "other_bucket_key": "other_messages",
+                    "keyed": false
+                },
+                "aggs": {
+                    "hosts": { "terms": { "field": "host" } }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::anonymous_filters([
+                Query::term("level", "error"),
+                Query::term("level", "warning"),
+            ]),
+            json!({
+                "filters": {
+                    "filters": [
+                        { "term": { "level": { "value": "error" } } },
+                        { "term": { "level": { "value": "warning" } } }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation that works on `geo_point` fields and conceptually works very similar to the range
+/// aggregation. The user can define a point of origin and a set of distance range buckets. The aggregation evaluates
+/// the distance of each document value from the origin point and determines the buckets it belongs to based on the
+/// ranges (a document belongs to a bucket if the distance between the document and the origin falls within the
+/// distance range of the bucket).
+///
+/// To create a geo distance aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::geo_distance(
+///     "location",
+///     GeoLocation::new(52.3760, 4.894),
+///     [
+///         AggregationRange::new().to(100),
+///         AggregationRange::new().from(100).to(300),
+///     ],
+/// )
+/// .unit(DistanceUnit::Kilometers);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geodistance-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoDistanceAggregation {
+    geo_distance: GeoDistanceAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoDistanceAggregationInner {
+    field: String,
+
+    origin: GeoLocation,
+
+    ranges: Vec<AggregationRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unit: Option<DistanceUnit>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    distance_type: Option<GeoDistanceType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoDistanceAggregation`]
+    ///
+    /// - `field` - `geo_point` field to measure the distance of
+    /// - `origin` - point the distances are measured from
+    /// - `ranges` - distance ranges of the buckets, in meters unless the `unit` is set
+    pub fn geo_distance<T, U, V>(field: T, origin: U, ranges: V) -> GeoDistanceAggregation
+    where
+        T: ToString,
+        U: Into<GeoLocation>,
+        V: IntoIterator<Item = AggregationRange>,
+    {
+        GeoDistanceAggregation {
+            geo_distance: GeoDistanceAggregationInner {
+                field: field.to_string(),
+                origin: origin.into(),
+                ranges: ranges.into_iter().collect(),
+                unit: None,
+                distance_type: None,
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GeoDistanceAggregation {
+    /// The unit of the range bounds
+    ///
+    /// Default value is meters
+    pub fn unit(mut self, unit: DistanceUnit) -> Self {
+        self.geo_distance.unit = Some(unit);
+        self
+    }
+
+    /// How to compute the distance. Can either be `Arc` (default), or `Plane` (faster, but
+    /// inaccurate on long distances and close to the poles).
+    pub fn distance_type(mut self, distance_type: GeoDistanceType) -> Self {
+        self.geo_distance.distance_type = Some(distance_type);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by the bucket key, instead of an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.geo_distance.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geo_distance(
+                "location",
+                GeoLocation::new(52.5, 4.5),
+                [
+                    AggregationRange::new().to(100),
+                    AggregationRange::new().from(100).key("far"),
+                ],
+            ),
+            json!({
+                "geo_distance": {
+                    "field": "location",
+                    "origin": [4.5, 52.5],
+                    "ranges": [
+                        { "to": 100 },
+                        { "key": "far", "from": 100 }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::geo_distance("location", [4.5, 52.5], [AggregationRange::new().to(10)])
+                .unit(DistanceUnit::Kilometers)
+                .distance_type(GeoDistanceType::Plane)
+                .keyed(true)
+                .aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "geo_distance": {
+                    "field": "location",
+                    "origin": [4.5, 52.5],
+                    "ranges": [{ "to": 10 }],
+                    "unit": "km",
+                    "distance_type": "plane",
+                    "keyed": true
+                },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+}
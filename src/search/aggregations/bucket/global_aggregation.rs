@@ -0,0 +1,52 @@
+use crate::search::*;
+use crate::util::*;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+/// Defines a single bucket of all the documents within the search execution context. This context is defined by the
+/// indices and the document types you're searching on, but is not influenced by the search query itself.
+///
+/// > Global aggregators can only be placed as top level aggregators because it doesn't make sense to embed a
+/// > global aggregator within another bucket aggregator.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-global-aggregation.html>
+pub struct GlobalAggregation {
+    global: GlobalAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GlobalAggregationInner {}
+
+impl Aggregation {
+    /// Creates an instance of [`GlobalAggregation`]
+    pub fn global() -> GlobalAggregation {
+        GlobalAggregation {
+            global: GlobalAggregationInner {},
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GlobalAggregation {
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::global().aggregate("avg_price", Aggregation::avg("price")),
+            json!({
+                "global": {},
+                "aggs": {
+                    "avg_price": { "avg": { "field": "price" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A multi-bucket values source based aggregation that can be applied on numeric values or numeric range values
+/// extracted from the documents. It dynamically builds fixed size (a.k.a. interval) buckets over the values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-histogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HistogramAggregation {
+    histogram: HistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct HistogramAggregationInner {
+    field: String,
+
+    interval: Number,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    offset: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    extended_bounds: Option<HistogramBounds>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hard_bounds: Option<HistogramBounds>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: TermsOrderCollection,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct HistogramBounds {
+    min: Number,
+    max: Number,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`HistogramAggregation`]
+    ///
+    /// - `field` - field to group by
+    /// - `interval` - size of the buckets, must be a positive number
+    pub fn histogram<T, U>(field: T, interval: U) -> HistogramAggregation
+    where
+        T: ToString,
+        U: Into<Number>,
+    {
+        HistogramAggregation {
+            histogram: HistogramAggregationInner {
+                field: field.to_string(),
+                interval: interval.into(),
+                min_doc_count: None,
+                offset: None,
+                extended_bounds: None,
+                hard_bounds: None,
+                missing: None,
+                keyed: None,
+                order: Default::default(),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl HistogramAggregation {
+    /// Only returns buckets that have at least `min_doc_count` documents, set it to `0` to return
+    /// the empty buckets in between too
+    ///
+    /// Default value is `0`
+    pub fn min_doc_count(mut self, min_doc_count: u32) -> Self {
+        self.histogram.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Shifts the bucket boundaries, by default the buckets start at `0`
+    pub fn offset<T>(mut self, offset: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.histogram.offset = Some(offset.into());
+        self
+    }
+
+    /// Forces the histogram to return empty buckets between `min` and `max`, even when no documents
+    /// fall into them. Only has an effect with `min_doc_count` set to `0`.
+    pub fn extended_bounds<T, U>(mut self, min: T, max: U) -> Self
+    where
+        T: Into<Number>,
+        U: Into<Number>,
+    {
+        self.histogram.extended_bounds = Some(HistogramBounds {
+            min: min.into(),
+            max: max.into(),
+        });
+        self
+    }
+
+    /// Limits the range of buckets in the histogram, values outside of `min` and `max` are ignored
+    pub fn hard_bounds<T, U>(mut self, min: T, max: U) -> Self
+    where
+        T: Into<Number>,
+        U: Into<Number>,
+    {
+        self.histogram.hard_bounds = Some(HistogramBounds {
+            min: min.into(),
+            max: max.into(),
+        });
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.histogram.missing = Some(missing.into());
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by the bucket key, instead of an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.histogram.keyed = Some(keyed);
+        self
+    }
+
+    /// The order of the buckets can be customized by setting the order parameter.
+    /// By default, the buckets are ordered by their key ascending.
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: Into<TermsOrderCollection>,
+    {
+        self.histogram.order = order.into();
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 50),
+            json!({ "histogram": { "field": "price", "interval": 50 } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 2.5)
+                .min_doc_count(0)
+                .offset(1)
+                .extended_bounds(0, 500)
+                .hard_bounds(0, 1000)
+                .missing(0)
+                .keyed(true)
+                .order(TermsOrder::new("_key", SortOrder::Desc))
+                .aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "histogram": {
+                    "field": "price",
+                    "interval": 2.5,
+                    "min_doc_count": 0,
+                    "offset": 1,
+                    "extended_bounds": { "min": 0, "max": 500 },
+                    "hard_bounds": { "min": 0, "max": 1000 },
+                    "missing": 0,
+                    "keyed": true,
+                    "order": [{ "_key": "desc" }]
+                },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// Just like the dedicated date range aggregation, there is also a dedicated range aggregation for IP typed fields.
+/// Ranges are defined either by their bounds or by a
+/// [CIDR mask](AggregationRange::mask).
+///
+/// To create an IP range aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::ip_range(
+///     "ip",
+///     [
+///         AggregationRange::new().to("10.0.0.5"),
+///         AggregationRange::new().mask("10.0.0.128/25"),
+///     ],
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-iprange-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct IpRangeAggregation {
+    ip_range: IpRangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct IpRangeAggregationInner {
+    field: String,
+
+    ranges: Vec<AggregationRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`IpRangeAggregation`]
+    ///
+    /// - `field` - IP field to group by
+    /// - `ranges` - ranges of the buckets, bounded by IP addresses or given as CIDR masks
+    pub fn ip_range<T, U>(field: T, ranges: U) -> IpRangeAggregation
+    where
+        T: ToString,
+        U: IntoIterator<Item = AggregationRange>,
+    {
+        IpRangeAggregation {
+            ip_range: IpRangeAggregationInner {
+                field: field.to_string(),
+                ranges: ranges.into_iter().collect(),
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl IpRangeAggregation {
+    /// Returns the buckets as a hash keyed by the bucket key, instead of an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.ip_range.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::ip_range(
+                "ip",
+                [
+                    AggregationRange::new().to("10.0.0.5"),
+                    AggregationRange::new().from("10.0.0.5"),
+                ],
+            ),
+            json!({
+                "ip_range": {
+                    "field": "ip",
+                    "ranges": [
+                        { "to": "10.0.0.5" },
+                        { "from": "10.0.0.5" }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::ip_range(
+                "ip",
+                [AggregationRange::new().mask("10.0.0.0/25").key("low")],
+            )
+            .keyed(true)
+            .aggregate("hosts", Aggregation::terms("host")),
+            json!({
+                "ip_range": {
+                    "field": "ip",
+                    "ranges": [{ "key": "low", "mask": "10.0.0.0/25" }],
+                    "keyed": true
+                },
+                "aggs": {
+                    "hosts": { "terms": { "field": "host" } }
+                }
+            }),
+        );
+    }
+}
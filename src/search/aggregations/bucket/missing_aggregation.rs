@@ -0,0 +1,58 @@
+use crate::search::*;
+use crate::util::*;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+/// A field data based single bucket aggregation, that creates a bucket of all documents in the current document set
+/// context that are missing a field value (effectively, missing a field or having the configured NULL value set).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-missing-aggregation.html>
+pub struct MissingAggregation {
+    missing: MissingAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MissingAggregationInner {
+    field: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MissingAggregation`]
+    ///
+    /// - `field` - field the documents of the bucket are missing
+    pub fn missing<T>(field: T) -> MissingAggregation
+    where
+        T: ToString,
+    {
+        MissingAggregation {
+            missing: MissingAggregationInner {
+                field: field.to_string(),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl MissingAggregation {
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::missing("price").aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "missing": { "field": "price" },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A multi-bucket value source based aggregation where buckets are dynamically built - one per unique set of values.
+/// The multi terms aggregation is very similar to the `terms` aggregation, however in most cases it will be slower
+/// than the terms aggregation and will consume more memory, use the `composite` aggregation for paging through
+/// all the combinations.
+///
+/// To create a multi terms aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::multi_terms([
+///     MultiTerm::from("genre"),
+///     MultiTerm::new("product").missing("Unknown"),
+/// ])
+/// .size(5);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-multi-terms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MultiTermsAggregation {
+    multi_terms: MultiTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MultiTermsAggregationInner {
+    terms: Vec<MultiTerm>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    show_term_doc_count_error: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: TermsOrderCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+}
+
+/// Field of a [`MultiTermsAggregation`], the buckets are keyed by the values of all the fields
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MultiTerm {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl MultiTerm {
+    /// Creates an instance of [`MultiTerm`]
+    ///
+    /// - `field` - field to group by
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            missing: None,
+        }
+    }
+
+    /// The value used for documents that are missing the field, by default they are ignored
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.missing = Term::new(missing);
+        self
+    }
+}
+
+impl From<&str> for MultiTerm {
+    fn from(field: &str) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<String> for MultiTerm {
+    fn from(field: String) -> Self {
+        Self::new(field)
+    }
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MultiTermsAggregation`]
+    ///
+    /// - `terms` - fields to group by
+    pub fn multi_terms<T>(terms: T) -> MultiTermsAggregation
+    where
+        T: IntoIterator,
+        T::Item: Into<MultiTerm>,
+    {
+        MultiTermsAggregation {
+            multi_terms: MultiTermsAggregationInner {
+                terms: terms.into_iter().map(Into::into).collect(),
+                size: None,
+                shard_size: None,
+                show_term_doc_count_error: None,
+                order: Default::default(),
+                min_doc_count: None,
+                shard_min_doc_count: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl MultiTermsAggregation {
+    /// The `size` parameter can be set to define how many term buckets should be returned out of the overall terms list.
+    pub fn size(mut self, size: u64) -> Self {
+        self.multi_terms.size = Some(size);
+        self
+    }
+
+    /// The number of candidate term buckets produced by each shard, higher values improve the
+    /// accuracy at the cost of performance
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.multi_terms.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Shows an error value for each term returned by the aggregation which represents the worst case error in the
+    /// document count
+    pub fn show_term_doc_count_error(mut self, show_term_doc_count_error: bool) -> Self {
+        self.multi_terms.show_term_doc_count_error = Some(show_term_doc_count_error);
+        self
+    }
+
+    /// The order of the buckets can be customized by setting the order parameter.
+    /// By default, the buckets are ordered by their doc_count descending.
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: Into<TermsOrderCollection>,
+    {
+        self.multi_terms.order = order.into();
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits using the `min_doc_count`
+    ///
+    /// Default value is `1`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.multi_terms.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Same as `min_doc_count`, but applied on the shard level to the candidate terms
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.multi_terms.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::multi_terms(["genre", "product"]),
+            json!({
+                "multi_terms": {
+                    "terms": [{ "field": "genre" }, { "field": "product" }]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::multi_terms([
+                MultiTerm::new("genre"),
+                MultiTerm::new("product").missing("Unknown"),
+            ])
+            .size(5)
+            .shard_size(25)
+            .show_term_doc_count_error(true)
+            .order(TermsOrder::ascending("_count"))
+            .min_doc_count(2)
+            .shard_min_doc_count(1)
+            .aggregate("total_sales", Aggregation::sum("price")),
+            json!({
+                "multi_terms": {
+                    "terms": [
+                        { "field": "genre" },
+                        { "field": "product", "missing": "Unknown" }
+                    ],
+                    "size": 5,
+                    "shard_size": 25,
+                    "show_term_doc_count_error": true,
+                    "order": [{ "_count": "asc" }],
+                    "min_doc_count": 2,
+                    "shard_min_doc_count": 1
+                },
+                "aggs": {
+                    "total_sales": { "sum": { "field": "price" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A multi-bucket value source based aggregation that enables the user to define a set of ranges - each representing
+/// a bucket. During the aggregation process, the values extracted from each document will be checked against each
+/// bucket range and "bucket" the relevant/matching document.
+///
+/// To create a range aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::range(
+///     "price",
+///     [
+///         AggregationRange::new().to(100),
+///         AggregationRange::new().from(100).to(200),
+///         AggregationRange::new().from(200),
+///     ],
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-range-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RangeAggregation {
+    range: RangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct RangeAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    ranges: Vec<AggregationRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`RangeAggregation`]
+    ///
+    /// - `field` - field to group by
+    /// - `ranges` - ranges of the buckets
+    pub fn range<T, U>(field: T, ranges: U) -> RangeAggregation
+    where
+        T: ToString,
+        U: IntoIterator<Item = AggregationRange>,
+    {
+        RangeAggregation {
+            range: RangeAggregationInner {
+                field: Some(field.to_string()),
+                ranges: ranges.into_iter().collect(),
+                missing: None,
+                keyed: None,
+                script: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    /// Creates an instance of [`RangeAggregation`] with a script
+    pub fn range_with_script<U>(script: Script, ranges: U) -> RangeAggregation
+    where
+        U: IntoIterator<Item = AggregationRange>,
+    {
+        RangeAggregation {
+            range: RangeAggregationInner {
+                field: None,
+                ranges: ranges.into_iter().collect(),
+                missing: None,
+                keyed: None,
+                script: Some(script),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl RangeAggregation {
+    /// The missing parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.range.missing = Some(missing.into());
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by the bucket key, instead of an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.range.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::range(
+                "price",
+                [
+                    AggregationRange::new().to(100.0),
+                    AggregationRange::new().from(100.0).to(200.0),
+                    AggregationRange::new().from(200.0).key("expensive"),
+                ],
+            ),
+            json!({
+                "range": {
+                    "field": "price",
+                    "ranges": [
+                        { "to": 100.0 },
+                        { "from": 100.0, "to": 200.0 },
+                        { "key": "expensive", "from": 200.0 }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::range_with_script(
+                Script::source("doc['price'].value * 2"),
+                [AggregationRange::new().from(0)],
+            )
+            .missing(0)
+            .keyed(true)
+            .aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "range": {
+                    "ranges": [{ "from": 0 }],
+                    "missing": 0,
+                    "keyed": true,
+                    "script": { "source": "doc['price'].value * 2" }
+                },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+}
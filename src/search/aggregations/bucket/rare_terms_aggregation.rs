@@ -0,0 +1,146 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A multi-bucket value source based aggregation which finds "rare" terms — terms that are at the long-tail of the
+/// distribution and are not frequent. Conceptually, this is like a `terms` aggregation that is sorted by `_count`
+/// ascending, without the unbounded error of doing so.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-rare-terms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RareTermsAggregation {
+    rare_terms: RareTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct RareTermsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`RareTermsAggregation`]
+    ///
+    /// - `field` - field to find the rare terms of
+    pub fn rare_terms<T>(field: T) -> RareTermsAggregation
+    where
+        T: ToString,
+    {
+        RareTermsAggregation {
+            rare_terms: RareTermsAggregationInner {
+                field: field.to_string(),
+                max_doc_count: None,
+                precision: None,
+                missing: None,
+                include: None,
+                exclude: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl RareTermsAggregation {
+    /// The maximum number of documents a term should appear in, can't exceed `100`
+    ///
+    /// Default value is `1`
+    pub fn max_doc_count(mut self, max_doc_count: u64) -> Self {
+        self.rare_terms.max_doc_count = Some(max_doc_count);
+        self
+    }
+
+    /// The precision of the internal CuckooFilters, smaller values mean better approximation at
+    /// the cost of memory. Can't be smaller than `0.00001`.
+    ///
+    /// Default value is `0.001`
+    pub fn precision<T>(mut self, precision: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.rare_terms.precision = Some(precision.as_float());
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.rare_terms.missing = Term::new(missing);
+        self
+    }
+
+    /// The `include` parameter can be set to include only specific terms in the response.
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.rare_terms.include = Some(include.into());
+        self
+    }
+
+    /// The `exclude` parameter can be set to exclude specific terms from the response.
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.rare_terms.exclude = Some(exclude.into());
+        self
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre"),
+            json!({ "rare_terms": { "field": "genre" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre")
+                .max_doc_count(2)
+                .precision(0.01)
+                .missing("N/A")
+                .include("swi.*")
+                .exclude(["electro"])
+                .aggregate("artists", Aggregation::terms("artist")),
+            json!({
+                "rare_terms": {
+                    "field": "genre",
+                    "max_doc_count": 2,
+                    "precision": 0.01,
+                    "missing": "N/A",
+                    "include": "swi.*",
+                    "exclude": ["electro"]
+                },
+                "aggs": {
+                    "artists": { "terms": { "field": "artist" } }
+                }
+            }),
+        );
+    }
+}
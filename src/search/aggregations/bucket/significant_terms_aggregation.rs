@@ -0,0 +1,174 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// An aggregation that returns interesting or unusual occurrences of terms in a set. The terms selected are not simply
+/// the most popular terms in a set. They are the terms that have undergone a significant change in popularity
+/// measured between a foreground and background set.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significantterms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SignificantTermsAggregation {
+    significant_terms: SignificantTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SignificantTermsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    background_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SignificantTermsAggregation`]
+    ///
+    /// - `field` - field to find the significant terms of
+    pub fn significant_terms<T>(field: T) -> SignificantTermsAggregation
+    where
+        T: ToString,
+    {
+        SignificantTermsAggregation {
+            significant_terms: SignificantTermsAggregationInner {
+                field: field.to_string(),
+                size: None,
+                shard_size: None,
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                background_filter: None,
+                include: None,
+                exclude: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl SignificantTermsAggregation {
+    /// The `size` parameter can be set to define how many term buckets should be returned out of
+    /// the overall terms list
+    pub fn size(mut self, size: u64) -> Self {
+        self.significant_terms.size = Some(size);
+        self
+    }
+
+    /// The number of candidate terms produced by each shard, higher values improve the accuracy
+    /// at the cost of performance
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.significant_terms.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Only returns terms that are found in more than a configured number of hits
+    ///
+    /// Default value is `3`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.significant_terms.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Same as `min_doc_count`, but applied on the shard level to the candidate terms
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.significant_terms.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    /// Narrows the background set the term frequencies are compared against, by default it's
+    /// the whole index
+    pub fn background_filter<T>(mut self, background_filter: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        self.significant_terms.background_filter = Some(background_filter.into());
+        self
+    }
+
+    /// The `include` parameter can be set to include only specific terms in the response.
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.significant_terms.include = Some(include.into());
+        self
+    }
+
+    /// The `exclude` parameter can be set to exclude specific terms from the response.
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.significant_terms.exclude = Some(exclude.into());
+        self
+    }
+
+    /// Mutable access to the background filter
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.significant_terms
+            .background_filter
+            .iter_mut()
+            .collect()
+    }
+
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::significant_terms("crime_type"),
+            json!({ "significant_terms": { "field": "crime_type" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::significant_terms("crime_type")
+                .size(5)
+                .shard_size(50)
+                .min_doc_count(10)
+                .shard_min_doc_count(2)
+                .background_filter(Query::term("city", "London"))
+                .include(["burglary", "theft"])
+                .exclude("bicycle.*")
+                .aggregate("forces", Aggregation::terms("force")),
+            json!({
+                "significant_terms": {
+                    "field": "crime_type",
+                    "size": 5,
+                    "shard_size": 50,
+                    "min_doc_count": 10,
+                    "shard_min_doc_count": 2,
+                    "background_filter": { "term": { "city": { "value": "London" } } },
+                    "include": ["burglary", "theft"],
+                    "exclude": "bicycle.*"
+                },
+                "aggs": {
+                    "forces": { "terms": { "field": "force" } }
+                }
+            }),
+        );
+    }
+}
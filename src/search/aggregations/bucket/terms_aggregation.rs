@@ -271,4 +271,83 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_aggregation_chains() {
+        assert_serialize_aggregation(
+            Aggregation::terms("category")
+                .try_aggregate_chain(vec![
+                    (
+                        "by_country",
+                        Aggregation::from(Aggregation::terms("country")),
+                    ),
+                    ("by_brand", Aggregation::from(Aggregation::terms("brand"))),
+                    (
+                        "average_price",
+                        Aggregation::from(Aggregation::avg("price")),
+                    ),
+                ])
+                .unwrap(),
+            json!({
+                "terms": { "field": "category" },
+                "aggs": {
+                    "by_country": {
+                        "terms": { "field": "country" },
+                        "aggs": {
+                            "by_brand": {
+                                "terms": { "field": "brand" },
+                                "aggs": {
+                                    "average_price": { "avg": { "field": "price" } }
+                                }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn modifies_sub_aggregations() {
+        let mut aggregation = Aggregation::from(
+            Aggregation::terms("category")
+                .aggregate("top1", Aggregation::terms("brand"))
+                .aggregate("unused", Aggregation::avg("price")),
+        );
+
+        if let Some(Aggregation::Terms(top1)) = aggregation.sub_aggregation_mut("top1") {
+            *top1 = top1.clone().size(1);
+        }
+
+        assert!(aggregation.remove_aggregation("unused").is_some());
+        assert!(aggregation.rename_aggregation("top1", "top_brand"));
+        assert!(!aggregation.rename_aggregation("missing", "other"));
+        assert!(aggregation.sub_aggregation("top1").is_none());
+
+        assert_serialize_aggregation(
+            aggregation,
+            json!({
+                "terms": { "field": "category" },
+                "aggs": {
+                    "top_brand": { "terms": { "field": "brand", "size": 1 } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn rejects_sub_aggregations_of_metrics() {
+        assert_eq!(
+            Aggregation::terms("category")
+                .try_aggregate_chain(vec![
+                    (
+                        "average_price",
+                        Aggregation::from(Aggregation::avg("price"))
+                    ),
+                    ("by_brand", Aggregation::from(Aggregation::terms("brand"))),
+                ])
+                .unwrap_err(),
+            ValidationError::SubAggregationsUnsupported
+        );
+    }
 }
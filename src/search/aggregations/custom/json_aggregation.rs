@@ -0,0 +1,84 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// Raw JSON aggregation for plugins or aggregations not yet supported, which can still hold typed
+/// sub-aggregations and be nested inside typed aggregations.
+///
+/// To create JSON aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::json(
+///     "multi_terms",
+///     serde_json::json!({ "terms": [{ "field": "genre" }, { "field": "product" }] }),
+/// )
+/// .aggregate("average_price", Aggregation::avg("price"));
+/// ```
+/// **NOTE**: This is fallible and can lead to incorrect aggregations and
+/// rejected search requests, use at your own risk.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonAggregation {
+    #[serde(flatten)]
+    aggregation: Map<String, serde_json::Value>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`JsonAggregation`]
+    ///
+    /// - `aggregation_type` - aggregation type, e.g. `multi_terms`
+    /// - `body` - aggregation body, serialized into JSON
+    pub fn json<T, U>(aggregation_type: T, body: U) -> JsonAggregation
+    where
+        T: ToString,
+        U: serde::Serialize,
+    {
+        let body = serde_json::to_value(body).unwrap_or_default();
+        let mut aggregation = Map::new();
+        let _ = aggregation.insert(aggregation_type.to_string(), body);
+
+        JsonAggregation {
+            aggregation,
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl JsonAggregation {
+    add_aggregate!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::json("rare_terms", json!({ "field": "genre" })),
+            json!({ "rare_terms": { "field": "genre" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("category").aggregate(
+                "genres",
+                Aggregation::json("rare_terms", json!({ "field": "genre" }))
+                    .aggregate("average_price", Aggregation::avg("price")),
+            ),
+            json!({
+                "terms": { "field": "category" },
+                "aggs": {
+                    "genres": {
+                        "rare_terms": { "field": "genre" },
+                        "aggs": {
+                            "average_price": { "avg": { "field": "price" } }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Non official aggregations, such as plugins or aggregations not yet supported by the crate
+
+mod json_aggregation;
+
+pub use self::json_aggregation::*;
@@ -0,0 +1,91 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that computes stats over numeric values extracted from the aggregated
+/// documents. The `extended_stats` aggregations is an extended version of the `stats` aggregation, where additional
+/// metrics are added such as `sum_of_squares`, `variance`, `std_deviation` and `std_deviation_bounds`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-extendedstats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ExtendedStatsAggregation {
+    extended_stats: ExtendedStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ExtendedStatsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sigma: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ExtendedStatsAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn extended_stats<T>(field: T) -> ExtendedStatsAggregation
+    where
+        T: ToString,
+    {
+        ExtendedStatsAggregation {
+            extended_stats: ExtendedStatsAggregationInner {
+                field: field.to_string(),
+                sigma: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl ExtendedStatsAggregation {
+    /// The number of standard deviations above/below the mean of the `std_deviation_bounds`,
+    /// can be any non-negative double
+    ///
+    /// Default value is `2`
+    pub fn sigma<T>(mut self, sigma: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.extended_stats.sigma = Some(sigma.into());
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.extended_stats.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::extended_stats("test_field"),
+            json!({ "extended_stats": { "field": "test_field" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::extended_stats("test_field")
+                .sigma(3)
+                .missing(0),
+            json!({
+                "extended_stats": {
+                    "field": "test_field",
+                    "sigma": 3,
+                    "missing": 0
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use crate::search::*;
+
+/// A metric aggregation that computes the weighted centroid from all coordinate values for geo fields.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-geocentroid-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoCentroidAggregation {
+    geo_centroid: GeoCentroidAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoCentroidAggregationInner {
+    field: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoCentroidAggregation`]
+    ///
+    /// - `field` - `geo_point` or `geo_shape` field to aggregate
+    pub fn geo_centroid<T>(field: T) -> GeoCentroidAggregation
+    where
+        T: ToString,
+    {
+        GeoCentroidAggregation {
+            geo_centroid: GeoCentroidAggregationInner {
+                field: field.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geo_centroid("location"),
+            json!({ "geo_centroid": { "field": "location" } }),
+        );
+    }
+}
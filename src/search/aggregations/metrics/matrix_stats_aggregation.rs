@@ -0,0 +1,78 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A numeric aggregation that computes the following statistics over a set of document fields: `count`, `mean`,
+/// `variance`, `skewness`, `kurtosis`, `covariance` and `correlation`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-matrix-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MatrixStatsAggregation {
+    matrix_stats: MatrixStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MatrixStatsAggregationInner {
+    fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Map<String, Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MatrixStatsAggregation`]
+    ///
+    /// - `fields` - numeric fields to compute the statistics of
+    pub fn matrix_stats<T>(fields: T) -> MatrixStatsAggregation
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        MatrixStatsAggregation {
+            matrix_stats: MatrixStatsAggregationInner {
+                fields: fields.into_iter().map(|field| field.to_string()).collect(),
+                missing: Map::new(),
+            },
+        }
+    }
+}
+
+impl MatrixStatsAggregation {
+    /// The value to use for documents that are missing the `field`, by default they are ignored.
+    ///
+    /// Calling this method multiple times accumulates the values of the fields.
+    pub fn missing<T, U>(mut self, field: T, missing: U) -> Self
+    where
+        T: ToString,
+        U: Into<Number>,
+    {
+        let _ = self
+            .matrix_stats
+            .missing
+            .insert(field.to_string(), missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::matrix_stats(["poverty", "income"]),
+            json!({ "matrix_stats": { "fields": ["poverty", "income"] } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::matrix_stats(["poverty", "income"]).missing("income", 50000),
+            json!({
+                "matrix_stats": {
+                    "fields": ["poverty", "income"],
+                    "missing": { "income": 50000 }
+                }
+            }),
+        );
+    }
+}
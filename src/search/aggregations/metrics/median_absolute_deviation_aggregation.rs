@@ -0,0 +1,93 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that approximates the
+/// [median absolute deviation](https://en.wikipedia.org/wiki/Median_absolute_deviation) of its search results.
+///
+/// Median absolute deviation is a measure of variability. It is a robust statistic, meaning that it is useful for
+/// describing data that may have outliers, or may not be normally distributed.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-median-absolute-deviation-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MedianAbsoluteDeviationAggregation {
+    median_absolute_deviation: MedianAbsoluteDeviationAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MedianAbsoluteDeviationAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    compression: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MedianAbsoluteDeviationAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn median_absolute_deviation<T>(field: T) -> MedianAbsoluteDeviationAggregation
+    where
+        T: ToString,
+    {
+        MedianAbsoluteDeviationAggregation {
+            median_absolute_deviation: MedianAbsoluteDeviationAggregationInner {
+                field: field.to_string(),
+                compression: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl MedianAbsoluteDeviationAggregation {
+    /// The TDigest `compression`, controlling the tradeoff between the accuracy and the memory
+    /// usage of the approximation
+    ///
+    /// Default value is `1000`
+    pub fn compression<T>(mut self, compression: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.median_absolute_deviation.compression = Some(compression.into());
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.median_absolute_deviation.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::median_absolute_deviation("rating"),
+            json!({ "median_absolute_deviation": { "field": "rating" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::median_absolute_deviation("rating")
+                .compression(100)
+                .missing(5),
+            json!({
+                "median_absolute_deviation": {
+                    "field": "rating",
+                    "compression": 100,
+                    "missing": 5
+                }
+            }),
+        );
+    }
+}
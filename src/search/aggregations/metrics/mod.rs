@@ -13,17 +13,41 @@
 mod avg_aggregation;
 mod boxplot_aggregation;
 mod cardinality_aggregation;
+mod extended_stats_aggregation;
+mod geo_bounds_aggregation;
+mod geo_centroid_aggregation;
+mod matrix_stats_aggregation;
 mod max_aggregation;
+mod median_absolute_deviation_aggregation;
 mod min_aggregation;
+mod percentile_ranks_aggregation;
+mod percentiles_aggregation;
 mod rate_aggregation;
+mod stats_aggregation;
+mod string_stats_aggregation;
 mod sum_aggregation;
+mod t_test_aggregation;
 mod top_hits_aggregation;
+mod value_count_aggregation;
+mod weighted_avg_aggregation;
 
 pub use self::avg_aggregation::*;
 pub use self::boxplot_aggregation::*;
 pub use self::cardinality_aggregation::*;
+pub use self::extended_stats_aggregation::*;
+pub use self::geo_bounds_aggregation::*;
+pub use self::geo_centroid_aggregation::*;
+pub use self::matrix_stats_aggregation::*;
 pub use self::max_aggregation::*;
+pub use self::median_absolute_deviation_aggregation::*;
 pub use self::min_aggregation::*;
+pub use self::percentile_ranks_aggregation::*;
+pub use self::percentiles_aggregation::*;
 pub use self::rate_aggregation::*;
+pub use self::stats_aggregation::*;
+pub use self::string_stats_aggregation::*;
 pub use self::sum_aggregation::*;
+pub use self::t_test_aggregation::*;
 pub use self::top_hits_aggregation::*;
+pub use self::value_count_aggregation::*;
+pub use self::weighted_avg_aggregation::*;
@@ -0,0 +1,140 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentile ranks over numeric values extracted
+/// from the aggregated documents.
+///
+/// Percentile rank show the percentage of observed values which are below certain value. For example, if a value is
+/// greater than or equal to 95% of the observed values it is said to be at the 95th percentile rank.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-rank-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentileRanksAggregation {
+    percentile_ranks: PercentileRanksAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentileRanksAggregationInner {
+    field: String,
+
+    values: Vec<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tdigest: Option<TDigest>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hdr: Option<Hdr>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentileRanksAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    /// - `values` - values to calculate the percentile ranks of
+    pub fn percentile_ranks<T, U>(field: T, values: U) -> PercentileRanksAggregation
+    where
+        T: ToString,
+        U: IntoIterator,
+        U::Item: Into<Number>,
+    {
+        PercentileRanksAggregation {
+            percentile_ranks: PercentileRanksAggregationInner {
+                field: field.to_string(),
+                values: values.into_iter().map(Into::into).collect(),
+                keyed: None,
+                tdigest: None,
+                hdr: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl PercentileRanksAggregation {
+    /// Returns the percentile ranks as a hash keyed by the value, instead of an array
+    ///
+    /// Default value is `true`
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentile_ranks.keyed = Some(keyed);
+        self
+    }
+
+    /// The TDigest `compression`, controlling the tradeoff between the accuracy and the memory
+    /// usage of the approximation
+    ///
+    /// Default value is `100`
+    pub fn compression<T>(mut self, compression: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentile_ranks.tdigest = Some(TDigest {
+            compression: compression.into(),
+        });
+        self
+    }
+
+    /// Uses HDR Histogram instead of TDigest, with the given number of significant digits of
+    /// precision, which is faster but requires non-negative values
+    pub fn hdr(mut self, number_of_significant_value_digits: u8) -> Self {
+        self.percentile_ranks.hdr = Some(Hdr {
+            number_of_significant_value_digits,
+        });
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentile_ranks.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::percentile_ranks("load_time", [500, 600]),
+            json!({ "percentile_ranks": { "field": "load_time", "values": [500, 600] } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentile_ranks("load_time", [500])
+                .keyed(false)
+                .hdr(3)
+                .missing(10),
+            json!({
+                "percentile_ranks": {
+                    "field": "load_time",
+                    "values": [500],
+                    "keyed": false,
+                    "hdr": { "number_of_significant_value_digits": 3 },
+                    "missing": 10
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentile_ranks("load_time", [500]).compression(50),
+            json!({
+                "percentile_ranks": {
+                    "field": "load_time",
+                    "values": [500],
+                    "tdigest": { "compression": 50 }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentiles over numeric values extracted from
+/// the aggregated documents.
+///
+/// Percentiles show the point at which a certain percentage of observed values occur. For example, the 95th
+/// percentile is the value which is greater than 95% of the observed values.
+///
+/// > Percentiles are approximated with TDigest by default, HDR Histogram can be used instead with
+/// > [`hdr`](PercentilesAggregation::hdr).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentilesAggregation {
+    percentiles: PercentilesAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentilesAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    percents: Vec<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tdigest: Option<TDigest>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hdr: Option<Hdr>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct TDigest {
+    pub(crate) compression: Number,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub(crate) struct Hdr {
+    pub(crate) number_of_significant_value_digits: u8,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentilesAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn percentiles<T>(field: T) -> PercentilesAggregation
+    where
+        T: ToString,
+    {
+        PercentilesAggregation {
+            percentiles: PercentilesAggregationInner {
+                field: field.to_string(),
+                percents: Vec::new(),
+                keyed: None,
+                tdigest: None,
+                hdr: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl PercentilesAggregation {
+    /// The percentiles to calculate
+    ///
+    /// Default value is `[1, 5, 25, 50, 75, 95, 99]`
+    pub fn percents<T>(mut self, percents: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Number>,
+    {
+        self.percentiles.percents = percents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the percentiles as a hash keyed by the percent, instead of an array
+    ///
+    /// Default value is `true`
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentiles.keyed = Some(keyed);
+        self
+    }
+
+    /// The TDigest `compression`, controlling the tradeoff between the accuracy and the memory
+    /// usage of the approximation
+    ///
+    /// Default value is `100`
+    pub fn compression<T>(mut self, compression: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentiles.tdigest = Some(TDigest {
+            compression: compression.into(),
+        });
+        self
+    }
+
+    /// Uses HDR Histogram instead of TDigest, with the given number of significant digits of
+    /// precision, which is faster but requires non-negative values
+    pub fn hdr(mut self, number_of_significant_value_digits: u8) -> Self {
+        self.percentiles.hdr = Some(Hdr {
+            number_of_significant_value_digits,
+        });
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentiles.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time"),
+            json!({ "percentiles": { "field": "load_time" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time")
+                .percents([95.0, 99.9])
+                .keyed(false)
+                .compression(200)
+                .missing(10),
+            json!({
+                "percentiles": {
+                    "field": "load_time",
+                    "percents": [95.0, 99.9],
+                    "keyed": false,
+                    "tdigest": { "compression": 200 },
+                    "missing": 10
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time").hdr(3),
+            json!({
+                "percentiles": {
+                    "field": "load_time",
+                    "hdr": { "number_of_significant_value_digits": 3 }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that computes stats over numeric values extracted from the aggregated
+/// documents. The stats that are returned consist of: `min`, `max`, `sum`, `count` and `avg`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StatsAggregation {
+    stats: StatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct StatsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`StatsAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn stats<T>(field: T) -> StatsAggregation
+    where
+        T: ToString,
+    {
+        StatsAggregation {
+            stats: StatsAggregationInner {
+                field: field.to_string(),
+                missing: None,
+            },
+        }
+    }
+}
+
+impl StatsAggregation {
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.stats.missing = Some(missing.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::stats("test_field"),
+            json!({ "stats": { "field": "test_field" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::stats("test_field").missing(0),
+            json!({
+                "stats": {
+                    "field": "test_field",
+                    "missing": 0
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that computes statistics over string values extracted from the aggregated
+/// documents: `count`, `min_length`, `max_length`, `avg_length` and `entropy`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-string-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StringStatsAggregation {
+    string_stats: StringStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct StringStatsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    show_distribution: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`StringStatsAggregation`]
+    ///
+    /// - `field` - keyword field to aggregate
+    pub fn string_stats<T>(field: T) -> StringStatsAggregation
+    where
+        T: ToString,
+    {
+        StringStatsAggregation {
+            string_stats: StringStatsAggregationInner {
+                field: field.to_string(),
+                show_distribution: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl StringStatsAggregation {
+    /// Also returns the probability distribution of all characters
+    ///
+    /// Default value is `false`
+    pub fn show_distribution(mut self, show_distribution: bool) -> Self {
+        self.string_stats.show_distribution = Some(show_distribution);
+        self
+    }
+
+    /// The missing parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.string_stats.missing = Some(missing.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::string_stats("message.keyword"),
+            json!({ "string_stats": { "field": "message.keyword" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::string_stats("message.keyword")
+                .show_distribution(true)
+                .missing("[empty message]"),
+            json!({
+                "string_stats": {
+                    "field": "message.keyword",
+                    "show_distribution": true,
+                    "missing": "[empty message]"
+                }
+            }),
+        );
+    }
+}
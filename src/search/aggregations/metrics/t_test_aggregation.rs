@@ -0,0 +1,148 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `t_test` metrics aggregation that performs a statistical hypothesis test in which the test statistic follows a
+/// Student's t-distribution under the null hypothesis on numeric values extracted from the aggregated documents.
+///
+/// To create a t-test aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::t_test("startup_time_before", "startup_time_after", TTestType::Paired);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-ttest-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TTestAggregation {
+    t_test: TTestAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct TTestAggregationInner {
+    a: TTestPopulation,
+
+    b: TTestPopulation,
+
+    #[serde(rename = "type")]
+    ty: TTestType,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct TTestPopulation {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<Query>,
+}
+
+/// The type of the t-test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TTestType {
+    /// Paired t-test, the populations are the two fields of the same documents
+    Paired,
+
+    /// Two-sample equal variance test
+    Homoscedastic,
+
+    /// Two-sample unequal variance test
+    Heteroscedastic,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`TTestAggregation`]
+    ///
+    /// - `a_field` - field of the first population
+    /// - `b_field` - field of the second population
+    /// - `ty` - type of the test
+    pub fn t_test<T, U>(a_field: T, b_field: U, ty: TTestType) -> TTestAggregation
+    where
+        T: ToString,
+        U: ToString,
+    {
+        TTestAggregation {
+            t_test: TTestAggregationInner {
+                a: TTestPopulation {
+                    field: a_field.to_string(),
+                    filter: None,
+                },
+                b: TTestPopulation {
+                    field: b_field.to_string(),
+                    filter: None,
+                },
+                ty,
+            },
+        }
+    }
+}
+
+impl TTestAggregation {
+    /// Narrows the documents of the first population, not supported by paired tests
+    pub fn a_filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        self.t_test.a.filter = Some(filter.into());
+        self
+    }
+
+    /// Narrows the documents of the second population, not supported by paired tests
+    pub fn b_filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        self.t_test.b.filter = Some(filter.into());
+        self
+    }
+
+    /// Mutable access to the population filters
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.t_test
+            .a
+            .filter
+            .iter_mut()
+            .chain(self.t_test.b.filter.iter_mut())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::t_test(
+                "startup_time_before",
+                "startup_time_after",
+                TTestType::Paired,
+            ),
+            json!({
+                "t_test": {
+                    "a": { "field": "startup_time_before" },
+                    "b": { "field": "startup_time_after" },
+                    "type": "paired"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::t_test("startup_time", "startup_time", TTestType::Heteroscedastic)
+                .a_filter(Query::term("group", "A"))
+                .b_filter(Query::term("group", "B")),
+            json!({
+                "t_test": {
+                    "a": {
+                        "field": "startup_time",
+                        "filter": { "term": { "group": { "value": "A" } } }
+                    },
+                    "b": {
+                        "field": "startup_time",
+                        "filter": { "term": { "group": { "value": "B" } } }
+                    },
+                    "type": "heteroscedastic"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that counts the number of values that are extracted from the aggregated
+/// documents. Typically, this aggregator will be used in conjunction with other single-value aggregations, e.g.
+/// when computing the `avg` one might be interested in the number of values the average is computed over.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-valuecount-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ValueCountAggregation {
+    value_count: ValueCountAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ValueCountAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ValueCountAggregation`]
+    ///
+    /// - `field` - field to count the values of
+    pub fn value_count<T>(field: T) -> ValueCountAggregation
+    where
+        T: ToString,
+    {
+        ValueCountAggregation {
+            value_count: ValueCountAggregationInner {
+                field: Some(field.to_string()),
+                script: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`ValueCountAggregation`] with a script
+    pub fn value_count_with_script(script: Script) -> ValueCountAggregation {
+        ValueCountAggregation {
+            value_count: ValueCountAggregationInner {
+                field: None,
+                script: Some(script),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::value_count("test_field"),
+            json!({ "value_count": { "field": "test_field" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::value_count_with_script(Script::source("doc['type'].value")),
+            json!({ "value_count": { "script": { "source": "doc['type'].value" } } }),
+        );
+    }
+}
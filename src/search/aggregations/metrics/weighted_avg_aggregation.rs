@@ -0,0 +1,118 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that computes the weighted average of numeric values that are extracted from
+/// the aggregated documents. Each value is weighted by the value of another numeric field of the document.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-weight-avg-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WeightedAvgAggregation {
+    weighted_avg: WeightedAvgAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct WeightedAvgAggregationInner {
+    value: WeightedAvgSource,
+
+    weight: WeightedAvgSource,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct WeightedAvgSource {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`WeightedAvgAggregation`]
+    ///
+    /// - `value_field` - field to average
+    /// - `weight_field` - field with the weights of the values
+    pub fn weighted_avg<T, U>(value_field: T, weight_field: U) -> WeightedAvgAggregation
+    where
+        T: ToString,
+        U: ToString,
+    {
+        WeightedAvgAggregation {
+            weighted_avg: WeightedAvgAggregationInner {
+                value: WeightedAvgSource {
+                    field: value_field.to_string(),
+                    missing: None,
+                },
+                weight: WeightedAvgSource {
+                    field: weight_field.to_string(),
+                    missing: None,
+                },
+                format: None,
+            },
+        }
+    }
+}
+
+impl WeightedAvgAggregation {
+    /// The value to use for documents that are missing the value field, by default they are
+    /// ignored
+    pub fn value_missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.weighted_avg.value.missing = Some(missing.into());
+        self
+    }
+
+    /// The weight to use for documents that are missing the weight field, by default they are
+    /// ignored
+    pub fn weight_missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.weighted_avg.weight.missing = Some(missing.into());
+        self
+    }
+
+    /// The numeric response format
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.weighted_avg.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::weighted_avg("grade", "weight"),
+            json!({
+                "weighted_avg": {
+                    "value": { "field": "grade" },
+                    "weight": { "field": "weight" }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::weighted_avg("grade", "weight")
+                .value_missing(2)
+                .weight_missing(3)
+                .format("0.00"),
+            json!({
+                "weighted_avg": {
+                    "value": { "field": "grade", "missing": 2 },
+                    "weight": { "field": "weight", "missing": 3 },
+                    "format": "0.00"
+                }
+            }),
+        );
+    }
+}
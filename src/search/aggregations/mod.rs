@@ -14,20 +14,27 @@
 //! - [Pipeline](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline.html) aggregations that take input from other aggregations instead of documents or fields.
 
 pub mod bucket;
+pub mod custom;
 pub mod metrics;
 pub mod params;
 pub mod pipeline;
 
-use crate::Map;
+use crate::search::{OrderedMap, Query, ValidationError};
 
 pub use self::bucket::*;
+pub use self::custom::*;
 pub use self::metrics::*;
 pub use self::params::*;
 pub use self::pipeline::*;
 
 macro_rules! aggregation {
     ($($variant:ident($query:ty)),+ $(,)?) => {
-        /// A container enum for supported Elasticsearch query types
+        /// A container enum for supported Elasticsearch aggregation types
+        ///
+        /// Serialization is keyed by the aggregation name, e.g. `{ "terms": { ... } }`.
+        /// Aggregations are serialize-only: the wrapped aggregation types don't implement
+        /// `Deserialize`, hence neither does the container. An aggregation stored as JSON can be
+        /// sent as is with [`Aggregation::json`].
         #[derive(Clone, PartialEq, Serialize)]
         #[serde(untagged)]
         #[allow(missing_docs, clippy::large_enum_variant)]
@@ -68,15 +75,255 @@ aggregation!(
     Rate(RateAggregation),
     Sampler(SamplerAggregation),
     Filter(FilterAggregation),
+    Filters(FiltersAggregation),
     DiversifiedSampler(DiversifiedSamplerAggregation),
     Boxplot(BoxplotAggregation),
+    ExtendedStats(ExtendedStatsAggregation),
+    GeoBounds(GeoBoundsAggregation),
+    GeoCentroid(GeoCentroidAggregation),
+    MatrixStats(MatrixStatsAggregation),
+    MedianAbsoluteDeviation(MedianAbsoluteDeviationAggregation),
+    PercentileRanks(PercentileRanksAggregation),
+    Percentiles(PercentilesAggregation),
+    Stats(StatsAggregation),
+    StringStats(StringStatsAggregation),
+    TTest(TTestAggregation),
+    ValueCount(ValueCountAggregation),
+    WeightedAvg(WeightedAvgAggregation),
     DateHistogram(DateHistogramAggregation),
     GeotileGrid(GeotileGridAggregation),
     BucketSelector(BucketSelectorAggregation),
     Children(ChildrenAggregation),
     Composite(CompositeAggregation),
     Nested(NestedAggregation),
+    AdjacencyMatrix(AdjacencyMatrixAggregation),
+    AutoDateHistogram(AutoDateHistogramAggregation),
+    DateRange(DateRangeAggregation),
+    GeoDistance(GeoDistanceAggregation),
+    Global(GlobalAggregation),
+    Histogram(HistogramAggregation),
+    IpRange(IpRangeAggregation),
+    Missing(MissingAggregation),
+    MultiTerms(MultiTermsAggregation),
+    Range(RangeAggregation),
+    RareTerms(RareTermsAggregation),
+    SignificantTerms(SignificantTermsAggregation),
+    AvgBucket(AvgBucketAggregation),
+    BucketScript(BucketScriptAggregation),
+    BucketSort(BucketSortAggregation),
+    CumulativeSum(CumulativeSumAggregation),
+    Derivative(DerivativeAggregation),
+    MaxBucket(MaxBucketAggregation),
+    MinBucket(MinBucketAggregation),
+    MovingFn(MovingFnAggregation),
+    MovingPercentiles(MovingPercentilesAggregation),
+    Normalize(NormalizeAggregation),
+    SerialDiff(SerialDiffAggregation),
+    StatsBucket(StatsBucketAggregation),
+    SumBucket(SumBucketAggregation),
+    Json(JsonAggregation),
 );
 
+impl Aggregation {
+    /// Pushes a sub-aggregation into a bucket aggregation, fails for metric and pipeline
+    /// aggregations, which can't have sub-aggregations
+    pub fn try_aggregate<N, A>(
+        mut self,
+        aggregation_name: N,
+        aggregation: A,
+    ) -> Result<Self, ValidationError>
+    where
+        N: Into<AggregationName>,
+        A: Into<Aggregation>,
+    {
+        let aggs = self
+            .aggs_mut()
+            .ok_or(ValidationError::SubAggregationsUnsupported)?;
+        let aggregation_name = aggregation_name.into();
+
+        if !aggs.contains_key(&aggregation_name) {
+            let _ = aggs.insert(aggregation_name, aggregation.into());
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the sub-aggregation with the `aggregation_name`. Metric aggregations have no
+    /// sub-aggregations.
+    pub fn sub_aggregation<N>(&self, aggregation_name: N) -> Option<&Aggregation>
+    where
+        N: Into<AggregationName>,
+    {
+        self.aggs()?.get(&aggregation_name.into())
+    }
+
+    /// Returns a mutable reference to the sub-aggregation with the `aggregation_name`
+    pub fn sub_aggregation_mut<N>(&mut self, aggregation_name: N) -> Option<&mut Aggregation>
+    where
+        N: Into<AggregationName>,
+    {
+        self.aggs_mut()?.get_mut(&aggregation_name.into())
+    }
+
+    /// Removes the sub-aggregation with the `aggregation_name` and returns it
+    pub fn remove_aggregation<N>(&mut self, aggregation_name: N) -> Option<Aggregation>
+    where
+        N: Into<AggregationName>,
+    {
+        self.aggs_mut()?.remove(&aggregation_name.into())
+    }
+
+    /// Moves the sub-aggregation named `from` to the name `to`. Returns `false` if there is no
+    /// aggregation named `from`.
+    pub fn rename_aggregation<F, T>(&mut self, from: F, to: T) -> bool
+    where
+        F: Into<AggregationName>,
+        T: Into<AggregationName>,
+    {
+        self.aggs_mut()
+            .is_some_and(|aggs| aggs.rename(&from.into(), to.into()))
+    }
+
+    /// Sub-aggregations of bucket aggregations, [`None`] for the aggregations that can't have
+    /// any
+    pub(crate) fn aggs(&self) -> Option<&Aggregations> {
+        match self {
+            Self::Terms(a) => Some(a.aggs()),
+            Self::Sampler(a) => Some(a.aggs()),
+            Self::Filter(a) => Some(a.aggs()),
+            Self::Filters(a) => Some(a.aggs()),
+            Self::DiversifiedSampler(a) => Some(a.aggs()),
+            Self::DateHistogram(a) => Some(a.aggs()),
+            Self::GeotileGrid(a) => Some(a.aggs()),
+            Self::BucketSelector(a) => Some(a.aggs()),
+            Self::Children(a) => Some(a.aggs()),
+            Self::Composite(a) => Some(a.aggs()),
+            Self::Nested(a) => Some(a.aggs()),
+            Self::AdjacencyMatrix(a) => Some(a.aggs()),
+            Self::AutoDateHistogram(a) => Some(a.aggs()),
+            Self::DateRange(a) => Some(a.aggs()),
+            Self::GeoDistance(a) => Some(a.aggs()),
+            Self::Global(a) => Some(a.aggs()),
+            Self::Histogram(a) => Some(a.aggs()),
+            Self::IpRange(a) => Some(a.aggs()),
+            Self::Missing(a) => Some(a.aggs()),
+            Self::MultiTerms(a) => Some(a.aggs()),
+            Self::Range(a) => Some(a.aggs()),
+            Self::RareTerms(a) => Some(a.aggs()),
+            Self::SignificantTerms(a) => Some(a.aggs()),
+            Self::Json(a) => Some(a.aggs()),
+            Self::TopHits(_)
+            | Self::Cardinality(_)
+            | Self::Avg(_)
+            | Self::Max(_)
+            | Self::Min(_)
+            | Self::Sum(_)
+            | Self::Rate(_)
+            | Self::Boxplot(_)
+            | Self::ExtendedStats(_)
+            | Self::GeoBounds(_)
+            | Self::GeoCentroid(_)
+            | Self::MatrixStats(_)
+            | Self::MedianAbsoluteDeviation(_)
+            | Self::PercentileRanks(_)
+            | Self::Percentiles(_)
+            | Self::Stats(_)
+            | Self::StringStats(_)
+            | Self::TTest(_)
+            | Self::ValueCount(_)
+            | Self::WeightedAvg(_)
+            | Self::AvgBucket(_)
+            | Self::BucketScript(_)
+            | Self::BucketSort(_)
+            | Self::CumulativeSum(_)
+            | Self::Derivative(_)
+            | Self::MaxBucket(_)
+            | Self::MinBucket(_)
+            | Self::MovingFn(_)
+            | Self::MovingPercentiles(_)
+            | Self::Normalize(_)
+            | Self::SerialDiff(_)
+            | Self::StatsBucket(_)
+            | Self::SumBucket(_) => None,
+        }
+    }
+
+    /// Mutable access to the sub-aggregations, see [`aggs`](Self::aggs)
+    pub(crate) fn aggs_mut(&mut self) -> Option<&mut Aggregations> {
+        match self {
+            Self::Terms(a) => Some(a.aggs_mut()),
+            Self::Sampler(a) => Some(a.aggs_mut()),
+            Self::Filter(a) => Some(a.aggs_mut()),
+            Self::Filters(a) => Some(a.aggs_mut()),
+            Self::DiversifiedSampler(a) => Some(a.aggs_mut()),
+            Self::DateHistogram(a) => Some(a.aggs_mut()),
+            Self::GeotileGrid(a) => Some(a.aggs_mut()),
+            Self::BucketSelector(a) => Some(a.aggs_mut()),
+            Self::Children(a) => Some(a.aggs_mut()),
+            Self::Composite(a) => Some(a.aggs_mut()),
+            Self::Nested(a) => Some(a.aggs_mut()),
+            Self::AdjacencyMatrix(a) => Some(a.aggs_mut()),
+            Self::AutoDateHistogram(a) => Some(a.aggs_mut()),
+            Self::DateRange(a) => Some(a.aggs_mut()),
+            Self::GeoDistance(a) => Some(a.aggs_mut()),
+            Self::Global(a) => Some(a.aggs_mut()),
+            Self::Histogram(a) => Some(a.aggs_mut()),
+            Self::IpRange(a) => Some(a.aggs_mut()),
+            Self::Missing(a) => Some(a.aggs_mut()),
+            Self::MultiTerms(a) => Some(a.aggs_mut()),
+            Self::Range(a) => Some(a.aggs_mut()),
+            Self::RareTerms(a) => Some(a.aggs_mut()),
+            Self::SignificantTerms(a) => Some(a.aggs_mut()),
+            Self::Json(a) => Some(a.aggs_mut()),
+            Self::TopHits(_)
+            | Self::Cardinality(_)
+            | Self::Avg(_)
+            | Self::Max(_)
+            | Self::Min(_)
+            | Self::Sum(_)
+            | Self::Rate(_)
+            | Self::Boxplot(_)
+            | Self::ExtendedStats(_)
+            | Self::GeoBounds(_)
+            | Self::GeoCentroid(_)
+            | Self::MatrixStats(_)
+            | Self::MedianAbsoluteDeviation(_)
+            | Self::PercentileRanks(_)
+            | Self::Percentiles(_)
+            | Self::Stats(_)
+            | Self::StringStats(_)
+            | Self::TTest(_)
+            | Self::ValueCount(_)
+            | Self::WeightedAvg(_)
+            | Self::AvgBucket(_)
+            | Self::BucketScript(_)
+            | Self::BucketSort(_)
+            | Self::CumulativeSum(_)
+            | Self::Derivative(_)
+            | Self::MaxBucket(_)
+            | Self::MinBucket(_)
+            | Self::MovingFn(_)
+            | Self::MovingPercentiles(_)
+            | Self::Normalize(_)
+            | Self::SerialDiff(_)
+            | Self::StatsBucket(_)
+            | Self::SumBucket(_) => None,
+        }
+    }
+
+    /// Mutable access to the queries of the aggregation, not including the ones of its
+    /// sub-aggregations
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        match self {
+            Self::Filter(a) => a.queries_mut(),
+            Self::Filters(a) => a.queries_mut(),
+            Self::AdjacencyMatrix(a) => a.queries_mut(),
+            Self::SignificantTerms(a) => a.queries_mut(),
+            Self::TTest(a) => a.queries_mut(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 /// Type alias for a collection of aggregations
-pub type Aggregations = Map<AggregationName, Aggregation>;
+pub type Aggregations = OrderedMap<AggregationName, Aggregation>;
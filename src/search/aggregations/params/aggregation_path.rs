@@ -0,0 +1,123 @@
+use crate::search::*;
+
+/// Builds a `buckets_path` referencing an aggregation or metric, following the
+/// `AGG_NAME[>AGG_NAME]*[.METRIC]` syntax:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let path = AggregationPath::new("sale_type")
+///     .key("hat")
+///     .then("sales")
+///     .metric("value");
+///
+/// assert_eq!(path.to_string(), "sale_type['hat']>sales.value");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline.html#buckets-path-syntax>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct AggregationPath(String);
+
+impl AggregationPath {
+    /// Creates an instance of [`AggregationPath`] starting at the `aggregation`
+    pub fn new<T>(aggregation: T) -> Self
+    where
+        T: ToString,
+    {
+        Self(aggregation.to_string())
+    }
+
+    /// Steps into the `aggregation` nested in the current one
+    pub fn then<T>(mut self, aggregation: T) -> Self
+    where
+        T: ToString,
+    {
+        self.0.push('>');
+        self.0.push_str(&aggregation.to_string());
+        self
+    }
+
+    /// Selects the bucket with the `key` of a multi-bucket aggregation. The path syntax has no
+    /// escaping, keys with `'`, `[`, `]` or `>` characters can't be referenced.
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.0.push_str(&format!("['{}']", key.to_string()));
+        self
+    }
+
+    /// Same as `key`, but fails if the key contains one of the characters of the path syntax
+    pub fn try_key<T>(self, key: T) -> Result<Self, ValidationError>
+    where
+        T: ToString,
+    {
+        let key = key.to_string();
+
+        if key.contains(['\'', '[', ']', '>']) {
+            return Err(ValidationError::InvalidBucketKey);
+        }
+
+        Ok(self.key(key))
+    }
+
+    /// References the `metric` of the current aggregation, e.g. `_count` or the `std_deviation`
+    /// of an extended stats aggregation
+    pub fn metric<T>(mut self, metric: T) -> Self
+    where
+        T: ToString,
+    {
+        self.0.push('.');
+        self.0.push_str(&metric.to_string());
+        self
+    }
+}
+
+impl std::fmt::Display for AggregationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<AggregationPath> for BucketsPath {
+    fn from(path: AggregationPath) -> Self {
+        BucketsPath::Single(path.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_paths() {
+        assert_eq!(AggregationPath::new("the_sum").to_string(), "the_sum");
+        assert_eq!(
+            AggregationPath::new("by_country")
+                .then("by_brand")
+                .metric("_count")
+                .to_string(),
+            "by_country>by_brand._count"
+        );
+        assert_eq!(
+            BucketsPath::from(AggregationPath::new("stats").metric("avg")),
+            BucketsPath::Single("stats.avg".to_string())
+        );
+    }
+
+    #[test]
+    fn validates_keys() {
+        assert_eq!(
+            AggregationPath::new("sale_type")
+                .try_key("hat")
+                .unwrap()
+                .to_string(),
+            "sale_type['hat']"
+        );
+
+        for key in ["men's", "[hat]", "a>b"] {
+            assert_eq!(
+                AggregationPath::new("sale_type").try_key(key).unwrap_err(),
+                ValidationError::InvalidBucketKey
+            );
+        }
+    }
+}
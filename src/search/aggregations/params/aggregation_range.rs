@@ -0,0 +1,70 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// Bucket of the range aggregations, the `from` value is included and the `to` value is
+/// excluded from the range.
+///
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let range =
+/// AggregationRange::new().from(100).to(200).key("medium");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AggregationRange {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    to: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mask: Option<String>,
+}
+
+impl AggregationRange {
+    /// Creates an unbounded range
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lower bound of the range, inclusive
+    pub fn from<T>(mut self, from: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.from = Term::new(from);
+        self
+    }
+
+    /// Upper bound of the range, exclusive
+    pub fn to<T>(mut self, to: T) -> Self
+    where
+        T: Serialize,
+    {
+        self.to = Term::new(to);
+        self
+    }
+
+    /// Custom key of the bucket, used instead of the default `from-to` key
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// CIDR mask of the range, e.g. `10.0.0.0/25`, only supported by the
+    /// [`IpRangeAggregation`] and used instead of the bounds
+    pub fn mask<T>(mut self, mask: T) -> Self
+    where
+        T: ToString,
+    {
+        self.mask = Some(mask.to_string());
+        self
+    }
+}
@@ -1,6 +1,8 @@
 //! Value types accepted by aggregation clauses
 
 mod aggregation_name;
+mod aggregation_path;
+mod aggregation_range;
 mod gap_policy;
 mod rate_mode;
 mod terms_exclude;
@@ -8,6 +10,8 @@ mod terms_include;
 mod terms_order;
 
 pub use self::aggregation_name::*;
+pub use self::aggregation_path::*;
+pub use self::aggregation_range::*;
 pub use self::gap_policy::*;
 pub use self::rate_mode::*;
 pub use self::terms_exclude::*;
@@ -0,0 +1,84 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A sibling pipeline aggregation which calculates the mean value of a specified metric in a sibling aggregation.
+/// The specified metric must be numeric and the sibling aggregation must be a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-avg-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AvgBucketAggregation {
+    avg_bucket: AvgBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AvgBucketAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AvgBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to aggregate, see [`AggregationPath`]
+    pub fn avg_bucket<T>(buckets_path: T) -> AvgBucketAggregation
+    where
+        T: ToString,
+    {
+        AvgBucketAggregation {
+            avg_bucket: AvgBucketAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                gap_policy: None,
+                format: None,
+            },
+        }
+    }
+}
+
+impl AvgBucketAggregation {
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.avg_bucket.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.avg_bucket.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::avg_bucket("sales_per_month>sales"),
+            json!({ "avg_bucket": { "buckets_path": "sales_per_month>sales" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::avg_bucket("sales_per_month>sales")
+                .gap_policy(GapPolicy::InsertZeros)
+                .format("0.00"),
+            json!({
+                "avg_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "gap_policy": "insert_zeros",
+                    "format": "0.00"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which executes a script which can perform per bucket computations on specified
+/// metrics in the parent multi-bucket aggregation. The specified metric must be numeric and the script must return
+/// a numeric value.
+///
+/// To create a bucket script aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::bucket_script(
+///     vec![("tShirtSales", "t-shirts>sales"), ("totalSales", "total_sales")],
+///     Script::source("params.tShirtSales / params.totalSales * 100"),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-bucket-script-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketScriptAggregation {
+    bucket_script: BucketScriptAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketScriptAggregationInner {
+    buckets_path: BucketsPath,
+
+    script: Script,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketScriptAggregation`]
+    ///
+    /// - `buckets_path` - map of script variables to the paths of the metrics they refer to
+    /// - `script` - the script to run for each bucket, its return value is added to the bucket
+    pub fn bucket_script<B, S>(buckets_path: B, script: S) -> BucketScriptAggregation
+    where
+        B: Into<BucketsPath>,
+        S: Into<Script>,
+    {
+        BucketScriptAggregation {
+            bucket_script: BucketScriptAggregationInner {
+                buckets_path: buckets_path.into(),
+                script: script.into(),
+                gap_policy: None,
+                format: None,
+            },
+        }
+    }
+}
+
+impl BucketScriptAggregation {
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.bucket_script.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.bucket_script.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::bucket_script("the_sum", Script::source("params._value * 2")),
+            json!({
+                "bucket_script": {
+                    "buckets_path": "the_sum",
+                    "script": { "source": "params._value * 2" }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::bucket_script(
+                vec![
+                    ("tShirtSales", "t-shirts>sales"),
+                    ("totalSales", "total_sales"),
+                ],
+                Script::source("params.tShirtSales / params.totalSales * 100").lang("painless"),
+            )
+            .gap_policy(GapPolicy::Skip)
+            .format("00.00"),
+            json!({
+                "bucket_script": {
+                    "buckets_path": {
+                        "tShirtSales": "t-shirts>sales",
+                        "totalSales": "total_sales"
+                    },
+                    "script": {
+                        "source": "params.tShirtSales / params.totalSales * 100",
+                        "lang": "painless"
+                    },
+                    "gap_policy": "skip",
+                    "format": "00.00"
+                }
+            }),
+        );
+    }
+}
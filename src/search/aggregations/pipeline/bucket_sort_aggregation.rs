@@ -0,0 +1,102 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which sorts the buckets of its parent multi-bucket aggregation. Zero or more sort
+/// fields may be specified together with the corresponding sort order. Each bucket may be sorted based on its
+/// `_key`, `_count` or its sub-aggregations. In addition, parameters `from` and `size` may be set in order to
+/// truncate the result buckets.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-bucket-sort-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketSortAggregation {
+    bucket_sort: BucketSortAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketSortAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: SortCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketSortAggregation`]
+    pub fn bucket_sort() -> BucketSortAggregation {
+        BucketSortAggregation {
+            bucket_sort: BucketSortAggregationInner {
+                sort: Default::default(),
+                from: None,
+                size: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl BucketSortAggregation {
+    /// The list of fields to sort on, the buckets are left in their original order when empty
+    pub fn sort<T>(mut self, sort: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Sort>,
+    {
+        self.bucket_sort.sort.extend(sort);
+        self
+    }
+
+    /// Buckets in positions prior to the set value will be truncated
+    pub fn from(mut self, from: u64) -> Self {
+        self.bucket_sort.from = Some(from);
+        self
+    }
+
+    /// The number of buckets to return
+    ///
+    /// Defaults to all buckets of the parent aggregation
+    pub fn size(mut self, size: u64) -> Self {
+        self.bucket_sort.size = Some(size);
+        self
+    }
+
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.bucket_sort.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(Aggregation::bucket_sort(), json!({ "bucket_sort": { } }));
+
+        assert_serialize_aggregation(
+            Aggregation::bucket_sort()
+                .sort(FieldSort::new("total_sales").order(SortOrder::Desc))
+                .from(1)
+                .size(3)
+                .gap_policy(GapPolicy::Skip),
+            json!({
+                "bucket_sort": {
+                    "sort": [
+                        { "total_sales": { "order": "desc" } }
+                    ],
+                    "from": 1,
+                    "size": 3,
+                    "gap_policy": "skip"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which calculates the cumulative sum of a specified metric in a parent histogram
+/// (or date_histogram) aggregation. The specified metric must be numeric and the enclosing histogram must have
+/// `min_doc_count` set to `0` (default for histogram aggregations).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-cumulative-sum-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CumulativeSumAggregation {
+    cumulative_sum: CumulativeSumAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct CumulativeSumAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`CumulativeSumAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to aggregate, see [`AggregationPath`]
+    pub fn cumulative_sum<T>(buckets_path: T) -> CumulativeSumAggregation
+    where
+        T: ToString,
+    {
+        CumulativeSumAggregation {
+            cumulative_sum: CumulativeSumAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                format: None,
+            },
+        }
+    }
+}
+
+impl CumulativeSumAggregation {
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.cumulative_sum.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::cumulative_sum("sales"),
+            json!({ "cumulative_sum": { "buckets_path": "sales" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::cumulative_sum("sales").format("0.00"),
+            json!({
+                "cumulative_sum": {
+                    "buckets_path": "sales",
+                    "format": "0.00"
+                }
+            }),
+        );
+    }
+}
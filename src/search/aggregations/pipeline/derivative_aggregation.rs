@@ -0,0 +1,101 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which calculates the derivative of a specified metric in a parent histogram (or
+/// date_histogram) aggregation. The specified metric must be numeric and the enclosing histogram must have
+/// `min_doc_count` set to `0` (default for histogram aggregations).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-derivative-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DerivativeAggregation {
+    derivative: DerivativeAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct DerivativeAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unit: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`DerivativeAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to aggregate, see [`AggregationPath`]
+    pub fn derivative<T>(buckets_path: T) -> DerivativeAggregation
+    where
+        T: ToString,
+    {
+        DerivativeAggregation {
+            derivative: DerivativeAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                gap_policy: None,
+                format: None,
+                unit: None,
+            },
+        }
+    }
+}
+
+impl DerivativeAggregation {
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.derivative.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.derivative.format = Some(format.to_string());
+        self
+    }
+
+    /// The date histogram unit to normalize the derivative to, e.g. `1d`, the
+    /// `normalized_value` of the response is then the change per unit
+    pub fn unit<T>(mut self, unit: T) -> Self
+    where
+        T: ToString,
+    {
+        self.derivative.unit = Some(unit.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::derivative("sales"),
+            json!({ "derivative": { "buckets_path": "sales" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::derivative("sales")
+                .gap_policy(GapPolicy::InsertZeros)
+                .format("0.00")
+                .unit("1d"),
+            json!({
+                "derivative": {
+                    "buckets_path": "sales",
+                    "gap_policy": "insert_zeros",
+                    "format": "0.00",
+                    "unit": "1d"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A sibling pipeline aggregation which identifies the bucket(s) with the maximum value of a specified metric in a
+/// sibling aggregation and outputs both the value and the key(s) of the bucket(s). The specified metric must be
+/// numeric and the sibling aggregation must be a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-max-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MaxBucketAggregation {
+    max_bucket: MaxBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MaxBucketAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MaxBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to aggregate, see [`AggregationPath`]
+    pub fn max_bucket<T>(buckets_path: T) -> MaxBucketAggregation
+    where
+        T: ToString,
+    {
+        MaxBucketAggregation {
+            max_bucket: MaxBucketAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                gap_policy: None,
+                format: None,
+            },
+        }
+    }
+}
+
+impl MaxBucketAggregation {
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.max_bucket.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.max_bucket.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::max_bucket("sales_per_month>sales"),
+            json!({ "max_bucket": { "buckets_path": "sales_per_month>sales" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::max_bucket("sales_per_month>sales")
+                .gap_policy(GapPolicy::InsertZeros)
+                .format("0.00"),
+            json!({
+                "max_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "gap_policy": "insert_zeros",
+                    "format": "0.00"
+                }
+            }),
+        );
+    }
+}
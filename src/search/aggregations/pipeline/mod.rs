@@ -23,5 +23,30 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline.html>
 
-#[doc(hidden)]
-pub mod todo {}
+mod avg_bucket_aggregation;
+mod bucket_script_aggregation;
+mod bucket_sort_aggregation;
+mod cumulative_sum_aggregation;
+mod derivative_aggregation;
+mod max_bucket_aggregation;
+mod min_bucket_aggregation;
+mod moving_fn_aggregation;
+mod moving_percentiles_aggregation;
+mod normalize_aggregation;
+mod serial_diff_aggregation;
+mod stats_bucket_aggregation;
+mod sum_bucket_aggregation;
+
+pub use self::avg_bucket_aggregation::*;
+pub use self::bucket_script_aggregation::*;
+pub use self::bucket_sort_aggregation::*;
+pub use self::cumulative_sum_aggregation::*;
+pub use self::derivative_aggregation::*;
+pub use self::max_bucket_aggregation::*;
+pub use self::min_bucket_aggregation::*;
+pub use self::moving_fn_aggregation::*;
+pub use self::moving_percentiles_aggregation::*;
+pub use self::normalize_aggregation::*;
+pub use self::serial_diff_aggregation::*;
+pub use self::stats_bucket_aggregation::*;
+pub use self::sum_bucket_aggregation::*;
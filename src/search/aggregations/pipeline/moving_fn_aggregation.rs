@@ -0,0 +1,107 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Given an ordered series of data, the moving function aggregation will slide a window across the data and allow
+/// the user to specify a custom script that is executed on each window of data. For convenience, a number of common
+/// functions are predefined such as min/max, moving averages, etc.
+///
+/// To create a moving function aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::moving_fn("the_sum", 10, "MovingFunctions.unweightedAvg(values)").shift(1);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-movfn-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MovingFnAggregation {
+    moving_fn: MovingFnAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MovingFnAggregationInner {
+    buckets_path: String,
+
+    window: u32,
+
+    script: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shift: Option<i32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MovingFnAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric of interest, see [`AggregationPath`]
+    /// - `window` - the size of the window to "slide" across the histogram
+    /// - `script` - the script that should be executed on each window of data, the values of
+    ///   the window are available as `values`
+    pub fn moving_fn<T, U>(buckets_path: T, window: u32, script: U) -> MovingFnAggregation
+    where
+        T: ToString,
+        U: ToString,
+    {
+        MovingFnAggregation {
+            moving_fn: MovingFnAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                window,
+                script: script.to_string(),
+                shift: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl MovingFnAggregation {
+    /// Shift of the window position, positive values move the window to the right
+    ///
+    /// Default value is `0`, the window includes every bucket before the current one
+    pub fn shift(mut self, shift: i32) -> Self {
+        self.moving_fn.shift = Some(shift);
+        self
+    }
+
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.moving_fn.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::moving_fn("the_sum", 10, "MovingFunctions.unweightedAvg(values)"),
+            json!({
+                "moving_fn": {
+                    "buckets_path": "the_sum",
+                    "window": 10,
+                    "script": "MovingFunctions.unweightedAvg(values)"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::moving_fn("the_sum", 5, "MovingFunctions.max(values)")
+                .shift(1)
+                .gap_policy(GapPolicy::KeepValues),
+            json!({
+                "moving_fn": {
+                    "buckets_path": "the_sum",
+                    "window": 5,
+                    "script": "MovingFunctions.max(values)",
+                    "shift": 1,
+                    "gap_policy": "keep_values"
+                }
+            }),
+        );
+    }
+}
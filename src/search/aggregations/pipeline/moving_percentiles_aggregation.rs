@@ -0,0 +1,81 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Given an ordered series of percentiles, the moving percentiles aggregation will slide a window across those
+/// percentiles and allow the user to compute the cumulative percentile. This is conceptually very similar to the
+/// moving function pipeline aggregation, except it works on the percentiles sketches instead of the actual bucket
+/// values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-moving-percentiles-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MovingPercentilesAggregation {
+    moving_percentiles: MovingPercentilesAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MovingPercentilesAggregationInner {
+    buckets_path: String,
+
+    window: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shift: Option<i32>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MovingPercentilesAggregation`]
+    ///
+    /// - `buckets_path` - path to the percentiles aggregation of interest, see [`AggregationPath`]
+    /// - `window` - the size of the window to "slide" across the histogram
+    pub fn moving_percentiles<T>(buckets_path: T, window: u32) -> MovingPercentilesAggregation
+    where
+        T: ToString,
+    {
+        MovingPercentilesAggregation {
+            moving_percentiles: MovingPercentilesAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                window,
+                shift: None,
+            },
+        }
+    }
+}
+
+impl MovingPercentilesAggregation {
+    /// Shift of the window position, positive values move the window to the right
+    ///
+    /// Default value is `0`, the window includes every bucket before the current one
+    pub fn shift(mut self, shift: i32) -> Self {
+        self.moving_percentiles.shift = Some(shift);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::moving_percentiles("the_percentile", 10),
+            json!({
+                "moving_percentiles": {
+                    "buckets_path": "the_percentile",
+                    "window": 10
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::moving_percentiles("the_percentile", 10).shift(-2),
+            json!({
+                "moving_percentiles": {
+                    "buckets_path": "the_percentile",
+                    "window": 10,
+                    "shift": -2
+                }
+            }),
+        );
+    }
+}
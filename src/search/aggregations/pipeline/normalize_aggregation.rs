@@ -0,0 +1,119 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which calculates the specific normalized/rescaled value for a specific bucket
+/// value. Values that cannot be normalized, will be skipped using the skip gap policy.
+///
+/// To create a normalize aggregation:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let aggregation =
+/// Aggregation::normalize("sales", NormalizeMethod::PercentOfSum).format("00.00%");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-normalize-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NormalizeAggregation {
+    normalize: NormalizeAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct NormalizeAggregationInner {
+    buckets_path: String,
+
+    method: NormalizeMethod,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+/// The normalization applied by a [`NormalizeAggregation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NormalizeMethod {
+    /// Rescales the data such that the minimum number is 0, and the maximum number is 1, with
+    /// the rest normalized linearly in-between
+    #[serde(rename = "rescale_0_1")]
+    Rescale0To1,
+
+    /// Rescales the data such that the minimum number is 0, and the maximum number is 100, with
+    /// the rest normalized linearly in-between
+    #[serde(rename = "rescale_0_100")]
+    Rescale0To100,
+
+    /// Normalizes each value so that it represents a percentage of the total sum it attributes to
+    #[serde(rename = "percent_of_sum")]
+    PercentOfSum,
+
+    /// Normalizes such that each value is normalized by how much it differs from the average
+    #[serde(rename = "mean")]
+    Mean,
+
+    /// Normalizes such that each value represents how far it is from the mean relative to the
+    /// standard deviation
+    #[serde(rename = "z-score")]
+    ZScore,
+
+    /// Normalizes such that each value is exponentiated and relative to the sum of the
+    /// exponents of the original values
+    #[serde(rename = "softmax")]
+    Softmax,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`NormalizeAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to normalize, see [`AggregationPath`]
+    /// - `method` - the specific method to apply
+    pub fn normalize<T>(buckets_path: T, method: NormalizeMethod) -> NormalizeAggregation
+    where
+        T: ToString,
+    {
+        NormalizeAggregation {
+            normalize: NormalizeAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                method,
+                format: None,
+            },
+        }
+    }
+}
+
+impl NormalizeAggregation {
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.normalize.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::normalize("sales", NormalizeMethod::ZScore),
+            json!({
+                "normalize": {
+                    "buckets_path": "sales",
+                    "method": "z-score"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::normalize("sales", NormalizeMethod::Rescale0To100).format("00.00%"),
+            json!({
+                "normalize": {
+                    "buckets_path": "sales",
+                    "method": "rescale_0_100",
+                    "format": "00.00%"
+                }
+            }),
+        );
+    }
+}
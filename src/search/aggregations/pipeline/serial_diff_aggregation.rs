@@ -0,0 +1,100 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Serial differencing is a technique where values in a time series are subtracted from itself at different time
+/// lags or periods. For example, the datapoint f(x) = f(x<sub>t</sub>) - f(x<sub>t-n</sub>), where n is the period
+/// being used.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-serialdiff-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SerialDiffAggregation {
+    serial_diff: SerialDiffAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SerialDiffAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lag: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SerialDiffAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to aggregate, see [`AggregationPath`]
+    pub fn serial_diff<T>(buckets_path: T) -> SerialDiffAggregation
+    where
+        T: ToString,
+    {
+        SerialDiffAggregation {
+            serial_diff: SerialDiffAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                lag: None,
+                gap_policy: None,
+                format: None,
+            },
+        }
+    }
+}
+
+impl SerialDiffAggregation {
+    /// The historical bucket to subtract from the current value, e.g. `7` subtracts the value of
+    /// a week ago with daily buckets
+    ///
+    /// Default value is `1`
+    pub fn lag(mut self, lag: u32) -> Self {
+        self.serial_diff.lag = Some(lag);
+        self
+    }
+
+    /// Policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.serial_diff.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value, used for the `value_as_string` of the response
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.serial_diff.format = Some(format.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::serial_diff("the_sum"),
+            json!({ "serial_diff": { "buckets_path": "the_sum" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::serial_diff("the_sum")
+                .lag(7)
+                .gap_policy(GapPolicy::InsertZeros)
+                .format("0.00"),
+            json!({
+                "serial_diff": {
+                    "buckets_path": "the_sum",
+                    "lag": 7,
+                    "gap_policy": "insert_zeros",
+                    "format": "0.00"
+                }
+            }),
+        );
+    }
+}
@@ -48,6 +48,14 @@ impl Collapse {
         self.max_concurrent_group_searches = Some(max_concurrent_group_searches);
         self
     }
+
+    /// Mutable access to the highlight queries of the inner hits
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.inner_hits
+            .iter_mut()
+            .flat_map(InnerHits::queries_mut)
+            .collect()
+    }
 }
 
 #[cfg(test)]
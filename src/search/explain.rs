@@ -0,0 +1,55 @@
+//! Returns information about why a specific document matches (or doesn't match) a query.
+use crate::search::*;
+
+/// Body of an
+/// [explain](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html)
+/// request, the response is parsed with [`ExplainResponse`].
+///
+/// The `_source` and `stored_fields` of the document are requested with the query parameters of
+/// the request, the body only accepts the query.
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let explain = Explain::new(Query::r#match("message", "elasticsearch"));
+///
+/// assert_eq!(
+///     serde_json::to_value(explain).unwrap(),
+///     serde_json::json!({ "query": { "match": { "message": { "query": "elasticsearch" } } } })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Explain {
+    query: Query,
+}
+
+impl Explain {
+    /// Creates an instance of [`Explain`]
+    ///
+    /// - `query` - query to explain the score of the document for
+    pub fn new<T>(query: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        Self {
+            query: query.into(),
+        }
+    }
+
+    /// Serializes the request into a JSON byte buffer, ready to be sent as a request body
+    pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Explain::new(Query::term("user.id", "kimchy")),
+            json!({ "query": { "term": { "user.id": { "value": "kimchy" } } } }),
+        );
+    }
+}
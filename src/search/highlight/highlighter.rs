@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use crate::Map;
 
 /// Highlighter settings
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -16,6 +17,9 @@ pub enum Highlighter {
 
     /// Unified highlighter
     Unified(UnifiedHighlighter),
+
+    /// Custom highlighter
+    Custom(CustomHighlighter),
 }
 
 /// Highlighting settings can be set on a global level and overridden at the field level
@@ -256,6 +260,60 @@ pub struct UnifiedHighlighter {
     boundary_scanner: Option<UnifiedBoundaryScanner>,
 }
 
+/// Any highlighter type not natively supported by Elasticsearch, such as the ones provided by
+/// plugins. The highlighter is selected by its registered `type` name and configured through
+/// the common highlighter settings and an arbitrary `options` map.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/highlighting.html#highlighting-settings>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CustomHighlighter {
+    // Common
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boundary_chars: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boundary_max_scan: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    encoder: Option<Encoder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    force_source: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fragment_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    highlight_query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    no_match_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    number_of_fragments: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<Order>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pre_tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    post_tags: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    require_field_match: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    tags: Option<Tags>,
+
+    // Highlighter specific
+    r#type: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    options: Map<String, serde_json::Value>,
+}
+
 impl Default for Highlighter {
     fn default() -> Self {
         Self::Default(Default::default())
@@ -283,6 +341,25 @@ impl Highlighter {
     pub fn unified() -> UnifiedHighlighter {
         UnifiedHighlighter::default()
     }
+
+    /// Creates a new instance of [CustomHighlighter](CustomHighlighter)
+    pub fn custom<T>(r#type: T) -> CustomHighlighter
+    where
+        T: ToString,
+    {
+        CustomHighlighter::new(r#type)
+    }
+
+    /// Mutable access to the `highlight_query`
+    pub(crate) fn highlight_query_mut(&mut self) -> Option<&mut Query> {
+        match self {
+            Self::Default(highlighter) => highlighter.highlight_query.as_mut(),
+            Self::Fvh(highlighter) => highlighter.highlight_query.as_mut(),
+            Self::Plain(highlighter) => highlighter.highlight_query.as_mut(),
+            Self::Unified(highlighter) => highlighter.highlight_query.as_mut(),
+            Self::Custom(highlighter) => highlighter.highlight_query.as_mut(),
+        }
+    }
 }
 
 impl Default for FastVectorHighlighter {
@@ -378,6 +455,12 @@ impl From<UnifiedHighlighter> for Highlighter {
     }
 }
 
+impl From<CustomHighlighter> for Highlighter {
+    fn from(highlighter: CustomHighlighter) -> Self {
+        Self::Custom(highlighter)
+    }
+}
+
 macro_rules! add_highlighter_methods {
     () => {
         /// A string that contains each boundary character. Defaults to `.,!? \t\n`.
@@ -510,6 +593,29 @@ impl DefaultHighlighter {
     convert_to_highlighter!(fvh, FastVectorHighlighter);
     convert_to_highlighter!(plain, PlainHighlighter);
     convert_to_highlighter!(unified, UnifiedHighlighter);
+
+    /// Converts [Highlighter](Highlighter) to [CustomHighlighter](CustomHighlighter)
+    pub fn custom<T>(self, r#type: T) -> CustomHighlighter
+    where
+        T: ToString,
+    {
+        CustomHighlighter {
+            boundary_chars: self.boundary_chars,
+            boundary_max_scan: self.boundary_max_scan,
+            encoder: self.encoder,
+            force_source: self.force_source,
+            fragment_size: self.fragment_size,
+            highlight_query: self.highlight_query,
+            no_match_size: self.no_match_size,
+            number_of_fragments: self.number_of_fragments,
+            order: self.order,
+            pre_tags: self.pre_tags,
+            post_tags: self.post_tags,
+            require_field_match: self.require_field_match,
+            tags: self.tags,
+            ..CustomHighlighter::new(r#type)
+        }
+    }
 }
 
 impl FastVectorHighlighter {
@@ -583,3 +689,47 @@ impl UnifiedHighlighter {
         self
     }
 }
+
+impl CustomHighlighter {
+    /// Creates a new [CustomHighlighter](CustomHighlighter) instance
+    pub fn new<T>(r#type: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            r#type: r#type.to_string(),
+            boundary_chars: None,
+            boundary_max_scan: None,
+            encoder: None,
+            force_source: None,
+            fragment_size: None,
+            highlight_query: None,
+            no_match_size: None,
+            number_of_fragments: None,
+            order: None,
+            pre_tags: None,
+            post_tags: None,
+            require_field_match: None,
+            tags: None,
+            options: Default::default(),
+        }
+    }
+
+    add_highlighter_methods!();
+
+    /// Adds highlighter specific option, passed as is to the highlighter implementation.
+    pub fn option<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<serde_json::Value>,
+    {
+        let _ = self.options.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Sets highlighter specific options, passed as is to the highlighter implementation.
+    pub fn options(mut self, options: Map<String, serde_json::Value>) -> Self {
+        self.options = options;
+        self
+    }
+}
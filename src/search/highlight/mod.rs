@@ -45,6 +45,8 @@ mod matched_fields;
 mod order;
 mod tags;
 
+use crate::search::params::{serialize_as_entries, OrderedMap};
+use crate::search::Query;
 use crate::util::*;
 
 pub use self::boundary_scanner::*;
@@ -61,8 +63,11 @@ pub use self::tags::*;
 pub struct Highlight {
     #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
     highlighter: Option<Highlighter>,
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    fields: Vec<KeyValuePair<String, Highlighter>>,
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        serialize_with = "serialize_as_entries"
+    )]
+    fields: OrderedMap<String, Highlighter>,
 }
 
 impl Highlight {
@@ -85,8 +90,7 @@ impl Highlight {
     where
         F: ToString,
     {
-        self.fields
-            .push(KeyValuePair::new(field.to_string(), Default::default()));
+        let _ = self.fields.insert(field.to_string(), Default::default());
         self
     }
 
@@ -96,10 +100,18 @@ impl Highlight {
         F: ToString,
         H: Into<Highlighter>,
     {
-        self.fields
-            .push(KeyValuePair::new(field.to_string(), highlighter.into()));
+        let _ = self.fields.insert(field.to_string(), highlighter.into());
         self
     }
+
+    /// Mutable access to the highlight queries of the highlighter and of the fields
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.highlighter
+            .iter_mut()
+            .chain(self.fields.iter_mut().map(|(_, highlighter)| highlighter))
+            .filter_map(Highlighter::highlight_query_mut)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -190,5 +202,31 @@ mod tests {
                 ]
             }),
         );
+
+        assert_serialize(
+            Highlight::new()
+                .highlighter(
+                    Highlighter::new()
+                        .tags((["<eim>"], ["</eim>"]))
+                        .custom("semantic")
+                        .option("model_id", "sentence-highlighting")
+                        .option("max_fragments", 3),
+                )
+                .field("field1")
+                .field_highlighter("field2", Highlighter::custom("experimental")),
+            json!({
+                "pre_tags": ["<eim>"],
+                "post_tags": ["</eim>"],
+                "type": "semantic",
+                "options": {
+                    "model_id": "sentence-highlighting",
+                    "max_fragments": 3,
+                },
+                "fields": [
+                    { "field1": {} },
+                    { "field2": { "type": "experimental" } },
+                ]
+            }),
+        );
     }
 }
@@ -25,7 +25,7 @@ use serde::Serialize;
 ///
 /// To create a knn search with a query vector or query vector builder:
 /// ```
-/// # use elasticsearch_dsl::*;
+/// # use elasticsearch_dsl::search::*;
 /// # let search =
 /// Search::new()
 ///     .knn(Knn::query_vector("test1", vec![1.0, 2.0, 3.0]))
@@ -49,7 +49,7 @@ pub struct Knn {
     num_candidates: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    filter: Option<Box<Query>>,
+    filter: QueryCollection,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     similarity: Option<f32>,
@@ -77,7 +77,7 @@ impl Knn {
             query_vector_builder: None,
             k: None,
             num_candidates: None,
-            filter: None,
+            filter: Default::default(),
             similarity: None,
             boost: None,
             _name: None,
@@ -98,7 +98,7 @@ impl Knn {
             query_vector_builder: Some(query_vector_builder.into()),
             k: None,
             num_candidates: None,
-            filter: None,
+            filter: Default::default(),
             similarity: None,
             boost: None,
             _name: None,
@@ -127,11 +127,14 @@ impl Knn {
     ///
     /// The filter is a pre-filter, meaning that it is applied **during** the approximate kNN search to ensure that
     /// `num_candidates` matching documents are returned.
+    ///
+    /// Calling this method multiple times accumulates the filter queries.
     pub fn filter<T>(mut self, filter: T) -> Self
     where
-        T: Into<Query>,
+        T: IntoIterator,
+        T::Item: Into<Option<Query>>,
     {
-        self.filter = Some(Box::new(filter.into()));
+        self.filter.extend(filter);
         self
     }
 
@@ -143,12 +146,18 @@ impl Knn {
         self
     }
 
+    /// Mutable access to the `filter` queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.filter.as_mut_slice().iter_mut().collect()
+    }
+
     add_boost_and_name!();
 }
 
 /// A configuration object indicating how to build a query_vector before executing the request.
 ///
-/// Currently, the only supported builder is [`TextEmbedding`].
+/// Natively supported builder is [`TextEmbedding`], any other builder can be configured with
+/// [`CustomQueryVectorBuilder`].
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/8.13/knn-search.html#knn-semantic-search>
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -156,6 +165,23 @@ impl Knn {
 pub enum QueryVectorBuilder {
     /// The natural language processing task to perform.
     TextEmbedding(TextEmbedding),
+
+    /// Query vector builder not yet supported by the library.
+    #[serde(untagged)]
+    Custom(CustomQueryVectorBuilder),
+}
+
+impl QueryVectorBuilder {
+    /// Creates an instance of [`CustomQueryVectorBuilder`]
+    ///
+    /// - `name` - Name of the query vector builder
+    /// - `payload` - Raw JSON configuration of the query vector builder
+    pub fn custom<T>(name: T, payload: serde_json::Value) -> CustomQueryVectorBuilder
+    where
+        T: ToString,
+    {
+        CustomQueryVectorBuilder::new(name, payload)
+    }
 }
 
 /// The natural language processing task to perform.
@@ -189,6 +215,47 @@ impl TextEmbedding {
     }
 }
 
+/// Query vector builder for something not yet supported, serialized as `{ "<name>": <payload> }`.
+///
+/// **NOTE**: This is fallible and can lead to incorrect queries and
+/// rejected search requests, use at your own risk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomQueryVectorBuilder {
+    name: String,
+    payload: serde_json::Value,
+}
+
+impl CustomQueryVectorBuilder {
+    /// Creates an instance of [`CustomQueryVectorBuilder`]
+    ///
+    /// - `name` - Name of the query vector builder
+    /// - `payload` - Raw JSON configuration of the query vector builder
+    pub fn new<T>(name: T, payload: serde_json::Value) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            name: name.to_string(),
+            payload,
+        }
+    }
+}
+
+impl From<CustomQueryVectorBuilder> for QueryVectorBuilder {
+    fn from(builder: CustomQueryVectorBuilder) -> Self {
+        Self::Custom(builder)
+    }
+}
+
+impl Serialize for CustomQueryVectorBuilder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        KeyValuePair::new(&self.name, &self.payload).serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,13 +301,9 @@ mod tests {
                         "query_vector": [4.0, 5.0, 6.0],
                         "k": 3,
                         "num_candidates": 100,
-                        "filter": {
-                            "term": {
-                                "field": {
-                                    "value": "value"
-                                }
-                            }
-                        },
+                        "filter": [
+                            { "term": { "field": { "value": "value" } } }
+                        ],
                         "similarity": 0.5,
                         "boost": 2.0,
                         "_name": "test2"
@@ -264,13 +327,9 @@ mod tests {
                         },
                         "k": 5,
                         "num_candidates": 200,
-                        "filter": {
-                            "term": {
-                                "field": {
-                                    "value": "value"
-                                }
-                            }
-                        },
+                        "filter": [
+                            { "term": { "field": { "value": "value" } } }
+                        ],
                         "similarity": 0.7,
                         "boost": 2.1,
                         "_name": "test4"
@@ -279,4 +338,48 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_custom_query_vector_builder() {
+        assert_serialize(
+            Knn::query_vector_builder(
+                "test",
+                QueryVectorBuilder::custom(
+                    "sparse_embedding",
+                    json!({ "model_id": "my-model", "model_text": "The opposite of pink" }),
+                ),
+            ),
+            json!({
+                "field": "test",
+                "query_vector_builder": {
+                    "sparse_embedding": {
+                        "model_id": "my-model",
+                        "model_text": "The opposite of pink"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn accumulates_filters() {
+        assert_serialize(
+            Knn::query_vector("test", vec![1.0, 2.0, 3.0])
+                .filter(Query::term("field1", "value1"))
+                .filter([
+                    Query::term("field2", "value2"),
+                    Query::term("field3", "value3"),
+                ])
+                .filter(Query::terms("field4", Vec::<String>::new())),
+            json!({
+                "field": "test",
+                "query_vector": [1.0, 2.0, 3.0],
+                "filter": [
+                    { "term": { "field1": { "value": "value1" } } },
+                    { "term": { "field2": { "value": "value2" } } },
+                    { "term": { "field3": { "value": "value3" } } },
+                ]
+            }),
+        );
+    }
 }
@@ -10,34 +10,46 @@
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search.html>
 
 // Private modules
-mod response;
+pub(crate) mod response;
 
 // Public modules
 pub mod aggregations;
 pub mod collapse;
+pub mod explain;
 pub mod highlight;
 pub mod knn;
+pub mod msearch;
 pub mod params;
 pub mod queries;
+pub mod rank;
 pub mod request;
 pub mod rescoring;
+pub mod retrievers;
+pub mod rollup;
 pub mod runtime_mappings;
 pub mod script_fields;
 pub mod sort;
+#[cfg(feature = "suggesters")]
 pub mod suggesters;
 
 // Public re-exports
 pub use self::aggregations::*;
 pub use self::collapse::*;
+pub use self::explain::*;
 pub use self::highlight::*;
 pub use self::knn::*;
+pub use self::msearch::*;
 pub use self::params::*;
 pub use self::queries::params::*;
 pub use self::queries::*;
+pub use self::rank::*;
 pub use self::request::*;
 pub use self::rescoring::*;
 pub use self::response::*;
+pub use self::retrievers::*;
+pub use self::rollup::*;
 pub use self::runtime_mappings::*;
 pub use self::script_fields::*;
 pub use self::sort::*;
+#[cfg(feature = "suggesters")]
 pub use self::suggesters::*;
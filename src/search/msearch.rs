@@ -0,0 +1,190 @@
+//! Executes several searches with a single API request.
+use crate::search::*;
+use crate::util::*;
+
+/// Body of a
+/// [multi search](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html)
+/// request, made of searches with their optional headers.
+///
+/// The body is newline delimited JSON and has to be sent with the `application/x-ndjson` content
+/// type, the response is parsed with [`MSearchResponse`].
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let msearch = MSearch::new()
+///     .search(Search::new().size(1))
+///     .search_with_header(
+///         MSearchHeader::new().index(["products"]).routing("user-1"),
+///         Search::new().query(Query::term("brand", "vinted")),
+///     );
+///
+/// assert_eq!(
+///     String::from_utf8(msearch.to_bytes().unwrap()).unwrap(),
+///     concat!(
+///         "{}\n",
+///         "{\"size\":1}\n",
+///         "{\"index\":[\"products\"],\"routing\":\"user-1\"}\n",
+///         "{\"query\":{\"term\":{\"brand\":{\"value\":\"vinted\"}}}}\n",
+///     )
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MSearch {
+    searches: Vec<(MSearchHeader, Search)>,
+}
+
+/// Header line of a search in a [`MSearch`] request, overriding the request parameters for that
+/// search only
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct MSearchHeader {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_type: Option<SearchType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preference: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    request_cache: Option<bool>,
+}
+
+impl MSearch {
+    /// Creates an empty multi search request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a search targeting the indices of the request path
+    pub fn search(self, search: Search) -> Self {
+        self.search_with_header(MSearchHeader::default(), search)
+    }
+
+    /// Adds a search with its own header
+    pub fn search_with_header(mut self, header: MSearchHeader, search: Search) -> Self {
+        self.searches.push((header, search));
+        self
+    }
+
+    /// Number of searches in the request, the responses come back in the same order
+    pub fn len(&self) -> usize {
+        self.searches.len()
+    }
+
+    /// Whether the request has no searches, which Elasticsearch rejects
+    pub fn is_empty(&self) -> bool {
+        self.searches.is_empty()
+    }
+
+    /// Serializes the request into a newline delimited JSON byte buffer
+    pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        let mut bytes = Vec::new();
+
+        self.write_to(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Serializes the request as newline delimited JSON straight into the `writer`
+    pub fn write_to<W>(&self, writer: W) -> Result<(), serde_json::Error>
+    where
+        W: std::io::Write,
+    {
+        Search::write_msearch(
+            writer,
+            self.searches
+                .iter()
+                .map(|(header, search)| (header, search)),
+        )
+    }
+}
+
+impl MSearchHeader {
+    /// Creates an empty header, the search targets the indices of the request path
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data streams, indices and aliases to search, supports wildcards
+    pub fn index<T>(mut self, index: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.index = index.into_iter().map(|index| index.to_string()).collect();
+        self
+    }
+
+    /// Custom value used to route the search to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /// How distributed term frequencies are calculated for relevance scoring
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type);
+        self
+    }
+
+    /// Nodes and shards used for the search, e.g. `_local`
+    pub fn preference<T>(mut self, preference: T) -> Self
+    where
+        T: ToString,
+    {
+        self.preference = Some(preference.to_string());
+        self
+    }
+
+    /// Whether the shard request cache is used for the search
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.request_cache = Some(request_cache);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_headers() {
+        assert_serialize(MSearchHeader::new(), json!({}));
+
+        assert_serialize(
+            MSearchHeader::new()
+                .index(["products", "brands*"])
+                .routing("user-1")
+                .search_type(SearchType::DfsQueryThenFetch)
+                .preference("_local")
+                .request_cache(false),
+            json!({
+                "index": ["products", "brands*"],
+                "routing": "user-1",
+                "search_type": "dfs_query_then_fetch",
+                "preference": "_local",
+                "request_cache": false
+            }),
+        );
+    }
+
+    #[test]
+    fn writes_body() {
+        let msearch = MSearch::new()
+            .search_with_header(MSearchHeader::new().index(["a"]), Search::new().size(0))
+            .search(Search::new().from(10));
+
+        assert_eq!(msearch.len(), 2);
+        assert!(MSearch::new().is_empty());
+        assert_eq!(
+            msearch.to_bytes().unwrap(),
+            b"{\"index\":[\"a\"]}\n{\"size\":0}\n{}\n{\"from\":10}\n"
+        );
+    }
+}
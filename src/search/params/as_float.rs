@@ -0,0 +1,37 @@
+/// Numeric value accepted by boosts, weights and other `f32` parameters, converted with an `as`
+/// cast.
+///
+/// Implemented for the primitive integer and float types and sealed, the set of accepted types
+/// doesn't depend on the enabled features.
+pub trait AsFloat: Copy + private::Sealed {
+    /// Converts the value into `f32`
+    fn as_float(self) -> f32;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! as_float {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl AsFloat for $ty {
+                fn as_float(self) -> f32 {
+                    self as f32
+                }
+            }
+        )+
+    };
+}
+
+as_float!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f64);
+
+impl private::Sealed for f32 {}
+
+impl AsFloat for f32 {
+    fn as_float(self) -> f32 {
+        self
+    }
+}
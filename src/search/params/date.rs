@@ -1,10 +1,11 @@
-use chrono::{DateTime, Utc};
 use std::time::SystemTime;
 
-/// [`DateTime<Utc>`] type alias
-pub type ChronoTime = DateTime<Utc>;
+/// [`DateTime<Utc>`](chrono::DateTime) type alias
+#[cfg(feature = "chrono")]
+pub type ChronoTime = chrono::DateTime<chrono::Utc>;
 
-/// Time variants to serialize
+/// Time variants to serialize, usable as terms, range bounds and decay function and distance
+/// feature query origins
 #[derive(Clone, Copy, Serialize)]
 #[serde(untagged)]
 pub enum Date {
@@ -12,68 +13,93 @@ pub enum Date {
     System(SystemTime),
 
     /// Chrono time
+    #[cfg(feature = "chrono")]
     Chrono(ChronoTime),
+
+    /// `time` crate date time, serialized in RFC 3339 format
+    #[cfg(feature = "time")]
+    Time(#[serde(serialize_with = "time::serde::rfc3339::serialize")] time::OffsetDateTime),
+
+    /// `jiff` crate timestamp
+    #[cfg(feature = "jiff")]
+    Jiff(jiff::Timestamp),
+}
+
+impl Date {
+    fn system_time(&self) -> SystemTime {
+        match *self {
+            Self::System(value) => value,
+            #[cfg(feature = "chrono")]
+            Self::Chrono(value) => value.into(),
+            #[cfg(feature = "time")]
+            Self::Time(value) => value.into(),
+            #[cfg(feature = "jiff")]
+            Self::Jiff(value) => value.into(),
+        }
+    }
 }
 
 impl std::fmt::Debug for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::System(value) => value.fmt(f),
+            #[cfg(feature = "chrono")]
             Self::Chrono(value) => value.fmt(f),
+            #[cfg(feature = "time")]
+            Self::Time(value) => value.fmt(f),
+            #[cfg(feature = "jiff")]
+            Self::Jiff(value) => value.fmt(f),
         }
     }
 }
 
-impl From<SystemTime> for Date {
-    fn from(value: SystemTime) -> Self {
-        Self::System(value)
-    }
-}
-
-impl From<ChronoTime> for Date {
-    fn from(value: ChronoTime) -> Self {
-        Self::Chrono(value)
-    }
-}
-
-impl From<&SystemTime> for Date {
-    fn from(value: &SystemTime) -> Self {
-        Self::System(*value)
-    }
+macro_rules! date_from {
+    ($($(#[$meta:meta])* $variant:ident($ty:ty)),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl From<$ty> for Date {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            $(#[$meta])*
+            impl From<&$ty> for Date {
+                fn from(value: &$ty) -> Self {
+                    Self::$variant(*value)
+                }
+            }
+
+            $(#[$meta])*
+            impl PartialEq<$ty> for Date {
+                fn eq(&self, other: &$ty) -> bool {
+                    self.eq(&Self::from(other))
+                }
+            }
+
+            $(#[$meta])*
+            impl PartialOrd<$ty> for Date {
+                fn partial_cmp(&self, other: &$ty) -> Option<std::cmp::Ordering> {
+                    self.partial_cmp(&Self::from(other))
+                }
+            }
+        )+
+    };
 }
 
-impl From<&ChronoTime> for Date {
-    fn from(value: &ChronoTime) -> Self {
-        Self::Chrono(*value)
-    }
-}
+date_from!(
+    System(SystemTime),
+    #[cfg(feature = "chrono")]
+    Chrono(ChronoTime),
+    #[cfg(feature = "time")]
+    Time(time::OffsetDateTime),
+    #[cfg(feature = "jiff")]
+    Jiff(jiff::Timestamp),
+);
 
 impl PartialEq for Date {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Date::System(s), Date::System(o)) => s.eq(o),
-            (Date::System(s), Date::Chrono(o)) => ChronoTime::from(*s).eq(o),
-            (Date::Chrono(s), Date::System(o)) => s.eq(&ChronoTime::from(*o)),
-            (Date::Chrono(s), Date::Chrono(o)) => s.eq(o),
-        }
-    }
-}
-
-impl PartialEq<SystemTime> for Date {
-    fn eq(&self, other: &SystemTime) -> bool {
-        match self {
-            Self::System(s) => s.eq(other),
-            Self::Chrono(s) => s.eq(&ChronoTime::from(*other)),
-        }
-    }
-}
-
-impl PartialEq<ChronoTime> for Date {
-    fn eq(&self, other: &ChronoTime) -> bool {
-        match self {
-            Self::Chrono(s) => s.eq(other),
-            Self::System(s) => ChronoTime::from(*s).eq(other),
-        }
+        self.system_time().eq(&other.system_time())
     }
 }
 
@@ -85,31 +111,8 @@ impl PartialOrd for Date {
     }
 }
 
-impl PartialOrd<SystemTime> for Date {
-    fn partial_cmp(&self, other: &SystemTime) -> Option<std::cmp::Ordering> {
-        match self {
-            Date::System(s) => s.partial_cmp(other),
-            Date::Chrono(s) => s.partial_cmp(&ChronoTime::from(*other)),
-        }
-    }
-}
-
-impl PartialOrd<ChronoTime> for Date {
-    fn partial_cmp(&self, other: &ChronoTime) -> Option<std::cmp::Ordering> {
-        match self {
-            Self::Chrono(s) => s.partial_cmp(other),
-            Self::System(s) => ChronoTime::from(*s).partial_cmp(other),
-        }
-    }
-}
-
 impl Ord for Date {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (Date::System(s), Date::System(o)) => s.cmp(o),
-            (Date::System(s), Date::Chrono(o)) => ChronoTime::from(*s).cmp(o),
-            (Date::Chrono(s), Date::System(o)) => s.cmp(&ChronoTime::from(*o)),
-            (Date::Chrono(s), Date::Chrono(o)) => s.cmp(o),
-        }
+        self.system_time().cmp(&other.system_time())
     }
 }
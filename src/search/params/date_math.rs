@@ -0,0 +1,246 @@
+use crate::search::*;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+/// Time units supported by [`DateMath`] expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum DateMathUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl DateMathUnit {
+    fn as_char(self) -> char {
+        match self {
+            Self::Years => 'y',
+            Self::Months => 'M',
+            Self::Weeks => 'w',
+            Self::Days => 'd',
+            Self::Hours => 'h',
+            Self::Minutes => 'm',
+            Self::Seconds => 's',
+        }
+    }
+
+    fn from_char(unit: char) -> Option<Self> {
+        match unit {
+            'y' => Some(Self::Years),
+            'M' => Some(Self::Months),
+            'w' => Some(Self::Weeks),
+            'd' => Some(Self::Days),
+            'h' | 'H' => Some(Self::Hours),
+            'm' => Some(Self::Minutes),
+            's' => Some(Self::Seconds),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum DateMathOperation {
+    Plus(u64, DateMathUnit),
+    Minus(u64, DateMathUnit),
+    Round(DateMathUnit),
+}
+
+/// Date math expression, an anchor date (`now` or a date followed by `||`) followed by unit
+/// additions, subtractions and rounding, accepted by range query bounds, decay function and
+/// distance feature query origins:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let expression = DateMath::now()
+///     .minus(1, DateMathUnit::Days)
+///     .round(DateMathUnit::Days);
+///
+/// assert_eq!(expression.to_string(), "now-1d/d");
+/// assert_eq!("now-1d/d".parse(), Ok(expression));
+///
+/// # let query =
+/// Query::range("timestamp").gte(DateMath::now().minus(1, DateMathUnit::Hours));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#date-math>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateMath {
+    anchor: Option<String>,
+    operations: Vec<DateMathOperation>,
+}
+
+impl DateMath {
+    /// Creates an expression anchored at the current time
+    pub fn now() -> Self {
+        Self {
+            anchor: None,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Creates an expression anchored at the `date`, e.g. `2001.02.01`, which is rendered as
+    /// `2001.02.01||`
+    pub fn anchor<T>(date: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            anchor: Some(date.to_string()),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Adds `amount` of `unit`s
+    pub fn plus(mut self, amount: u64, unit: DateMathUnit) -> Self {
+        self.operations.push(DateMathOperation::Plus(amount, unit));
+        self
+    }
+
+    /// Subtracts `amount` of `unit`s
+    pub fn minus(mut self, amount: u64, unit: DateMathUnit) -> Self {
+        self.operations.push(DateMathOperation::Minus(amount, unit));
+        self
+    }
+
+    /// Rounds down to the nearest `unit`
+    pub fn round(mut self, unit: DateMathUnit) -> Self {
+        self.operations.push(DateMathOperation::Round(unit));
+        self
+    }
+}
+
+impl std::fmt::Display for DateMath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.anchor {
+            Some(anchor) => write!(f, "{anchor}||")?,
+            None => "now".fmt(f)?,
+        }
+
+        for operation in &self.operations {
+            match operation {
+                DateMathOperation::Plus(amount, unit) => write!(f, "+{amount}{}", unit.as_char())?,
+                DateMathOperation::Minus(amount, unit) => write!(f, "-{amount}{}", unit.as_char())?,
+                DateMathOperation::Round(unit) => write!(f, "/{}", unit.as_char())?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for DateMath {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValidationError::InvalidDateMath;
+
+        let (mut expression, mut operations) = match value.find("||") {
+            Some(index) if index > 0 => (Self::anchor(&value[..index]), &value[index + 2..]),
+            Some(_) => return Err(invalid()),
+            None => (Self::now(), value.strip_prefix("now").ok_or_else(invalid)?),
+        };
+
+        while let Some(operator) = operations.chars().next() {
+            let rest = &operations[1..];
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(invalid)?;
+            let mut chars = rest[digits..].chars();
+            let unit = chars
+                .next()
+                .and_then(DateMathUnit::from_char)
+                .ok_or_else(invalid)?;
+
+            expression = match (operator, digits) {
+                ('+', 1..) => expression.plus(rest[..digits].parse().map_err(|_| invalid())?, unit),
+                ('-', 1..) => {
+                    expression.minus(rest[..digits].parse().map_err(|_| invalid())?, unit)
+                }
+                ('/', 0) => expression.round(unit),
+                _ => return Err(invalid()),
+            };
+            operations = chars.as_str();
+        }
+
+        Ok(expression)
+    }
+}
+
+impl Serialize for DateMath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateMath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        value
+            .parse()
+            .map_err(|_| D::Error::custom(format!("invalid date math expression `{value}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(DateMath::now(), json!("now"));
+        assert_serialize(
+            DateMath::anchor("2001.02.01")
+                .plus(1, DateMathUnit::Months)
+                .round(DateMathUnit::Days),
+            json!("2001.02.01||+1M/d"),
+        );
+        assert_serialize(
+            DateMath::now()
+                .plus(1, DateMathUnit::Hours)
+                .minus(30, DateMathUnit::Minutes)
+                .round(DateMathUnit::Hours),
+            json!("now+1h-30m/h"),
+        );
+    }
+
+    #[test]
+    fn parses_expressions() {
+        for expression in ["now", "now-1d/d", "2001.02.01||+1M/d", "now+1y-2w/s"] {
+            assert_eq!(
+                expression.parse::<DateMath>().unwrap().to_string(),
+                expression
+            );
+        }
+
+        assert_eq!(
+            "now-1H".parse::<DateMath>().unwrap(),
+            DateMath::now().minus(1, DateMathUnit::Hours)
+        );
+
+        for expression in [
+            "",
+            "yesterday",
+            "now-d",
+            "now/1d",
+            "now-1x",
+            "||+1d",
+            "now+",
+        ] {
+            assert_eq!(
+                expression.parse::<DateMath>(),
+                Err(ValidationError::InvalidDateMath),
+                "{expression}"
+            );
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::Field;
+
+/// Types whose fields can be referenced in queries without spelling out their names as strings.
+///
+/// With the `derive` feature enabled the trait can be derived for structs, generating a
+/// companion `<Name>Fields` type with a [`Field`] accessor per struct field. Fields are named
+/// the same way [`EsMapping`](crate::EsMapping) names them: `serde` renames and
+/// `#[es(rename = "...")]` are respected and `#[serde(skip)]` fields are left out. Accessors of
+/// `#[es(object)]` and `#[es(nested)]` fields return the field accessors of the field type, which
+/// must implement [`EsDocument`] as well.
+pub trait EsDocument {
+    /// Companion type with a [`Field`] accessor per document field
+    type Fields;
+
+    /// Returns field accessors with every field path prefixed with `prefix`, used for object and
+    /// nested fields
+    fn fields_at(prefix: Option<Field>) -> Self::Fields;
+
+    /// Returns the field accessors of the document
+    fn fields() -> Self::Fields {
+        Self::fields_at(None)
+    }
+}
+
+impl<T> EsDocument for Option<T>
+where
+    T: EsDocument,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(prefix: Option<Field>) -> Self::Fields {
+        T::fields_at(prefix)
+    }
+}
+
+impl<T> EsDocument for Vec<T>
+where
+    T: EsDocument,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(prefix: Option<Field>) -> Self::Fields {
+        T::fields_at(prefix)
+    }
+}
+
+impl<T> EsDocument for Box<T>
+where
+    T: EsDocument,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(prefix: Option<Field>) -> Self::Fields {
+        T::fields_at(prefix)
+    }
+}
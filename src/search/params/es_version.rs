@@ -0,0 +1,23 @@
+/// Search engine version targeted by
+/// [`Search::serialize_for`](crate::Search::serialize_for)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EsVersion {
+    /// Elasticsearch `7.x`
+    V7,
+
+    /// Elasticsearch `8.x`
+    V8,
+
+    /// OpenSearch `2.x`, which was forked from Elasticsearch `7.10`
+    OpenSearch2,
+}
+
+impl std::fmt::Display for EsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V7 => "Elasticsearch 7.x".fmt(f),
+            Self::V8 => "Elasticsearch 8.x".fmt(f),
+            Self::OpenSearch2 => "OpenSearch 2.x".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::search::*;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// A typed document field name.
+///
+/// Field names are accepted as [`ToString`] throughout the DSL, so a misspelled or renamed field
+/// only surfaces when Elasticsearch returns unexpected results. Declaring the fields of a
+/// document schema as [`Field`] constants keeps the names in one place and lets the compiler
+/// catch any drift:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// struct Product;
+///
+/// impl Product {
+///     const TITLE: Field = Field::new("title");
+///     const PRICE: Field = Field::new("price");
+/// }
+///
+/// # let search =
+/// Search::new()
+///     .query(Query::r#match(Product::TITLE, "shirt"))
+///     .sort([Product::PRICE]);
+/// ```
+///
+/// The field can also build the most common leaf queries directly:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let query =
+/// Field::new("title").r#match("shirt");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Field(Cow<'static, str>);
+
+impl Field {
+    /// Creates an instance of [`Field`]
+    ///
+    /// - `name` - Full path of the field, e.g. `title` or `user.name`
+    pub const fn new(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    /// Same as [`Field::new`], but fails on an empty name
+    pub const fn try_new(name: &'static str) -> Result<Self, ValidationError> {
+        if name.is_empty() {
+            return Err(ValidationError::EmptyFieldName);
+        }
+
+        Ok(Self::new(name))
+    }
+
+    /// Returns the full path of the field
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the path of a sub-field, e.g. a multi-field or an object property
+    pub fn join(&self, name: &str) -> Self {
+        Self(Cow::Owned(format!("{}.{name}", self.0)))
+    }
+
+    /// Creates an instance of [`MatchQuery`] for the field
+    pub fn r#match<T>(&self, query: T) -> MatchQuery
+    where
+        T: Into<Text>,
+    {
+        Query::r#match(self, query)
+    }
+
+    /// Creates an instance of [`MatchPhraseQuery`] for the field
+    pub fn match_phrase<T>(&self, query: T) -> MatchPhraseQuery
+    where
+        T: Into<Text>,
+    {
+        Query::match_phrase(self, query)
+    }
+
+    /// Creates an instance of [`TermQuery`] for the field
+    pub fn term<T>(&self, value: T) -> TermQuery
+    where
+        T: Serialize,
+    {
+        Query::term(self, value)
+    }
+
+    /// Creates an instance of [`TermsQuery`] for the field
+    pub fn terms<T>(&self, terms: T) -> TermsQuery
+    where
+        T: Into<Terms>,
+    {
+        Query::terms(self, terms)
+    }
+
+    /// Creates an instance of [`RangeQuery`] for the field
+    pub fn range(&self) -> RangeQuery {
+        Query::range(self)
+    }
+
+    /// Creates an instance of [`ExistsQuery`] for the field
+    pub fn exists(&self) -> ExistsQuery {
+        Query::exists(self)
+    }
+
+    /// Creates an instance of [`PrefixQuery`] for the field
+    pub fn prefix<T>(&self, value: T) -> PrefixQuery
+    where
+        T: Serialize,
+    {
+        Query::prefix(self, value)
+    }
+
+    /// Creates an instance of [`WildcardQuery`] for the field
+    pub fn wildcard<T>(&self, value: T) -> WildcardQuery
+    where
+        T: Serialize,
+    {
+        Query::wildcard(self, value)
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Field> for String {
+    fn from(value: Field) -> Self {
+        value.0.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    const TITLE: Field = Field::new("title");
+    const TAGS: Field = Field::new("tags.keyword");
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Search::new()
+                .query(Query::bool().must(Query::r#match(TITLE, "shirt")))
+                .aggregate("tags", Aggregation::terms(TAGS))
+                .sort([TITLE])
+                .source([TITLE, TAGS]),
+            json!({
+                "_source": ["title", "tags.keyword"],
+                "query": {
+                    "bool": {
+                        "must": [
+                            { "match": { "title": { "query": "shirt" } } }
+                        ]
+                    }
+                },
+                "aggs": {
+                    "tags": { "terms": { "field": "tags.keyword" } }
+                },
+                "sort": ["title"]
+            }),
+        );
+    }
+
+    #[test]
+    fn builds_queries() {
+        let title = Field::new("title");
+
+        assert_eq!(title.join("raw").name(), "title.raw");
+        assert_eq!(Field::try_new("title"), Ok(title.clone()));
+        assert_eq!(Field::try_new(""), Err(ValidationError::EmptyFieldName));
+
+        assert_serialize_query(
+            Query::bool()
+                .must(title.r#match("shirt"))
+                .filter(title.join("raw").term("Red shirt"))
+                .filter(Field::new("price").range().lt(10))
+                .filter(Field::new("tags").exists()),
+            json!({
+                "bool": {
+                    "must": [
+                        { "match": { "title": { "query": "shirt" } } }
+                    ],
+                    "filter": [
+                        { "term": { "title.raw": { "value": "Red shirt" } } },
+                        { "range": { "price": { "lt": 10 } } },
+                        { "exists": { "field": "tags" } }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use super::Field;
+use serde::{Serialize, Serializer};
+
+/// Field to retrieve with the `fields` or `docvalue_fields` section of a search, optionally with
+/// the format of the returned values.
+///
+/// Plain field names and wildcard patterns convert into it directly:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new().fields([
+///     FieldAndFormat::from("user.id"),
+///     FieldAndFormat::from("http.response.*"),
+///     FieldAndFormat::new("@timestamp").format("epoch_millis"),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldAndFormat {
+    field: String,
+    format: Option<String>,
+    include_unmapped: Option<bool>,
+}
+
+impl FieldAndFormat {
+    /// Creates an instance of [`FieldAndFormat`]
+    ///
+    /// - `field` - field name or wildcard pattern
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            format: None,
+            include_unmapped: None,
+        }
+    }
+
+    /// Format of the returned values, a date format for `date` fields (e.g. `epoch_millis`),
+    /// `wkt` or `geojson` for geo fields
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Whether to also return the unmapped fields of the `_source` matching a wildcard pattern,
+    /// only supported by the `fields` section
+    pub fn include_unmapped(mut self, include_unmapped: bool) -> Self {
+        self.include_unmapped = Some(include_unmapped);
+        self
+    }
+}
+
+impl Serialize for FieldAndFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Object<'a> {
+            field: &'a str,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            format: Option<&'a str>,
+
+            #[serde(skip_serializing_if = "Option::is_none")]
+            include_unmapped: Option<bool>,
+        }
+
+        if self.format.is_none() && self.include_unmapped.is_none() {
+            return self.field.serialize(serializer);
+        }
+
+        Object {
+            field: &self.field,
+            format: self.format.as_deref(),
+            include_unmapped: self.include_unmapped,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl From<&str> for FieldAndFormat {
+    fn from(field: &str) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<String> for FieldAndFormat {
+    fn from(field: String) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<Field> for FieldAndFormat {
+    fn from(field: Field) -> Self {
+        Self::new(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(FieldAndFormat::from("user.id"), json!("user.id"));
+
+        assert_serialize(
+            FieldAndFormat::new("@timestamp").format("epoch_millis"),
+            json!({ "field": "@timestamp", "format": "epoch_millis" }),
+        );
+
+        assert_serialize(
+            FieldAndFormat::new("user.*").include_unmapped(true),
+            json!({ "field": "user.*", "include_unmapped": true }),
+        );
+    }
+}
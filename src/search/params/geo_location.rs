@@ -1,10 +1,122 @@
+use crate::search::*;
 use serde::Serialize;
 
+/// Longest geohash accepted by Elasticsearch, a cell of roughly 3.7cm x 1.8cm
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
 /// Represents a point in two dimensional space
+///
+/// Points can be created from coordinates or from a
+/// [geohash](https://en.wikipedia.org/wiki/Geohash), which is kept and sent as is:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let point = GeoLocation::geohash("u15pmus").unwrap();
+///
+/// assert_eq!(point.to_geohash(5), "u15pm");
+///
+/// assert_eq!(
+///     serde_json::to_value(GeoLocation::new(54.69, 25.28).geohash_precision(6)).unwrap(),
+///     serde_json::json!("u99zpk"),
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GeoLocation {
     latitude: f32,
     longitude: f32,
+    geohash: Option<Geohash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Geohash {
+    chars: [u8; MAX_GEOHASH_PRECISION],
+    len: usize,
+}
+
+impl Geohash {
+    fn encode(latitude: f64, longitude: f64, precision: usize) -> Self {
+        let len = precision.clamp(1, MAX_GEOHASH_PRECISION);
+        let mut chars = [0; MAX_GEOHASH_PRECISION];
+        let mut latitude_range = (-90.0, 90.0);
+        let mut longitude_range = (-180.0, 180.0);
+
+        for (bit, char) in (0..len * 5).step_by(5).zip(chars.iter_mut()) {
+            let mut index = 0;
+
+            for bit in bit..bit + 5 {
+                let (value, range) = if bit % 2 == 0 {
+                    (longitude, &mut longitude_range)
+                } else {
+                    (latitude, &mut latitude_range)
+                };
+                let middle = (range.0 + range.1) / 2.0;
+
+                index <<= 1;
+
+                if value >= middle {
+                    index |= 1;
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+            }
+
+            *char = GEOHASH_ALPHABET[index];
+        }
+
+        Self { chars, len }
+    }
+
+    fn parse(geohash: &str) -> Result<(Self, f64, f64), ValidationError> {
+        let len = geohash.len();
+
+        if len == 0 || len > MAX_GEOHASH_PRECISION {
+            return Err(ValidationError::InvalidGeohash);
+        }
+
+        let mut chars = [0; MAX_GEOHASH_PRECISION];
+        let mut latitude_range = (-90.0, 90.0);
+        let mut longitude_range = (-180.0, 180.0);
+        let mut even = true;
+
+        for (char, stored) in geohash.bytes().zip(chars.iter_mut()) {
+            let char = char.to_ascii_lowercase();
+            let index = GEOHASH_ALPHABET
+                .iter()
+                .position(|c| *c == char)
+                .ok_or(ValidationError::InvalidGeohash)?;
+
+            *stored = char;
+
+            for shift in (0..5).rev() {
+                let range: &mut (f64, f64) = if even {
+                    &mut longitude_range
+                } else {
+                    &mut latitude_range
+                };
+                let middle = (range.0 + range.1) / 2.0;
+
+                if index >> shift & 1 == 1 {
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+
+                even = !even;
+            }
+        }
+
+        Ok((
+            Self { chars, len },
+            (latitude_range.0 + latitude_range.1) / 2.0,
+            (longitude_range.0 + longitude_range.1) / 2.0,
+        ))
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.chars[..self.len]).unwrap_or_default()
+    }
 }
 
 impl GeoLocation {
@@ -13,6 +125,56 @@ impl GeoLocation {
         Self {
             latitude,
             longitude,
+            geohash: None,
+        }
+    }
+
+    /// Creates an instance of [GeoLocation] from a geohash of up to [`MAX_GEOHASH_PRECISION`]
+    /// characters, such as the keys of geohash grid buckets. The point is placed in the center of
+    /// the geohash cell and serialized as the given geohash.
+    pub fn geohash<T>(geohash: T) -> Result<Self, ValidationError>
+    where
+        T: AsRef<str>,
+    {
+        let (geohash, latitude, longitude) = Geohash::parse(geohash.as_ref())?;
+
+        Ok(Self {
+            latitude: latitude as f32,
+            longitude: longitude as f32,
+            geohash: Some(geohash),
+        })
+    }
+
+    /// Serializes the point as a geohash of the given precision, clamped between `1` and
+    /// [`MAX_GEOHASH_PRECISION`] characters
+    pub fn geohash_precision(mut self, precision: usize) -> Self {
+        self.geohash = Some(self.encode(precision));
+        self
+    }
+
+    /// Latitude of the point
+    pub fn latitude(&self) -> f32 {
+        self.latitude
+    }
+
+    /// Longitude of the point
+    pub fn longitude(&self) -> f32 {
+        self.longitude
+    }
+
+    /// Encodes the point as a geohash of the given precision, clamped between `1` and
+    /// [`MAX_GEOHASH_PRECISION`] characters
+    pub fn to_geohash(&self, precision: usize) -> String {
+        self.encode(precision).as_str().to_string()
+    }
+
+    fn encode(&self, precision: usize) -> Geohash {
+        match self.geohash {
+            Some(geohash) if geohash.len >= precision => Geohash {
+                len: precision.max(1),
+                ..geohash
+            },
+            _ => Geohash::encode(self.latitude.into(), self.longitude.into(), precision),
         }
     }
 }
@@ -22,25 +184,81 @@ impl Serialize for GeoLocation {
     where
         S: serde::Serializer,
     {
-        [self.longitude, self.latitude].serialize(serializer)
+        match &self.geohash {
+            Some(geohash) => geohash.as_str().serialize(serializer),
+            None => [self.longitude, self.latitude].serialize(serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoLocation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Point {
+            Array([f32; 2]),
+            ArrayWithAltitude([f32; 3]),
+            Object { lat: f32, lon: f32 },
+            Geohash(String),
+        }
+
+        Ok(match Point::deserialize(deserializer)? {
+            Point::Array(value) => value.into(),
+            Point::ArrayWithAltitude([lon, lat, _]) => Self::new(lat, lon),
+            Point::Object { lat, lon } => Self::new(lat, lon),
+            Point::Geohash(value) => Self::geohash(value).map_err(serde::de::Error::custom)?,
+        })
     }
 }
 
 impl From<[f32; 2]> for GeoLocation {
     fn from(value: [f32; 2]) -> Self {
-        Self {
-            latitude: value[1],
-            longitude: value[0],
-        }
+        Self::new(value[1], value[0])
     }
 }
 
 impl From<(f32, f32)> for GeoLocation {
     fn from(value: (f32, f32)) -> Self {
-        Self {
-            latitude: value.1,
-            longitude: value.0,
-        }
+        Self::new(value.1, value.0)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T> From<geo_types::Coord<T>> for GeoLocation
+where
+    T: geo_types::CoordNum + Into<f64>,
+{
+    fn from(value: geo_types::Coord<T>) -> Self {
+        let (longitude, latitude): (f64, f64) = (value.x.into(), value.y.into());
+
+        Self::new(latitude as f32, longitude as f32)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T> From<geo_types::Point<T>> for GeoLocation
+where
+    T: geo_types::CoordNum + Into<f64>,
+{
+    fn from(value: geo_types::Point<T>) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<GeoLocation> for geo_types::Coord<f64> {
+    fn from(value: GeoLocation) -> Self {
+        geo_types::coord! { x: value.longitude.into(), y: value.latitude.into() }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<GeoLocation> for geo_types::Point<f64> {
+    fn from(value: GeoLocation) -> Self {
+        geo_types::Point(value.into())
     }
 }
 
@@ -65,4 +283,53 @@ mod tests {
         assert_serialize(GeoLocation::from([2.2, 1.1]), json!([2.2, 1.1]));
         assert_serialize(GeoLocation::from((2.2, 1.1)), json!([2.2, 1.1]));
     }
+
+    #[test]
+    fn deserialization() {
+        assert_eq!(
+            serde_json::from_value::<GeoLocation>(json!([2.2, 1.1])).unwrap(),
+            GeoLocation::new(1.1, 2.2),
+        );
+        assert_eq!(
+            serde_json::from_value::<GeoLocation>(json!({ "lat": 1.1, "lon": 2.2 })).unwrap(),
+            GeoLocation::new(1.1, 2.2),
+        );
+        assert_eq!(
+            serde_json::from_value::<GeoLocation>(json!("u15pmus")).unwrap(),
+            GeoLocation::geohash("u15pmus").unwrap(),
+        );
+        assert!(serde_json::from_value::<GeoLocation>(json!("u15pmua")).is_err());
+    }
+
+    #[test]
+    fn geohash() {
+        let point = GeoLocation::geohash("u15pmus").unwrap();
+
+        assert!((point.latitude() - 51.9248).abs() < 0.001);
+        assert!((point.longitude() - 4.4776).abs() < 0.001);
+        assert_serialize(point, json!("u15pmus"));
+        assert_serialize(GeoLocation::geohash("U15P").unwrap(), json!("u15p"));
+
+        assert_eq!(point.to_geohash(3), "u15");
+        assert_eq!(point.to_geohash(0), "u");
+        assert_eq!(point.to_geohash(9).len(), 9);
+        assert!(point.to_geohash(9).starts_with("u15pmus"));
+        assert_eq!(point.to_geohash(20).len(), MAX_GEOHASH_PRECISION);
+
+        assert_eq!(
+            GeoLocation::new(57.64911, 10.40744).to_geohash(9),
+            "u4pruydqq"
+        );
+        assert_serialize(
+            GeoLocation::new(57.64911, 10.40744).geohash_precision(5),
+            json!("u4pru"),
+        );
+
+        for geohash in ["", "u15a", "u15pmuspmuspm", "u15p "] {
+            assert_eq!(
+                GeoLocation::geohash(geohash),
+                Err(ValidationError::InvalidGeohash)
+            );
+        }
+    }
 }
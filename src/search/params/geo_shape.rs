@@ -1,55 +1,75 @@
 use crate::search::*;
+use serde::Serialize;
 
 /// The `geo_shape` data type facilitates the indexing of and searching with
 /// arbitrary geo shapes such as rectangles and polygons. It should be used
 /// when either the data being indexed or the queries being executed contain
 /// shapes other than just points.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+///
+/// Shapes can be deserialized from [GeoJSON](https://geojson.org/) geometry objects, so geometries
+/// produced by other geospatial crates can be converted by round-tripping through JSON:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # use serde_json::json;
+/// let geometry = json!({ "type": "Point", "coordinates": [-77.03653, 38.897676] });
+///
+/// assert_eq!(
+///     serde_json::from_value::<GeoShape>(geometry).unwrap(),
+///     GeoShape::point([-77.03653, 38.897676]),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum GeoShape {
     /// A single geographic coordinate
     ///
     /// Note: Elasticsearch uses WGS-84 coordinates only
-    #[serde(rename = "point")]
+    #[serde(rename = "point", alias = "Point")]
     Point {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: GeoLocation,
     },
 
     /// An arbitrary line given two or more points
-    #[serde(rename = "linestring")]
+    #[serde(rename = "linestring", alias = "LineString")]
     LineString {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: Vec<GeoLocation>,
     },
 
     /// A closed polygon whose first and last point must match, thus requiring
     /// `n + 1` vertices to create an `n-sided` polygon and a minimum of `4`
     /// vertices
-    #[serde(rename = "polygon")]
+    #[serde(rename = "polygon", alias = "Polygon")]
     Polygon {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: Vec<Vec<GeoLocation>>,
     },
 
     /// An array of unconnected, but likely related points
-    #[serde(rename = "multipoint")]
+    #[serde(rename = "multipoint", alias = "MultiPoint")]
     MultiPoint {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: Vec<GeoLocation>,
     },
 
     /// An array of separate linestrings
-    #[serde(rename = "multilinestring")]
+    #[serde(rename = "multilinestring", alias = "MultiLineString")]
     MultiLineString {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: Vec<Vec<GeoLocation>>,
     },
 
     /// An array of separate polygons
-    #[serde(rename = "multipolygon")]
+    #[serde(rename = "multipolygon", alias = "MultiPolygon")]
     MultiPolygon {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: Vec<Vec<Vec<GeoLocation>>>,
     },
 
@@ -58,6 +78,7 @@ pub enum GeoShape {
     #[serde(rename = "envelope")]
     Envelope {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: (GeoLocation, GeoLocation),
     },
 
@@ -66,6 +87,7 @@ pub enum GeoShape {
     #[serde(rename = "circle")]
     Circle {
         /// Coordinates
+        #[serde(serialize_with = "serialize_coordinates")]
         coordinates: GeoLocation,
 
         /// Circle radius
@@ -74,14 +96,76 @@ pub enum GeoShape {
 
     /// A GeoJSON shape similar to the `multi*` shapes except that multiple
     /// types can coexist (e.g., a Point and a LineString)
-    #[serde(rename = "geometrycollection")]
+    #[serde(rename = "geometrycollection", alias = "GeometryCollection")]
     GeometryCollection {
         /// A collection of geo shapes
         geometries: Vec<GeoShape>,
     },
 }
 
+/// GeoJSON positions, which are always `[lon, lat]` arrays as opposed to the geohashes
+/// [`GeoLocation`] can serialize as
+trait Coordinates {
+    type Positions: Serialize;
+
+    fn positions(&self) -> Self::Positions;
+}
+
+impl Coordinates for GeoLocation {
+    type Positions = [f32; 2];
+
+    fn positions(&self) -> Self::Positions {
+        [self.longitude(), self.latitude()]
+    }
+}
+
+impl<T> Coordinates for Vec<T>
+where
+    T: Coordinates,
+{
+    type Positions = Vec<T::Positions>;
+
+    fn positions(&self) -> Self::Positions {
+        self.iter().map(Coordinates::positions).collect()
+    }
+}
+
+impl Coordinates for (GeoLocation, GeoLocation) {
+    type Positions = [[f32; 2]; 2];
+
+    fn positions(&self) -> Self::Positions {
+        [self.0.positions(), self.1.positions()]
+    }
+}
+
+fn serialize_coordinates<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Coordinates,
+    S: serde::Serializer,
+{
+    value.positions().serialize(serializer)
+}
+
 impl GeoShape {
+    /// Parses a [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+    /// geometry, such as `POLYGON ((...))` or `BBOX (minX, maxX, maxY, minY)`. Fails if the text
+    /// can't be parsed, polygon rings aren't closed or the geometry is `EMPTY`.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// assert_eq!(
+    ///     GeoShape::from_wkt("BBOX (-74.1, -73.5, 40.73, 40.01)").unwrap(),
+    ///     GeoShape::envelope([-74.1, 40.73], [-73.5, 40.01]),
+    /// );
+    ///
+    /// # #[cfg(feature = "geo")]
+    /// # let query =
+    /// Query::geo_shape("location", GeoShape::from_wkt("POINT (-77.03 38.89)").unwrap());
+    /// ```
+    pub fn from_wkt(value: &str) -> Result<Self, ValidationError> {
+        Wkt::parse(value).map(Self::from_parsed_wkt)
+    }
+
     /// Creates an instance of [`GeoShape::Point`]
     pub fn point<T>(coordinates: T) -> Self
     where
@@ -198,11 +282,460 @@ impl GeoShape {
     }
 }
 
+impl std::str::FromStr for GeoShape {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_wkt(value)
+    }
+}
+
+impl GeoShape {
+    fn from_parsed_wkt(value: Wkt) -> Self {
+        match value {
+            Wkt::Point(position) => Self::point(position),
+            Wkt::LineString(positions) => Self::line_string(positions),
+            Wkt::Polygon(rings) => Self::polygon(rings),
+            Wkt::MultiPoint(positions) => Self::multi_point(positions),
+            Wkt::MultiLineString(lines) => Self::multi_line_string(lines),
+            Wkt::MultiPolygon(polygons) => Self::multi_polygon(polygons),
+            Wkt::GeometryCollection(geometries) => {
+                Self::geometry_collection(geometries.into_iter().map(Self::from_parsed_wkt))
+            }
+            Wkt::Envelope(top_left, bottom_right) => Self::envelope(top_left, bottom_right),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_conversions {
+    use super::*;
+    use geo_types::CoordNum;
+    use std::convert::TryFrom;
+
+    fn ring<T>(line: geo_types::LineString<T>) -> Vec<GeoLocation>
+    where
+        T: CoordNum + Into<f64>,
+    {
+        line.0.into_iter().map(Into::into).collect()
+    }
+
+    fn rings<T>(polygon: geo_types::Polygon<T>) -> Vec<Vec<GeoLocation>>
+    where
+        T: CoordNum + Into<f64>,
+    {
+        let (exterior, interiors) = polygon.into_inner();
+
+        std::iter::once(exterior)
+            .chain(interiors)
+            .map(ring)
+            .collect()
+    }
+
+    impl<T> From<geo_types::Point<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Point<T>) -> Self {
+            Self::point(value)
+        }
+    }
+
+    impl<T> From<geo_types::Line<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Line<T>) -> Self {
+            Self::line_string([value.start, value.end])
+        }
+    }
+
+    impl<T> From<geo_types::LineString<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::LineString<T>) -> Self {
+            Self::line_string(ring(value))
+        }
+    }
+
+    impl<T> From<geo_types::Polygon<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Polygon<T>) -> Self {
+            Self::polygon(rings(value))
+        }
+    }
+
+    impl<T> From<geo_types::MultiPoint<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::MultiPoint<T>) -> Self {
+            Self::multi_point(value.0)
+        }
+    }
+
+    impl<T> From<geo_types::MultiLineString<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::MultiLineString<T>) -> Self {
+            Self::multi_line_string(value.0.into_iter().map(ring))
+        }
+    }
+
+    impl<T> From<geo_types::MultiPolygon<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::MultiPolygon<T>) -> Self {
+            Self::multi_polygon(value.0.into_iter().map(rings))
+        }
+    }
+
+    impl<T> From<geo_types::Rect<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Rect<T>) -> Self {
+            let (min, max) = (value.min(), value.max());
+
+            Self::envelope(
+                GeoLocation::from(geo_types::coord! { x: min.x, y: max.y }),
+                GeoLocation::from(geo_types::coord! { x: max.x, y: min.y }),
+            )
+        }
+    }
+
+    impl<T> From<geo_types::Triangle<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Triangle<T>) -> Self {
+            Self::polygon([[value.v1(), value.v2(), value.v3(), value.v1()]])
+        }
+    }
+
+    impl<T> From<geo_types::GeometryCollection<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::GeometryCollection<T>) -> Self {
+            Self::geometry_collection(value.0)
+        }
+    }
+
+    impl<T> From<geo_types::Geometry<T>> for GeoShape
+    where
+        T: CoordNum + Into<f64>,
+    {
+        fn from(value: geo_types::Geometry<T>) -> Self {
+            match value {
+                geo_types::Geometry::Point(value) => value.into(),
+                geo_types::Geometry::Line(value) => value.into(),
+                geo_types::Geometry::LineString(value) => value.into(),
+                geo_types::Geometry::Polygon(value) => value.into(),
+                geo_types::Geometry::MultiPoint(value) => value.into(),
+                geo_types::Geometry::MultiLineString(value) => value.into(),
+                geo_types::Geometry::MultiPolygon(value) => value.into(),
+                geo_types::Geometry::GeometryCollection(value) => value.into(),
+                geo_types::Geometry::Rect(value) => value.into(),
+                geo_types::Geometry::Triangle(value) => value.into(),
+            }
+        }
+    }
+
+    fn line(positions: Vec<GeoLocation>) -> geo_types::LineString<f64> {
+        positions.into_iter().map(geo_types::Coord::from).collect()
+    }
+
+    fn polygon(rings: Vec<Vec<GeoLocation>>) -> geo_types::Polygon<f64> {
+        let mut rings = rings.into_iter().map(line);
+        let exterior = rings
+            .next()
+            .unwrap_or_else(|| geo_types::LineString(vec![]));
+
+        geo_types::Polygon::new(exterior, rings.collect())
+    }
+
+    /// Fails for circles, which `geo-types` has no geometry for, and for envelopes crossing the
+    /// antimeridian, which a [`Rect`](geo_types::Rect) can't represent
+    impl TryFrom<GeoShape> for geo_types::Geometry<f64> {
+        type Error = ValidationError;
+
+        fn try_from(value: GeoShape) -> Result<Self, Self::Error> {
+            Ok(match value {
+                GeoShape::Point { coordinates } => geo_types::Geometry::Point(coordinates.into()),
+                GeoShape::LineString { coordinates } => {
+                    geo_types::Geometry::LineString(line(coordinates))
+                }
+                GeoShape::Polygon { coordinates } => {
+                    geo_types::Geometry::Polygon(polygon(coordinates))
+                }
+                GeoShape::MultiPoint { coordinates } => geo_types::Geometry::MultiPoint(
+                    coordinates
+                        .into_iter()
+                        .map(geo_types::Point::from)
+                        .collect(),
+                ),
+                GeoShape::MultiLineString { coordinates } => geo_types::Geometry::MultiLineString(
+                    coordinates.into_iter().map(line).collect(),
+                ),
+                GeoShape::MultiPolygon { coordinates } => geo_types::Geometry::MultiPolygon(
+                    coordinates.into_iter().map(polygon).collect(),
+                ),
+                GeoShape::Envelope {
+                    coordinates: (top_left, bottom_right),
+                } => {
+                    if top_left.longitude() > bottom_right.longitude() {
+                        return Err(ValidationError::UnsupportedGeometry);
+                    }
+
+                    geo_types::Geometry::Rect(geo_types::Rect::new(top_left, bottom_right))
+                }
+                GeoShape::Circle { .. } => return Err(ValidationError::UnsupportedGeometry),
+                GeoShape::GeometryCollection { geometries } => {
+                    geo_types::Geometry::GeometryCollection(
+                        geometries
+                            .into_iter()
+                            .map(geo_types::Geometry::try_from)
+                            .collect::<Result<_, _>>()?,
+                    )
+                }
+            })
+        }
+    }
+}
+
+#[cfg(feature = "geojson")]
+mod geojson_conversions {
+    use super::*;
+    use std::convert::{TryFrom, TryInto};
+
+    fn location(position: geojson::Position) -> Result<GeoLocation, ValidationError> {
+        match position[..] {
+            [longitude, latitude, ..] => Ok(GeoLocation::new(latitude as f32, longitude as f32)),
+            _ => Err(ValidationError::UnsupportedGeometry),
+        }
+    }
+
+    fn line(positions: Vec<geojson::Position>) -> Result<Vec<GeoLocation>, ValidationError> {
+        positions.into_iter().map(location).collect()
+    }
+
+    fn rings(lines: Vec<Vec<geojson::Position>>) -> Result<Vec<Vec<GeoLocation>>, ValidationError> {
+        lines.into_iter().map(line).collect()
+    }
+
+    fn position(location: GeoLocation) -> geojson::Position {
+        vec![location.longitude().into(), location.latitude().into()]
+    }
+
+    fn positions(line: Vec<GeoLocation>) -> Vec<geojson::Position> {
+        line.into_iter().map(position).collect()
+    }
+
+    /// Fails for positions without both coordinates
+    impl TryFrom<geojson::Value> for GeoShape {
+        type Error = ValidationError;
+
+        fn try_from(value: geojson::Value) -> Result<Self, Self::Error> {
+            Ok(match value {
+                geojson::Value::Point(value) => Self::point(location(value)?),
+                geojson::Value::MultiPoint(value) => Self::multi_point(line(value)?),
+                geojson::Value::LineString(value) => Self::line_string(line(value)?),
+                geojson::Value::MultiLineString(value) => Self::multi_line_string(rings(value)?),
+                geojson::Value::Polygon(value) => Self::polygon(rings(value)?),
+                geojson::Value::MultiPolygon(value) => Self::multi_polygon(
+                    value
+                        .into_iter()
+                        .map(rings)
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+                geojson::Value::GeometryCollection(value) => Self::geometry_collection(
+                    value
+                        .into_iter()
+                        .map(Self::try_from)
+                        .collect::<Result<Vec<_>, _>>()?,
+                ),
+            })
+        }
+    }
+
+    impl TryFrom<geojson::Geometry> for GeoShape {
+        type Error = ValidationError;
+
+        fn try_from(value: geojson::Geometry) -> Result<Self, Self::Error> {
+            value.value.try_into()
+        }
+    }
+
+    /// Fails for features without a geometry
+    impl TryFrom<geojson::Feature> for GeoShape {
+        type Error = ValidationError;
+
+        fn try_from(value: geojson::Feature) -> Result<Self, Self::Error> {
+            value
+                .geometry
+                .ok_or(ValidationError::UnsupportedGeometry)?
+                .try_into()
+        }
+    }
+
+    /// Fails for envelopes and circles, which are Elasticsearch extensions of GeoJSON
+    impl TryFrom<GeoShape> for geojson::Value {
+        type Error = ValidationError;
+
+        fn try_from(value: GeoShape) -> Result<Self, Self::Error> {
+            Ok(match value {
+                GeoShape::Point { coordinates } => geojson::Value::Point(position(coordinates)),
+                GeoShape::LineString { coordinates } => {
+                    geojson::Value::LineString(positions(coordinates))
+                }
+                GeoShape::Polygon { coordinates } => {
+                    geojson::Value::Polygon(coordinates.into_iter().map(positions).collect())
+                }
+                GeoShape::MultiPoint { coordinates } => {
+                    geojson::Value::MultiPoint(positions(coordinates))
+                }
+                GeoShape::MultiLineString { coordinates } => geojson::Value::MultiLineString(
+                    coordinates.into_iter().map(positions).collect(),
+                ),
+                GeoShape::MultiPolygon { coordinates } => geojson::Value::MultiPolygon(
+                    coordinates
+                        .into_iter()
+                        .map(|rings| rings.into_iter().map(positions).collect())
+                        .collect(),
+                ),
+                GeoShape::Envelope { .. } | GeoShape::Circle { .. } => {
+                    return Err(ValidationError::UnsupportedGeometry)
+                }
+                GeoShape::GeometryCollection { geometries } => geojson::Value::GeometryCollection(
+                    geometries
+                        .into_iter()
+                        .map(geojson::Geometry::try_from)
+                        .collect::<Result<_, _>>()?,
+                ),
+            })
+        }
+    }
+
+    impl TryFrom<GeoShape> for geojson::Geometry {
+        type Error = ValidationError;
+
+        fn try_from(value: GeoShape) -> Result<Self, Self::Error> {
+            geojson::Value::try_from(value).map(geojson::Geometry::new)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::*;
 
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn converts_geo_types() {
+        use std::convert::TryFrom;
+
+        let polygon = geo_types::Polygon::new(
+            geo_types::LineString::from(vec![
+                (-17.0, 10.0),
+                (16.0, 15.0),
+                (12.0, 0.0),
+                (-17.0, 10.0),
+            ]),
+            vec![],
+        );
+
+        assert_eq!(
+            GeoShape::from(geo_types::Geometry::Polygon(polygon.clone())),
+            GeoShape::polygon([[[-17.0, 10.0], [16.0, 15.0], [12.0, 0.0], [-17.0, 10.0]]]),
+        );
+        assert_eq!(
+            geo_types::Geometry::try_from(GeoShape::from(polygon.clone())),
+            Ok(geo_types::Geometry::Polygon(polygon)),
+        );
+        assert_eq!(
+            GeoShape::from(geo_types::Rect::new(
+                geo_types::coord! { x: -74.0, y: 40.0 },
+                geo_types::coord! { x: -73.0, y: 41.0 },
+            )),
+            GeoShape::envelope([-74.0, 41.0], [-73.0, 40.0]),
+        );
+        assert_eq!(
+            GeoLocation::from(geo_types::Point::new(4.5, 52.5)),
+            GeoLocation::new(52.5, 4.5),
+        );
+        assert_eq!(
+            geo_types::Geometry::try_from(GeoShape::circle([4.5, 52.5], Distance::Meters(10))),
+            Err(ValidationError::UnsupportedGeometry),
+        );
+        assert_eq!(
+            geo_types::Geometry::try_from(GeoShape::envelope([170.0, 10.0], [-170.0, -10.0])),
+            Err(ValidationError::UnsupportedGeometry),
+        );
+    }
+
+    #[cfg(feature = "geojson")]
+    #[test]
+    fn converts_geojson() {
+        use std::convert::TryFrom;
+
+        let feature: geojson::Feature = serde_json::from_value(json!({
+            "type": "Feature",
+            "properties": {},
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [[-77.0, 38.0, 10.0], [-76.0, 39.0, 10.0]]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            GeoShape::try_from(feature),
+            Ok(GeoShape::line_string([[-77.0, 38.0], [-76.0, 39.0]])),
+        );
+        assert_eq!(
+            geojson::Geometry::try_from(GeoShape::point([-77.0, 38.0])),
+            Ok(geojson::Geometry::new(geojson::Value::Point(vec![
+                -77.0, 38.0
+            ]))),
+        );
+        assert_eq!(
+            GeoShape::try_from(geojson::Value::Point(vec![-77.0])),
+            Err(ValidationError::UnsupportedGeometry),
+        );
+        assert_eq!(
+            geojson::Value::try_from(GeoShape::envelope([-74.0, 41.0], [-73.0, 40.0])),
+            Err(ValidationError::UnsupportedGeometry),
+        );
+    }
+
+    #[test]
+    fn serializes_geohashes_as_positions() {
+        let location = GeoLocation::geohash("u15pmus").unwrap();
+
+        assert_serialize(
+            GeoShape::point(location),
+            json!({
+                "type": "point",
+                "coordinates": [4.4776154, 51.92482]
+            }),
+        );
+
+        assert_serialize(
+            GeoShape::envelope(location, GeoLocation::new(52.0, 4.0)),
+            json!({
+                "type": "envelope",
+                "coordinates": [[4.4776154, 51.92482], [4.0, 52.0]]
+            }),
+        );
+    }
+
     #[test]
     fn serialization() {
         assert_serialize(
@@ -370,4 +903,27 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn deserializes_geojson() {
+        let value = json!({
+            "type": "GeometryCollection",
+            "geometries": [
+                { "type": "Point", "coordinates": [-77.0, 38.0, 12.5] },
+                { "type": "LineString", "coordinates": [[-77.0, 38.0], [-76.0, 39.0]] },
+                { "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]] },
+                { "type": "circle", "coordinates": [-45.0, 45.0], "radius": "100m" },
+            ]
+        });
+
+        assert_eq!(
+            serde_json::from_value::<GeoShape>(value).unwrap(),
+            GeoShape::geometry_collection([
+                GeoShape::point([-77.0, 38.0]),
+                GeoShape::line_string([[-77.0, 38.0], [-76.0, 39.0]]),
+                GeoShape::polygon([[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]),
+                GeoShape::circle([-45.0, 45.0], Distance::Meters(100)),
+            ]),
+        );
+    }
 }
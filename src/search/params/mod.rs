@@ -1,35 +1,62 @@
 //! Value types accepted by leaf query clauses
 
+mod as_float;
 mod coordinate;
 mod date;
+mod date_math;
+mod es_document;
+mod es_version;
+mod field;
+mod field_and_format;
 mod geo_distance_type;
 mod geo_location;
 mod geo_shape;
 mod number;
+mod ordered_map;
 mod point_in_time;
+mod request_key;
 mod score_mode;
 mod script_sort_type;
+mod search_after;
 mod search_filter;
+mod search_type;
+mod serialize_error;
 mod shape;
 mod term;
 mod terms;
 mod text;
 mod track_total_hits;
 mod units;
+mod validation_error;
+mod wkt;
 
+pub use self::as_float::*;
 pub use self::coordinate::*;
 pub use self::date::*;
+pub use self::date_math::*;
+pub use self::es_document::*;
+pub use self::es_version::*;
+pub use self::field::*;
+pub use self::field_and_format::*;
 pub use self::geo_distance_type::*;
 pub use self::geo_location::*;
 pub use self::geo_shape::*;
 pub use self::number::*;
+pub use self::ordered_map::*;
 pub use self::point_in_time::*;
+pub use self::request_key::*;
 pub use self::score_mode::*;
 pub use self::script_sort_type::*;
+pub use self::search_after::*;
 pub use self::search_filter::*;
+pub use self::search_type::*;
+pub use self::serialize_error::*;
 pub use self::shape::*;
 pub use self::term::*;
 pub use self::terms::*;
 pub use self::text::*;
 pub use self::track_total_hits::*;
 pub use self::units::*;
+pub use self::validation_error::*;
+
+pub(crate) use self::wkt::Wkt;
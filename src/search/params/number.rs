@@ -1,10 +1,10 @@
 use std::cmp::Ordering;
 
 /// Numeric enum
-#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd)]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd)]
 pub struct Number(N);
 
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 enum N {
     /// Non-negative integers
@@ -18,26 +18,89 @@ enum N {
 
     /// 64-bit floats
     F64(f64),
+
+    /// Fixed precision decimals
+    #[cfg(feature = "rust_decimal")]
+    #[serde(serialize_with = "serialize_decimal")]
+    Decimal(rust_decimal::Decimal),
+
+    /// Arbitrary precision decimals
+    #[cfg(feature = "bigdecimal")]
+    #[serde(serialize_with = "serialize_decimal")]
+    BigDecimal(bigdecimal::BigDecimal),
+}
+
+/// Serializes decimals as strings, which Elasticsearch coerces into numeric values, as numbers
+/// can't be represented without a lossy floating point conversion in every serializer
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+fn serialize_decimal<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: std::fmt::Display,
+    S: serde::Serializer,
+{
+    serializer.collect_str(value)
+}
+
+#[cfg(feature = "rust_decimal")]
+impl N {
+    fn to_decimal(&self) -> Option<rust_decimal::Decimal> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        match self {
+            N::Pos(value) => Some((*value).into()),
+            N::Neg(value) => Some((*value).into()),
+            N::F32(value) => rust_decimal::Decimal::from_f32(*value),
+            N::F64(value) => rust_decimal::Decimal::from_f64(*value),
+            N::Decimal(value) => Some(*value),
+            #[cfg(feature = "bigdecimal")]
+            N::BigDecimal(value) => value.to_string().parse().ok(),
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl N {
+    fn to_big_decimal(&self) -> Option<bigdecimal::BigDecimal> {
+        use std::convert::TryFrom;
+
+        match self {
+            N::Pos(value) => Some((*value).into()),
+            N::Neg(value) => Some((*value).into()),
+            N::F32(value) => bigdecimal::BigDecimal::try_from(*value).ok(),
+            N::F64(value) => bigdecimal::BigDecimal::try_from(*value).ok(),
+            #[cfg(feature = "rust_decimal")]
+            N::Decimal(value) => value.to_string().parse().ok(),
+            N::BigDecimal(value) => Some(value.clone()),
+        }
+    }
 }
 
 impl std::fmt::Debug for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0 {
+        match &self.0 {
             N::Pos(value) => value.fmt(f),
             N::Neg(value) => value.fmt(f),
             N::F32(value) => value.fmt(f),
             N::F64(value) => value.fmt(f),
+            #[cfg(feature = "rust_decimal")]
+            N::Decimal(value) => value.fmt(f),
+            #[cfg(feature = "bigdecimal")]
+            N::BigDecimal(value) => value.fmt(f),
         }
     }
 }
 
 impl std::fmt::Display for Number {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.0 {
+        match &self.0 {
             N::Pos(value) => value.fmt(f),
             N::Neg(value) => value.fmt(f),
             N::F32(value) => value.fmt(f),
             N::F64(value) => value.fmt(f),
+            #[cfg(feature = "rust_decimal")]
+            N::Decimal(value) => value.fmt(f),
+            #[cfg(feature = "bigdecimal")]
+            N::BigDecimal(value) => value.fmt(f),
         }
     }
 }
@@ -198,6 +261,34 @@ impl From<&f64> for Number {
     }
 }
 
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Number {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self(N::Decimal(value))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<&rust_decimal::Decimal> for Number {
+    fn from(value: &rust_decimal::Decimal) -> Self {
+        Self(N::Decimal(*value))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<bigdecimal::BigDecimal> for Number {
+    fn from(value: bigdecimal::BigDecimal) -> Self {
+        Self(N::BigDecimal(value))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl From<&bigdecimal::BigDecimal> for Number {
+    fn from(value: &bigdecimal::BigDecimal) -> Self {
+        Self(N::BigDecimal(value.clone()))
+    }
+}
+
 impl PartialEq for N {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -224,6 +315,16 @@ impl PartialEq for N {
             (N::F64(value), N::Neg(other)) => value.eq(&(*other as f64)),
             (N::F64(value), N::F32(other)) => (*value as f32).eq(other),
             (N::F64(value), N::F64(other)) => value.eq(other),
+
+            // Decimals, compared as the widest of the two decimal types
+            #[cfg(feature = "bigdecimal")]
+            (N::BigDecimal(value), other) | (other, N::BigDecimal(value)) => {
+                other.to_big_decimal().as_ref() == Some(value)
+            }
+            #[cfg(feature = "rust_decimal")]
+            (N::Decimal(value), other) | (other, N::Decimal(value)) => {
+                other.to_decimal() == Some(*value)
+            }
         }
     }
 }
@@ -256,6 +357,24 @@ impl PartialOrd for N {
             (N::F64(value), N::Neg(other)) => value.partial_cmp(&(*other as f64)),
             (N::F64(value), N::F32(other)) => (*value as f32).partial_cmp(other),
             (N::F64(value), N::F64(other)) => value.partial_cmp(other),
+
+            // Decimals, compared as the widest of the two decimal types
+            #[cfg(feature = "bigdecimal")]
+            (N::BigDecimal(value), other) => other
+                .to_big_decimal()
+                .and_then(|other| value.partial_cmp(&other)),
+            #[cfg(feature = "bigdecimal")]
+            (value, N::BigDecimal(other)) => value
+                .to_big_decimal()
+                .and_then(|value| value.partial_cmp(other)),
+            #[cfg(feature = "rust_decimal")]
+            (N::Decimal(value), other) => other
+                .to_decimal()
+                .and_then(|other| value.partial_cmp(&other)),
+            #[cfg(feature = "rust_decimal")]
+            (value, N::Decimal(other)) => value
+                .to_decimal()
+                .and_then(|value| value.partial_cmp(other)),
         }
     }
 }
@@ -278,6 +397,76 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn serializes_decimals_without_precision_loss() {
+        assert_serialize(
+            [
+                Number::from(rust_decimal::Decimal::new(1234567890123456789, 2)),
+                Number::from(rust_decimal::Decimal::new(-1050, 2)),
+            ],
+            json!(["12345678901234567.89", "-10.50"]),
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn serializes_big_decimals_without_precision_loss() {
+        assert_serialize(
+            [
+                Number::from(bigdecimal::BigDecimal::new(
+                    1234567890123456789u64.into(),
+                    2,
+                )),
+                Number::from(bigdecimal::BigDecimal::new((-105).into(), 1)),
+            ],
+            json!(["12345678901234567.89", "-10.5"]),
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn compares_big_decimals() {
+        assert_eq!(
+            Number::from(bigdecimal::BigDecimal::new(20.into(), 1)),
+            Number::from(2)
+        );
+        assert_eq!(
+            Number::from(2.5f64),
+            Number::from(bigdecimal::BigDecimal::new(25.into(), 1))
+        );
+        assert!(Number::from(bigdecimal::BigDecimal::new(25.into(), 1)) > Number::from(2));
+        assert!(Number::from(-3) < Number::from(bigdecimal::BigDecimal::new((-25).into(), 1)));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn compares_decimals() {
+        assert_eq!(
+            Number::from(rust_decimal::Decimal::new(20, 1)),
+            Number::from(2)
+        );
+        assert_eq!(
+            Number::from(2.5f64),
+            Number::from(rust_decimal::Decimal::new(25, 1))
+        );
+        assert!(Number::from(rust_decimal::Decimal::new(25, 1)) > Number::from(2));
+        assert!(Number::from(-3) < Number::from(rust_decimal::Decimal::new(-25, 1)));
+    }
+
+    #[cfg(all(feature = "rust_decimal", feature = "bigdecimal"))]
+    #[test]
+    fn compares_decimal_types() {
+        assert_eq!(
+            Number::from(rust_decimal::Decimal::new(25, 1)),
+            Number::from(bigdecimal::BigDecimal::new(250.into(), 2))
+        );
+        assert!(
+            Number::from(rust_decimal::Decimal::new(25, 1))
+                < Number::from(bigdecimal::BigDecimal::new(26.into(), 1))
+        );
+    }
+
     #[test]
     fn partial_eq() {
         assert_eq!(Number::from(2f32), Number::from(2));
@@ -0,0 +1,266 @@
+use crate::util::*;
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use std::borrow::Borrow;
+use std::iter::FromIterator;
+
+/// Map which keeps its entries in insertion order, so that aggregations, script fields and
+/// highlighted fields are serialized in the order they were added.
+///
+/// Inserting an existing key replaces its value and keeps the original position. Lookups are
+/// linear, which is faster than hashing for the handful of entries a search request holds.
+///
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let mut map = OrderedMap::new();
+/// let _ = map.insert("z", 1);
+/// let _ = map.insert("a", 2);
+///
+/// assert_eq!(map.keys().collect::<Vec<_>>(), [&"z", &"a"]);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OrderedMap<K, V>(Vec<(K, V)>);
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<K, V> std::fmt::Debug for OrderedMap<K, V>
+where
+    K: std::fmt::Debug,
+    V: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Creates an empty instance of [`OrderedMap`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of entries
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the entries in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.0.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Iterates over the entries in insertion order, allowing to modify the values
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.0.iter_mut().map(|(key, value)| (&*key, value))
+    }
+
+    /// Iterates over the keys in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.0.iter().map(|(key, _)| key)
+    }
+
+    /// Iterates over the values in insertion order
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.0.iter().map(|(_, value)| value)
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.0.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Returns `true` if the map has an entry with the `key`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Returns the value of the `key`
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(|index| &self.0[index].1)
+    }
+
+    /// Returns a mutable reference to the value of the `key`
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(move |index| &mut self.0[index].1)
+    }
+
+    /// Inserts the entry at the end of the map, or replaces the value in place if the `key` is
+    /// already present and returns the previous value
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: PartialEq,
+    {
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.0[index].1, value)),
+            None => {
+                self.0.push((key, value));
+                None
+            }
+        }
+    }
+
+    /// Removes the entry of the `key`, keeping the order of the remaining entries
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.position(key).map(|index| self.0.remove(index).1)
+    }
+
+    /// Changes the key of the entry, keeping its position. Returns `false` if there is no entry
+    /// with the `from` key. An entry with the `to` key, if any, is removed.
+    pub fn rename<Q>(&mut self, from: &Q, to: K) -> bool
+    where
+        K: Borrow<Q> + PartialEq,
+        Q: PartialEq + ?Sized,
+    {
+        match self.position(from) {
+            Some(index) => {
+                if let Some(existing) = self.position(to.borrow()).filter(|i| *i != index) {
+                    let _ = self.0.remove(existing);
+                }
+
+                let index = self.position(from).unwrap_or(index);
+                self.0[index].0 = to;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<K, V> ShouldSkip for OrderedMap<K, V> {
+    fn should_skip(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in iter {
+            let _ = self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for OrderedMap<K, V>
+where
+    K: PartialEq,
+{
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<K, V> Serialize for OrderedMap<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+    }
+}
+
+/// Serializes the map as an array of single entry objects, for the APIs that accept the order
+/// only in that form
+pub(crate) fn serialize_as_entries<K, V, S>(
+    map: &OrderedMap<K, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(map.len()))?;
+
+    for (key, value) in map.iter() {
+        seq.serialize_element(&KeyValuePair::new(key, value))?;
+    }
+
+    seq.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_insertion_order() {
+        let mut map: OrderedMap<String, u32> = vec![("b".to_string(), 1), ("a".to_string(), 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(map.insert("b".to_string(), 3), Some(1));
+        assert_eq!(map.insert("c".to_string(), 4), None);
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"b":3,"a":2,"c":4}"#
+        );
+
+        assert_eq!(map.remove("a"), Some(2));
+        assert!(map.rename("b", "d".to_string()));
+        assert!(!map.rename("a", "e".to_string()));
+        assert_eq!(map.get("d"), Some(&3));
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"d":3,"c":4}"#);
+
+        assert!(map.rename("d", "c".to_string()));
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"c":3}"#);
+    }
+}
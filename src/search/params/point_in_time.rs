@@ -23,6 +23,29 @@ impl PointInTime {
     }
 }
 
+/// Request body of the close point in time API, point in times are released automatically once
+/// their `keep_alive` has passed but closing them as soon as they are no longer used frees the
+/// resources earlier.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html#close-point-in-time-api>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClosePointInTime {
+    id: String,
+}
+
+impl ClosePointInTime {
+    /// Creates a new instance of [`ClosePointInTime`].
+    ///
+    /// - `id` - the id of the point in time to close, as returned by the open point in time API
+    ///   or the `pit_id` of the latest search response
+    pub fn new<T>(id: T) -> Self
+    where
+        T: ToString,
+    {
+        Self { id: id.to_string() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +63,12 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_close_point_in_time() {
+        assert_serialize(
+            ClosePointInTime::new("46ToAwMDaWR5BXV1aWQy"),
+            json!({ "id": "46ToAwMDaWR5BXV1aWQy" }),
+        );
+    }
 }
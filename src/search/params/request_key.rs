@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+/// Normalized representation of a [`Search`](crate::Search) or [`Query`](crate::Query) body,
+/// for deduplicating requests, e.g. in a multi search batch, or keying caches.
+///
+/// Requests and queries are only [`PartialEq`], as they carry floating point values, whereas
+/// keys are plain bytes of the serialized body. Two keys are equal exactly when the bodies
+/// serialize identically, so `NaN` boosts are equal to themselves, while `0.1f32` and `0.1f64`
+/// terms, which compare equal as [`Term`](crate::Term)s, produce different keys.
+///
+/// The body is serialized once, when the key is created, rather than on every hash or
+/// comparison:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # use std::collections::HashMap;
+/// let search = Search::new().query(Query::term("user.id", "kimchy"));
+///
+/// let mut cache = HashMap::new();
+/// cache.insert(search.key()?, "response");
+///
+/// assert!(cache.contains_key(&search.clone().key()?));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestKey(Box<[u8]>);
+
+impl RequestKey {
+    pub(crate) fn new<T>(body: &T) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        serde_json::to_vec(body).map(|bytes| Self(bytes.into_boxed_slice()))
+    }
+
+    /// Serialized JSON body the key was created from
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn keys_follow_serialization() {
+        let f32 = Query::term("field", 0.1f32);
+        let f64 = Query::term("field", 0.10000000001f64);
+
+        assert_eq!(f32, f64);
+        assert_ne!(
+            Query::from(f32).key().unwrap(),
+            Query::from(f64).key().unwrap()
+        );
+        assert_eq!(
+            Query::from(Query::term("field", "value"))
+                .key()
+                .unwrap()
+                .as_bytes(),
+            br#"{"term":{"field":{"value":"value"}}}"#
+        );
+    }
+}
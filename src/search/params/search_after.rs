@@ -0,0 +1,56 @@
+use crate::util::*;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Sort values of the last hit of the previous page, to retrieve the next page of hits with
+/// [`Search::search_after`](crate::Search::search_after).
+///
+/// Unlike [`Terms`](crate::Terms) the values are kept as is, including `null` values, as the
+/// values are matched by position against the sort of the search.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#search-after>
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SearchAfter(Vec<Value>);
+
+impl SearchAfter {
+    /// Returns the sort values
+    pub fn values(&self) -> &[Value] {
+        &self.0
+    }
+}
+
+impl<T> From<T> for SearchAfter
+where
+    T: IntoIterator,
+    T::Item: Serialize,
+{
+    fn from(values: T) -> Self {
+        Self(
+            values
+                .into_iter()
+                .map(|value| serde_json::to_value(value).unwrap_or_default())
+                .collect(),
+        )
+    }
+}
+
+impl ShouldSkip for SearchAfter {
+    fn should_skip(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            SearchAfter::from([json!(1463538857), json!("654323"), Value::Null]),
+            json!([1463538857, "654323", null]),
+        );
+
+        assert_serialize(SearchAfter::from([1.5, 2.0]), json!([1.5, 2.0]));
+    }
+}
@@ -1,9 +1,26 @@
+use super::Field;
+use crate::util::ShouldSkip;
 use std::borrow::Cow;
 
 /// Control how the `_source` field is returned with every hit.
 ///
 /// By default operations return the contents of the `_source` field
 /// unless you have used the `stored_fields` parameter or if the `_source` field is disabled.
+///
+/// The same filter is accepted by [`Search::source`](crate::Search::source),
+/// [`InnerHits::source`](crate::InnerHits::source) and
+/// [`TopHitsAggregation::source`](crate::TopHitsAggregation::source):
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new().source("obj.*");
+/// # let search =
+/// Search::new().source(["obj1.*", "obj2.*"]);
+/// # let search =
+/// Search::new().source((["obj.*"], ["obj.secret"]));
+/// # let search =
+/// Search::new().source(SourceFilter::excludes(["*.description"]));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SourceFilter {
@@ -19,15 +36,33 @@ pub enum SourceFilter {
     /// A collection of wildcard patterns to control what parts of `_source` should
     /// and should not be returned
     IncludesExcludes {
-        /// A collection of wildcard patterns to control what parts of `_source` should be returned
+        /// A collection of wildcard patterns to control what parts of `_source` should be
+        /// returned, everything is included when empty
+        #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
         includes: Vec<String>,
 
         /// A collection of wildcard patterns to control what parts of `_source` should not be
         /// returned
+        #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
         excludes: Vec<String>,
     },
 }
 
+impl SourceFilter {
+    /// Creates a filter returning the whole `_source` except the parts matching the wildcard
+    /// patterns
+    pub fn excludes<I>(excludes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        SourceFilter::IncludesExcludes {
+            includes: Vec::new(),
+            excludes: excludes.into_iter().map(|x| x.to_string()).collect(),
+        }
+    }
+}
+
 // -- Boolean
 
 impl From<bool> for SourceFilter {
@@ -56,6 +91,12 @@ impl From<Cow<'_, str>> for SourceFilter {
     }
 }
 
+impl From<Field> for SourceFilter {
+    fn from(include: Field) -> Self {
+        SourceFilter::Include(include.to_string())
+    }
+}
+
 // -- Include multiple fields
 
 impl From<Vec<String>> for SourceFilter {
@@ -94,6 +135,18 @@ impl<const N: usize> From<[Cow<'_, str>; N]> for SourceFilter {
     }
 }
 
+impl From<Vec<Field>> for SourceFilter {
+    fn from(includes: Vec<Field>) -> Self {
+        SourceFilter::Includes(includes.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl<const N: usize> From<[Field; N]> for SourceFilter {
+    fn from(includes: [Field; N]) -> Self {
+        SourceFilter::Includes(includes.iter().map(ToString::to_string).collect())
+    }
+}
+
 // -- Include exclude fields
 
 impl From<(Vec<String>, Vec<String>)> for SourceFilter {
@@ -149,6 +202,7 @@ impl<const M: usize, const N: usize> From<([Cow<'_, str>; M], [Cow<'_, str>; N])
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::{util::assert_serialize, Search};
 
     #[test]
@@ -183,4 +237,39 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn adds_excludes() {
+        assert_serialize(
+            Search::new().source(SourceFilter::excludes(["def"])),
+            json!({
+                "_source": {
+                    "excludes": ["def"]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserializes_successfully() {
+        let filters: Vec<SourceFilter> = serde_json::from_value(json!([
+            true,
+            "abc",
+            ["abc"],
+            { "includes": ["abc"], "excludes": ["def"] },
+            { "excludes": ["def"] }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            filters,
+            [
+                SourceFilter::Enable(true),
+                SourceFilter::Include("abc".into()),
+                SourceFilter::Includes(vec!["abc".into()]),
+                SourceFilter::from((["abc"], ["def"])),
+                SourceFilter::excludes(["def"]),
+            ]
+        );
+    }
 }
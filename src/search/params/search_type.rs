@@ -0,0 +1,12 @@
+/// How distributed term frequencies are calculated for relevance scoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    /// Documents are scored using local term and document frequencies of the shard. This is
+    /// usually faster but less accurate.
+    QueryThenFetch,
+
+    /// Documents are scored using global term and document frequencies across all shards. This
+    /// is usually slower but more accurate.
+    DfsQueryThenFetch,
+}
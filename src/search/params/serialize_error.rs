@@ -0,0 +1,41 @@
+use crate::search::*;
+
+/// Error returned by [`Search::serialize_for`](crate::Search::serialize_for)
+#[derive(Debug)]
+pub enum SerializeError {
+    /// Request uses a section or a query the targeted search engine version doesn't support
+    Validation(ValidationError),
+
+    /// Request can't be serialized into JSON
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Validation(error) => error.fmt(f),
+            Self::Json(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SerializeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Validation(error) => Some(error),
+            Self::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<ValidationError> for SerializeError {
+    fn from(error: ValidationError) -> Self {
+        Self::Validation(error)
+    }
+}
+
+impl From<serde_json::Error> for SerializeError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
@@ -69,6 +69,25 @@ pub enum Shape {
 }
 
 impl Shape {
+    /// Parses a [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+    /// geometry, such as `POLYGON ((...))` or `BBOX (minX, maxX, maxY, minY)`. Fails if the text
+    /// can't be parsed, polygon rings aren't closed or the geometry is `EMPTY`.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// assert_eq!(
+    ///     Shape::from_wkt("BBOX (1000.0, 1001.0, 100.0, 99.0)").unwrap(),
+    ///     Shape::envelope([1000.0, 100.0], [1001.0, 99.0]),
+    /// );
+    ///
+    /// # #[cfg(feature = "geo")]
+    /// # let query =
+    /// Query::shape("geometry", Shape::from_wkt("POINT (1000 100)").unwrap());
+    /// ```
+    pub fn from_wkt(value: &str) -> Result<Self, ValidationError> {
+        Wkt::parse(value).map(Self::from_parsed_wkt)
+    }
+
     /// Creates an instance of [`Shape::Point`]
     pub fn point<T>(coordinates: T) -> Self
     where
@@ -173,6 +192,31 @@ impl Shape {
     }
 }
 
+impl std::str::FromStr for Shape {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_wkt(value)
+    }
+}
+
+impl Shape {
+    fn from_parsed_wkt(value: Wkt) -> Self {
+        match value {
+            Wkt::Point(position) => Self::point(position),
+            Wkt::LineString(positions) => Self::line_string(positions),
+            Wkt::Polygon(rings) => Self::polygon(rings),
+            Wkt::MultiPoint(positions) => Self::multi_point(positions),
+            Wkt::MultiLineString(lines) => Self::multi_line_string(lines),
+            Wkt::MultiPolygon(polygons) => Self::multi_polygon(polygons),
+            Wkt::GeometryCollection(geometries) => {
+                Self::geometry_collection(geometries.into_iter().map(Self::from_parsed_wkt))
+            }
+            Wkt::Envelope(top_left, bottom_right) => Self::envelope(top_left, bottom_right),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
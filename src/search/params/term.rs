@@ -64,6 +64,15 @@ impl Term {
 
         term.ok()
     }
+
+    /// Same as `new`, but accepts compound values, such as arrays and objects, returning `None`
+    /// for them
+    pub(crate) fn scalar<T>(term: T) -> Option<Self>
+    where
+        T: Serialize,
+    {
+        term.serialize(Serializer).ok()
+    }
 }
 
 struct Serializer;
@@ -403,7 +412,6 @@ impl ser::Error for TermSerializeError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::prelude::*;
 
     #[test]
     fn serializes_primitives_correctly() {
@@ -414,12 +422,39 @@ mod tests {
         assert_eq!(Term::new(1_f64), Some(Term::Float64(1.0)));
         assert_eq!(Term::new('s'), Some(Term::String("s".into())));
         assert_eq!(Term::new("str"), Some(Term::String("str".into())));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn serializes_chrono_dates_correctly() {
+        use chrono::prelude::*;
+
         assert_eq!(
             Term::new(Utc.with_ymd_and_hms(2022, 3, 21, 0, 5, 8).single().unwrap()),
             Some(Term::String("2022-03-21T00:05:08Z".into()))
         );
     }
 
+    #[cfg(feature = "time")]
+    #[test]
+    fn serializes_time_dates_correctly() {
+        assert_eq!(
+            Term::new(crate::search::Date::from(
+                time::OffsetDateTime::from_unix_timestamp(1_647_821_108).unwrap()
+            )),
+            Some(Term::String("2022-03-21T00:05:08Z".into()))
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn serializes_jiff_dates_correctly() {
+        assert_eq!(
+            Term::new(jiff::Timestamp::from_second(1_647_821_108).unwrap()),
+            Some(Term::String("2022-03-21T00:05:08Z".into()))
+        );
+    }
+
     #[test]
     fn serializes_newtypes_correctly() {
         #[derive(Serialize)]
@@ -452,6 +487,26 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn serializes_rust_decimals_without_precision_loss() {
+        assert_eq!(
+            Term::new(rust_decimal::Decimal::new(1234567890123456789, 2)),
+            Some(Term::String("12345678901234567.89".into()))
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn serializes_big_decimals_without_precision_loss() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            Term::new(bigdecimal::BigDecimal::from_str("12345678901234567.89").unwrap()),
+            Some(Term::String("12345678901234567.89".into()))
+        );
+    }
+
     #[test]
     fn custom_partial_eq() {
         assert_eq!(Term::Float32(1.0), Term::Float64(1.0));
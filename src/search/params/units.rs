@@ -1,4 +1,7 @@
+use crate::search::ValidationError;
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
+use std::convert::TryFrom;
 
 /// Whenever durations need to be specified, e.g. for a `timeout` parameter,
 /// the duration must specify the unit, like `2d` for 2 days.
@@ -21,16 +24,55 @@ impl Serialize for Time {
     where
         S: Serializer,
     {
-        match self {
-            Self::Days(u) => format!("{u}d"),
-            Self::Hours(u) => format!("{u}h"),
-            Self::Minutes(u) => format!("{u}m"),
-            Self::Seconds(u) => format!("{u}s"),
-            Self::Milliseconds(u) => format!("{u}ms"),
-            Self::Microseconds(u) => format!("{u}micros"),
-            Self::Nanoseconds(u) => format!("{u}nanos"),
+        let (amount, unit) = match self {
+            Self::Days(amount) => (amount, "d"),
+            Self::Hours(amount) => (amount, "h"),
+            Self::Minutes(amount) => (amount, "m"),
+            Self::Seconds(amount) => (amount, "s"),
+            Self::Milliseconds(amount) => (amount, "ms"),
+            Self::Microseconds(amount) => (amount, "micros"),
+            Self::Nanoseconds(amount) => (amount, "nanos"),
+        };
+
+        serializer.collect_str(&format_args!("{amount}{unit}"))
+    }
+}
+
+impl std::str::FromStr for Time {
+    type Err = ValidationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ValidationError::InvalidTime;
+        let (amount, unit) = value.split_at(
+            value
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(value.len()),
+        );
+        let amount = amount.parse().map_err(|_| invalid())?;
+
+        match unit {
+            "d" => Ok(Self::Days(amount)),
+            "h" => Ok(Self::Hours(amount)),
+            "m" => Ok(Self::Minutes(amount)),
+            "s" => Ok(Self::Seconds(amount)),
+            "ms" => Ok(Self::Milliseconds(amount)),
+            "micros" => Ok(Self::Microseconds(amount)),
+            "nanos" => Ok(Self::Nanoseconds(amount)),
+            _ => Err(invalid()),
         }
-        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        value
+            .parse()
+            .map_err(|_| D::Error::custom(format!("invalid time unit `{value}`")))
     }
 }
 
@@ -95,15 +137,16 @@ impl Serialize for Byte {
     where
         S: Serializer,
     {
-        match self {
-            Self::Bytes(u) => format!("{u}b"),
-            Self::Kilobytes(u) => format!("{u}kb"),
-            Self::Megabytes(u) => format!("{u}mb"),
-            Self::Gigabytes(u) => format!("{u}gb"),
-            Self::Terabytes(u) => format!("{u}tb"),
-            Self::Petabytes(u) => format!("{u}pb"),
-        }
-        .serialize(serializer)
+        let (amount, unit) = match self {
+            Self::Bytes(amount) => (amount, "b"),
+            Self::Kilobytes(amount) => (amount, "kb"),
+            Self::Megabytes(amount) => (amount, "mb"),
+            Self::Gigabytes(amount) => (amount, "gb"),
+            Self::Terabytes(amount) => (amount, "tb"),
+            Self::Petabytes(amount) => (amount, "pb"),
+        };
+
+        serializer.collect_str(&format_args!("{amount}{unit}"))
     }
 }
 
@@ -129,14 +172,97 @@ impl Serialize for Size {
     where
         S: Serializer,
     {
-        match self {
-            Self::Kilo(u) => format!("{u}k"),
-            Self::Mega(u) => format!("{u}m"),
-            Self::Giga(u) => format!("{u}g"),
-            Self::Tera(u) => format!("{u}t"),
-            Self::Peta(u) => format!("{u}p"),
+        let (amount, unit) = match self {
+            Self::Kilo(amount) => (amount, "k"),
+            Self::Mega(amount) => (amount, "m"),
+            Self::Giga(amount) => (amount, "g"),
+            Self::Tera(amount) => (amount, "t"),
+            Self::Peta(amount) => (amount, "p"),
+        };
+
+        serializer.collect_str(&format_args!("{amount}{unit}"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Distance {
+    /// Parses distances such as `"2km"`, a fractional amount like `"1.5km"` is converted into
+    /// the largest smaller unit it's a whole number of, `1500m` in this case
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        let invalid = || D::Error::custom(format!("invalid distance `{value}`"));
+        let (amount, unit) = value.split_at(
+            value
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(value.len()),
+        );
+        let unit = match unit {
+            "mi" => DistanceUnit::Miles,
+            "yd" => DistanceUnit::Yards,
+            "ft" => DistanceUnit::Feet,
+            "in" => DistanceUnit::Inches,
+            "km" => DistanceUnit::Kilometers,
+            "m" | "" => DistanceUnit::Meters,
+            "cm" => DistanceUnit::Centimeter,
+            "mm" => DistanceUnit::Millimeters,
+            "nmi" => DistanceUnit::NauticalMiles,
+            _ => return Err(invalid()),
+        };
+
+        Distance::from_decimal(amount, unit).ok_or_else(invalid)
+    }
+}
+
+impl Distance {
+    /// Converts a decimal amount into a whole number of `unit`, or of the first smaller unit the
+    /// amount is a whole number of
+    fn from_decimal(amount: &str, mut unit: DistanceUnit) -> Option<Self> {
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+        if integer.is_empty() && fraction.is_empty() || fraction.len() > 18 {
+            return None;
+        }
+
+        let mut numerator = format!("{integer}{fraction}").parse::<u128>().ok()?;
+        let mut denominator = 10u128.pow(fraction.len() as u32);
+
+        loop {
+            if numerator % denominator == 0 {
+                let amount = u64::try_from(numerator / denominator).ok()?;
+
+                return Some(match unit {
+                    DistanceUnit::Miles => Self::Miles(amount),
+                    DistanceUnit::Yards => Self::Yards(amount),
+                    DistanceUnit::Feet => Self::Feet(amount),
+                    DistanceUnit::Inches => Self::Inches(amount),
+                    DistanceUnit::Kilometers => Self::Kilometers(amount),
+                    DistanceUnit::Meters => Self::Meters(amount),
+                    DistanceUnit::Centimeter => Self::Centimeter(amount),
+                    DistanceUnit::Millimeters => Self::Millimeters(amount),
+                    DistanceUnit::NauticalMiles => Self::NauticalMiles(amount),
+                });
+            }
+
+            // Exact ratio to the next smaller unit
+            let (smaller, multiplier, divisor) = match unit {
+                DistanceUnit::Miles => (DistanceUnit::Yards, 1760, 1),
+                DistanceUnit::Yards => (DistanceUnit::Feet, 3, 1),
+                DistanceUnit::Feet => (DistanceUnit::Inches, 12, 1),
+                DistanceUnit::Inches => (DistanceUnit::Millimeters, 254, 10),
+                DistanceUnit::Kilometers => (DistanceUnit::Meters, 1000, 1),
+                DistanceUnit::NauticalMiles => (DistanceUnit::Meters, 1852, 1),
+                DistanceUnit::Meters => (DistanceUnit::Centimeter, 100, 1),
+                DistanceUnit::Centimeter => (DistanceUnit::Millimeters, 10, 1),
+                DistanceUnit::Millimeters => return None,
+            };
+
+            numerator = numerator.checked_mul(multiplier)?;
+            denominator = denominator.checked_mul(divisor)?;
+            unit = smaller;
         }
-        .serialize(serializer)
     }
 }
 
@@ -167,18 +293,19 @@ impl Serialize for Distance {
     where
         S: Serializer,
     {
-        match self {
-            Self::Miles(u) => format!("{u}mi"),
-            Self::Yards(u) => format!("{u}yd"),
-            Self::Feet(u) => format!("{u}ft"),
-            Self::Inches(u) => format!("{u}in"),
-            Self::Kilometers(u) => format!("{u}km"),
-            Self::Meters(u) => format!("{u}m"),
-            Self::Centimeter(u) => format!("{u}cm"),
-            Self::Millimeters(u) => format!("{u}mm"),
-            Self::NauticalMiles(u) => format!("{u}nmi"),
-        }
-        .serialize(serializer)
+        let (amount, unit) = match self {
+            Self::Miles(amount) => (amount, "mi"),
+            Self::Yards(amount) => (amount, "yd"),
+            Self::Feet(amount) => (amount, "ft"),
+            Self::Inches(amount) => (amount, "in"),
+            Self::Kilometers(amount) => (amount, "km"),
+            Self::Meters(amount) => (amount, "m"),
+            Self::Centimeter(amount) => (amount, "cm"),
+            Self::Millimeters(amount) => (amount, "mm"),
+            Self::NauticalMiles(amount) => (amount, "nmi"),
+        };
+
+        serializer.collect_str(&format_args!("{amount}{unit}"))
     }
 }
 
@@ -223,3 +350,90 @@ impl Serialize for DistanceUnit {
         .serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_time() {
+        let values: Vec<Time> =
+            serde_json::from_value(json!(["1d", "2h", "3m", "4s", "5ms", "6micros", "7nanos"]))
+                .unwrap();
+
+        assert_eq!(
+            values,
+            [
+                Time::Days(1),
+                Time::Hours(2),
+                Time::Minutes(3),
+                Time::Seconds(4),
+                Time::Milliseconds(5),
+                Time::Microseconds(6),
+                Time::Nanoseconds(7),
+            ]
+        );
+
+        assert!(serde_json::from_value::<Time>(json!("5 weeks")).is_err());
+        assert!(serde_json::from_value::<Time>(json!("s")).is_err());
+    }
+
+    #[test]
+    fn parses_time() {
+        assert_eq!("500ms".parse(), Ok(Time::Milliseconds(500)));
+        assert_eq!("1.5s".parse::<Time>(), Err(ValidationError::InvalidTime));
+    }
+
+    #[test]
+    fn deserializes_distance() {
+        let values: Vec<Distance> =
+            serde_json::from_value(json!(["1mi", "2km", "3m", "4", "5nmi"])).unwrap();
+
+        assert_eq!(
+            values,
+            [
+                Distance::Miles(1),
+                Distance::Kilometers(2),
+                Distance::Meters(3),
+                Distance::Meters(4),
+                Distance::NauticalMiles(5),
+            ]
+        );
+
+        assert!(serde_json::from_value::<Distance>(json!("km")).is_err());
+    }
+
+    #[test]
+    fn deserializes_fractional_distance() {
+        let values: Vec<Distance> = serde_json::from_value(json!([
+            "1.5km", "0.25mi", "0.5ft", "1.0m", ".5cm", "2.5nmi"
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            values,
+            [
+                Distance::Meters(1500),
+                Distance::Yards(440),
+                Distance::Inches(6),
+                Distance::Meters(1),
+                Distance::Millimeters(5),
+                Distance::Meters(4630),
+            ]
+        );
+
+        for value in [
+            "0.5mm",
+            "2.5in",
+            "1.2.3km",
+            ".km",
+            "1.123456789123456789123m",
+        ] {
+            assert!(
+                serde_json::from_value::<Distance>(json!(value)).is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::search::*;
+
+/// Default `index.max_result_window`, the deepest `from + size` page a search may request
+pub const MAX_RESULT_WINDOW: u64 = 10_000;
+
+/// Parameter value rejected by one of the fallible `try_*` builder methods.
+///
+/// The infallible builder methods accept any value and leave validation to the cluster, the
+/// `try_*` variants catch the most common mistakes before a request is sent:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// assert_eq!(
+///     Query::term("user", "kimchy").try_boost(-1.0).unwrap_err(),
+///     ValidationError::InvalidBoost(-1.0),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationError {
+    /// Boost has to be a finite, non-negative number
+    InvalidBoost(f32),
+
+    /// `from + size` exceeds the [`MAX_RESULT_WINDOW`]
+    ResultWindowTooLarge {
+        /// Starting document offset
+        from: u64,
+
+        /// Number of hits to return
+        size: u64,
+    },
+
+    /// Edit distance exceeds `2` or the `AUTO` range bounds are reversed
+    InvalidFuzziness(Fuzziness),
+
+    /// Field name is empty
+    EmptyFieldName,
+
+    /// Date math expression can't be parsed
+    InvalidDateMath,
+
+    /// Time value isn't a whole number followed by one of the time units, e.g. `500ms`
+    InvalidTime,
+
+    /// Well-Known Text geometry can't be parsed or isn't a valid shape
+    InvalidWkt,
+
+    /// Geohash is empty, longer than [`MAX_GEOHASH_PRECISION`] or has characters outside of the
+    /// geohash alphabet
+    InvalidGeohash,
+
+    /// Geometry has no equivalent in the type it's converted into, e.g. circles in `geo-types`
+    /// or GeoJSON positions without both coordinates
+    UnsupportedGeometry,
+
+    /// Request section isn't supported by the targeted search engine version
+    Unsupported {
+        /// Name of the unsupported section
+        feature: &'static str,
+
+        /// Targeted search engine version
+        version: EsVersion,
+    },
+
+    /// Metric and pipeline aggregations can't have sub-aggregations
+    SubAggregationsUnsupported,
+
+    /// Bucket key of an [`AggregationPath`](crate::search::AggregationPath) contains one of the `'`,
+    /// `[`, `]` or `>` characters of the path syntax
+    InvalidBucketKey,
+
+    /// Query or aggregation isn't one of the kinds supported by
+    /// [`RollupSearch`](crate::RollupSearch), the name of the rejected section is given
+    RollupUnsupported(&'static str),
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidBoost(boost) => {
+                write!(
+                    f,
+                    "boost must be a finite non-negative number, got `{boost}`"
+                )
+            }
+            Self::ResultWindowTooLarge { from, size } => write!(
+                f,
+                "from + size must not exceed {MAX_RESULT_WINDOW}, got `{from} + {size}`"
+            ),
+            Self::InvalidFuzziness(fuzziness) => write!(f, "invalid fuzziness `{fuzziness:?}`"),
+            Self::EmptyFieldName => "field name must not be empty".fmt(f),
+            Self::InvalidDateMath => "invalid date math expression".fmt(f),
+            Self::InvalidTime => "invalid time value".fmt(f),
+            Self::InvalidWkt => "invalid well-known text geometry".fmt(f),
+            Self::InvalidGeohash => "invalid geohash".fmt(f),
+            Self::UnsupportedGeometry => "geometry has no equivalent in the target type".fmt(f),
+            Self::Unsupported { feature, version } => {
+                write!(f, "`{feature}` is not supported on {version}")
+            }
+            Self::SubAggregationsUnsupported => {
+                "metric and pipeline aggregations can't have sub-aggregations".fmt(f)
+            }
+            Self::InvalidBucketKey => {
+                "bucket key must not contain `'`, `[`, `]` or `>` characters".fmt(f)
+            }
+            Self::RollupUnsupported(section) => {
+                write!(
+                    f,
+                    "`{section}` uses features not supported by rollup search"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
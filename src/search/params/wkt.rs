@@ -0,0 +1,287 @@
+//! Parser of [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+//! geometries shared by [`GeoShape`](crate::GeoShape) and [`Shape`](crate::Shape)
+
+use crate::search::*;
+
+/// Position as written in WKT, `x` (longitude) followed by `y` (latitude)
+pub(crate) type Position = [f32; 2];
+
+/// Nesting depth of geometry collections the parser accepts
+const MAX_COLLECTION_DEPTH: usize = 128;
+
+/// Parsed WKT geometry
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Wkt {
+    Point(Position),
+    LineString(Vec<Position>),
+    Polygon(Vec<Vec<Position>>),
+    MultiPoint(Vec<Position>),
+    MultiLineString(Vec<Vec<Position>>),
+    MultiPolygon(Vec<Vec<Vec<Position>>>),
+    GeometryCollection(Vec<Wkt>),
+    /// Top left and bottom right corners
+    Envelope(Position, Position),
+}
+
+impl Wkt {
+    /// Parses and validates a WKT geometry. Z and M values are accepted and dropped, `EMPTY`
+    /// geometries are rejected as they can't be searched by.
+    pub(crate) fn parse(value: &str) -> Result<Self, ValidationError> {
+        let mut parser = Parser {
+            input: value,
+            depth: 0,
+        };
+        let geometry = parser.geometry()?;
+
+        if parser.input.trim().is_empty() {
+            Ok(geometry)
+        } else {
+            Err(ValidationError::InvalidWkt)
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        self.input = self.input.trim_start();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input.chars().next()
+    }
+
+    fn expect(&mut self, token: char) -> Result<(), ValidationError> {
+        if self.peek() == Some(token) {
+            self.input = &self.input[token.len_utf8()..];
+            Ok(())
+        } else {
+            Err(ValidationError::InvalidWkt)
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let end = self
+            .input
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.input.len());
+        let (word, rest) = self.input.split_at(end);
+        self.input = rest;
+        word.to_ascii_uppercase()
+    }
+
+    fn number(&mut self) -> Result<f32, ValidationError> {
+        self.skip_whitespace();
+        let end = self
+            .input
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+            .unwrap_or(self.input.len());
+        let (number, rest) = self.input.split_at(end);
+        self.input = rest;
+        number
+            .parse::<f32>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or(ValidationError::InvalidWkt)
+    }
+
+    /// Parses comma separated items enclosed in parentheses
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ValidationError>,
+    ) -> Result<Vec<T>, ValidationError> {
+        self.expect('(')?;
+        let mut items = vec![item(self)?];
+
+        while self.peek() == Some(',') {
+            self.expect(',')?;
+            items.push(item(self)?);
+        }
+
+        self.expect(')')?;
+        Ok(items)
+    }
+
+    fn position(&mut self) -> Result<Position, ValidationError> {
+        let position = [self.number()?, self.number()?];
+
+        // Z and M values
+        for _ in 0..2 {
+            if matches!(self.peek(), Some(c) if c != ',' && c != ')') {
+                let _ = self.number()?;
+            }
+        }
+
+        Ok(position)
+    }
+
+    fn line(&mut self, min: usize) -> Result<Vec<Position>, ValidationError> {
+        let line = self.list(Self::position)?;
+
+        if line.len() < min {
+            return Err(ValidationError::InvalidWkt);
+        }
+
+        Ok(line)
+    }
+
+    fn polygon(&mut self) -> Result<Vec<Vec<Position>>, ValidationError> {
+        self.list(|parser| {
+            let ring = parser.line(4)?;
+
+            if ring.first() == ring.last() {
+                Ok(ring)
+            } else {
+                Err(ValidationError::InvalidWkt)
+            }
+        })
+    }
+
+    fn geometry(&mut self) -> Result<Wkt, ValidationError> {
+        let keyword = self.word();
+
+        if self
+            .input
+            .trim_start()
+            .starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            match self.word().as_str() {
+                "Z" | "M" | "ZM" => {}
+                _ => return Err(ValidationError::InvalidWkt),
+            }
+        }
+
+        match keyword.as_str() {
+            "POINT" => {
+                self.expect('(')?;
+                let position = self.position()?;
+                self.expect(')')?;
+                Ok(Wkt::Point(position))
+            }
+            "LINESTRING" => self.line(2).map(Wkt::LineString),
+            "POLYGON" => self.polygon().map(Wkt::Polygon),
+            "MULTIPOINT" => self
+                .list(|parser| {
+                    if parser.peek() == Some('(') {
+                        parser.expect('(')?;
+                        let position = parser.position()?;
+                        parser.expect(')')?;
+                        Ok(position)
+                    } else {
+                        parser.position()
+                    }
+                })
+                .map(Wkt::MultiPoint),
+            "MULTILINESTRING" => self.list(|parser| parser.line(2)).map(Wkt::MultiLineString),
+            "MULTIPOLYGON" => self.list(Self::polygon).map(Wkt::MultiPolygon),
+            "GEOMETRYCOLLECTION" => {
+                if self.depth == MAX_COLLECTION_DEPTH {
+                    return Err(ValidationError::InvalidWkt);
+                }
+
+                self.depth += 1;
+                let geometries = self.list(Self::geometry);
+                self.depth -= 1;
+
+                geometries.map(Wkt::GeometryCollection)
+            }
+            "BBOX" | "ENVELOPE" => {
+                let values = self.list(Self::number)?;
+
+                match values[..] {
+                    // Longitudes aren't compared, envelopes crossing the antimeridian have
+                    // `min_x` greater than `max_x`
+                    [min_x, max_x, max_y, min_y] if min_y <= max_y => {
+                        Ok(Wkt::Envelope([min_x, max_y], [max_x, min_y]))
+                    }
+                    _ => Err(ValidationError::InvalidWkt),
+                }
+            }
+            _ => Err(ValidationError::InvalidWkt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_geometries() {
+        assert_eq!(Wkt::parse("POINT (1 2)"), Ok(Wkt::Point([1.0, 2.0])));
+        assert_eq!(Wkt::parse("point z (1 2 3)"), Ok(Wkt::Point([1.0, 2.0])));
+        assert_eq!(
+            Wkt::parse("MULTIPOINT ((1 2), 3 4)"),
+            Ok(Wkt::MultiPoint(vec![[1.0, 2.0], [3.0, 4.0]]))
+        );
+        assert_eq!(
+            Wkt::parse("POLYGON ((0 0, 1 0, 1 1, 0 0))"),
+            Ok(Wkt::Polygon(vec![vec![
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [1.0, 1.0],
+                [0.0, 0.0]
+            ]]))
+        );
+        assert_eq!(
+            Wkt::parse("BBOX (-74.1, -73.5, 40.73, 40.01)"),
+            Ok(Wkt::Envelope([-74.1, 40.73], [-73.5, 40.01]))
+        );
+        assert_eq!(
+            Wkt::parse("BBOX (170, -170, 10, -10)"),
+            Ok(Wkt::Envelope([170.0, 10.0], [-170.0, -10.0]))
+        );
+        assert_eq!(
+            Wkt::parse("GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (1 2, 3 4))"),
+            Ok(Wkt::GeometryCollection(vec![
+                Wkt::Point([1.0, 2.0]),
+                Wkt::LineString(vec![[1.0, 2.0], [3.0, 4.0]]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_geometries() {
+        for value in [
+            "",
+            "POINT",
+            "POINT EMPTY",
+            "POINT (1)",
+            "POINT (1 2",
+            "POINT (1 2) POINT (3 4)",
+            "LINESTRING (1 2)",
+            "POLYGON ((0 0, 1 0, 1 1, 0 1))",
+            "BBOX (0, 1, 0, 1)",
+            "CIRCLE (1 2)",
+        ] {
+            assert_eq!(
+                Wkt::parse(value),
+                Err(ValidationError::InvalidWkt),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn limits_collection_depth() {
+        let nested = |depth: usize| {
+            format!(
+                "{}POINT (1 2){}",
+                "GEOMETRYCOLLECTION (".repeat(depth),
+                ")".repeat(depth)
+            )
+        };
+
+        assert!(Wkt::parse(&nested(MAX_COLLECTION_DEPTH)).is_ok());
+        assert_eq!(
+            Wkt::parse(&nested(MAX_COLLECTION_DEPTH + 1)),
+            Err(ValidationError::InvalidWkt)
+        );
+    }
+}
@@ -25,6 +25,17 @@ use crate::util::*;
 ///    .boost(1.3)
 ///    .name("test");
 /// ```
+/// Clauses accept anything that converts into `Option<Query>`, so `None` values produced by
+/// conditional iterator pipelines are skipped:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # let brand: Option<&str> = None;
+/// # let query =
+/// Query::bool().filter([
+///     brand.map(|brand| Query::from(Query::term("brand", brand))),
+///     Some(Query::from(Query::exists("price"))),
+/// ]);
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html>
 #[derive(Debug, Default, Clone, PartialEq, Serialize)]
 #[serde(remote = "Self")]
@@ -63,7 +74,7 @@ impl BoolQuery {
     pub fn must<T>(mut self, query: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.must.extend(query);
         self
@@ -73,7 +84,7 @@ impl BoolQuery {
     pub fn should<T>(mut self, query: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.should.extend(query);
         self
@@ -86,7 +97,7 @@ impl BoolQuery {
     pub fn filter<T>(mut self, query: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.filter.extend(query);
         self
@@ -99,7 +110,7 @@ impl BoolQuery {
     pub fn must_not<T>(mut self, query: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.must_not.extend(query);
         self
@@ -122,6 +133,42 @@ impl BoolQuery {
         self
     }
 
+    /// Returns the `must` clauses
+    pub fn musts(&self) -> &[Query] {
+        self.must.as_slice()
+    }
+
+    /// Returns the `filter` clauses
+    pub fn filters(&self) -> &[Query] {
+        self.filter.as_slice()
+    }
+
+    /// Returns the `should` clauses
+    pub fn shoulds(&self) -> &[Query] {
+        self.should.as_slice()
+    }
+
+    /// Returns the `must_not` clauses
+    pub fn must_nots(&self) -> &[Query] {
+        self.must_not.as_slice()
+    }
+
+    /// Returns the `minimum_should_match` parameter
+    pub fn get_minimum_should_match(&self) -> Option<&str> {
+        self.minimum_should_match.as_deref()
+    }
+
+    /// Mutable access to the clauses of all the occurrence types
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.must
+            .as_mut_slice()
+            .iter_mut()
+            .chain(self.filter.as_mut_slice())
+            .chain(self.should.as_mut_slice())
+            .chain(self.must_not.as_mut_slice())
+            .collect()
+    }
+
     add_boost_and_name!();
 }
 
@@ -216,4 +263,54 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn getters() {
+        let query = Query::bool()
+            .must(Query::term("test1", 1))
+            .filter([Query::term("tenant", 2), Query::term("test2", 3)])
+            .should(Query::term("test3", 4))
+            .must_not(Query::term("test4", 5))
+            .minimum_should_match("1");
+
+        assert_eq!(query.musts(), [Query::from(Query::term("test1", 1))]);
+        assert!(query
+            .filters()
+            .iter()
+            .any(|filter| filter == &Query::term("tenant", 2)));
+        assert_eq!(query.shoulds().len(), 1);
+        assert_eq!(query.must_nots().len(), 1);
+        assert_eq!(query.get_minimum_should_match(), Some("1"));
+        assert_eq!(Query::bool().get_minimum_should_match(), None);
+    }
+
+    #[test]
+    fn skips_optional_clauses() {
+        let brand: Option<&str> = None;
+        let min_price = Some(10);
+        let excluded = vec![("status", Some("archived")), ("owner", None)];
+
+        assert_serialize_query(
+            Query::bool()
+                .filter([
+                    brand.map(|brand| Query::term("brand", brand).into()),
+                    min_price.map(|price| Query::range("price").gte(price).into()),
+                ])
+                .must_not(
+                    excluded
+                        .into_iter()
+                        .map(|(field, value)| value.map(|value| Query::term(field, value).into())),
+                ),
+            json!({
+                "bool": {
+                    "filter": [
+                        { "range": { "price": { "gte": 10 } } },
+                    ],
+                    "must_not": [
+                        { "term": { "status": { "value": "archived" } } },
+                    ]
+                }
+            }),
+        );
+    }
 }
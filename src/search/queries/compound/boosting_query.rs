@@ -65,6 +65,21 @@ impl Query {
 }
 
 impl BoostingQuery {
+    /// Returns the `positive` query
+    pub fn positive(&self) -> &Query {
+        &self.positive
+    }
+
+    /// Returns the `negative` query
+    pub fn negative(&self) -> &Query {
+        &self.negative
+    }
+
+    /// Mutable access to the `positive` and `negative` queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.positive, &mut self.negative]
+    }
+
     add_boost_and_name!();
 }
 
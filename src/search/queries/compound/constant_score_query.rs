@@ -49,6 +49,16 @@ impl Query {
 }
 
 impl ConstantScoreQuery {
+    /// Returns the `filter` query
+    pub fn filter(&self) -> &Query {
+        &self.filter
+    }
+
+    /// Mutable access to the `filter` query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.filter]
+    }
+
     add_boost_and_name!();
 }
 
@@ -54,7 +54,7 @@ impl DisMaxQuery {
     pub fn query<T>(mut self, query: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.queries.extend(query);
         self
@@ -83,6 +83,16 @@ impl DisMaxQuery {
         self
     }
 
+    /// Returns the `queries` clauses
+    pub fn queries(&self) -> &[Query] {
+        self.queries.as_slice()
+    }
+
+    /// Mutable access to the `queries` clauses
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.queries.as_mut_slice().iter_mut().collect()
+    }
+
     add_boost_and_name!();
 }
 
@@ -97,9 +97,9 @@ impl FunctionScoreQuery {
     /// Maximum score value after applying all the functions
     pub fn max_boost<T>(mut self, max_boost: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.max_boost = Some(max_boost.as_());
+        self.max_boost = Some(max_boost.as_float());
         self
     }
 
@@ -128,6 +128,15 @@ impl FunctionScoreQuery {
         self
     }
 
+    /// Mutable access to the query and the function filters
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.query
+            .as_deref_mut()
+            .into_iter()
+            .chain(self.functions.iter_mut().filter_map(Function::filter_mut))
+            .collect()
+    }
+
     add_boost_and_name!();
 }
 
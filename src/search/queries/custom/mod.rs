@@ -1,5 +1,7 @@
 //! Non official queries, such as plugins or raw JSON queries
 
 mod json_query;
+mod raw_query;
 
 pub use self::json_query::*;
+pub use self::raw_query::*;
@@ -0,0 +1,133 @@
+use crate::search::*;
+use crate::util::*;
+use serde_json::value::RawValue;
+use std::sync::Arc;
+
+/// Pre-serialized query, which is spliced into the request as is.
+///
+/// Useful for static query fragments, such as tenant filters or permission clauses, which can be
+/// serialized once and then reused in every search request. Cloning a raw query is cheap as the
+/// serialized JSON is shared.
+///
+/// To create raw query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// let tenant_filter = Query::raw(Query::term("tenant_id", 42)).unwrap();
+///
+/// # let query =
+/// Query::bool()
+///     .filter(tenant_filter.clone())
+///     .must(Query::r#match("title", "elasticsearch"));
+/// ```
+/// **NOTE**: A raw query parsed from a string is only checked to be valid JSON, it can still
+/// lead to rejected search requests.
+#[derive(Clone)]
+pub struct RawQuery(Arc<RawValue>);
+
+impl Query {
+    /// Creates an instance of [`RawQuery`] by serializing the given query once
+    ///
+    /// - `query` - query to serialize
+    pub fn raw<T>(query: T) -> Result<RawQuery, serde_json::Error>
+    where
+        T: serde::Serialize,
+    {
+        serde_json::value::to_raw_value(&query).map(RawQuery::from)
+    }
+}
+
+impl RawQuery {
+    /// Returns the serialized JSON of the query
+    pub fn get(&self) -> &str {
+        self.0.get()
+    }
+}
+
+impl std::fmt::Debug for RawQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for RawQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq for RawQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for RawQuery {}
+
+impl std::str::FromStr for RawQuery {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str::<&RawValue>(s).map(Self::from)
+    }
+}
+
+impl From<Box<RawValue>> for RawQuery {
+    fn from(value: Box<RawValue>) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl<'a> From<&'a RawValue> for RawQuery {
+    fn from(value: &'a RawValue) -> Self {
+        Self::from(value.to_owned())
+    }
+}
+
+impl serde::Serialize for RawQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl ShouldSkip for RawQuery {
+    fn should_skip(&self) -> bool {
+        !self.get().trim_start().starts_with('{')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::raw(Query::term("user", "username")).unwrap(),
+            json!({ "term": { "user": { "value": "username" } } }),
+        );
+
+        assert_serialize_query(
+            Query::bool().filter(
+                " { \"term\": { \"user\": \"username\" } } "
+                    .parse::<RawQuery>()
+                    .unwrap(),
+            ),
+            json!({ "bool": { "filter": [{ "term": { "user": "username" } }] } }),
+        );
+    }
+
+    #[test]
+    fn skips_non_objects() {
+        assert!(Query::raw(json!(null)).unwrap().should_skip());
+        assert!(!Query::raw(json!({})).unwrap().should_skip());
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!("{ \"term\": ".parse::<RawQuery>().is_err());
+    }
+}
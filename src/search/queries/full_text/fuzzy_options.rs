@@ -0,0 +1,29 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Fuzzy matching parameters of the `match` and `multi_match` queries, which are boxed to keep
+/// the queries within the size budget of `Query` variants
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub(crate) struct FuzzyOptions {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) fuzziness: Option<Fuzziness>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) max_expansions: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) prefix_length: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) fuzzy_transpositions: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) fuzzy_rewrite: Option<Rewrite>,
+}
+
+impl FuzzyOptions {
+    /// Returns the options of the query, allocating them on the first use
+    pub(crate) fn get(options: &mut Option<Box<Self>>) -> &mut Self {
+        options.get_or_insert_with(Default::default)
+    }
+}
@@ -32,20 +32,8 @@ pub struct MatchQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     auto_generate_synonyms_phrase_query: Option<bool>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    fuzziness: Option<Fuzziness>,
-
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
-
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    prefix_length: Option<u8>,
-
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    fuzzy_transpositions: Option<bool>,
-
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    fuzzy_rewrite: Option<Rewrite>,
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    fuzzy: Option<Box<FuzzyOptions>>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     lenient: Option<bool>,
@@ -88,11 +76,7 @@ impl Query {
             query: query.into(),
             analyzer: None,
             auto_generate_synonyms_phrase_query: None,
-            fuzziness: None,
-            max_expansions: None,
-            prefix_length: None,
-            fuzzy_transpositions: None,
-            fuzzy_rewrite: None,
+            fuzzy: None,
             lenient: None,
             operator: None,
             minimum_should_match: None,
@@ -139,28 +123,37 @@ impl MatchQuery {
     where
         T: Into<Fuzziness>,
     {
-        self.fuzziness = Some(fuzziness.into());
+        FuzzyOptions::get(&mut self.fuzzy).fuzziness = Some(fuzziness.into());
         self
     }
 
+    /// Same as `fuzziness`, but fails on edit distances above `2` and reversed `AUTO` ranges.
+    pub fn try_fuzziness<T>(mut self, fuzziness: T) -> Result<Self, ValidationError>
+    where
+        T: Into<Fuzziness>,
+    {
+        FuzzyOptions::get(&mut self.fuzzy).fuzziness = Some(fuzziness.into().validate()?);
+        Ok(self)
+    }
+
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
     pub fn max_expansions(mut self, max_expansions: u8) -> Self {
-        self.max_expansions = Some(max_expansions);
+        FuzzyOptions::get(&mut self.fuzzy).max_expansions = Some(max_expansions);
         self
     }
 
     /// Number of beginning characters left unchanged for fuzzy matching.
     /// Defaults to `0`.
     pub fn prefix_length(mut self, prefix_length: u8) -> Self {
-        self.prefix_length = Some(prefix_length);
+        FuzzyOptions::get(&mut self.fuzzy).prefix_length = Some(prefix_length);
         self
     }
 
     /// If `true`, edits for fuzzy matching include transpositions of two
     /// adjacent characters (ab → ba). Defaults to `true`.
     pub fn fuzzy_transpositions(mut self, fuzzy_transpositions: bool) -> Self {
-        self.fuzzy_transpositions = Some(fuzzy_transpositions);
+        FuzzyOptions::get(&mut self.fuzzy).fuzzy_transpositions = Some(fuzzy_transpositions);
         self
     }
 
@@ -172,7 +165,7 @@ impl MatchQuery {
     /// `fuzzy_rewrite` method of `top_terms_blended_freqs_${max_expansions}`
     /// by default.
     pub fn fuzzy_rewrite(mut self, fuzzy_rewrite: Rewrite) -> Self {
-        self.fuzzy_rewrite = Some(fuzzy_rewrite);
+        FuzzyOptions::get(&mut self.fuzzy).fuzzy_rewrite = Some(fuzzy_rewrite);
         self
     }
 
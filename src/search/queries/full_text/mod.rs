@@ -5,6 +5,7 @@
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/full-text-queries.html>
 
 mod combined_fields_query;
+mod fuzzy_options;
 mod match_bool_prefix_query;
 mod match_phrase_prefix_query;
 mod match_phrase_query;
@@ -14,6 +15,7 @@ mod query_string_query;
 mod simple_query_string_query;
 
 pub use self::combined_fields_query::*;
+pub(crate) use self::fuzzy_options::*;
 pub use self::match_bool_prefix_query::*;
 pub use self::match_phrase_prefix_query::*;
 pub use self::match_phrase_query::*;
@@ -83,6 +83,12 @@ pub struct QueryStringQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     time_zone: Option<String>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#type: Option<TextQueryType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    tie_breaker: Option<f32>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
 
@@ -121,6 +127,8 @@ impl Query {
             quote_analyzer: None,
             rewrite: None,
             time_zone: None,
+            r#type: None,
+            tie_breaker: None,
             boost: None,
             _name: None,
         }
@@ -241,6 +249,15 @@ impl QueryStringQuery {
         self
     }
 
+    /// Same as `fuzziness`, but fails on edit distances above `2` and reversed `AUTO` ranges.
+    pub fn try_fuzziness<T>(mut self, fuzziness: T) -> Result<Self, ValidationError>
+    where
+        T: Into<Fuzziness>,
+    {
+        self.fuzziness = Some(fuzziness.into().validate()?);
+        Ok(self)
+    }
+
     /// Maximum number of terms to which the query expands for fuzzy matching.
     ///
     /// Defaults to `50`.
@@ -367,6 +384,24 @@ impl QueryStringQuery {
         self
     }
 
+    /// Determines how the query matches and scores documents when searching
+    /// multiple [`fields`](Self::fields), see
+    /// [Search multiple fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html#query-string-multi-field).
+    ///
+    /// Defaults to [`TextQueryType::BestFields`]
+    pub fn r#type(mut self, r#type: TextQueryType) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    /// Floating point number between `0` and `1.0` used to increase the
+    /// relevance scores of documents matching the query in multiple
+    /// [`fields`](Self::fields). Defaults to `0.0`.
+    pub fn tie_breaker(mut self, tie_breaker: f32) -> Self {
+        self.tie_breaker = Some(tie_breaker);
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -406,6 +441,8 @@ mod tests {
                 .lenient(true)
                 .minimum_should_match("22")
                 .quote_field_suffix("s")
+                .r#type(TextQueryType::CrossFields)
+                .tie_breaker(0.3)
                 .boost(2)
                 .name("test"),
             json!({
@@ -422,6 +459,8 @@ mod tests {
                     "lenient": true,
                     "minimum_should_match": "22",
                     "quote_field_suffix": "s",
+                    "type": "cross_fields",
+                    "tie_breaker": 0.3,
                     "boost": 2.0,
                     "_name": "test",
                 }
@@ -0,0 +1,102 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// Matches [geo_point](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+/// and [geo_shape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
+/// values that intersect a grid cell from a geo grid aggregation, e.g. to narrow the search down
+/// to a bucket of a `geotile_grid` aggregation.
+///
+/// To create a geo grid query:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let query =
+/// Query::geo_grid("location", GeoGridCell::geotile(6, 32, 22));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-grid-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct GeoGridQuery {
+    #[serde(skip)]
+    field: String,
+
+    #[serde(skip)]
+    cell: GeoGridCell,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`GeoGridQuery`]
+    ///
+    /// - `field` - Field you wish to search.
+    /// - `cell` - Grid cell the values should intersect
+    pub fn geo_grid<T>(field: T, cell: GeoGridCell) -> GeoGridQuery
+    where
+        T: ToString,
+    {
+        GeoGridQuery {
+            field: field.to_string(),
+            cell,
+            boost: None,
+            _name: None,
+        }
+    }
+}
+
+impl GeoGridQuery {
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for GeoGridQuery {}
+
+serialize_with_root_key_value_pair!("geo_grid": GeoGridQuery, field, cell);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::geo_grid("location", GeoGridCell::geohash("u0")),
+            json!({
+                "geo_grid": {
+                    "location": {
+                        "geohash": "u0"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::geo_grid("location", GeoGridCell::geotile(6, 32, 22))
+                .boost(2)
+                .name("test"),
+            json!({
+                "geo_grid": {
+                    "boost": 2.0,
+                    "_name": "test",
+                    "location": {
+                        "geotile": "6/32/22"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::geo_grid("location", GeoGridCell::geohex("811fbffffffffff")),
+            json!({
+                "geo_grid": {
+                    "location": {
+                        "geohex": "811fbffffffffff"
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// A query returning hits that only fall within a polygon of points.
+///
+/// Deprecated since Elasticsearch 7.12 in favour of the
+/// [`geo_shape`](crate::GeoShapeQuery) query, but still supported.
+///
+/// To create a geo polygon query:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let query =
+/// Query::geo_polygon(
+///     "person.location",
+///     [
+///         GeoLocation::new(40.0, -70.0),
+///         GeoLocation::new(30.0, -80.0),
+///         GeoLocation::new(20.0, -90.0),
+///     ],
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-polygon-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct GeoPolygonQuery {
+    #[serde(skip)]
+    field: String,
+
+    #[serde(skip)]
+    polygon: GeoPolygon,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    validation_method: Option<ValidationMethod>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct GeoPolygon {
+    points: Vec<GeoLocation>,
+}
+
+impl Query {
+    /// Creates an instance of [`GeoPolygonQuery`]
+    ///
+    /// - `field` - Field you wish to search.
+    /// - `points` - Vertices of the polygon
+    pub fn geo_polygon<T, U>(field: T, points: U) -> GeoPolygonQuery
+    where
+        T: ToString,
+        U: IntoIterator,
+        U::Item: Into<GeoLocation>,
+    {
+        GeoPolygonQuery {
+            field: field.to_string(),
+            polygon: GeoPolygon {
+                points: points.into_iter().map(Into::into).collect(),
+            },
+            validation_method: None,
+            boost: None,
+            _name: None,
+        }
+    }
+}
+
+impl GeoPolygonQuery {
+    /// Set to `IGNORE_MALFORMED` to accept geo points with invalid latitude or longitude, set to
+    /// `COERCE` to also try to infer correct latitude or longitude. (default is `STRICT`).
+    pub fn validation_method(mut self, validation_method: ValidationMethod) -> Self {
+        self.validation_method = Some(validation_method);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for GeoPolygonQuery {
+    fn should_skip(&self) -> bool {
+        self.polygon.points.is_empty()
+    }
+}
+
+serialize_with_root_key_value_pair!("geo_polygon": GeoPolygonQuery, field, polygon);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::geo_polygon(
+                "person.location",
+                [
+                    GeoLocation::new(40.0, -70.0),
+                    GeoLocation::new(30.0, -80.0),
+                    GeoLocation::new(20.0, -90.0),
+                ],
+            ),
+            json!({
+                "geo_polygon": {
+                    "person.location": {
+                        "points": [[-70.0, 40.0], [-80.0, 30.0], [-90.0, 20.0]]
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::geo_polygon("person.location", [GeoLocation::new(40.0, -70.0)])
+                .validation_method(ValidationMethod::IgnoreMalformed)
+                .boost(2)
+                .name("test"),
+            json!({
+                "geo_polygon": {
+                    "validation_method": "IGNORE_MALFORMED",
+                    "boost": 2.0,
+                    "_name": "test",
+                    "person.location": {
+                        "points": [[-70.0, 40.0]]
+                    }
+                }
+            }),
+        );
+    }
+}
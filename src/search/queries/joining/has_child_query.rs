@@ -123,6 +123,17 @@ impl HasChildQuery {
         self
     }
 
+    /// Mutable access to the query and the highlight queries of the inner hits
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        let mut queries = vec![&mut *self.query];
+
+        if let Some(inner_hits) = self.inner_hits.as_deref_mut() {
+            queries.extend(inner_hits.queries_mut());
+        }
+
+        queries
+    }
+
     add_boost_and_name!();
 }
 
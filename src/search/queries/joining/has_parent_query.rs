@@ -80,6 +80,11 @@ impl HasParentQuery {
         self
     }
 
+    /// Mutable access to the query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.query]
+    }
+
     add_boost_and_name!();
 }
 
@@ -18,9 +18,12 @@ pub mod params;
 pub mod compound;
 pub mod custom;
 pub mod full_text;
+#[cfg(feature = "geo")]
 pub mod geo;
 pub mod joining;
+#[cfg(feature = "geo")]
 pub mod shape;
+#[cfg(feature = "span")]
 pub mod span;
 pub mod specialized;
 pub mod term_level;
@@ -28,9 +31,12 @@ pub mod term_level;
 pub use self::compound::*;
 pub use self::custom::*;
 pub use self::full_text::*;
+#[cfg(feature = "geo")]
 pub use self::geo::*;
 pub use self::joining::*;
+#[cfg(feature = "geo")]
 pub use self::shape::*;
+#[cfg(feature = "span")]
 pub use self::span::*;
 pub use self::specialized::*;
 pub use self::term_level::*;
@@ -45,16 +51,64 @@ pub use self::match_none_query::*;
 pub use self::query_collection::*;
 
 use crate::util::*;
+use crate::RequestKey;
+
+/// Canonical conversion of a type into a [`Query`], implemented by all the query types. Implement
+/// it for application specific filter structs, so that they can be passed directly to functions
+/// expecting a query:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// struct ActiveUsers {
+///     country_id: u32,
+/// }
+///
+/// impl IntoQuery for ActiveUsers {
+///     fn into_query(self) -> Query {
+///         Query::bool()
+///             .filter(Query::term("status", "active"))
+///             .filter(Query::term("country_id", self.country_id))
+///             .into()
+///     }
+/// }
+///
+/// fn count<Q: IntoQuery>(query: Q) -> Search {
+///     Search::new().size(0).query(query.into_query())
+/// }
+///
+/// # let search =
+/// count(ActiveUsers { country_id: 1 });
+/// # let search =
+/// count(Query::term("user", "kimchy"));
+/// ```
+pub trait IntoQuery {
+    /// Converts the value into a [`Query`]
+    fn into_query(self) -> Query;
+}
+
+impl IntoQuery for Query {
+    fn into_query(self) -> Query {
+        self
+    }
+}
 
 macro_rules! query {
-    ($($variant:ident($query:ty)),+ $(,)?) => {
+    (@storage $query:ty) => { $query };
+    (@storage $query:ty, boxed) => { Box<$query> };
+    (@new $query:expr) => { $query };
+    (@new $query:expr, boxed) => { Box::new($query) };
+    ($($(#[$meta:meta])* $variant:ident($query:ty) $($boxed:ident)?),+ $(,)?) => {
         /// A container enum for supported Elasticsearch query types
+        ///
+        /// Serialization is keyed by the query name, e.g. `{ "term": { ... } }`. Queries are
+        /// serialize-only: the wrapped query types don't implement `Deserialize`, hence neither
+        /// does the container. A query stored as JSON can be sent as is with [`Query::json`].
         #[derive(Clone, PartialEq, Serialize)]
         #[serde(untagged)]
         #[allow(missing_docs)]
         pub enum Query {
             $(
-                $variant($query),
+                $(#[$meta])*
+                $variant(query!(@storage $query $(, $boxed)?)),
             )*
         }
 
@@ -75,6 +129,7 @@ macro_rules! query {
             fn should_skip(&self) -> bool {
                 match self {
                     $(
+                        $(#[$meta])*
                         Self::$variant(q) => q.should_skip(),
                     )+
                 }
@@ -85,6 +140,7 @@ macro_rules! query {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
                     $(
+                        $(#[$meta])*
                         Self::$variant(q) => q.fmt(f),
                     )+
                 }
@@ -92,40 +148,56 @@ macro_rules! query {
         }
 
         $(
+            $(#[$meta])*
             impl From<$query> for Query {
                 fn from(q: $query) -> Self {
-                    Query::$variant(q)
+                    Query::$variant(query!(@new q $(, $boxed)?))
                 }
             }
 
+            $(#[$meta])*
+            impl IntoQuery for $query {
+                fn into_query(self) -> Query {
+                    Query::$variant(query!(@new self $(, $boxed)?))
+                }
+            }
+
+            $(#[$meta])*
             impl PartialEq<$query> for Query {
                 fn eq(&self, other: &$query) -> bool {
                     match self {
-                        Self::$variant(query) => query.eq(other),
+                        Self::$variant(query) => {
+                            std::borrow::Borrow::<$query>::borrow(query).eq(other)
+                        }
                         _ => false,
                     }
                 }
             }
 
+            $(#[$meta])*
             impl PartialEq<Query> for $query {
                 fn eq(&self, other: &Query) -> bool {
                     match other {
-                        Query::$variant(query) => self.eq(query),
+                        Query::$variant(query) => {
+                            self.eq(std::borrow::Borrow::<$query>::borrow(query))
+                        }
                         _ => false,
                     }
                 }
             }
 
+            $(#[$meta])*
             impl From<$query> for Option<Query> {
                 fn from(q: $query) -> Self {
                     if q.should_skip() {
                         None
                     } else {
-                        Some(Query::$variant(q))
+                        Some(Query::$variant(query!(@new q $(, $boxed)?)))
                     }
                 }
             }
 
+            $(#[$meta])*
             impl IntoIterator for $query {
                 type Item = $query;
 
@@ -143,24 +215,29 @@ macro_rules! query {
     };
 }
 
+// Variants marked as `boxed` are stored on the heap, so that the largest queries don't inflate
+// the size of every `Query` and every clause list of compound queries
 query!(
     Bool(BoolQuery),
     Prefix(PrefixQuery),
-    Regexp(RegexpQuery),
+    Regexp(RegexpQuery) boxed,
     Wildcard(WildcardQuery),
-    TermsSet(TermsSetQuery),
+    TermsSet(TermsSetQuery) boxed,
     Term(TermQuery),
     Terms(TermsQuery),
-    TermsLookup(TermsLookupQuery),
+    TermsLookup(TermsLookupQuery) boxed,
     Exists(ExistsQuery),
     Range(RangeQuery),
     Ids(IdsQuery),
     ConstantScore(ConstantScoreQuery),
+    #[cfg(feature = "chrono")]
     DistanceFeatureDate(DistanceFeatureQuery<chrono::DateTime<chrono::Utc>>),
-    DistanceFeatureGeo(DistanceFeatureQuery<crate::GeoLocation>),
+    DistanceFeatureDateMath(DistanceFeatureQuery<crate::DateMath>) boxed,
+    DistanceFeatureAnyDate(DistanceFeatureQuery<crate::Date>) boxed,
+    DistanceFeatureGeo(DistanceFeatureQuery<crate::GeoLocation>) boxed,
     Match(MatchQuery),
-    MatchBoolPrefix(MatchBoolPrefixQuery),
-    MatchPhrasePrefix(MatchPhrasePrefixQuery),
+    MatchBoolPrefix(MatchBoolPrefixQuery) boxed,
+    MatchPhrasePrefix(MatchPhrasePrefixQuery) boxed,
     MatchAll(MatchAllQuery),
     MatchNone(MatchNoneQuery),
     MatchPhrase(MatchPhraseQuery),
@@ -169,44 +246,107 @@ query!(
     Boosting(BoostingQuery),
     DisMax(DisMaxQuery),
     Pinned(PinnedQuery),
+    #[cfg(feature = "percolate")]
     Percolate(PercolateQuery),
-    PercolateLookup(PercolateLookupQuery),
+    #[cfg(feature = "percolate")]
+    PercolateLookup(PercolateLookupQuery) boxed,
     FunctionScore(FunctionScoreQuery),
+    #[cfg(feature = "rank-feature")]
     RankFeature(RankFeatureQuery),
+    #[cfg(feature = "rank-feature")]
     RankFeatureSaturation(RankFeatureSaturationQuery),
+    #[cfg(feature = "rank-feature")]
     RankFeatureLogarithm(RankFeatureLogarithmQuery),
+    #[cfg(feature = "rank-feature")]
     RankFeatureSigmoid(RankFeatureSigmoidQuery),
+    #[cfg(feature = "rank-feature")]
     RankFeatureLinear(RankFeatureLinearQuery),
-    MoreLikeThis(MoreLikeThisQuery),
+    MoreLikeThis(MoreLikeThisQuery) boxed,
     Fuzzy(FuzzyQuery),
-    GeoDistance(GeoDistanceQuery),
-    GeoBoundingBox(GeoBoundingBoxQuery),
-    GeoShapeLookup(GeoShapeLookupQuery),
-    GeoShape(GeoShapeQuery),
-    ShapeLookup(ShapeLookupQuery),
+    #[cfg(feature = "geo")]
+    GeoDistance(GeoDistanceQuery) boxed,
+    #[cfg(feature = "geo")]
+    GeoBoundingBox(GeoBoundingBoxQuery) boxed,
+    #[cfg(feature = "geo")]
+    GeoPolygon(GeoPolygonQuery),
+    #[cfg(feature = "geo")]
+    GeoGrid(GeoGridQuery),
+    #[cfg(feature = "geo")]
+    GeoShapeLookup(GeoShapeLookupQuery) boxed,
+    #[cfg(feature = "geo")]
+    GeoShape(GeoShapeQuery) boxed,
+    #[cfg(feature = "geo")]
+    ShapeLookup(ShapeLookupQuery) boxed,
+    #[cfg(feature = "geo")]
     Shape(ShapeQuery),
     Json(JsonQuery),
+    Raw(RawQuery),
     Wrapper(WrapperQuery),
-    Script(ScriptQuery),
-    ScriptScore(ScriptScoreQuery),
+    Script(ScriptQuery) boxed,
+    ScriptScore(ScriptScoreQuery) boxed,
+    Semantic(SemanticQuery),
+    SparseVector(SparseVectorQuery) boxed,
     ParentId(ParentIdQuery),
     HasParent(HasParentQuery),
     HasChild(HasChildQuery),
-    SimpleQueryString(SimpleQueryStringQuery),
-    QueryString(QueryStringQuery),
-    CombinedFields(CombinedFieldsQuery),
+    SimpleQueryString(SimpleQueryStringQuery) boxed,
+    QueryString(QueryStringQuery) boxed,
+    CombinedFields(CombinedFieldsQuery) boxed,
+    #[cfg(feature = "span")]
     SpanContaining(SpanContainingQuery),
+    #[cfg(feature = "span")]
     SpanFieldMasking(SpanFieldMaskingQuery),
+    #[cfg(feature = "span")]
     SpanFirst(SpanFirstQuery),
+    #[cfg(feature = "span")]
     SpanMulti(SpanMultiQuery),
+    #[cfg(feature = "span")]
     SpanNear(SpanNearQuery),
+    #[cfg(feature = "span")]
     SpanNot(SpanNotQuery),
+    #[cfg(feature = "span")]
     SpanOr(SpanOrQuery),
+    #[cfg(feature = "span")]
     SpanTerm(SpanTermQuery),
+    #[cfg(feature = "span")]
     SpanWithin(SpanWithinQuery),
-    Knn(KnnQuery),
+    Knn(KnnQuery) boxed,
+    Neural(NeuralQuery) boxed,
 );
 
+impl Query {
+    /// Normalized representation of the query body, see [`RequestKey`]
+    pub fn key(&self) -> Result<RequestKey, serde_json::Error> {
+        RequestKey::new(self)
+    }
+
+    /// Renders the query as YAML, see [`Search::to_yaml`](crate::Search::to_yaml)
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_json::Error> {
+        yaml::to_string(self)
+    }
+
+    /// Mutable access to the queries nested directly in the query, empty for leaf queries as
+    /// well as for JSON, raw and wrapper queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        match self {
+            Self::Bool(query) => query.queries_mut(),
+            Self::Boosting(query) => query.queries_mut(),
+            Self::ConstantScore(query) => query.queries_mut(),
+            Self::DisMax(query) => query.queries_mut(),
+            Self::FunctionScore(query) => query.queries_mut(),
+            Self::HasChild(query) => query.queries_mut(),
+            Self::HasParent(query) => query.queries_mut(),
+            Self::Nested(query) => query.queries_mut(),
+            Self::Pinned(query) => query.queries_mut(),
+            Self::ScriptScore(query) => query.queries_mut(),
+            Self::Knn(query) => query.queries_mut(),
+            Self::Neural(query) => query.queries_mut(),
+            _ => Vec::new(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +362,43 @@ mod tests {
             Query::term("field", "value"),
         );
     }
+
+    #[test]
+    fn partial_eq_boxed() {
+        assert_eq!(
+            Query::from(Query::regexp("field", "value")),
+            Query::regexp("field", "value"),
+        );
+        assert_eq!(
+            Query::regexp("field", "value"),
+            Query::from(Query::regexp("field", "value")),
+        );
+        assert_ne!(
+            Query::from(Query::regexp("field", "value")),
+            Query::term("field", "value"),
+        );
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size() {
+        // The common leaf queries (`match`, `range`, etc.) are kept inline, variants larger than
+        // this have to be boxed
+        assert!(size_of::<Query>() <= 160, "{}", size_of::<Query>());
+    }
+
+    #[test]
+    fn key() {
+        let queries: std::collections::HashSet<_> = [
+            Query::from(Query::term("field", "value")),
+            Query::from(Query::term("field", "value")),
+            Query::from(Query::term("field", "other")),
+            Query::from(Query::term("field", "value").boost(2)),
+        ]
+        .iter()
+        .map(|query| query.key().unwrap())
+        .collect();
+
+        assert_eq!(queries.len(), 3);
+    }
 }
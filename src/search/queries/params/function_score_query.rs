@@ -1,15 +1,15 @@
 use crate::search::*;
 use crate::util::*;
-use chrono::{DateTime, Utc};
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use std::fmt::Debug;
 
 /// Each document is scored by the defined functions. The parameter `score_mode` specifies how
 /// the computed scores are combined
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionScoreMode {
     /// Scores are multiplied (default)
+    #[default]
     Multiply,
 
     /// Scores are summed
@@ -28,18 +28,13 @@ pub enum FunctionScoreMode {
     Min,
 }
 
-impl Default for FunctionScoreMode {
-    fn default() -> Self {
-        Self::Multiply
-    }
-}
-
 /// The newly computed score is combined with the score of the query. The parameter
 /// `boost_mode` defines how.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionBoostMode {
     /// Query score and function score is multiplied (default)
+    #[default]
     Multiply,
 
     /// Only function score is used, the query score is ignored
@@ -58,25 +53,21 @@ pub enum FunctionBoostMode {
     Min,
 }
 
-impl Default for FunctionBoostMode {
-    fn default() -> Self {
-        Self::Multiply
-    }
-}
-
 macro_rules! function {
-    ($name:ident { $($variant:ident($query:ty)),+ $(,)? }) => {
+    ($name:ident { $($(#[$meta:meta])* $variant:ident($query:ty)),+ $(,)? }) => {
         /// Functions available for use in [FunctionScoreQuery](crate::FunctionScoreQuery)
         #[derive(Debug, Clone, PartialEq, Serialize)]
         #[allow(missing_docs)]
         #[serde(untagged)]
         pub enum $name {
             $(
+                $(#[$meta])*
                 $variant($query),
             )*
         }
 
         $(
+            $(#[$meta])*
             impl From<$query> for $name {
                 fn from(q: $query) -> Self {
                     $name::$variant(q)
@@ -85,6 +76,7 @@ macro_rules! function {
         )+
 
         $(
+            $(#[$meta])*
             impl From<$query> for Option<$name> {
                 fn from(q: $query) -> Self {
                     Some($name::$variant(q))
@@ -98,7 +90,10 @@ function!(Function {
     Weight(Weight),
     RandomScore(RandomScore),
     FieldValueFactor(FieldValueFactor),
-    DecayDateTime(Decay<DateTime<Utc>>),
+    #[cfg(feature = "chrono")]
+    DecayDateTime(Decay<chrono::DateTime<chrono::Utc>>),
+    DecayDateMath(Decay<DateMath>),
+    DecayAnyDate(Decay<Date>),
     DecayLocation(Decay<GeoLocation>),
     DecayI8(Decay<i8>),
     DecayI16(Decay<i16>),
@@ -165,6 +160,31 @@ impl Function {
     pub fn script(source: Script) -> ScriptScore {
         ScriptScore::new(source)
     }
+
+    /// Mutable access to the filter of the function, [`None`] for `script_score` functions
+    pub(crate) fn filter_mut(&mut self) -> Option<&mut Query> {
+        match self {
+            Self::Weight(f) => f.filter.as_mut(),
+            Self::RandomScore(f) => f.filter.as_mut(),
+            Self::FieldValueFactor(f) => f.filter.as_mut(),
+            #[cfg(feature = "chrono")]
+            Self::DecayDateTime(f) => f.filter.as_mut(),
+            Self::DecayDateMath(f) => f.filter.as_mut(),
+            Self::DecayAnyDate(f) => f.filter.as_mut(),
+            Self::DecayLocation(f) => f.filter.as_mut(),
+            Self::DecayI8(f) => f.filter.as_mut(),
+            Self::DecayI16(f) => f.filter.as_mut(),
+            Self::DecayI32(f) => f.filter.as_mut(),
+            Self::DecayI64(f) => f.filter.as_mut(),
+            Self::DecayU8(f) => f.filter.as_mut(),
+            Self::DecayU16(f) => f.filter.as_mut(),
+            Self::DecayU32(f) => f.filter.as_mut(),
+            Self::DecayU64(f) => f.filter.as_mut(),
+            Self::DecayF32(f) => f.filter.as_mut(),
+            Self::DecayF64(f) => f.filter.as_mut(),
+            Self::ScriptScore(_) => None,
+        }
+    }
 }
 
 /// The `weight` score allows you to multiply the score by the provided weight.
@@ -252,9 +272,9 @@ impl RandomScore {
     /// The number value is of type float.
     pub fn weight<T>(mut self, weight: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.weight = Some(weight.as_());
+        self.weight = Some(weight.as_float());
         self
     }
 
@@ -285,7 +305,7 @@ impl RandomScore {
 /// As an example, imagine you have a document indexed with a numeric `my-int` field and wish to
 /// influence the score of a document with this field, an example doing so would look like:
 /// ```
-/// # use elasticsearch_dsl::{FieldValueFactor, FieldValueFactorModifier};
+/// # use elasticsearch_dsl::queries::{FieldValueFactor, FieldValueFactorModifier};
 /// # fn main() {
 /// # let _ =
 /// FieldValueFactor::new("my-int")
@@ -357,9 +377,9 @@ impl FieldValueFactor {
     /// The number value is of type float.
     pub fn weight<T>(mut self, weight: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.weight = Some(weight.as_());
+        self.weight = Some(weight.as_float());
         self
     }
 
@@ -437,7 +457,18 @@ pub trait Origin: Debug + PartialEq + Serialize + Clone {
     type Offset: Debug + PartialEq + Serialize + Clone;
 }
 
-impl Origin for DateTime<Utc> {
+#[cfg(feature = "chrono")]
+impl Origin for chrono::DateTime<chrono::Utc> {
+    type Scale = Time;
+    type Offset = Time;
+}
+
+impl Origin for DateMath {
+    type Scale = Time;
+    type Offset = Time;
+}
+
+impl Origin for Date {
     type Scale = Time;
     type Offset = Time;
 }
@@ -550,9 +581,9 @@ where
     /// The number value is of type float.
     pub fn weight<T>(mut self, weight: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.weight = Some(weight.as_());
+        self.weight = Some(weight.as_float());
         self
     }
 
@@ -650,10 +681,38 @@ impl ScriptScore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::prelude::*;
 
     #[test]
     fn serialization() {
+        assert_serialize(
+            Decay::new(DecayFunction::Linear, "test", 1, 2),
+            json!({
+                "linear": {
+                    "test": {
+                        "origin": 1,
+                        "scale": 2,
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            ScriptScore::new(Script::source("Math.log(2 + doc['my-int'].value)")),
+            json!({
+                "script_score": {
+                    "script": {
+                        "source": "Math.log(2 + doc['my-int'].value)"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_decay() {
+        use chrono::prelude::*;
+
         assert_serialize(
             Decay::new(
                 DecayFunction::Gauss,
@@ -670,25 +729,27 @@ mod tests {
                 }
             }),
         );
+    }
 
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_decay() {
         assert_serialize(
-            Decay::new(DecayFunction::Linear, "test", 1, 2),
+            Decay::new(
+                DecayFunction::Exp,
+                "test",
+                Date::from(
+                    time::OffsetDateTime::from_unix_timestamp(1_404_802_860)
+                        .unwrap()
+                        .to_offset(time::UtcOffset::from_hms(2, 0, 0).unwrap()),
+                ),
+                Time::Days(7),
+            ),
             json!({
-                "linear": {
+                "exp": {
                     "test": {
-                        "origin": 1,
-                        "scale": 2,
-                    }
-                }
-            }),
-        );
-
-        assert_serialize(
-            ScriptScore::new(Script::source("Math.log(2 + doc['my-int'].value)")),
-            json!({
-                "script_score": {
-                    "script": {
-                        "source": "Math.log(2 + doc['my-int'].value)"
+                        "origin": "2014-07-08T09:01:00+02:00",
+                        "scale": "7d",
                     }
                 }
             }),
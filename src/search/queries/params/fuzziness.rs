@@ -1,3 +1,4 @@
+use crate::search::ValidationError;
 use serde::{Serialize, Serializer};
 use std::ops::Range;
 
@@ -50,6 +51,20 @@ impl Serialize for Fuzziness {
     }
 }
 
+impl Fuzziness {
+    /// Returns the fuzziness if Elasticsearch accepts it: edit distances can't exceed `2` and the
+    /// low `AUTO` bound can't exceed the high one
+    pub fn validate(self) -> Result<Self, ValidationError> {
+        match self {
+            Self::Distance(distance) if distance > 2 => {
+                Err(ValidationError::InvalidFuzziness(self))
+            }
+            Self::Range(low, high) if low > high => Err(ValidationError::InvalidFuzziness(self)),
+            _ => Ok(self),
+        }
+    }
+}
+
 impl From<Range<u8>> for Fuzziness {
     fn from(v: Range<u8>) -> Self {
         Self::Range(v.start, v.end)
@@ -91,6 +106,27 @@ mod tests {
         assert_eq!(result, expectation);
     }
 
+    #[test]
+    fn validates() {
+        assert_eq!(Fuzziness::Auto.validate(), Ok(Fuzziness::Auto));
+        assert_eq!(
+            Fuzziness::Range(3, 6).validate(),
+            Ok(Fuzziness::Range(3, 6))
+        );
+        assert_eq!(
+            Fuzziness::Distance(2).validate(),
+            Ok(Fuzziness::Distance(2))
+        );
+        assert_eq!(
+            Fuzziness::Distance(3).validate(),
+            Err(ValidationError::InvalidFuzziness(Fuzziness::Distance(3)))
+        );
+        assert_eq!(
+            Fuzziness::Range(6, 3).validate(),
+            Err(ValidationError::InvalidFuzziness(Fuzziness::Range(6, 3)))
+        );
+    }
+
     #[test]
     fn serializes() {
         assert_serialize(
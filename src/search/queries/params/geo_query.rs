@@ -57,6 +57,43 @@ pub enum GeoBoundingBox {
     },
 }
 
+/// Cell of a geo grid, identified the same way as the bucket keys of the geo grid aggregations
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoGridCell {
+    /// Cell of a `geohash_grid` aggregation, e.g. `u0`
+    Geohash(String),
+
+    /// Cell of a `geotile_grid` aggregation in the `{zoom}/{x}/{y}` format, e.g. `6/32/22`
+    Geotile(String),
+
+    /// Cell of a `geohex_grid` aggregation, an H3 index, e.g. `811fbffffffffff`
+    Geohex(String),
+}
+
+impl GeoGridCell {
+    /// Creates a geohash cell
+    pub fn geohash<T>(geohash: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geohash(geohash.to_string())
+    }
+
+    /// Creates a geotile cell from its zoom level and tile coordinates
+    pub fn geotile(zoom: u8, x: u32, y: u32) -> Self {
+        Self::Geotile(format!("{zoom}/{x}/{y}"))
+    }
+
+    /// Creates a geohex cell from its H3 index
+    pub fn geohex<T>(geohex: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geohex(geohex.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
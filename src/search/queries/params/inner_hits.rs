@@ -41,7 +41,7 @@ pub struct InnerHits {
     highlight: Option<Highlight>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    docvalue_fields: Set<String>,
+    docvalue_fields: Set<FieldAndFormat>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     collapse: Option<InnerHitsCollapse>,
@@ -111,14 +111,15 @@ impl InnerHits {
         self
     }
 
-    /// A collection of docvalue fields
+    /// Fields to retrieve from the doc values of the inner hits, optionally with the format of
+    /// the values
     pub fn docvalue_fields<T>(mut self, docvalue_fields: T) -> Self
     where
         T: IntoIterator,
-        T::Item: ToString,
+        T::Item: Into<FieldAndFormat>,
     {
         self.docvalue_fields
-            .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+            .extend(docvalue_fields.into_iter().map(Into::into));
         self
     }
 
@@ -132,4 +133,12 @@ impl InnerHits {
         });
         self
     }
+
+    /// Mutable access to the highlight queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.highlight
+            .as_mut()
+            .map(Highlight::queries_mut)
+            .unwrap_or_default()
+    }
 }
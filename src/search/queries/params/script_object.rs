@@ -7,15 +7,15 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting.html>
 
-use crate::{util::*, Map};
-use serde::{Serialize, Serializer};
+use crate::{util::*, Map, Term};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Wherever scripting is supported in the Elasticsearch APIs, the syntax follows the same pattern;
 /// you specify the language of your script, provide the script logic (or source, and add parameters
 /// that are passed into the script.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Script {
     #[serde(flatten)]
     source: ScriptSource,
@@ -23,15 +23,74 @@ pub struct Script {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     lang: Option<ScriptLang>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    params: Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    params: Map<String, ScriptParam>,
+}
+
+/// Script parameter value, scalars are kept as typed terms and only compound values, such as
+/// arrays and objects, are converted into JSON
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+enum ScriptParam {
+    Term(Term),
+    Json(serde_json::Value),
+}
+
+impl ScriptParam {
+    fn new<T>(param: T) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        match Term::scalar(&param) {
+            Some(term) => Ok(Self::Term(term)),
+            None => serde_json::to_value(param).map(Self::Json),
+        }
+    }
+
+    fn from_value(value: serde_json::Value) -> Self {
+        match Term::scalar(&value) {
+            Some(term) => Self::Term(term),
+            None => Self::Json(value),
+        }
+    }
+}
+
+/// Floats are compared bitwise, unlike [`Term`]s, which are compared across precisions, so that
+/// the comparison is an equivalence relation like the one of JSON parameter values
+impl PartialEq for ScriptParam {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Term(Term::Float32(value)), Self::Term(Term::Float32(other))) => {
+                value.to_bits() == other.to_bits()
+            }
+            (Self::Term(Term::Float64(value)), Self::Term(Term::Float64(other))) => {
+                value.to_bits() == other.to_bits()
+            }
+            (Self::Term(Term::Float32(_) | Term::Float64(_)), _)
+            | (_, Self::Term(Term::Float32(_) | Term::Float64(_))) => false,
+            (Self::Term(value), Self::Term(other)) => value == other,
+            (Self::Json(value), Self::Json(other)) => value == other,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ScriptParam {}
+
+impl<'de> Deserialize<'de> for ScriptParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_json::Value::deserialize(deserializer).map(Self::from_value)
+    }
 }
 
 /// The script itself, which you specify as `source` for an inline script or
 /// `id` for a stored script. Use the
 /// [stored script APIs](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#prefer-params)
 /// to create and manage stored scripts.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ScriptSource {
     /// Inline script
@@ -82,11 +141,57 @@ impl Script {
         S: ToString,
         T: Serialize,
     {
-        if let Ok(param) = serde_json::to_value(param) {
+        if let Ok(param) = ScriptParam::new(param) {
             let _ = self.params.entry(name.to_string()).or_insert(param);
         }
         self
     }
+
+    /// Same as `param`, but fails if the parameter can't be serialized into JSON instead of
+    /// skipping it.
+    pub fn try_param<T, S>(mut self, name: S, param: T) -> Result<Self, serde_json::Error>
+    where
+        S: ToString,
+        T: Serialize,
+    {
+        let param = ScriptParam::new(param)?;
+        let _ = self.params.entry(name.to_string()).or_insert(param);
+        Ok(self)
+    }
+
+    /// Passes the fields of a struct (or entries of a map) as the named parameters of the
+    /// script, so that the parameters of a script can be defined as a single typed struct.
+    /// Fails if `params` doesn't serialize into a JSON object.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// #[derive(serde::Serialize)]
+    /// struct Params {
+    ///     factor: f32,
+    ///     offset: u32,
+    /// }
+    ///
+    /// let script = Script::source("doc['price'].value * params.factor + params.offset")
+    ///     .params(Params { factor: 1.5, offset: 10 })
+    ///     .unwrap();
+    /// ```
+    pub fn params<T>(mut self, params: T) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        match serde_json::to_value(params)? {
+            serde_json::Value::Object(params) => {
+                for (name, param) in params {
+                    let param = ScriptParam::from_value(param);
+                    let _ = self.params.entry(name).or_insert(param);
+                }
+                Ok(self)
+            }
+            _ => Err(serde::ser::Error::custom(
+                "script params must serialize into a JSON object",
+            )),
+        }
+    }
 }
 /// Available scripting language
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -144,6 +249,15 @@ impl Serialize for ScriptLang {
     }
 }
 
+impl<'de> Deserialize<'de> for ScriptLang {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
 impl<T> From<T> for ScriptLang
 where
     T: ToString,
@@ -192,6 +306,45 @@ mod tests {
             }),
         );
 
+        #[derive(Serialize)]
+        struct Params {
+            multiplier: u32,
+            fields: Vec<&'static str>,
+        }
+
+        assert_serialize(
+            Script::source("params.multiplier")
+                .params(Params {
+                    multiplier: 2,
+                    fields: vec!["price"],
+                })
+                .unwrap(),
+            json!({
+                "source": "params.multiplier",
+                "params": {
+                    "multiplier": 2,
+                    "fields": ["price"]
+                }
+            }),
+        );
+
+        assert_eq!(
+            Script::source("params.multiplier")
+                .params(Params {
+                    multiplier: 2,
+                    fields: vec!["price"],
+                })
+                .unwrap(),
+            Script::source("params.multiplier")
+                .param("multiplier", 2)
+                .param("fields", ["price"]),
+        );
+
+        assert!(Script::source("params.multiplier").params(2).is_err());
+        assert!(Script::source("params.multiplier")
+            .try_param("map", Map::from([((1, 2), 3)]))
+            .is_err());
+
         assert_serialize(
             Script::id(123).param("multiplier", [1, 2, 3]),
             json!({
@@ -202,4 +355,37 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn compares_float_params_exactly() {
+        let nan = Script::source("params.x").param("x", f64::NAN);
+
+        assert_eq!(nan, nan.clone());
+        assert_eq!(
+            Script::source("params.x").param("x", 0.1f32),
+            Script::source("params.x").param("x", 0.1f32)
+        );
+        assert_ne!(
+            Script::source("params.x").param("x", 0.1f32),
+            Script::source("params.x").param("x", 0.1f64)
+        );
+        assert_ne!(
+            Script::source("params.x").param("x", 0.0),
+            Script::source("params.x").param("x", -0.0)
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        assert_eq!(
+            serde_json::from_value::<Script>(json!({
+                "source": "params.factor * params.fields.length",
+                "params": { "factor": 1.5, "fields": ["a", "b"] }
+            }))
+            .unwrap(),
+            Script::source("params.factor * params.fields.length")
+                .param("factor", 1.5)
+                .param("fields", ["a", "b"]),
+        );
+    }
 }
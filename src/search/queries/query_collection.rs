@@ -28,16 +28,26 @@ impl ShouldSkip for QueryCollection {
 }
 
 impl QueryCollection {
-    /// Extends query collection
+    /// Returns the queries of the collection
+    pub fn as_slice(&self) -> &[Query] {
+        &self.0
+    }
+
+    /// Mutable access to the queries of the collection
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [Query] {
+        &mut self.0
+    }
+
+    /// Extends query collection, skipping `None` and empty queries
     pub fn extend<T>(&mut self, query: T)
     where
         T: IntoIterator,
-        T::Item: Into<Query>,
+        T::Item: Into<Option<Query>>,
     {
         self.0.extend(
             query
                 .into_iter()
-                .map(Into::into)
+                .filter_map(Into::into)
                 .filter(ShouldSkip::should_keep),
         )
     }
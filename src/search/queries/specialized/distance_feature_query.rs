@@ -1,6 +1,5 @@
 use crate::search::*;
 use crate::util::*;
-use chrono::{DateTime, Utc};
 use serde::ser::Serialize;
 use std::fmt::Debug;
 
@@ -9,7 +8,16 @@ pub trait Origin: Debug + PartialEq + Serialize + Clone {
     type Pivot: Debug + PartialEq + Serialize + Clone;
 }
 
-impl Origin for DateTime<Utc> {
+#[cfg(feature = "chrono")]
+impl Origin for chrono::DateTime<chrono::Utc> {
+    type Pivot = Time;
+}
+
+impl Origin for DateMath {
+    type Pivot = Time;
+}
+
+impl Origin for Date {
     type Pivot = Time;
 }
 
@@ -57,7 +65,8 @@ impl Origin for GeoLocation {
 ///
 /// To create distance feature query date query:
 /// ```
-/// # use elasticsearch_dsl::Time;
+/// # #[cfg(feature = "chrono")] {
+/// # use elasticsearch_dsl::search::Time;
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # use chrono::prelude::*;
@@ -65,10 +74,11 @@ impl Origin for GeoLocation {
 /// Query::distance_feature("test", Utc.with_ymd_and_hms(2014, 7, 8, 9, 1, 0).unwrap(), Time::Days(7))
 ///     .boost(1.5)
 ///     .name("test");
+/// # }
 /// ```
 /// To create distance feature query geo query:
 /// ```
-/// # use elasticsearch_dsl::{Distance, GeoLocation};
+/// # use elasticsearch_dsl::search::{Distance, GeoLocation};
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # let query =
@@ -79,7 +89,7 @@ impl Origin for GeoLocation {
 /// Distance Feature is built to allow only valid origin and pivot values,
 /// the following won't compile:
 /// ```compile_fail
-/// # use elasticsearch_dsl::Distance;
+/// # use elasticsearch_dsl::search::Distance;
 /// # use chrono::prelude::*;
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
@@ -171,16 +181,21 @@ where
 
 impl<O> ShouldSkip for DistanceFeatureQuery<O> where O: Origin {}
 
-serialize_with_root!("distance_feature": DistanceFeatureQuery<DateTime<Utc>>);
+#[cfg(feature = "chrono")]
+serialize_with_root!("distance_feature": DistanceFeatureQuery<chrono::DateTime<chrono::Utc>>);
+serialize_with_root!("distance_feature": DistanceFeatureQuery<DateMath>);
+serialize_with_root!("distance_feature": DistanceFeatureQuery<Date>);
 serialize_with_root!("distance_feature": DistanceFeatureQuery<GeoLocation>);
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::prelude::*;
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn serialization() {
+    fn chrono_serialization() {
+        use chrono::prelude::*;
+
         assert_serialize_query(
             Query::distance_feature(
                 "test",
@@ -214,6 +229,66 @@ mod tests {
                 }
             }),
         );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_serialization() {
+        assert_serialize_query(
+            Query::distance_feature(
+                "test",
+                Date::from(time::OffsetDateTime::from_unix_timestamp(1_404_810_060).unwrap()),
+                Time::Days(7),
+            ),
+            json!({
+                "distance_feature": {
+                    "field": "test",
+                    "origin": "2014-07-08T09:01:00Z",
+                    "pivot": "7d",
+                }
+            }),
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_serialization() {
+        assert_serialize_query(
+            Query::distance_feature(
+                "test",
+                Date::from(jiff::Timestamp::from_second(1_404_810_060).unwrap()),
+                Time::Days(7),
+            ),
+            json!({
+                "distance_feature": {
+                    "field": "test",
+                    "origin": "2014-07-08T09:01:00Z",
+                    "pivot": "7d",
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn date_math_serialization() {
+        assert_serialize_query(
+            Query::distance_feature(
+                "test",
+                DateMath::now().round(DateMathUnit::Days),
+                Time::Days(7),
+            ),
+            json!({
+                "distance_feature": {
+                    "field": "test",
+                    "origin": "now/d",
+                    "pivot": "7d",
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization() {
         assert_serialize_query(
             Query::distance_feature(
                 "test",
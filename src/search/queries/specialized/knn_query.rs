@@ -8,9 +8,8 @@ use serde::Serialize;
 /// [top level knn section](https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html) of a
 /// search request. _knn_ query is reserved for expert cases, where there is a need to combine this query with other queries.
 ///
-/// > `knn` query doesn’t have a separate `k` parameter. `k` is defined by `size` parameter of a search request
-/// > similar to other queries. `knn` query collects `num_candidates` results from each shard, then merges them to get
-/// > the top `size` results.
+/// > `knn` query collects `k` results from each shard, then merges them to get the top `size` results. When `k` is
+/// > not set, it is defined by the `size` parameter of a search request similar to other queries.
 ///
 /// To create a knn query:
 /// ```
@@ -26,11 +25,14 @@ pub struct KnnQuery {
 
     query_vector: Vec<f32>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    k: Option<u32>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     num_candidates: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    filter: Option<Box<Query>>,
+    filter: QueryCollection,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     similarity: Option<f32>,
@@ -43,6 +45,13 @@ pub struct KnnQuery {
 }
 
 impl KnnQuery {
+    /// The number of nearest neighbors to return from each shard. Must be less than or equal to
+    /// `num_candidates`. Defaults to the search request `size`.
+    pub fn k(mut self, k: u32) -> Self {
+        self.k = Some(k);
+        self
+    }
+
     /// The number of nearest neighbor candidates to consider per shard. Cannot exceed 10,000. Elasticsearch collects
     /// `num_candidates` results from each shard, then merges them to find the top results. Increasing `num_candidates`
     /// tends to improve the accuracy of the final results. Defaults to `Math.min(1.5 * size, 10_000)`.
@@ -57,11 +66,14 @@ impl KnnQuery {
     ///
     /// The filter is a pre-filter, meaning that it is applied **during** the approximate kNN search to ensure that
     /// `num_candidates` matching documents are returned.
+    ///
+    /// Calling this method multiple times accumulates the filter queries.
     pub fn filter<T>(mut self, filter: T) -> Self
     where
-        T: Into<Query>,
+        T: IntoIterator,
+        T::Item: Into<Option<Query>>,
     {
-        self.filter = Some(Box::new(filter.into()));
+        self.filter.extend(filter);
         self
     }
 
@@ -73,6 +85,47 @@ impl KnnQuery {
         self
     }
 
+    /// Mutable access to the `filter` queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.filter.as_mut_slice().iter_mut().collect()
+    }
+
+    /// Converts the query into the `knn` query of the OpenSearch k-NN plugin, keyed by the field
+    /// and taking the `vector` and `k` parameters. `k` defaults to the search request `size`.
+    pub(crate) fn opensearch(&self, size: u64) -> Result<Query, ValidationError> {
+        if self.similarity.is_some() {
+            return Err(ValidationError::Unsupported {
+                feature: "knn query similarity",
+                version: EsVersion::OpenSearch2,
+            });
+        }
+
+        let mut body = serde_json::Map::new();
+
+        let _ = body.insert("vector".into(), serde_json::json!(self.query_vector));
+        let _ = body.insert("k".into(), self.k.map_or(size, u64::from).into());
+
+        let filter = match self.filter.as_slice() {
+            [] => None,
+            [filter] => Some(filter.clone()),
+            filters => Some(Query::bool().filter(filters.to_vec()).into()),
+        };
+
+        if let Some(filter) = filter {
+            let _ = body.insert("filter".into(), serde_json::json!(filter));
+        }
+
+        if let Some(boost) = self.boost {
+            let _ = body.insert("boost".into(), serde_json::json!(boost));
+        }
+
+        if let Some(name) = &self._name {
+            let _ = body.insert("_name".into(), serde_json::json!(name));
+        }
+
+        Ok(Query::json(serde_json::json!({ "knn": { self.field.as_str(): body } })).into())
+    }
+
     add_boost_and_name!();
 }
 
@@ -93,8 +146,9 @@ impl Query {
         KnnQuery {
             field: field.to_string(),
             query_vector,
+            k: None,
             num_candidates: None,
-            filter: None,
+            filter: Default::default(),
             similarity: None,
             boost: None,
             _name: None,
@@ -120,6 +174,7 @@ mod tests {
 
         assert_serialize_query(
             Query::knn("test", vec![1.0, 2.0, 3.0])
+                .k(10)
                 .num_candidates(100)
                 .filter(Query::term("field", "value"))
                 .similarity(0.5)
@@ -129,14 +184,11 @@ mod tests {
                 "knn": {
                     "field": "test",
                     "query_vector": [1.0, 2.0, 3.0],
+                    "k": 10,
                     "num_candidates": 100,
-                    "filter": {
-                        "term": {
-                            "field": {
-                                "value": "value"
-                            }
-                        }
-                    },
+                    "filter": [
+                        { "term": { "field": { "value": "value" } } }
+                    ],
                     "similarity": 0.5,
                     "boost": 2.0,
                     "_name": "test"
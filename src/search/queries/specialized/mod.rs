@@ -3,21 +3,33 @@
 mod distance_feature_query;
 mod knn_query;
 mod more_like_this_query;
+mod neural_query;
+#[cfg(feature = "percolate")]
 mod percolate_lookup_query;
+#[cfg(feature = "percolate")]
 mod percolate_query;
 mod pinned_query;
+#[cfg(feature = "rank-feature")]
 mod rank_feature_query;
 mod script_query;
 mod script_score_query;
+mod semantic_query;
+mod sparse_vector_query;
 mod wrapper_query;
 
 pub use self::distance_feature_query::*;
 pub use self::knn_query::*;
 pub use self::more_like_this_query::*;
+pub use self::neural_query::*;
+#[cfg(feature = "percolate")]
 pub use self::percolate_lookup_query::*;
+#[cfg(feature = "percolate")]
 pub use self::percolate_query::*;
 pub use self::pinned_query::*;
+#[cfg(feature = "rank-feature")]
 pub use self::rank_feature_query::*;
 pub use self::script_query::*;
 pub use self::script_score_query::*;
+pub use self::semantic_query::*;
+pub use self::sparse_vector_query::*;
 pub use self::wrapper_query::*;
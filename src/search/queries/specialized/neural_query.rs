@@ -0,0 +1,190 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// OpenSearch semantic search query, the query text (or image) is turned into a vector by the
+/// given machine learning model and the _k_ nearest documents of the vector field are returned.
+///
+/// The query is only understood by OpenSearch `2.x` clusters, use
+/// [`Search::serialize_for`](crate::Search::serialize_for) with [`EsVersion::OpenSearch2`] to
+/// catch it being sent to Elasticsearch.
+///
+/// To create a neural query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # let query =
+/// Query::neural("passage_embedding", "wild west")
+///     .model_id("aVeif4oB5Vm0Tdw8zYO2")
+///     .k(5);
+/// ```
+/// <https://opensearch.org/docs/latest/query-dsl/specialized/neural/>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct NeuralQuery {
+    #[serde(skip)]
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_text: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_image: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    model_id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    k: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_score: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_distance: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`NeuralQuery`]
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_text` - The text to convert into a query vector
+    pub fn neural<T, U>(field: T, query_text: U) -> NeuralQuery
+    where
+        T: ToString,
+        U: ToString,
+    {
+        NeuralQuery {
+            field: field.to_string(),
+            query_text: Some(query_text.to_string()),
+            query_image: None,
+            model_id: None,
+            k: None,
+            min_score: None,
+            max_distance: None,
+            filter: None,
+            boost: None,
+            _name: None,
+        }
+    }
+}
+
+impl NeuralQuery {
+    /// Base64 encoded image to convert into a query vector, for multimodal models
+    pub fn query_image<T>(mut self, query_image: T) -> Self
+    where
+        T: ToString,
+    {
+        self.query_image = Some(query_image.to_string());
+        self
+    }
+
+    /// The ID of the model used to generate the query vector. Can be omitted when a default model
+    /// is set for the index or the field by the `neural_query_enricher` search pipeline.
+    pub fn model_id<T>(mut self, model_id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.model_id = Some(model_id.to_string());
+        self
+    }
+
+    /// The number of nearest neighbors to return
+    pub fn k(mut self, k: u32) -> Self {
+        self.k = Some(k);
+        self
+    }
+
+    /// The minimum score a document must have to be returned, replaces `k` for radial search
+    pub fn min_score<T>(mut self, min_score: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.min_score = Some(min_score.as_float());
+        self
+    }
+
+    /// The maximum distance a document vector may have from the query vector to be returned,
+    /// replaces `k` for radial search
+    pub fn max_distance<T>(mut self, max_distance: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.max_distance = Some(max_distance.as_float());
+        self
+    }
+
+    /// Query to filter the documents that can match during the nearest neighbor search
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Option<Query>>,
+    {
+        self.filter = filter.into();
+        self
+    }
+
+    /// Mutable access to the `filter` query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.filter.iter_mut().collect()
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for NeuralQuery {}
+
+serialize_with_root_keyed!("neural": NeuralQuery);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::neural("passage_embedding", "wild west"),
+            json!({
+                "neural": {
+                    "passage_embedding": {
+                        "query_text": "wild west"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::neural("passage_embedding", "wild west")
+                .query_image("aGVsbG8=")
+                .model_id("model")
+                .k(5)
+                .min_score(0.5)
+                .max_distance(2)
+                .filter(Query::term("genre", "western"))
+                .boost(2)
+                .name("test"),
+            json!({
+                "neural": {
+                    "passage_embedding": {
+                        "query_text": "wild west",
+                        "query_image": "aGVsbG8=",
+                        "model_id": "model",
+                        "k": 5,
+                        "min_score": 0.5,
+                        "max_distance": 2.0,
+                        "filter": { "term": { "genre": { "value": "western" } } },
+                        "boost": 2.0,
+                        "_name": "test"
+                    }
+                }
+            }),
+        );
+    }
+}
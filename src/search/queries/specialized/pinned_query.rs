@@ -47,6 +47,11 @@ impl Query {
 }
 
 impl PinnedQuery {
+    /// Mutable access to the `organic` query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.organic]
+    }
+
     add_boost_and_name!();
 }
 
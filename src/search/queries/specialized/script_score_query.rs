@@ -55,6 +55,11 @@ impl Query {
 }
 
 impl ScriptScoreQuery {
+    /// Mutable access to the query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        vec![&mut self.query]
+    }
+
     add_boost_and_name!();
 }
 
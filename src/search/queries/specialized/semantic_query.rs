@@ -0,0 +1,90 @@
+use crate::search::*;
+use crate::util::*;
+use serde::Serialize;
+
+/// Searches a [`semantic_text`](https://www.elastic.co/guide/en/elasticsearch/reference/current/semantic-text.html)
+/// field, the query text is turned into embeddings with the inference endpoint of the field.
+///
+/// To create a semantic query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # let query =
+/// Query::semantic("inference_field", "Best surfing places");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-semantic-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct SemanticQuery {
+    field: String,
+
+    query: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SemanticQuery`]
+    ///
+    /// - `field` - The `semantic_text` field to search against
+    /// - `query` - The text to search for
+    pub fn semantic<T, U>(field: T, query: U) -> SemanticQuery
+    where
+        T: ToString,
+        U: ToString,
+    {
+        SemanticQuery {
+            field: field.to_string(),
+            query: query.to_string(),
+            boost: None,
+            _name: None,
+        }
+    }
+}
+
+impl SemanticQuery {
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for SemanticQuery {
+    fn should_skip(&self) -> bool {
+        self.query.should_skip()
+    }
+}
+
+serialize_with_root!("semantic": SemanticQuery);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::semantic("inference_field", "Best surfing places"),
+            json!({
+                "semantic": {
+                    "field": "inference_field",
+                    "query": "Best surfing places"
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::semantic("inference_field", "Best surfing places")
+                .boost(2)
+                .name("test"),
+            json!({
+                "semantic": {
+                    "field": "inference_field",
+                    "query": "Best surfing places",
+                    "boost": 2.0,
+                    "_name": "test"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::indices::TokenPruningConfig;
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+use serde::Serialize;
+
+/// Searches a [`sparse_vector`](https://www.elastic.co/guide/en/elasticsearch/reference/current/sparse-vector.html)
+/// field with weighted tokens, either given directly or inferred from a text by a sparse
+/// embedding model such as ELSER.
+///
+/// To create a sparse vector query:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # let query =
+/// Query::sparse_vector_inference("ml.tokens", "my-elser-model", "How is the weather in Jamaica?")
+///     .prune(true);
+/// # let query =
+/// Query::sparse_vector("ml.tokens", [("jamaica", 2.5), ("weather", 1.2)]);
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-sparse-vector-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(remote = "Self")]
+pub struct SparseVectorQuery {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inference_id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<Map<String, f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prune: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pruning_config: Option<TokenPruningConfig>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+impl Query {
+    /// Creates an instance of [`SparseVectorQuery`] with precomputed token weights
+    ///
+    /// - `field` - The `sparse_vector` field to search against
+    /// - `query_vector` - Tokens with their weights
+    pub fn sparse_vector<T, I, K>(field: T, query_vector: I) -> SparseVectorQuery
+    where
+        T: ToString,
+        I: IntoIterator<Item = (K, f32)>,
+        K: ToString,
+    {
+        SparseVectorQuery {
+            query_vector: Some(
+                query_vector
+                    .into_iter()
+                    .map(|(token, weight)| (token.to_string(), weight))
+                    .collect(),
+            ),
+            ..SparseVectorQuery::new(field)
+        }
+    }
+
+    /// Creates an instance of [`SparseVectorQuery`] with token weights inferred from a text
+    ///
+    /// - `field` - The `sparse_vector` field to search against
+    /// - `inference_id` - The inference endpoint converting the query text into tokens
+    /// - `query` - The text to convert into tokens
+    pub fn sparse_vector_inference<T, U, V>(
+        field: T,
+        inference_id: U,
+        query: V,
+    ) -> SparseVectorQuery
+    where
+        T: ToString,
+        U: ToString,
+        V: ToString,
+    {
+        SparseVectorQuery {
+            inference_id: Some(inference_id.to_string()),
+            query: Some(query.to_string()),
+            ..SparseVectorQuery::new(field)
+        }
+    }
+}
+
+impl SparseVectorQuery {
+    fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            inference_id: None,
+            query: None,
+            query_vector: None,
+            prune: None,
+            pruning_config: None,
+            boost: None,
+            _name: None,
+        }
+    }
+
+    /// Whether to perform pruning, omitting the non-significant tokens from the query to improve
+    /// query performance
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = Some(prune);
+        self
+    }
+
+    /// Token pruning configuration, only used if `prune` is set to `true`
+    pub fn pruning_config(mut self, pruning_config: TokenPruningConfig) -> Self {
+        self.pruning_config = Some(pruning_config);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for SparseVectorQuery {
+    fn should_skip(&self) -> bool {
+        self.query.as_deref().is_none_or(ShouldSkip::should_skip)
+            && self.query_vector.as_ref().is_none_or(Map::is_empty)
+    }
+}
+
+serialize_with_root!("sparse_vector": SparseVectorQuery);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::sparse_vector("ml.tokens", [("jamaica", 2.5), ("weather", 1.0)]),
+            json!({
+                "sparse_vector": {
+                    "field": "ml.tokens",
+                    "query_vector": { "jamaica": 2.5, "weather": 1.0 }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::sparse_vector_inference("ml.tokens", "my-elser-model", "weather in Jamaica")
+                .prune(true)
+                .pruning_config(
+                    TokenPruningConfig::new()
+                        .tokens_freq_ratio_threshold(5.0)
+                        .tokens_weight_threshold(0.5)
+                        .only_score_pruned_tokens(false),
+                )
+                .boost(2)
+                .name("test"),
+            json!({
+                "sparse_vector": {
+                    "field": "ml.tokens",
+                    "inference_id": "my-elser-model",
+                    "query": "weather in Jamaica",
+                    "prune": true,
+                    "pruning_config": {
+                        "tokens_freq_ratio_threshold": 5.0,
+                        "tokens_weight_threshold": 0.5,
+                        "only_score_pruned_tokens": false
+                    },
+                    "boost": 2.0,
+                    "_name": "test"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::sparse_vector("ml.tokens", Vec::<(String, f32)>::new()).should_skip());
+        assert!(Query::sparse_vector_inference("ml.tokens", "model", " ").should_skip());
+    }
+}
@@ -99,6 +99,15 @@ impl FuzzyQuery {
         self
     }
 
+    /// Same as `fuzziness`, but fails on edit distances above `2` and reversed `AUTO` ranges.
+    pub fn try_fuzziness<T>(mut self, fuzziness: T) -> Result<Self, ValidationError>
+    where
+        T: Into<Fuzziness>,
+    {
+        self.fuzziness = Some(fuzziness.into().validate()?);
+        Ok(self)
+    }
+
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
     pub fn max_expansions(mut self, max_expansions: u8) -> Self {
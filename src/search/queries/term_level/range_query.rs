@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::ops::{Bound, RangeBounds};
 
 /// Returns documents that contain terms within a provided range.
 ///
@@ -15,6 +16,13 @@ use serde::Serialize;
 ///     .boost(2)
 ///     .name("range_query");
 /// ```
+/// To create a range query from a standard library range:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::range("age").from_std(18..65);
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-range-query.html>
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(remote = "Self")]
@@ -34,20 +42,28 @@ pub struct RangeQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     lte: Option<Term>,
 
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    options: Option<Box<RangeOptions>>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    format: Option<String>,
+    boost: Option<f32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    relation: Option<RangeRelation>,
+    _name: Option<String>,
+}
 
+/// Parameters rarely set on range queries, boxed to keep the query within the size budget of
+/// `Query` variants
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+struct RangeOptions {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    time_zone: Option<String>,
+    format: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    boost: Option<f32>,
+    relation: Option<RangeRelation>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    _name: Option<String>,
+    time_zone: Option<String>,
 }
 
 impl Query {
@@ -64,9 +80,7 @@ impl Query {
             gte: Default::default(),
             lt: Default::default(),
             lte: Default::default(),
-            format: None,
-            relation: None,
-            time_zone: None,
+            options: None,
             boost: None,
             _name: None,
         }
@@ -110,6 +124,33 @@ impl RangeQuery {
         self
     }
 
+    /// Sets the bounds from a standard library range, e.g. `18..65` becomes `gte: 18, lt: 65`
+    /// and `18..=65` becomes `gte: 18, lte: 65`. Replaces any previously set bounds.
+    pub fn from_std<R, T>(mut self, range: R) -> Self
+    where
+        R: RangeBounds<T>,
+        T: Serialize,
+    {
+        self.gt = None;
+        self.gte = None;
+        self.lt = None;
+        self.lte = None;
+
+        match range.start_bound() {
+            Bound::Included(value) => self.gte = Term::new(value),
+            Bound::Excluded(value) => self.gt = Term::new(value),
+            Bound::Unbounded => {}
+        }
+
+        match range.end_bound() {
+            Bound::Included(value) => self.lte = Term::new(value),
+            Bound::Excluded(value) => self.lt = Term::new(value),
+            Bound::Unbounded => {}
+        }
+
+        self
+    }
+
     /// Date format used to convert `date` values in the query.
     ///
     /// By default, Elasticsearch uses the
@@ -126,13 +167,13 @@ impl RangeQuery {
     where
         T: ToString,
     {
-        self.format = Some(format.to_string());
+        self.options_mut().format = Some(format.to_string());
         self
     }
 
     /// Indicates how the range query matches values for range fields.
     pub fn relation(mut self, relation: RangeRelation) -> Self {
-        self.relation = Some(relation);
+        self.options_mut().relation = Some(relation);
         self
     }
 
@@ -149,10 +190,14 @@ impl RangeQuery {
     where
         T: ToString,
     {
-        self.time_zone = Some(time_zone.to_string());
+        self.options_mut().time_zone = Some(time_zone.to_string());
         self
     }
 
+    fn options_mut(&mut self) -> &mut RangeOptions {
+        self.options.get_or_insert_with(Default::default)
+    }
+
     add_boost_and_name!();
 }
 
@@ -170,7 +215,6 @@ serialize_with_root_keyed!("range": RangeQuery);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::prelude::*;
 
     #[test]
     fn serialization() {
@@ -219,6 +263,67 @@ mod tests {
                 }
             }),
         );
+    }
+
+    #[test]
+    fn from_std_ranges() {
+        assert_serialize_query(
+            Query::range("age").from_std(18..65),
+            json!({ "range": { "age": { "gte": 18, "lt": 65 } } }),
+        );
+
+        assert_serialize_query(
+            Query::range("age").from_std(18..=65),
+            json!({ "range": { "age": { "gte": 18, "lte": 65 } } }),
+        );
+
+        assert_serialize_query(
+            Query::range("age").from_std(18..),
+            json!({ "range": { "age": { "gte": 18 } } }),
+        );
+
+        assert_serialize_query(
+            Query::range("age").from_std(..=65),
+            json!({ "range": { "age": { "lte": 65 } } }),
+        );
+
+        assert_serialize_query(
+            Query::range("price")
+                .gt(1)
+                .from_std((Bound::Excluded(0.5), Bound::Unbounded)),
+            json!({ "range": { "price": { "gt": 0.5 } } }),
+        );
+
+        assert_serialize_query(
+            Query::range("date").from_std("2024-01-01".."2025-01-01"),
+            json!({ "range": { "date": { "gte": "2024-01-01", "lt": "2025-01-01" } } }),
+        );
+    }
+
+    #[cfg(all(feature = "time", feature = "jiff"))]
+    #[test]
+    fn time_and_jiff_serialization() {
+        assert_serialize_query(
+            Query::range("test_date_field")
+                .gte(Date::from(
+                    time::OffsetDateTime::from_unix_timestamp(1_417_176_001).unwrap(),
+                ))
+                .lt(jiff::Timestamp::from_second(1_417_176_002).unwrap()),
+            json!({
+                "range": {
+                    "test_date_field": {
+                        "gte": "2014-11-28T12:00:01Z",
+                        "lt": "2014-11-28T12:00:02Z"
+                    }
+                }
+            }),
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_serialization() {
+        use chrono::prelude::*;
 
         assert_serialize_query(
             Query::range("test_date_field")
@@ -0,0 +1,21 @@
+//! Ranking methods combining the result sets of the `query` and `knn` sections of the request.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-api-rank>
+
+mod rrf;
+
+pub use self::rrf::*;
+
+/// A container enum for supported Elasticsearch rank methods
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum Rank {
+    Rrf(Rrf),
+}
+
+impl From<Rrf> for Rank {
+    fn from(rrf: Rrf) -> Self {
+        Self::Rrf(rrf)
+    }
+}
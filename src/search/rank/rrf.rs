@@ -0,0 +1,62 @@
+use crate::util::*;
+
+/// Reciprocal rank fusion (RRF) combines the result sets of the `query` and `knn` sections of
+/// the request by scoring the documents by their rank in each of them.
+///
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new()
+///     .query(Query::r#match("title", "wild west"))
+///     .knn(Knn::query_vector("embedding", vec![0.1, 0.2]))
+///     .rank(Rrf::new().rank_constant(20).rank_window_size(100));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/rrf.html>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Rrf {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_constant: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_window_size: Option<u32>,
+}
+
+impl Rrf {
+    /// Creates an instance of [`Rrf`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines how much influence the documents in the individual result sets have over the
+    /// final ranking, higher values give lower ranked documents more influence
+    ///
+    /// Default value is `60`
+    pub fn rank_constant(mut self, rank_constant: u32) -> Self {
+        self.rank_constant = Some(rank_constant);
+        self
+    }
+
+    /// The number of top documents of each result set to combine
+    ///
+    /// Default value is the `size` of the search
+    pub fn rank_window_size(mut self, rank_window_size: u32) -> Self {
+        self.rank_window_size = Some(rank_window_size);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rank;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Rank::from(Rrf::new()), json!({ "rrf": {} }));
+
+        assert_serialize(
+            Rank::from(Rrf::new().rank_constant(20).rank_window_size(100)),
+            json!({ "rrf": { "rank_constant": 20, "rank_window_size": 100 } }),
+        );
+    }
+}
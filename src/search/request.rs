@@ -1,4 +1,5 @@
 //! Allows you to execute a search query and get back search hits that match the query.
+use crate::document::Slice;
 use crate::search::*;
 use crate::util::*;
 use crate::Map;
@@ -48,17 +49,21 @@ pub struct Search {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     rescore: RescoreCollection,
 
+    #[cfg(feature = "suggesters")]
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    suggest: Map<String, Suggester>,
+    suggest: SuggestCollection,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     stored_fields: StoredFields,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    docvalue_fields: Set<String>,
+    docvalue_fields: Set<FieldAndFormat>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    script_fields: Map<String, ScriptField>,
+    fields: Set<FieldAndFormat>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script_fields: OrderedMap<String, ScriptField>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     post_filter: Option<Query>,
@@ -67,14 +72,26 @@ pub struct Search {
     pit: Option<PointInTime>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    search_after: Terms,
+    slice: Option<Slice>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_after: SearchAfter,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     timeout: Option<Time>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    terminate_after: Option<u64>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     knn: Vec<Knn>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    retriever: Option<Retriever>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank: Option<Rank>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     collapse: Option<Collapse>,
 
@@ -84,6 +101,15 @@ pub struct Search {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     track_scores: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    profile: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    seq_no_primary_term: Option<bool>,
 }
 
 impl Search {
@@ -115,13 +141,26 @@ impl Search {
     /// across more than one indices. This is very handy when hits coming from
     /// one index matter more than hits coming from another index (think social
     /// graph where each user has an index).
-    pub fn indices_boost<T, U>(mut self, field: T, boost: U) -> Self
+    ///
+    /// Index names, aliases and wildcard patterns can be boosted, the boosts are sent in the
+    /// given order and the first match of an index is used:
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// # let search =
+    /// Search::new().indices_boost([("my-alias", 1.4), ("my-index*", 1.3)]);
+    /// ```
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multiple-indices.html#index-boost>
+    pub fn indices_boost<I, T, U>(mut self, indices_boost: I) -> Self
     where
+        I: IntoIterator<Item = (T, U)>,
         T: ToString,
-        U: num_traits::AsPrimitive<f32>,
+        U: AsFloat,
     {
-        self.indices_boost
-            .push(KeyValuePair::new(field.to_string(), boost.as_()));
+        self.indices_boost.extend(
+            indices_boost
+                .into_iter()
+                .map(|(index, boost)| KeyValuePair::new(index.to_string(), boost.as_float())),
+        );
         self
     }
 
@@ -172,6 +211,20 @@ impl Search {
         self
     }
 
+    /// Same as `size`, but fails if `from + size` exceeds the default
+    /// [`MAX_RESULT_WINDOW`], so set `from` first. Use
+    /// [`search_after`](Self::search_after) to page through deeper results.
+    pub fn try_size(mut self, size: u64) -> Result<Self, ValidationError> {
+        let from = self.from.unwrap_or_default();
+
+        if from.saturating_add(size) > MAX_RESULT_WINDOW {
+            return Err(ValidationError::ResultWindowTooLarge { from, size });
+        }
+
+        self.size = Some(size);
+        Ok(self)
+    }
+
     /// Defines the search definition using the
     /// [Query DSL](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html).
     pub fn query<Q>(mut self, query: Q) -> Self
@@ -184,6 +237,18 @@ impl Search {
 
     /// When you use the `post_filter` parameter to filter search results, the search hits are filtered after the
     /// aggregations are calculated. A post filter has no impact on the aggregation results.
+    ///
+    /// Faceted navigation keeps the counts of all the facet values while only returning the
+    /// hits of the selected one:
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// # let search =
+    /// Search::new()
+    ///     .query(Query::term("brand", "gucci"))
+    ///     .aggregate("colors", Aggregation::terms("color"))
+    ///     .post_filter(Query::term("color", "red"));
+    /// ```
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/filter-search-results.html#post-filter>
     pub fn post_filter<Q>(mut self, post_filter: Q) -> Self
     where
         Q: Into<Query>,
@@ -217,6 +282,29 @@ impl Search {
         self
     }
 
+    /// If true, returns detailed timing information about the execution of the search in the
+    /// [`profile`](crate::SearchResponse::profile) section of the response.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-profile.html>
+    pub fn profile(mut self, enabled: bool) -> Self {
+        self.profile = Some(enabled);
+        self
+    }
+
+    /// If true, returns the document version as part of a hit.
+    pub fn version(mut self, enabled: bool) -> Self {
+        self.version = Some(enabled);
+        self
+    }
+
+    /// If true, returns the sequence number and primary term of the last modification of each
+    /// hit, to be used for
+    /// [optimistic concurrency control](https://www.elastic.co/guide/en/elasticsearch/reference/current/optimistic-concurrency-control.html).
+    pub fn seq_no_primary_term(mut self, enabled: bool) -> Self {
+        self.seq_no_primary_term = Some(enabled);
+        self
+    }
+
     /// Highlight
     pub fn highlight<H>(mut self, highlight: H) -> Self
     where
@@ -237,16 +325,34 @@ impl Search {
     }
 
     /// Suggest
+    #[cfg(feature = "suggesters")]
     pub fn suggest<T, U>(mut self, name: T, suggester: U) -> Self
     where
         T: ToString,
         U: Into<Suggester>,
     {
-        let _ = self.suggest.insert(name.to_string(), suggester.into());
+        self.suggest.insert(name, suggester);
         self
     }
 
-    /// A collection of stored fields
+    /// Global suggest text, shared across all the suggesters that don't
+    /// specify their own text.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html>
+    #[cfg(feature = "suggesters")]
+    pub fn suggest_text<T>(mut self, text: T) -> Self
+    where
+        T: ToString,
+    {
+        self.suggest.text(text);
+        self
+    }
+
+    /// Stored fields to return for the matching documents, use [`StoredFields::None`] to
+    /// disable the stored fields (and metadata fields) entirely. Setting the stored fields
+    /// stops the `_source` from being returned unless requested explicitly.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-fields.html#stored-fields>
     pub fn stored_fields<T>(mut self, stored_fields: T) -> Self
     where
         T: Into<StoredFields>,
@@ -255,27 +361,62 @@ impl Search {
         self
     }
 
-    /// A collection of docvalue fields
+    /// Fields to retrieve from the doc values of the matching documents, returned in the
+    /// [`fields`](crate::Hit::fields) of the hits, optionally with the format of the values
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-fields.html#docvalue-fields>
     pub fn docvalue_fields<T>(mut self, docvalue_fields: T) -> Self
     where
         T: IntoIterator,
-        T::Item: ToString,
+        T::Item: Into<FieldAndFormat>,
     {
         self.docvalue_fields
-            .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+            .extend(docvalue_fields.into_iter().map(Into::into));
         self
     }
 
-    /// Point in time
+    /// Fields to retrieve from the mappings of the matching documents, returned in the
+    /// [`fields`](crate::Hit::fields) of the hits. Unlike `_source`, the values are always
+    /// arrays and follow the mapping type, e.g. with dates formatted as requested.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-fields.html#search-fields-param>
+    pub fn fields<T>(mut self, fields: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<FieldAndFormat>,
+    {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Searches a point in time, opened with the open point in time API, and therefore a
+    /// consistent snapshot of the data that doesn't shift while paginating with `search_after`
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html>
     pub fn pit(mut self, pit: PointInTime) -> Self {
         self.pit = Some(pit);
         self
     }
 
-    /// Search after a set of sort values.
+    /// Processes only a slice of the matching documents, so that a scroll or a
+    /// [`pit`](Self::pit) search can be consumed by several requests in parallel
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#slice-scroll>
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Retrieves the hits following the given sort values, usually the
+    /// [`sort_values`](crate::Hit::sort_values) of the last hit of the previous page.
+    ///
+    /// The search must be sorted with a tiebreaker and is best combined with a
+    /// [`pit`](Self::pit), so that the pages are consistent with each other.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#search-after>
     pub fn search_after<T>(mut self, sort_values: T) -> Self
     where
-        T: Into<Terms>,
+        T: Into<SearchAfter>,
     {
         self.search_after = sort_values.into();
         self
@@ -292,6 +433,23 @@ impl Search {
         self
     }
 
+    /// Same as `timeout`, but parses the duration from its string form, e.g. `500ms`
+    pub fn try_timeout<T>(self, timeout: T) -> Result<Self, ValidationError>
+    where
+        T: AsRef<str>,
+    {
+        Ok(self.timeout(timeout.as_ref().parse::<Time>()?))
+    }
+
+    /// Maximum number of documents to collect for each shard, the query execution terminates
+    /// early once it is reached and `terminated_early` is set in the response.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-your-data.html#quickly-check-for-matching-docs>
+    pub fn terminate_after(mut self, terminate_after: u64) -> Self {
+        self.terminate_after = Some(terminate_after);
+        self
+    }
+
     /// Defines the kNN query to run.
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-api-knn>
@@ -300,6 +458,29 @@ impl Search {
         self
     }
 
+    /// Defines how the top documents are retrieved, replaces the `query`, `knn` and `rank`
+    /// sections. Available since Elasticsearch `8.14`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-api-retriever>
+    pub fn retriever<T>(mut self, retriever: T) -> Self
+    where
+        T: Into<Retriever>,
+    {
+        self.retriever = Some(retriever.into());
+        self
+    }
+
+    /// Defines how the result sets of the `query` and `knn` sections are combined
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-api-rank>
+    pub fn rank<T>(mut self, rank: T) -> Self
+    where
+        T: Into<Rank>,
+    {
+        self.rank = Some(rank.into());
+        self
+    }
+
     /// Parameter to specify collapsing results on some field
     ///
     /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/collapse-search-results.html>
@@ -314,7 +495,7 @@ impl Search {
     /// Extra fields for something not yet supported.
     ///
     /// ```
-    /// # use elasticsearch_dsl::Search;
+    /// # use elasticsearch_dsl::search::Search;
     /// # use serde_json::json;
     /// # let search =
     /// Search::new()
@@ -335,19 +516,819 @@ impl Search {
         self
     }
 
+    /// Serializes the request for a specific search engine version, adapting the queries to the
+    /// syntax of the version and failing fast on sections it doesn't support instead of letting
+    /// the cluster reject the request.
+    ///
+    /// - Elasticsearch `7.x` and OpenSearch `2.x` don't support the top-level `knn`, `retriever`
+    ///   and `rank` sections, the `sub_searches` section, also when set through
+    ///   [`extra`](Self::extra), nor the `semantic` and `sparse_vector` queries
+    /// - Elasticsearch `7.x` doesn't support `knn` queries
+    /// - OpenSearch `2.x` doesn't support `runtime_mappings`. Its k-NN plugin `knn` query is
+    ///   keyed by the field and takes the `vector` and `k` parameters, Elasticsearch `knn`
+    ///   queries are rewritten into it, with `k` taken from [`KnnQuery::k`], or from the
+    ///   request `size` when unset. Queries with a `similarity` have no equivalent and are
+    ///   rejected.
+    /// - `neural` queries are only supported by OpenSearch `2.x`
+    ///
+    /// Queries are adapted in every section that can hold one: `query`, `post_filter`,
+    /// `rescore`, `knn` filters, retrievers, aggregations, highlighting, inner hits and phrase
+    /// suggester collate queries. JSON, raw and wrapper queries, as well as the
+    /// [`extra`](Self::extra) fields, are sent as is.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// # use serde_json::json;
+    /// let search = Search::new().knn(Knn::query_vector("embedding", vec![0.1, 0.2]));
+    ///
+    /// assert!(search.serialize_for(EsVersion::V8).is_ok());
+    /// assert!(search.serialize_for(EsVersion::V7).is_err());
+    ///
+    /// let search = Search::new().query(Query::knn("embedding", vec![0.5, 1.0]).k(5));
+    ///
+    /// assert_eq!(
+    ///     search.serialize_for(EsVersion::OpenSearch2).unwrap(),
+    ///     json!({ "query": { "knn": { "embedding": { "vector": [0.5, 1.0], "k": 5 } } } })
+    /// );
+    ///
+    /// let search = Search::new().query(Query::neural("embedding", "wild west").k(10));
+    ///
+    /// assert!(search.serialize_for(EsVersion::OpenSearch2).is_ok());
+    /// assert!(search.serialize_for(EsVersion::V8).is_err());
+    /// ```
+    pub fn serialize_for(&self, version: EsVersion) -> Result<serde_json::Value, SerializeError> {
+        let unsupported = |feature| ValidationError::Unsupported { feature, version };
+
+        if version != EsVersion::V8 {
+            if !self.knn.is_empty() {
+                return Err(unsupported("knn").into());
+            }
+
+            if self.retriever.is_some() {
+                return Err(unsupported("retriever").into());
+            }
+
+            if self.rank.is_some() {
+                return Err(unsupported("rank").into());
+            }
+
+            for feature in ["rank", "retriever", "sub_searches"] {
+                if self.extra.contains_key(feature) {
+                    return Err(unsupported(feature).into());
+                }
+            }
+        }
+
+        if version == EsVersion::OpenSearch2 && !self.runtime_mappings.is_empty() {
+            return Err(unsupported("runtime_mappings").into());
+        }
+
+        let mut search = self.clone();
+        let size = self.size.unwrap_or(10);
+
+        for query in search.queries_mut() {
+            adapt_query(query, version, size)?;
+        }
+
+        for (_, aggregation) in search.aggs.iter_mut() {
+            adapt_aggregation(aggregation, version, size)?;
+        }
+
+        Ok(serde_json::to_value(&search)?)
+    }
+
+    /// Mutable access to the queries of all the sections, aggregations aside
+    fn queries_mut(&mut self) -> Vec<&mut Query> {
+        #[cfg_attr(not(feature = "suggesters"), allow(unused_mut))]
+        let mut queries: Vec<_> = self
+            .query
+            .iter_mut()
+            .chain(self.post_filter.iter_mut())
+            .chain(self.rescore.queries_mut())
+            .chain(self.knn.iter_mut().flat_map(Knn::queries_mut))
+            .chain(self.retriever.iter_mut().flat_map(Retriever::queries_mut))
+            .chain(self.highlight.iter_mut().flat_map(Highlight::queries_mut))
+            .chain(self.collapse.iter_mut().flat_map(Collapse::queries_mut))
+            .collect();
+
+        #[cfg(feature = "suggesters")]
+        queries.extend(self.suggest.queries_mut());
+
+        queries
+    }
+
+    /// Overlays `other` on top of the request, so that a base template (source filtering,
+    /// stats, runtime mappings, aggregations) can be defined once and combined with the
+    /// per-request parts.
+    ///
+    /// Precedence rules:
+    /// - single values (`query`, `post_filter`, `retriever`, `from`, `size`, `_source`, etc.) set
+    ///   in `other` replace the ones of the request
+    /// - `sort`, `search_after` and `stored_fields` set in `other` replace the ones of the
+    ///   request as a whole
+    /// - named sections (`runtime_mappings`, `script_fields`, `aggs`, `suggest` and
+    ///   [`extra`](Self::extra) fields) are combined, entries of `other` replace the ones with
+    ///   the same name
+    /// - list sections (`stats`, `indices_boost`, `docvalue_fields`, `fields`, `rescore` and
+    ///   `knn`) are appended
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// let template = Search::new().source(false).stats("catalog").size(20);
+    ///
+    /// let search = template
+    ///     .clone()
+    ///     .merge(Search::new().query(Query::term("brand", "vinted")).size(10));
+    ///
+    /// assert_eq!(
+    ///     search,
+    ///     Search::new()
+    ///         .source(false)
+    ///         .stats("catalog")
+    ///         .size(10)
+    ///         .query(Query::term("brand", "vinted"))
+    /// );
+    /// ```
+    pub fn merge(mut self, other: Search) -> Self {
+        let Search {
+            runtime_mappings,
+            indices_boost,
+            min_score,
+            _source,
+            stats,
+            from,
+            size,
+            query,
+            sort,
+            aggs,
+            track_total_hits,
+            highlight,
+            rescore,
+            #[cfg(feature = "suggesters")]
+            suggest,
+            stored_fields,
+            docvalue_fields,
+            fields,
+            script_fields,
+            post_filter,
+            pit,
+            slice,
+            search_after,
+            timeout,
+            terminate_after,
+            knn,
+            retriever,
+            rank,
+            collapse,
+            extra,
+            track_scores,
+            profile,
+            version,
+            seq_no_primary_term,
+        } = other;
+
+        fn overlay<T>(value: &mut Option<T>, other: Option<T>) {
+            if other.is_some() {
+                *value = other;
+            }
+        }
+
+        overlay(&mut self.min_score, min_score);
+        overlay(&mut self._source, _source);
+        overlay(&mut self.from, from);
+        overlay(&mut self.size, size);
+        overlay(&mut self.query, query);
+        overlay(&mut self.track_total_hits, track_total_hits);
+        overlay(&mut self.highlight, highlight);
+        overlay(&mut self.post_filter, post_filter);
+        overlay(&mut self.pit, pit);
+        overlay(&mut self.slice, slice);
+        overlay(&mut self.timeout, timeout);
+        overlay(&mut self.retriever, retriever);
+        overlay(&mut self.rank, rank);
+        overlay(&mut self.collapse, collapse);
+        overlay(&mut self.track_scores, track_scores);
+        overlay(&mut self.profile, profile);
+        overlay(&mut self.terminate_after, terminate_after);
+        overlay(&mut self.version, version);
+        overlay(&mut self.seq_no_primary_term, seq_no_primary_term);
+
+        if !sort.should_skip() {
+            self.sort = sort;
+        }
+
+        if !search_after.should_skip() {
+            self.search_after = search_after;
+        }
+
+        if !stored_fields.should_skip() {
+            self.stored_fields = stored_fields;
+        }
+
+        self.runtime_mappings.extend(runtime_mappings);
+        self.script_fields.extend(script_fields);
+        self.aggs.extend(aggs);
+        #[cfg(feature = "suggesters")]
+        self.suggest.merge(suggest);
+        self.extra.extend(extra);
+
+        self.stats.extend(stats);
+        self.indices_boost.extend(indices_boost);
+        self.docvalue_fields.extend(docvalue_fields);
+        self.fields.extend(fields);
+        self.rescore.extend(rescore);
+        self.knn.extend(knn);
+
+        self
+    }
+
+    /// Normalized representation of the request body, see [`RequestKey`]
+    pub fn key(&self) -> Result<RequestKey, serde_json::Error> {
+        RequestKey::new(self)
+    }
+
+    /// Serializes the request into a JSON byte buffer, ready to be sent as a request body
+    pub fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+
+    /// Serializes the request as JSON straight into the `writer`, e.g. a network or compression
+    /// buffer
+    pub fn write_to<W>(&self, writer: W) -> Result<(), serde_json::Error>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Serializes the request as [CBOR](https://www.rfc-editor.org/rfc/rfc8949), to be sent with
+    /// the `application/cbor` content type. Floats are encoded in binary, which makes kNN query
+    /// vectors a lot smaller than in JSON.
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Result<Vec<u8>, serde_json::Error> {
+        cbor::to_vec(self)
+    }
+
+    /// Renders the request as YAML, with object keys in the same order as in the JSON body, which
+    /// is easier to review and diff than JSON. Elasticsearch accepts it with the `application/yaml` content
+    /// type.
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// assert_eq!(
+    ///     Search::new().size(1).query(Query::term("user", "kimchy")).to_yaml().unwrap(),
+    ///     "size: 1\nquery:\n  term:\n    user:\n      value: kimchy\n"
+    /// );
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_json::Error> {
+        yaml::to_string(self)
+    }
+
+    /// Writes the newline delimited JSON body of a
+    /// [multi search](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html)
+    /// request, each search preceded by its header (`index`, `routing`, `preference`, etc.)
+    ///
+    /// ```
+    /// # use elasticsearch_dsl::search::*;
+    /// # use serde_json::json;
+    /// let searches = [
+    ///     (json!({ "index": "products" }), Search::new().size(1)),
+    ///     (json!({}), Search::new().size(2)),
+    /// ];
+    ///
+    /// let mut body = Vec::new();
+    ///
+    /// Search::write_msearch(&mut body, searches.iter().map(|(h, s)| (h, s))).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(body).unwrap(),
+    ///     "{\"index\":\"products\"}\n{\"size\":1}\n{}\n{\"size\":2}\n"
+    /// );
+    /// ```
+    pub fn write_msearch<'a, W, I, H>(mut writer: W, searches: I) -> Result<(), serde_json::Error>
+    where
+        W: std::io::Write,
+        I: IntoIterator<Item = (H, &'a Search)>,
+        H: serde::Serialize,
+    {
+        for (header, search) in searches {
+            serde_json::to_writer(&mut writer, &header)?;
+            writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+            search.write_to(&mut writer)?;
+            writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+        }
+
+        Ok(())
+    }
+
     add_aggregate!();
 }
 
+/// Canonical conversion of a type into a [`Search`] request. Implement it for application specific
+/// request structs, so that they can be passed directly to functions executing searches:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// struct ProductSearch {
+///     text: String,
+///     page: u64,
+/// }
+///
+/// impl IntoSearch for ProductSearch {
+///     fn into_search(self) -> Search {
+///         Search::new()
+///             .query(Query::r#match("title", self.text))
+///             .from(self.page * 20)
+///             .size(20)
+///     }
+/// }
+///
+/// fn body<S: IntoSearch>(search: S) -> serde_json::Value {
+///     serde_json::to_value(search.into_search()).unwrap()
+/// }
+///
+/// # let value =
+/// body(ProductSearch { text: "shoes".to_string(), page: 1 });
+/// # let value =
+/// body(Search::new());
+/// ```
+pub trait IntoSearch {
+    /// Converts the value into a [`Search`] request
+    fn into_search(self) -> Search;
+}
+
+impl IntoSearch for Search {
+    fn into_search(self) -> Search {
+        self
+    }
+}
+
+/// Looks for `knn` query clauses, telling them apart from field names by their query vector
+/// Rewrites the query for the targeted version, failing on the queries it doesn't support
+/// Adapts the query and its nested queries to the syntax of `version`
+fn adapt_query(query: &mut Query, version: EsVersion, size: u64) -> Result<(), ValidationError> {
+    let unsupported = |feature| ValidationError::Unsupported { feature, version };
+
+    for query in query.queries_mut() {
+        adapt_query(query, version, size)?;
+    }
+
+    match query {
+        Query::Knn(knn) => match version {
+            EsVersion::V8 => {}
+            EsVersion::V7 => return Err(unsupported("knn query")),
+            EsVersion::OpenSearch2 => *query = knn.opensearch(size)?,
+        },
+        Query::Neural(_) if version != EsVersion::OpenSearch2 => {
+            return Err(unsupported("neural query"))
+        }
+        Query::Semantic(_) if version != EsVersion::V8 => {
+            return Err(unsupported("semantic query"))
+        }
+        Query::SparseVector(_) if version != EsVersion::V8 => {
+            return Err(unsupported("sparse_vector query"))
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Adapts the queries of the aggregation and of its sub-aggregations to the syntax of `version`
+fn adapt_aggregation(
+    aggregation: &mut Aggregation,
+    version: EsVersion,
+    size: u64,
+) -> Result<(), ValidationError> {
+    for query in aggregation.queries_mut() {
+        adapt_query(query, version, size)?;
+    }
+
+    for (_, aggregation) in aggregation
+        .aggs_mut()
+        .into_iter()
+        .flat_map(|aggs| aggs.iter_mut())
+    {
+        adapt_aggregation(aggregation, version, size)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn unsupported(search: &Search, version: EsVersion) -> Option<&'static str> {
+        match search.serialize_for(version) {
+            Err(SerializeError::Validation(ValidationError::Unsupported { feature, .. })) => {
+                Some(feature)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn key() {
+        let searches: std::collections::HashSet<_> = [
+            Search::new().query(Query::term("user", "kimchy")).size(10),
+            Search::new().query(Query::term("user", "kimchy")).size(10),
+            Search::new().query(Query::term("user", "kimchy")).size(20),
+        ]
+        .iter()
+        .map(|search| search.key().unwrap())
+        .collect();
+
+        assert_eq!(searches.len(), 2);
+
+        let nan = Search::new().query(Query::term("user", "kimchy").boost(f32::NAN));
+
+        assert_eq!(nan.key().unwrap(), nan.key().unwrap());
+    }
+
+    #[test]
+    fn serializes_for_version() {
+        let search = Search::new().query(Query::term("knn", "value"));
+
+        assert_eq!(
+            search.serialize_for(EsVersion::V7).unwrap(),
+            json!({ "query": { "term": { "knn": { "value": "value" } } } })
+        );
+
+        let search =
+            Search::new().query(Query::bool().should(Query::knn("embedding", vec![0.1, 0.2])));
+
+        assert!(search.serialize_for(EsVersion::V8).is_ok());
+        assert_eq!(unsupported(&search, EsVersion::V7), Some("knn query"));
+
+        let search = Search::new().extra([("rank".to_string(), json!({ "rrf": {} }))].into());
+
+        assert_eq!(unsupported(&search, EsVersion::V7), Some("rank"));
+
+        let search = Search::new().rank(Rrf::new());
+
+        assert_eq!(unsupported(&search, EsVersion::V7), Some("rank"));
+
+        let search = Search::new().retriever(Retriever::standard().query(Query::match_all()));
+
+        assert!(search.serialize_for(EsVersion::V8).is_ok());
+        assert_eq!(unsupported(&search, EsVersion::V7), Some("retriever"));
+    }
+
+    #[test]
+    fn serializes_for_opensearch() {
+        let search = Search::new().knn(Knn::query_vector("embedding", vec![0.1, 0.2]));
+
+        assert_eq!(unsupported(&search, EsVersion::OpenSearch2), Some("knn"));
+
+        let search = Search::new()
+            .query(Query::term("neural", "value"))
+            .post_filter(Query::bool().filter(Query::neural("embedding", "wild west")));
+
+        assert!(search.serialize_for(EsVersion::OpenSearch2).is_ok());
+        assert_eq!(unsupported(&search, EsVersion::V8), Some("neural query"));
+        assert_eq!(
+            search.serialize_for(EsVersion::V7).unwrap_err().to_string(),
+            "`neural query` is not supported on Elasticsearch 7.x"
+        );
+    }
+
+    #[test]
+    fn rejects_elasticsearch_8_queries() {
+        for query in [
+            Query::from(Query::semantic("content", "wild west")),
+            Query::from(Query::sparse_vector("content", [("west", 1.0)])),
+        ] {
+            let search = Search::new().query(Query::bool().must(query));
+
+            assert!(search.serialize_for(EsVersion::V8).is_ok());
+            assert!(unsupported(&search, EsVersion::V7)
+                .is_some_and(|feature| feature.ends_with(" query")));
+            assert!(unsupported(&search, EsVersion::OpenSearch2).is_some());
+        }
+
+        let search = Search::new().query(Query::term("semantic", "value"));
+
+        assert!(search.serialize_for(EsVersion::V7).is_ok());
+    }
+
+    #[test]
+    fn adapts_knn_queries_for_opensearch() {
+        let search = Search::new().size(20).query(
+            Query::bool().should(
+                Query::knn("embedding", vec![0.1, 0.2])
+                    .filter([Query::term("brand", "vinted"), Query::term("size", "m")])
+                    .boost(2),
+            ),
+        );
+
+        assert_eq!(
+            search.serialize_for(EsVersion::OpenSearch2).unwrap(),
+            json!({
+                "size": 20,
+                "query": {
+                    "bool": {
+                        "should": [{
+                            "knn": {
+                                "embedding": {
+                                    "vector": [0.1f32, 0.2f32],
+                                    "k": 20,
+                                    "filter": {
+                                        "bool": {
+                                            "filter": [
+                                                { "term": { "brand": { "value": "vinted" } } },
+                                                { "term": { "size": { "value": "m" } } }
+                                            ]
+                                        }
+                                    },
+                                    "boost": 2.0
+                                }
+                            }
+                        }]
+                    }
+                }
+            })
+        );
+
+        let search = Search::new().query(
+            Query::knn("embedding", vec![0.1, 0.2])
+                .k(5)
+                .num_candidates(50),
+        );
+
+        assert_eq!(
+            search.serialize_for(EsVersion::OpenSearch2).unwrap(),
+            json!({ "query": { "knn": { "embedding": { "vector": [0.1f32, 0.2f32], "k": 5 } } } })
+        );
+
+        let search = Search::new().post_filter(
+            Query::knn("embedding", vec![0.1, 0.2])
+                .num_candidates(50)
+                .similarity(0.5),
+        );
+
+        assert_eq!(
+            unsupported(&search, EsVersion::OpenSearch2),
+            Some("knn query similarity")
+        );
+    }
+
+    #[test]
+    fn adapts_queries_of_all_sections() {
+        let neural = || Query::neural("embedding", "wild west");
+
+        let searches = vec![
+            Search::new().rescore(Rescore::new(neural())),
+            Search::new().knn(Knn::query_vector("embedding", vec![0.1]).filter(neural())),
+            Search::new().retriever(Retriever::standard().filter(neural())),
+            Search::new().highlight(
+                Highlight::new()
+                    .field_highlighter("title", Highlighter::new().highlight_query(neural())),
+            ),
+            Search::new().query(Query::nested("comments", Query::match_all()).inner_hits(
+                InnerHits::new().highlight(
+                    Highlight::new().highlighter(Highlighter::new().highlight_query(neural())),
+                ),
+            )),
+            Search::new()
+                .query(Query::function_score().function(Function::weight(2.0).filter(neural()))),
+            Search::new().aggregate(
+                "brands",
+                Aggregation::terms("brand").aggregate(
+                    "filtered",
+                    Aggregation::filter(Query::bool().must(neural())),
+                ),
+            ),
+            Search::new().aggregate("buckets", Aggregation::filters([("neural", neural())])),
+        ];
+
+        for search in searches {
+            assert_eq!(
+                unsupported(&search, EsVersion::V8),
+                Some("neural query"),
+                "{:?}",
+                search
+            );
+        }
+
+        let search = Search::new().aggregate(
+            "similar",
+            Aggregation::filter(Query::knn("embedding", vec![0.1]).k(3)),
+        );
+
+        assert_eq!(
+            search.serialize_for(EsVersion::OpenSearch2).unwrap(),
+            json!({
+                "aggs": {
+                    "similar": {
+                        "filter": { "knn": { "embedding": { "vector": [0.1f32], "k": 3 } } }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_runtime_mappings_on_opensearch() {
+        let search =
+            Search::new().runtime_mapping("day", RuntimeMapping::keyword("emit('monday')"));
+
+        assert!(search.serialize_for(EsVersion::V7).is_ok());
+        assert_eq!(
+            unsupported(&search, EsVersion::OpenSearch2),
+            Some("runtime_mappings")
+        );
+    }
+
+    #[test]
+    fn validates_result_window() {
+        assert!(Search::new().try_size(MAX_RESULT_WINDOW).is_ok());
+        assert_eq!(
+            Search::new().from(9_990).try_size(20).unwrap_err(),
+            ValidationError::ResultWindowTooLarge {
+                from: 9_990,
+                size: 20
+            }
+        );
+    }
+
+    #[test]
+    fn merges_searches() {
+        let template = Search::new()
+            .source(false)
+            .stats("base")
+            .size(20)
+            .sort(vec![Sort::from("created_at")])
+            .aggregate("brands", Aggregation::terms("brand"))
+            .aggregate("sizes", Aggregation::terms("size"));
+
+        assert_eq!(template.clone().merge(Search::new()), template);
+
+        assert_serialize(
+            template.merge(
+                Search::new()
+                    .stats("request")
+                    .from(40)
+                    .query(Query::term("brand", "vinted"))
+                    .sort(vec![Sort::from("price")])
+                    .aggregate("sizes", Aggregation::terms("size_id")),
+            ),
+            json!({
+                "_source": false,
+                "stats": ["base", "request"],
+                "from": 40,
+                "size": 20,
+                "query": { "term": { "brand": { "value": "vinted" } } },
+                "sort": ["price"],
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } },
+                    "sizes": { "terms": { "field": "size_id" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn keeps_aggregation_order() {
+        let search = Search::new()
+            .aggregate("sizes", Aggregation::terms("size"))
+            .aggregate("brands", Aggregation::terms("brand"));
+
+        assert_eq!(
+            serde_json::to_string(&search).unwrap(),
+            r#"{"aggs":{"sizes":{"terms":{"field":"size"}},"brands":{"terms":{"field":"brand"}}}}"#
+        );
+    }
+
     #[test]
     fn serializes_to_empty_object_by_default() {
         assert_serialize(Search::new(), json!({}));
         assert_serialize(Search::default(), json!({}));
     }
 
+    #[test]
+    fn serializes_fields() {
+        assert_serialize(
+            Search::new().fields([
+                FieldAndFormat::from("user.id"),
+                FieldAndFormat::new("@timestamp").format("epoch_millis"),
+            ]),
+            json!({
+                "fields": [
+                    { "field": "@timestamp", "format": "epoch_millis" },
+                    "user.id"
+                ]
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_docvalue_fields() {
+        assert_serialize(
+            Search::new().docvalue_fields([
+                FieldAndFormat::from("my_ip*"),
+                FieldAndFormat::new("my_date_field").format("epoch_millis"),
+            ]),
+            json!({
+                "docvalue_fields": [
+                    { "field": "my_date_field", "format": "epoch_millis" },
+                    "my_ip*"
+                ]
+            }),
+        );
+    }
+
+    #[test]
+    fn skips_empty_field_sections() {
+        assert_serialize(
+            Search::new()
+                .fields(Vec::<FieldAndFormat>::new())
+                .docvalue_fields(Vec::<FieldAndFormat>::new())
+                .stored_fields(Vec::<String>::new()),
+            json!({}),
+        );
+    }
+
+    #[test]
+    fn serializes_post_filter_and_stored_fields() {
+        assert_serialize(
+            Search::new()
+                .query(Query::term("brand", "gucci"))
+                .aggregate("colors", Aggregation::terms("color"))
+                .post_filter(Query::term("color", "red"))
+                .stored_fields(["user", "postDate"]),
+            json!({
+                "query": { "term": { "brand": { "value": "gucci" } } },
+                "aggs": { "colors": { "terms": { "field": "color" } } },
+                "post_filter": { "term": { "color": { "value": "red" } } },
+                "stored_fields": ["postDate", "user"]
+            }),
+        );
+
+        assert_serialize(
+            Search::new().stored_fields(StoredFields::None),
+            json!({ "stored_fields": "_none_" }),
+        );
+    }
+
+    #[test]
+    fn serializes_indices_boost_in_order() {
+        assert_serialize(
+            Search::new()
+                .indices_boost([("index-b", 1.5), ("alias-a", 0.7)])
+                .indices_boost([("index-*", 1.1)]),
+            json!({
+                "indices_boost": [
+                    { "index-b": 1.5 },
+                    { "alias-a": 0.7 },
+                    { "index-*": 1.1 }
+                ]
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_slice() {
+        assert_serialize(
+            Search::new()
+                .pit(PointInTime::new("46ToAwMDaWR5BXV1aWQy", Time::Minutes(1)))
+                .slice(Slice::new(0, 2)),
+            json!({
+                "pit": { "id": "46ToAwMDaWR5BXV1aWQy", "keep_alive": "1m" },
+                "slice": { "id": 0, "max": 2 }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_execution_parameters() {
+        assert_serialize(
+            Search::new()
+                .track_scores(true)
+                .min_score(0.5)
+                .terminate_after(1000)
+                .try_timeout("500ms")
+                .unwrap()
+                .version(true)
+                .seq_no_primary_term(true)
+                .profile(true),
+            json!({
+                "min_score": 0.5,
+                "timeout": "500ms",
+                "terminate_after": 1000,
+                "track_scores": true,
+                "profile": true,
+                "version": true,
+                "seq_no_primary_term": true
+            }),
+        );
+
+        assert_eq!(
+            Search::new().try_timeout("half a second").unwrap_err(),
+            ValidationError::InvalidTime
+        );
+    }
+
     #[test]
     fn serializes_extra_fields() {
         assert_serialize(
@@ -366,4 +1347,16 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn writes_to_bytes() {
+        let search = Search::new().query(Query::term("user", "kimchy")).size(10);
+
+        let mut buffer = Vec::new();
+
+        search.write_to(&mut buffer).unwrap();
+
+        assert_eq!(buffer, search.to_bytes().unwrap());
+        assert_eq!(buffer, serde_json::to_vec(&search).unwrap());
+    }
 }
@@ -1,5 +1,5 @@
 use crate::util::ShouldSkip;
-use crate::{Query, ScoreMode};
+use crate::{AsFloat, Query, ScoreMode};
 
 /// Rescoring can help to improve precision by reordering just the top (eg 100 - 500)
 /// documents returned by the [query](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#request-body-search-query)
@@ -13,7 +13,7 @@ use crate::{Query, ScoreMode};
 ///
 /// To create a `rescore` query with simple `term` query:
 /// ```
-/// # use elasticsearch_dsl::rescoring::*;
+/// # use elasticsearch_dsl::search::rescoring::*;
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # let rescore =
@@ -21,7 +21,7 @@ use crate::{Query, ScoreMode};
 /// ```
 /// To create a `rescore` query with simple `term` query and optional fields:
 /// ```
-/// # use elasticsearch_dsl::rescoring::*;
+/// # use elasticsearch_dsl::search::rescoring::*;
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
 /// # let query =
@@ -80,18 +80,18 @@ impl Rescore {
     /// The relative importance of the rescore query can be controlled with the `rescore_query_weight` respectively. Both default to 1.
     pub fn rescore_query_weight<T>(mut self, rescore_query_weight: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.query.rescore_query_weight = Some(rescore_query_weight.as_());
+        self.query.rescore_query_weight = Some(rescore_query_weight.as_float());
         self
     }
 
     /// The relative importance of the original query can be controlled with the `query_weight` respectively. Both default to 1.
     pub fn query_weight<T>(mut self, query_weight: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.query.query_weight = Some(query_weight.as_());
+        self.query.query_weight = Some(query_weight.as_float());
         self
     }
 
@@ -100,6 +100,11 @@ impl Rescore {
         self.query.score_mode = Some(score_mode);
         self
     }
+
+    /// Mutable access to the rescore query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.query.rescore_query.iter_mut().collect()
+    }
 }
 
 impl ShouldSkip for Rescore {
@@ -107,7 +112,7 @@ impl ShouldSkip for Rescore {
         self.query
             .rescore_query
             .as_ref()
-            .map_or(true, ShouldSkip::should_skip)
+            .is_none_or(ShouldSkip::should_skip)
     }
 }
 
@@ -1,4 +1,5 @@
 use super::Rescore;
+use crate::search::Query;
 use crate::util::ShouldSkip;
 
 /// Rescoring criteria
@@ -46,4 +47,9 @@ impl RescoreCollection {
                 .filter(ShouldSkip::should_keep),
         )
     }
+
+    /// Mutable access to the rescore queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.0.iter_mut().flat_map(Rescore::queries_mut).collect()
+    }
 }
@@ -0,0 +1,429 @@
+use serde::de::{Deserialize, DeserializeOwned, Error as _};
+use serde_json::Error;
+use std::io::{BufRead, BufReader, Read};
+use std::marker::PhantomData;
+
+/// Pull-based iterator over the buckets of a bucket aggregation, deserializing them one by one
+/// straight from the search response body.
+///
+/// Unlike [`SearchResponse`](crate::SearchResponse), which keeps whole aggregation trees in
+/// memory, only the current bucket is held at a time. That makes exporting hundreds of
+/// thousands of composite aggregation buckets possible without buffering the response:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// #[derive(serde::Deserialize)]
+/// struct Bucket {
+///     key: serde_json::Value,
+///     doc_count: u64,
+/// }
+///
+/// let body = r#"{
+///     "took": 3,
+///     "aggregations": {
+///         "export": {
+///             "after_key": { "brand": "b" },
+///             "buckets": [
+///                 { "key": { "brand": "a" }, "doc_count": 2 },
+///                 { "key": { "brand": "b" }, "doc_count": 1 }
+///             ]
+///         }
+///     }
+/// }"#;
+///
+/// let doc_count = BucketStream::<_, Bucket>::new(body.as_bytes(), "export")
+///     .map(|bucket| bucket.map(|bucket| bucket.doc_count))
+///     .sum::<Result<u64, _>>()
+///     .unwrap();
+///
+/// assert_eq!(doc_count, 3);
+/// ```
+///
+/// Keyed buckets, returned by aggregations with `keyed` enabled, are streamed in the same way,
+/// with the object key set as the bucket `key` unless the bucket already has one.
+///
+/// The stream is empty when the response has no such aggregation. Buckets of aggregations nested
+/// under single bucket aggregations, such as `filter` or `nested`, are reached with
+/// [`BucketStream::with_path`].
+pub struct BucketStream<R, T> {
+    reader: BufReader<R>,
+    path: Vec<String>,
+    state: State,
+    keyed: bool,
+    bucket: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    First,
+    Next,
+    Done,
+}
+
+impl<R, T> BucketStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    /// Creates an instance of [`BucketStream`] over the buckets of a top-level aggregation
+    ///
+    /// - `reader` - search response body
+    /// - `aggregation` - name of the bucket aggregation
+    pub fn new<A>(reader: R, aggregation: A) -> Self
+    where
+        A: ToString,
+    {
+        Self::with_path(
+            reader,
+            ["aggregations".to_string(), aggregation.to_string()],
+        )
+    }
+
+    /// Creates an instance of [`BucketStream`] over the `buckets` array found by following the
+    /// object keys of `path` from the root of the response, e.g. `["aggregations", "brands"]`
+    pub fn with_path<P>(reader: R, path: P) -> Self
+    where
+        P: IntoIterator,
+        P::Item: ToString,
+    {
+        let mut path: Vec<_> = path.into_iter().map(|key| key.to_string()).collect();
+
+        path.push("buckets".to_string());
+
+        Self {
+            reader: BufReader::new(reader),
+            path,
+            state: State::Start,
+            keyed: false,
+            bucket: PhantomData,
+        }
+    }
+
+    fn next_bucket(&mut self) -> Result<Option<T>, Error> {
+        if self.state == State::Start {
+            if !self.find_buckets()? {
+                return Ok(None);
+            }
+
+            self.state = State::First;
+        }
+
+        if self.state == State::Done {
+            return Ok(None);
+        }
+
+        self.skip_whitespace()?;
+
+        if self.peek()? == Some(if self.keyed { b'}' } else { b']' }) {
+            self.consume();
+            return Ok(None);
+        }
+
+        if self.state == State::Next {
+            self.expect(b',')?;
+            self.skip_whitespace()?;
+        }
+
+        self.state = State::Next;
+
+        if !self.keyed {
+            let mut deserializer = serde_json::Deserializer::from_reader(&mut self.reader);
+
+            return T::deserialize(&mut deserializer).map(Some);
+        }
+
+        let key = self.read_key()?;
+
+        self.skip_whitespace()?;
+        self.expect(b':')?;
+
+        let mut deserializer = serde_json::Deserializer::from_reader(&mut self.reader);
+        let mut bucket = serde_json::Value::deserialize(&mut deserializer)?;
+
+        if let Some(bucket) = bucket.as_object_mut() {
+            let _ = bucket
+                .entry("key")
+                .or_insert(serde_json::Value::String(key));
+        }
+
+        T::deserialize(bucket).map(Some)
+    }
+
+    /// Walks the object keys of the path, skipping all the other values, and enters the array or,
+    /// for keyed buckets, the object
+    fn find_buckets(&mut self) -> Result<bool, Error> {
+        for index in 0..self.path.len() {
+            self.skip_whitespace()?;
+
+            if self.peek()? != Some(b'{') {
+                return Ok(false);
+            }
+
+            self.consume();
+
+            loop {
+                self.skip_whitespace()?;
+
+                if self.peek()? == Some(b'}') {
+                    return Ok(false);
+                }
+
+                let key = self.read_key()?;
+
+                self.skip_whitespace()?;
+                self.expect(b':')?;
+
+                if key == self.path[index] {
+                    break;
+                }
+
+                self.skip_value()?;
+                self.skip_whitespace()?;
+
+                match self.peek()? {
+                    Some(b',') => self.consume(),
+                    Some(b'}') => return Ok(false),
+                    _ => return Err(Error::custom("expected `,` or `}`")),
+                }
+            }
+        }
+
+        self.skip_whitespace()?;
+
+        match self.peek()? {
+            Some(b'[') => self.keyed = false,
+            Some(b'{') => self.keyed = true,
+            _ => return Ok(false),
+        }
+
+        self.consume();
+
+        Ok(true)
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, Error> {
+        self.reader
+            .fill_buf()
+            .map(|buffer| buffer.first().copied())
+            .map_err(Error::io)
+    }
+
+    fn consume(&mut self) {
+        self.reader.consume(1);
+    }
+
+    fn next_byte(&mut self) -> Result<u8, Error> {
+        let byte = self
+            .peek()?
+            .ok_or_else(|| Error::custom("unexpected end of response"))?;
+
+        self.consume();
+
+        Ok(byte)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        if self.next_byte()? == expected {
+            Ok(())
+        } else {
+            Err(Error::custom(format!("expected `{}`", expected as char)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), Error> {
+        while let Some(b' ' | b'\n' | b'\r' | b'\t') = self.peek()? {
+            self.consume();
+        }
+
+        Ok(())
+    }
+
+    /// Reads a string, collecting its raw bytes, including the quotes, into `raw` if provided
+    fn read_string(&mut self, mut raw: Option<&mut Vec<u8>>) -> Result<(), Error> {
+        self.expect(b'"')?;
+
+        let mut push = |byte| {
+            if let Some(raw) = raw.as_mut() {
+                raw.push(byte);
+            }
+        };
+
+        push(b'"');
+
+        loop {
+            match self.next_byte()? {
+                b'"' => break,
+                b'\\' => {
+                    push(b'\\');
+                    push(self.next_byte()?);
+                }
+                byte => push(byte),
+            }
+        }
+
+        push(b'"');
+
+        Ok(())
+    }
+
+    fn read_key(&mut self) -> Result<String, Error> {
+        let mut raw = Vec::new();
+
+        self.read_string(Some(&mut raw))?;
+
+        serde_json::from_slice(&raw)
+    }
+
+    fn skip_value(&mut self) -> Result<(), Error> {
+        self.skip_whitespace()?;
+
+        let mut depth = 0usize;
+
+        loop {
+            match self.peek()? {
+                Some(b'"') => self.read_string(None)?,
+                Some(b'{' | b'[') => {
+                    self.consume();
+                    depth += 1;
+                }
+                Some(b'}' | b']') if depth > 0 => {
+                    self.consume();
+                    depth -= 1;
+                }
+                Some(b',' | b'}' | b']') | None if depth == 0 => return Ok(()),
+                Some(_) => self.consume(),
+                None => return Err(Error::custom("unexpected end of response")),
+            }
+
+            if depth == 0 && !self.scalar_continues()? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Whether the value being skipped at the top level continues, i.e. it's a number or a
+    /// literal, which have no closing delimiter
+    fn scalar_continues(&mut self) -> Result<bool, Error> {
+        Ok(matches!(
+            self.peek()?,
+            Some(byte) if !matches!(byte, b',' | b'}' | b']' | b' ' | b'\n' | b'\r' | b'\t')
+        ))
+    }
+}
+
+impl<R, T> Iterator for BucketStream<R, T>
+where
+    R: Read,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_bucket() {
+            Ok(Some(bucket)) => Some(Ok(bucket)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(error) => {
+                self.state = State::Done;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<R, T> std::fmt::Debug for BucketStream<R, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BucketStream")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    const RESPONSE: &str = r#"{
+        "took": 3,
+        "timed_out": false,
+        "hits": { "total": { "value": 3 }, "hits": [{ "_source": { "buckets": [1] } }] },
+        "aggregations": {
+            "empty": { "buckets": [] },
+            "active": { "doc_count": 1, "brands": { "buckets": [{ "key": "c", "doc_count": 1 }] } },
+            "brands": {
+                "doc_count_error_upper_bound": 0,
+                "meta": { "note": "braces } and \"quotes\" [ in strings" },
+                "buckets" : [
+                    {
+                        "key": "a",
+                        "doc_count": 2,
+                        "sizes": { "buckets": [{ "key": "s", "doc_count": 2 }] }
+                    },
+                    { "key": "b", "doc_count": 1, "sizes": { "buckets": [] } }
+                ]
+            }
+        }
+    }"#;
+
+    fn keys(stream: BucketStream<&[u8], Value>) -> Vec<Value> {
+        stream
+            .map(|bucket| bucket.unwrap()["key"].clone())
+            .collect()
+    }
+
+    #[test]
+    fn streams_buckets() {
+        assert_eq!(
+            keys(BucketStream::new(RESPONSE.as_bytes(), "brands")),
+            vec![json!("a"), json!("b")]
+        );
+
+        assert_eq!(
+            keys(BucketStream::with_path(
+                RESPONSE.as_bytes(),
+                ["aggregations", "active", "brands"]
+            )),
+            vec![json!("c")]
+        );
+
+        assert!(keys(BucketStream::new(RESPONSE.as_bytes(), "empty")).is_empty());
+        assert!(keys(BucketStream::new(RESPONSE.as_bytes(), "missing")).is_empty());
+        assert!(keys(BucketStream::new(&b"{}"[..], "brands")).is_empty());
+    }
+
+    #[test]
+    fn streams_keyed_buckets() {
+        let body = br#"{
+            "aggregations": {
+                "prices": {
+                    "buckets": {
+                        "cheap": { "to": 10.0, "doc_count": 2 },
+                        "expensive" : { "key": "10.0-*", "from": 10.0, "doc_count": 1 }
+                    }
+                },
+                "empty": { "buckets": {} }
+            }
+        }"#;
+
+        assert_eq!(
+            keys(BucketStream::new(&body[..], "prices")),
+            vec![json!("cheap"), json!("10.0-*")]
+        );
+        assert!(keys(BucketStream::new(&body[..], "empty")).is_empty());
+    }
+
+    #[test]
+    fn fails_on_malformed_buckets() {
+        let mut stream = BucketStream::<_, Value>::new(
+            &br#"{ "aggregations": { "brands": { "buckets": [{ "key": "a" } { "key": "b" }"#[..],
+            "brands",
+        );
+
+        assert!(stream.next().unwrap().is_ok());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{AfterKey, Map};
+use serde_json::Value;
+
+/// Result of a [`CompositeAggregation`](crate::CompositeAggregation)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompositeAggregationResult {
+    /// Key of the last bucket, to pass to
+    /// [`CompositeAggregation::after`](crate::CompositeAggregation::after) when retrieving the
+    /// next page. [`None`] when there are no more buckets.
+    #[serde(default)]
+    pub after_key: Option<AfterKey>,
+
+    /// Composite buckets
+    #[serde(default)]
+    pub buckets: Vec<CompositeBucket>,
+}
+
+/// Bucket of a [`CompositeAggregation`](crate::CompositeAggregation)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompositeBucket {
+    /// Values of the composite sources, keyed by the source name
+    pub key: Map<String, Value>,
+
+    /// Number of documents in the bucket
+    pub doc_count: u64,
+
+    /// Results of the sub-aggregations
+    #[serde(flatten)]
+    pub aggregations: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let subject: CompositeAggregationResult = serde_json::from_value(json!({
+            "after_key": { "brand": "b", "day": 1700006400000u64 },
+            "buckets": [
+                {
+                    "key": { "brand": "a", "day": 1700006400000u64 },
+                    "doc_count": 2,
+                    "sales": { "value": 10.0 }
+                },
+                {
+                    "key": { "brand": "b", "day": 1700006400000u64 },
+                    "doc_count": 1,
+                    "sales": { "value": 5.0 }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            subject.after_key.as_ref().and_then(|key| key.get("brand")),
+            Some(&json!("b"))
+        );
+        assert_eq!(subject.buckets.len(), 2);
+        assert_eq!(subject.buckets[0].key["brand"], json!("a"));
+        assert_eq!(subject.buckets[0].doc_count, 2);
+        assert_eq!(
+            subject.buckets[0].aggregations["sales"],
+            json!({ "value": 10.0 })
+        );
+    }
+
+    #[test]
+    fn deserializes_last_page() {
+        let subject: CompositeAggregationResult =
+            serde_json::from_value(json!({ "buckets": [] })).unwrap();
+
+        assert_eq!(subject.after_key, None);
+        assert!(subject.buckets.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use super::{ErrorCause, SearchResponse};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error};
+use serde_json::Value;
+
+/// Error response returned by Elasticsearch APIs with a non-successful status code
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#common-options-error-options>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ErrorResponse {
+    /// Error cause, some errors (e.g. unsupported HTTP methods) only carry the reason
+    #[serde(deserialize_with = "deserialize_error")]
+    pub error: Box<ErrorCause>,
+
+    /// HTTP status code of the response
+    pub status: u16,
+}
+
+impl std::fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "status {}", self.status)?;
+
+        if let Some(ty) = &self.error.ty {
+            write!(f, ", {ty}")?;
+        }
+
+        if let Some(reason) = &self.error.reason {
+            write!(f, ": {reason}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ErrorResponse {}
+
+fn deserialize_error<'de, D>(deserializer: D) -> Result<Box<ErrorCause>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ErrorValue {
+        Cause(Box<ErrorCause>),
+        Reason(String),
+    }
+
+    Ok(match ErrorValue::deserialize(deserializer)? {
+        ErrorValue::Cause(cause) => cause,
+        ErrorValue::Reason(reason) => Box::new(ErrorCause {
+            caused_by: None,
+            reason: Some(reason),
+            root_cause: Vec::new(),
+            stack_trace: None,
+            suppressed: Vec::new(),
+            ty: None,
+            additional_details: Default::default(),
+        }),
+    })
+}
+
+/// Response of a search request, which is either a [`SearchResponse`] or an [`ErrorResponse`],
+/// so that the body can be parsed regardless of the status code
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let body = r#"{"error":{"type":"index_not_found_exception","reason":"no such index [products]"},"status":404}"#;
+///
+/// match serde_json::from_str::<SearchResult>(body).unwrap() {
+///     SearchResult::Ok(response) => println!("{} hits", response.hits.hits.len()),
+///     SearchResult::Err(error) => assert_eq!(error.status, 404),
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum SearchResult {
+    /// Successful search
+    Ok(Box<SearchResponse>),
+
+    /// Failed search
+    Err(ErrorResponse),
+}
+
+impl SearchResult {
+    /// Converts the response into a result, so that errors can be handled with `?`
+    pub fn into_result(self) -> Result<SearchResponse, ErrorResponse> {
+        match self {
+            Self::Ok(response) => Ok(*response),
+            Self::Err(error) => Err(error),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SearchResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn parse<T, E>(value: Value) -> Result<T, E>
+        where
+            T: DeserializeOwned,
+            E: Error,
+        {
+            serde_json::from_value(value).map_err(E::custom)
+        }
+
+        let value = Value::deserialize(deserializer)?;
+
+        // Successful responses never have an `error` key, parsing by shape would report the
+        // mismatched fields of the wrong variant
+        if value.get("error").is_some() {
+            parse(value).map(Self::Err)
+        } else {
+            parse(value).map(Self::Ok)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_error_response() {
+        let subject: ErrorResponse = serde_json::from_value(json!({
+            "error": {
+                "root_cause": [
+                    {
+                        "type": "index_not_found_exception",
+                        "reason": "no such index [products]",
+                        "index": "products"
+                    }
+                ],
+                "type": "index_not_found_exception",
+                "reason": "no such index [products]",
+                "index": "products",
+                "caused_by": {
+                    "type": "illegal_argument_exception",
+                    "reason": "index missing"
+                }
+            },
+            "status": 404
+        }))
+        .unwrap();
+
+        assert_eq!(subject.status, 404);
+        assert_eq!(
+            subject.error.ty.as_deref(),
+            Some("index_not_found_exception")
+        );
+        assert_eq!(subject.error.root_cause.len(), 1);
+        assert_eq!(
+            subject
+                .error
+                .caused_by
+                .as_ref()
+                .and_then(|cause| cause.ty.as_deref()),
+            Some("illegal_argument_exception")
+        );
+        assert_eq!(subject.error.additional_details["index"], json!("products"));
+        assert_eq!(
+            subject.to_string(),
+            "status 404, index_not_found_exception: no such index [products]"
+        );
+    }
+
+    #[test]
+    fn deserializes_error_response_with_reason_only() {
+        let subject: ErrorResponse = serde_json::from_value(json!({
+            "error": "Incorrect HTTP method for uri [/_search] and method [PUT]",
+            "status": 405
+        }))
+        .unwrap();
+
+        assert_eq!(
+            subject.to_string(),
+            "status 405: Incorrect HTTP method for uri [/_search] and method [PUT]"
+        );
+    }
+
+    #[test]
+    fn deserializes_search_result() {
+        let subject: SearchResult = serde_json::from_value(json!({
+            "took": 1,
+            "timed_out": false,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+            "hits": { "hits": [] }
+        }))
+        .unwrap();
+
+        assert_eq!(subject.into_result().map(|response| response.took), Ok(1));
+
+        let subject: SearchResult = serde_json::from_value(json!({
+            "error": { "type": "parsing_exception", "reason": "unknown query [foo]" },
+            "status": 400
+        }))
+        .unwrap();
+
+        assert_eq!(
+            subject.into_result().map_err(|error| error.status),
+            Err(400)
+        );
+
+        let subject = serde_json::from_value::<SearchResult>(json!({ "took": "soon" }));
+
+        assert!(subject.is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use super::{Explanation, Source};
+use crate::{util::ShouldSkip, Map};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Response of an [`Explain`](crate::Explain) request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainResponse {
+    /// Document index
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the document matches the query
+    pub matched: bool,
+
+    /// Score explanation, [`None`] when the document doesn't exist
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub explanation: Option<Explanation>,
+
+    /// Document source and stored fields, when requested with the `_source` or
+    /// `stored_fields` query parameters
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub get: Option<ExplainedDocument>,
+}
+
+/// Document returned with an [`ExplainResponse`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainedDocument {
+    /// Whether the document exists
+    pub found: bool,
+
+    /// Document source
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "_source",
+        default
+    )]
+    pub source: Source,
+
+    /// Stored fields of the document
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub fields: Map<String, Value>,
+}
+
+impl ExplainResponse {
+    /// Parses document source into a concrete type, [`None`] when the source wasn't requested
+    pub fn source<T>(&self) -> Option<Result<T, serde_json::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.get.as_ref().map(|document| document.source.parse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let subject: ExplainResponse = serde_json::from_value(json!({
+            "_index": "my-index-000001",
+            "_id": "0",
+            "matched": true,
+            "explanation": {
+                "value": 1.6943598,
+                "description": "weight(message:elasticsearch in 0) [PerFieldSimilarity], result of:",
+                "details": [
+                    {
+                        "value": 1.3862944,
+                        "description": "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5)) from:",
+                        "details": []
+                    }
+                ]
+            },
+            "get": {
+                "found": true,
+                "_source": { "message": "elasticsearch" }
+            }
+        }))
+        .unwrap();
+
+        assert!(subject.matched);
+        assert_eq!(subject.explanation.as_ref().unwrap().details.len(), 1);
+        assert_eq!(
+            subject.source::<Value>().unwrap().unwrap(),
+            json!({ "message": "elasticsearch" })
+        );
+    }
+
+    #[test]
+    fn deserializes_missing_document() {
+        let subject: ExplainResponse = serde_json::from_value(json!({
+            "_index": "my-index-000001",
+            "_id": "1",
+            "matched": false
+        }))
+        .unwrap();
+
+        assert!(!subject.matched);
+        assert_eq!(subject.explanation, None);
+        assert!(subject.source::<Value>().is_none());
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Explanation, NestedIdentity, Source};
-use crate::{util::ShouldSkip, InnerHitsResult, Map};
+use crate::{util::ShouldSkip, InnerHitsResult, Map, SearchAfter};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
@@ -29,6 +29,23 @@ pub struct Hit {
     )]
     pub id: String,
 
+    /// Document version, when requested with [`Search::version`](crate::Search::version)
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_version")]
+    pub version: Option<u64>,
+
+    /// Sequence number of the last modification of the document, when requested with
+    /// [`Search::seq_no_primary_term`](crate::Search::seq_no_primary_term)
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_seq_no")]
+    pub seq_no: Option<u64>,
+
+    /// Primary term of the last modification of the document, when requested with
+    /// [`Search::seq_no_primary_term`](crate::Search::seq_no_primary_term)
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "_primary_term"
+    )]
+    pub primary_term: Option<u64>,
+
     /// Document score. [`None`] when documents are implicitly sorted by a
     /// field other than `_score`
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_score")]
@@ -75,4 +92,20 @@ impl Hit {
     {
         self.source.parse()
     }
+
+    /// Parses the values of a field retrieved with [`Search::fields`](crate::Search::fields)
+    /// into a concrete type, the values are always returned as an array. [`None`] when the hit
+    /// has no values for the field.
+    pub fn field<T>(&self, name: &str) -> Option<Result<T, serde_json::Error>>
+    where
+        T: DeserializeOwned,
+    {
+        self.fields.get(name).map(T::deserialize)
+    }
+
+    /// Sort values of the hit, to pass to [`Search::search_after`](crate::Search::search_after)
+    /// for the next page
+    pub fn sort_values(&self) -> SearchAfter {
+        SearchAfter::from(&self.sort)
+    }
 }
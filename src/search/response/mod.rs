@@ -1,10 +1,17 @@
+mod bucket_stream;
 mod cluster_statistics;
+mod composite_aggregation_result;
 mod error_cause;
+mod error_response;
+mod explain_response;
 mod explanation;
 mod hit;
 mod hits_metadata;
 mod inner_hits_result;
+mod msearch_response;
 mod nested_identity;
+mod point_in_time_response;
+mod profile_result;
 mod search_response;
 mod shard_failure;
 mod shard_statistics;
@@ -14,13 +21,20 @@ mod suggest_option;
 mod total_hits;
 mod total_hits_relation;
 
+pub use self::bucket_stream::*;
 pub use self::cluster_statistics::*;
+pub use self::composite_aggregation_result::*;
 pub use self::error_cause::*;
+pub use self::error_response::*;
+pub use self::explain_response::*;
 pub use self::explanation::*;
 pub use self::hit::*;
 pub use self::hits_metadata::*;
 pub use self::inner_hits_result::*;
+pub use self::msearch_response::*;
 pub use self::nested_identity::*;
+pub use self::point_in_time_response::*;
+pub use self::profile_result::*;
 pub use self::search_response::*;
 pub use self::shard_failure::*;
 pub use self::shard_statistics::*;
@@ -0,0 +1,114 @@
+use super::{ErrorCause, SearchResponse};
+use crate::util::ShouldSkip;
+
+/// Multi search response, with the responses in the same order as the searches of the request
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MSearchResponse {
+    /// The time that it took Elasticsearch to process all the searches
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub took: Option<u32>,
+
+    /// Responses of the individual searches
+    pub responses: Vec<MSearchResponseItem>,
+}
+
+/// Outcome of a single search of a multi search request, which fails or succeeds independently
+/// of the others
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum MSearchResponseItem {
+    /// The search failed, e.g. its index doesn't exist
+    Failure(MSearchFailure),
+
+    /// The search succeeded
+    Success(Box<SearchResponse>),
+}
+
+/// Failed search of a multi search request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MSearchFailure {
+    /// HTTP status code the search would have failed with on its own
+    pub status: u16,
+
+    /// Error cause
+    pub error: Box<ErrorCause>,
+}
+
+impl MSearchResponse {
+    /// Parses a JSON response with [`simd-json`](https://docs.rs/simd-json), see
+    /// [`SearchResponse::from_simd_json`]
+    #[cfg(feature = "simd-json")]
+    pub fn from_simd_json(bytes: &mut [u8]) -> Result<Self, simd_json::Error> {
+        simd_json::serde::from_slice(bytes)
+    }
+
+    /// Converts the responses into results, so that failures can be handled with `?`
+    pub fn into_results(self) -> Vec<Result<SearchResponse, MSearchFailure>> {
+        self.responses
+            .into_iter()
+            .map(MSearchResponseItem::into_result)
+            .collect()
+    }
+}
+
+impl MSearchResponseItem {
+    /// Converts the response into a result
+    pub fn into_result(self) -> Result<SearchResponse, MSearchFailure> {
+        match self {
+            Self::Failure(failure) => Err(failure),
+            Self::Success(response) => Ok(*response),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = json!({
+            "took": 4,
+            "responses": [
+                {
+                    "took": 2,
+                    "timed_out": false,
+                    "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+                    "hits": { "total": { "value": 0, "relation": "eq" }, "hits": [] },
+                    "status": 200
+                },
+                {
+                    "error": {
+                        "root_cause": [],
+                        "type": "index_not_found_exception",
+                        "reason": "no such index [missing]"
+                    },
+                    "status": 404
+                }
+            ]
+        });
+
+        #[cfg(feature = "simd-json")]
+        assert_eq!(
+            MSearchResponse::from_simd_json(&mut serde_json::to_vec(&json).unwrap()).unwrap(),
+            serde_json::from_value::<MSearchResponse>(json.clone()).unwrap()
+        );
+
+        let response: MSearchResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.took, Some(4));
+
+        let results = response.into_results();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().took, 2);
+
+        let failure = results[1].as_ref().unwrap_err();
+
+        assert_eq!(failure.status, 404);
+        assert_eq!(
+            failure.error.ty.as_deref(),
+            Some("index_not_found_exception")
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use super::ShardStatistics;
+use crate::search::{PointInTime, Time};
+use crate::util::ShouldSkip;
+
+/// Response of the open point in time API
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpenPointInTimeResponse {
+    /// Point in time Id, to be passed to the subsequent search requests
+    pub id: String,
+
+    /// Number of shards touched with their states, not returned by Elasticsearch 7.x
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_shards")]
+    pub shards: Option<ShardStatistics>,
+}
+
+impl OpenPointInTimeResponse {
+    /// Creates a [`PointInTime`] for [`Search::pit`](crate::Search::pit) from the opened point in
+    /// time, extending its time to live by `keep_alive` with each search
+    pub fn pit(&self, keep_alive: Time) -> PointInTime {
+        PointInTime::new(&self.id, keep_alive)
+    }
+}
+
+/// Response of the close point in time API
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html#close-point-in-time-api>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ClosePointInTimeResponse {
+    /// Whether the point in time was closed, `false` if it had already expired
+    pub succeeded: bool,
+
+    /// Number of search contexts that have been freed
+    pub num_freed: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{util::assert_serialize, Search};
+
+    #[test]
+    fn deserializes_open_point_in_time_response() {
+        let response: OpenPointInTimeResponse = serde_json::from_value(json!({
+            "id": "46ToAwMDaWR5BXV1aWQy",
+            "_shards": {
+                "total": 1,
+                "successful": 1,
+                "skipped": 0,
+                "failed": 0
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.id, "46ToAwMDaWR5BXV1aWQy");
+        assert_eq!(response.shards.map(|shards| shards.total), Some(1));
+
+        let response: OpenPointInTimeResponse =
+            serde_json::from_value(json!({ "id": "46ToAwMDaWR5BXV1aWQy" })).unwrap();
+
+        assert_eq!(response.shards, None);
+        assert_serialize(
+            Search::new().pit(response.pit(Time::Minutes(1))),
+            json!({
+                "pit": {
+                    "id": "46ToAwMDaWR5BXV1aWQy",
+                    "keep_alive": "1m"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserializes_close_point_in_time_response() {
+        let response: ClosePointInTimeResponse =
+            serde_json::from_value(json!({ "succeeded": true, "num_freed": 3 })).unwrap();
+
+        assert_eq!(
+            response,
+            ClosePointInTimeResponse {
+                succeeded: true,
+                num_freed: 3,
+            }
+        );
+    }
+}
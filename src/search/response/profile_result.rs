@@ -0,0 +1,267 @@
+use crate::{util::ShouldSkip, Map};
+use serde_json::Value;
+
+/// Detailed timing information of the execution of a search, returned for requests with
+/// [`Search::profile`](crate::Search::profile) enabled
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-profile.html>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileResult {
+    /// Profiles of the shards taking part in the search
+    #[serde(default)]
+    pub shards: Vec<ShardProfile>,
+}
+
+/// Profile of the search execution on a single shard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShardProfile {
+    /// Shard identifier in the `[node_id][index][shard]` format
+    pub id: String,
+
+    /// Node the shard is allocated on
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub node_id: Option<String>,
+
+    /// Shard number
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub shard_id: Option<u32>,
+
+    /// Index name
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub index: Option<String>,
+
+    /// Cluster alias, for cross-cluster searches
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub cluster: Option<String>,
+
+    /// Query phase profiles, one for each search executed against the shard
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub searches: Vec<SearchProfile>,
+
+    /// Aggregation profiles
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub aggregations: Vec<AggregationProfile>,
+
+    /// Fetch phase profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub fetch: Option<FetchProfile>,
+}
+
+/// Profile of the query phase of a search
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchProfile {
+    /// Profiles of the executed Lucene queries
+    #[serde(default)]
+    pub query: Vec<QueryProfile>,
+
+    /// Time spent rewriting the queries, in nanoseconds
+    #[serde(default)]
+    pub rewrite_time: u64,
+
+    /// Profiles of the Lucene collectors
+    #[serde(default)]
+    pub collector: Vec<CollectorProfile>,
+}
+
+/// Profile of a Lucene query, the query DSL clauses are rewritten into
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueryProfile {
+    /// Lucene class name of the query, e.g. `BooleanQuery`
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Lucene explanation text of the query
+    pub description: String,
+
+    /// Total time spent executing the query and its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Low-level timings (e.g. `create_weight`, `next_doc`) and their invocation counts
+    #[serde(default)]
+    pub breakdown: Map<String, u64>,
+
+    /// Profiles of the sub-queries
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<QueryProfile>,
+}
+
+/// Profile of a Lucene collector, which coordinates the traversal, scoring and collection of
+/// the matching documents
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CollectorProfile {
+    /// Lucene class name of the collector
+    pub name: String,
+
+    /// Description of the collector purpose, e.g. `search_top_hits`
+    pub reason: String,
+
+    /// Total time spent by the collector and its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Profiles of the wrapped collectors
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<CollectorProfile>,
+}
+
+/// Profile of an aggregation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AggregationProfile {
+    /// Class name of the aggregator, e.g. `GlobalOrdinalsStringTermsAggregator`
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Aggregation name
+    pub description: String,
+
+    /// Total time spent executing the aggregation and its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Low-level timings (e.g. `collect`, `build_aggregation`) and their invocation counts
+    #[serde(default)]
+    pub breakdown: Map<String, u64>,
+
+    /// Aggregator specific debug information
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub debug: Map<String, Value>,
+
+    /// Profiles of the sub-aggregations
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<AggregationProfile>,
+}
+
+/// Profile of the fetch phase of a search
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FetchProfile {
+    /// Fetch phase or sub-phase name
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Description of the phase
+    pub description: String,
+
+    /// Total time spent in the phase and its children, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Low-level timings and their invocation counts
+    #[serde(default)]
+    pub breakdown: Map<String, u64>,
+
+    /// Phase specific debug information
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub debug: Map<String, Value>,
+
+    /// Profiles of the fetch sub-phases
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<FetchProfile>,
+}
+
+impl ProfileResult {
+    /// Query profiles of all the shards, e.g. to find the slowest clauses
+    pub fn queries(&self) -> impl Iterator<Item = &QueryProfile> {
+        self.shards
+            .iter()
+            .flat_map(|shard| shard.searches.iter())
+            .flat_map(|search| search.query.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let subject: ProfileResult = serde_json::from_value(json!({
+            "shards": [
+                {
+                    "id": "[q2aE02wS1R8qQFnYu6vDVQ][my-index-000001][0]",
+                    "node_id": "q2aE02wS1R8qQFnYu6vDVQ",
+                    "shard_id": 0,
+                    "index": "my-index-000001",
+                    "cluster": "(local)",
+                    "searches": [
+                        {
+                            "query": [
+                                {
+                                    "type": "BooleanQuery",
+                                    "description": "message:get message:search",
+                                    "time_in_nanos": 11972972,
+                                    "breakdown": {
+                                        "create_weight": 43488,
+                                        "create_weight_count": 1
+                                    },
+                                    "children": [
+                                        {
+                                            "type": "TermQuery",
+                                            "description": "message:search",
+                                            "time_in_nanos": 3129,
+                                            "breakdown": { "score": 0 }
+                                        }
+                                    ]
+                                }
+                            ],
+                            "rewrite_time": 451233,
+                            "collector": [
+                                {
+                                    "name": "QueryPhaseCollector",
+                                    "reason": "search_query_phase",
+                                    "time_in_nanos": 775274,
+                                    "children": [
+                                        {
+                                            "name": "SimpleTopScoreDocCollector",
+                                            "reason": "search_top_hits",
+                                            "time_in_nanos": 775274
+                                        }
+                                    ]
+                                }
+                            ]
+                        }
+                    ],
+                    "aggregations": [
+                        {
+                            "type": "NumericTermsAggregator",
+                            "description": "my_scoped_agg",
+                            "time_in_nanos": 79294,
+                            "breakdown": { "collect": 28203 },
+                            "debug": { "total_buckets": 1, "result_strategy": "long_terms" }
+                        }
+                    ],
+                    "fetch": {
+                        "type": "fetch",
+                        "description": "",
+                        "time_in_nanos": 660555,
+                        "breakdown": { "next_reader": 7292 },
+                        "debug": { "stored_fields": ["_id", "_routing", "_source"] },
+                        "children": [
+                            {
+                                "type": "FetchSourcePhase",
+                                "description": "",
+                                "time_in_nanos": 20443,
+                                "breakdown": { "process": 9561 },
+                                "debug": { "fast_path": 4 }
+                            }
+                        ]
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let queries: Vec<_> = subject.queries().collect();
+
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].ty, "BooleanQuery");
+        assert_eq!(queries[0].breakdown["create_weight"], 43488);
+        assert_eq!(queries[0].children[0].ty, "TermQuery");
+
+        let shard = &subject.shards[0];
+
+        assert_eq!(shard.shard_id, Some(0));
+        assert_eq!(shard.searches[0].collector[0].children.len(), 1);
+        assert_eq!(shard.aggregations[0].debug["total_buckets"], json!(1));
+        assert_eq!(
+            shard.fetch.as_ref().unwrap().children[0].ty,
+            "FetchSourcePhase"
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use super::{ClusterStatistics, HitsMetadata, ShardStatistics, Suggest};
-use crate::{util::ShouldSkip, Map};
+use super::{ClusterStatistics, Hit, HitsMetadata, ProfileResult, ShardStatistics, Suggest};
+use crate::{util::ShouldSkip, Map, SearchAfter};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
@@ -56,9 +56,28 @@ pub struct SearchResponse {
     /// Suggest response
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
     pub suggest: Map<String, Vec<Suggest>>,
+
+    /// Timing information of the search execution, when profiling is enabled
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub profile: Option<ProfileResult>,
 }
 
 impl SearchResponse {
+    /// Parses a [CBOR](https://www.rfc-editor.org/rfc/rfc8949) encoded response, returned for
+    /// requests sent with the `Accept: application/cbor` header
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        crate::util::cbor::from_slice(bytes)
+    }
+
+    /// Parses a JSON response with [`simd-json`](https://docs.rs/simd-json), which is
+    /// considerably faster than `serde_json` on large, aggregation heavy responses. The bytes
+    /// are used as scratch space and modified during parsing.
+    #[cfg(feature = "simd-json")]
+    pub fn from_simd_json(bytes: &mut [u8]) -> Result<Self, simd_json::Error> {
+        simd_json::serde::from_slice(bytes)
+    }
+
     /// A shorthand for retrieving the _source for each hit
     pub fn documents<T>(&self) -> Result<Vec<T>, serde_json::Error>
     where
@@ -66,6 +85,30 @@ impl SearchResponse {
     {
         self.hits.hits.iter().map(|hit| hit.source()).collect()
     }
+
+    /// Deserializes the result of the top-level aggregation with the given name, e.g. into a
+    /// [`CompositeAggregationResult`](crate::CompositeAggregationResult). [`None`] when the
+    /// response has no such aggregation.
+    pub fn aggregation<T>(&self, name: &str) -> Result<Option<T>, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.aggregations
+            .as_ref()
+            .and_then(|aggregations| aggregations.get(name))
+            .map(|aggregation| T::deserialize(aggregation))
+            .transpose()
+    }
+
+    /// Sort values of the last hit, to pass to [`Search::search_after`](crate::Search::search_after)
+    /// for the next page. [`None`] when there are no more hits or they weren't sorted.
+    pub fn search_after(&self) -> Option<SearchAfter> {
+        self.hits
+            .hits
+            .last()
+            .filter(|hit| !hit.sort.is_empty())
+            .map(Hit::sort_values)
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +141,9 @@ mod tests {
                 "_index": "_index",
                 "_type": "_doc",
                 "_id": "123",
+                "_version": 2,
+                "_seq_no": 5,
+                "_primary_term": 1,
                 "_score": 1.0
               }
             ]
@@ -185,6 +231,9 @@ mod tests {
                     nested: None,
                     index: "_index".into(),
                     id: "123".into(),
+                    version: Some(2),
+                    seq_no: Some(5),
+                    primary_term: Some(1),
                     score: Some(1.0),
                     source: Source::from_string("null".to_string()).unwrap(),
                     highlight: Default::default(),
@@ -195,6 +244,7 @@ mod tests {
                 }],
             },
             aggregations: None,
+            profile: None,
             terminated_early: None,
             scroll_id: None,
             fields: Default::default(),
@@ -266,6 +316,59 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn parses_with_simd_json() {
+        let json = json!({
+          "took": 6,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 2, "relation": "eq" },
+            "max_score": 1.5,
+            "hits": [
+              {
+                "_index": "products",
+                "_id": "1",
+                "_score": 1.5,
+                "_source": { "title": "Shirt", "sizes": ["m", "l"], "price": 12.5 },
+                "sort": [1.5, "1"],
+                "inner_hits": {
+                  "comments": {
+                    "hits": {
+                      "total": { "value": 1, "relation": "eq" },
+                      "max_score": 1.0,
+                      "hits": [{
+                        "_index": "products",
+                        "_id": "1",
+                        "_nested": { "field": "comments", "offset": 0 },
+                        "_score": 1.0,
+                        "_source": { "text": "nice" }
+                      }]
+                    }
+                  }
+                }
+              },
+              { "_index": "products", "_id": "2", "_score": null, "_source": null }
+            ]
+          },
+          "aggregations": {
+            "brands": {
+              "doc_count_error_upper_bound": 0,
+              "sum_other_doc_count": 0,
+              "buckets": [{ "key": "vinted", "doc_count": 2, "price": { "value": 25.0 } }]
+            }
+          }
+        });
+
+        let mut bytes = serde_json::to_vec(&json).unwrap();
+
+        assert_eq!(
+            SearchResponse::from_simd_json(&mut bytes).unwrap(),
+            serde_json::from_value::<SearchResponse>(json).unwrap()
+        );
+    }
+
     #[test]
     fn parses_documents() {
         let json = json!({
@@ -317,4 +420,79 @@ mod tests {
 
         assert_eq!(subject, expectation);
     }
+
+    #[test]
+    fn parses_fields() {
+        let json = json!({
+          "took": 6,
+          "timed_out": false,
+          "_shards": {
+            "total": 1,
+            "successful": 1,
+            "skipped": 0,
+            "failed": 0
+          },
+          "hits": {
+            "hits": [
+              {
+                "_id": "1",
+                "fields": {
+                  "user.id": ["kimchy"],
+                  "@timestamp": [4098435132000u64]
+                }
+              }
+            ]
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let hit = &subject.hits.hits[0];
+
+        assert_eq!(
+            hit.field::<Vec<String>>("user.id").unwrap().unwrap(),
+            ["kimchy"]
+        );
+        assert_eq!(
+            hit.field::<Vec<u64>>("@timestamp").unwrap().unwrap(),
+            [4098435132000]
+        );
+        assert!(hit.field::<Vec<u64>>("user.id").unwrap().is_err());
+        assert!(hit.field::<Vec<String>>("message").is_none());
+    }
+
+    #[test]
+    fn returns_search_after_of_last_hit() {
+        let json = json!({
+          "took": 6,
+          "timed_out": false,
+          "_shards": {
+            "total": 1,
+            "successful": 1,
+            "skipped": 0,
+            "failed": 0
+          },
+          "hits": {
+            "hits": [
+              { "_id": "1", "sort": [1463538855, "654322"] },
+              { "_id": "2", "sort": [1463538857, null] }
+            ]
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            subject.search_after(),
+            Some(SearchAfter::from([json!(1463538857), Value::Null]))
+        );
+        assert_eq!(
+            serde_json::to_value(
+                crate::Search::new().search_after(subject.search_after().unwrap())
+            )
+            .unwrap(),
+            json!({ "search_after": [1463538857, null] })
+        );
+
+        assert_eq!(SearchResponse::default().search_after(), None);
+    }
 }
@@ -1,7 +1,7 @@
 use super::ErrorCause;
 use crate::util::ShouldSkip;
 
-/// Shard failure details
+/// Shard failure details of a partial response, e.g. a search where some of the shards failed
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ShardFailure {
     /// Index name
@@ -10,13 +10,13 @@ pub struct ShardFailure {
     /// Node name
     pub node: Option<String>,
 
-    /// Status
+    /// Status name of the failure, e.g. `BAD_REQUEST`
     pub status: Option<String>,
 
-    /// Shard
+    /// Shard number
     pub shard: Option<u32>,
 
-    /// Reason
+    /// Cause of the failure, with its `type`, `reason` and `caused_by` chain
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     pub reason: Option<ErrorCause>,
 }
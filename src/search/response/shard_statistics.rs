@@ -10,7 +10,8 @@ pub struct ShardStatistics {
     /// Total number of successful shards
     pub successful: u32,
 
-    /// Total number of skipped shards
+    /// Total number of skipped shards, not returned by the document APIs
+    #[serde(default)]
     pub skipped: u32,
 
     /// Total number of failed shards
@@ -74,6 +75,27 @@ mod tests {
           ]
         });
 
-        let _ = serde_json::from_value::<ShardStatistics>(value).unwrap();
+        let subject = serde_json::from_value::<ShardStatistics>(value).unwrap();
+
+        match subject.failures.as_slice() {
+            [ShardFailure {
+                shard: Some(1),
+                index: Some(index),
+                reason: Some(reason),
+                ..
+            }] => {
+                assert_eq!(index, "nbs_comprehend-2021-w41");
+                assert_eq!(reason.ty.as_deref(), Some("script_exception"));
+                assert_eq!(
+                    reason
+                        .caused_by
+                        .as_ref()
+                        .and_then(|cause| cause.ty.as_deref()),
+                    Some("illegal_state_exception")
+                );
+                assert_eq!(reason.additional_details["lang"], json!("painless"));
+            }
+            failures => panic!("Unexpected failures {:?}", failures),
+        }
     }
 }
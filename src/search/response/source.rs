@@ -1,11 +1,51 @@
-use crate::util::ShouldSkip;
-use serde::de::DeserializeOwned;
+use crate::util::{ShouldSkip, RAW_VALUE_TOKEN};
+use serde::de::{self, value::MapAccessDeserializer, DeserializeOwned, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 use serde_json::{value::RawValue, Value};
 
 /// Document source with delayed deserialization
-#[derive(Clone, Default, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize)]
 pub struct Source(Box<RawValue>);
 
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(RAW_VALUE_TOKEN, SourceVisitor)
+    }
+}
+
+/// Takes the raw value handed over by serde_json deserializers, other deserializers, such as
+/// the `simd-json` one, don't know about raw values and the source is re-serialized
+struct SourceVisitor;
+
+impl<'de> Visitor<'de> for SourceVisitor {
+    type Value = Source;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Box::<RawValue>::deserialize(MapAccessDeserializer::new(map)).map(Source)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        serde_json::value::to_raw_value(&value)
+            .map(Source)
+            .map_err(de::Error::custom)
+    }
+}
+
 impl std::fmt::Debug for Source {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
@@ -25,6 +25,26 @@ impl std::fmt::Debug for SuggestOption {
     }
 }
 
+impl SuggestOption {
+    /// Suggested text, regardless of the suggester
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Completion(suggest_option) => &suggest_option.text,
+            Self::Term(suggest_option) => &suggest_option.text,
+            Self::Phrase(suggest_option) => &suggest_option.text,
+        }
+    }
+
+    /// Document score for completion suggester, suggest score for term, phrase
+    pub fn score(&self) -> f32 {
+        match self {
+            Self::Completion(suggest_option) => suggest_option.score,
+            Self::Term(suggest_option) => suggest_option.score,
+            Self::Phrase(suggest_option) => suggest_option.score,
+        }
+    }
+}
+
 /// Suggester response item option
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompletionSuggestOption {
@@ -102,3 +122,61 @@ pub struct PhraseSuggestOption {
     #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     pub highlighted: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_completion_option_with_source_and_contexts() {
+        let subject: SuggestOption = serde_json::from_value(json!({
+            "text": "Nevermind",
+            "_index": "music",
+            "_id": "1",
+            "_score": 34.0,
+            "_source": { "suggest": ["Nevermind", "Nirvana"] },
+            "contexts": { "place_type": ["cafe"] }
+        }))
+        .unwrap();
+
+        let SuggestOption::Completion(ref option) = subject else {
+            panic!("expected a completion option, got {:?}", subject);
+        };
+
+        assert_eq!(subject.text(), "Nevermind");
+        assert_eq!(subject.score(), 34.0);
+        assert_eq!(option.contexts["place_type"], ["cafe"]);
+        assert_eq!(
+            option.parse::<serde_json::Value>().unwrap(),
+            json!({ "suggest": ["Nevermind", "Nirvana"] })
+        );
+    }
+
+    #[test]
+    fn deserializes_term_and_phrase_options() {
+        let subject: SuggestOption =
+            serde_json::from_value(json!({ "text": "nirvana", "score": 0.8, "freq": 3 })).unwrap();
+
+        assert!(matches!(subject, SuggestOption::Term(_)));
+        assert_eq!(subject.text(), "nirvana");
+
+        let subject: SuggestOption = serde_json::from_value(json!({
+            "text": "nirvana band",
+            "highlighted": "<em>nirvana</em> band",
+            "score": 0.5,
+            "collate_match": true
+        }))
+        .unwrap();
+
+        assert_eq!(
+            subject,
+            SuggestOption::Phrase(PhraseSuggestOption {
+                text: "nirvana band".to_string(),
+                score: 0.5,
+                collate_match: Some(true),
+                highlighted: Some("<em>nirvana</em> band".to_string()),
+            })
+        );
+        assert_eq!(subject.score(), 0.5);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Retrieves the top documents with a k-nearest neighbor search, the equivalent of the `knn`
+/// section of the request.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#knn-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KnnRetriever {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector: Option<Vec<f32>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query_vector_builder: Option<QueryVectorBuilder>,
+
+    k: u32,
+
+    num_candidates: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: QueryCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<f32>,
+}
+
+impl Retriever {
+    /// Creates an instance of [`KnnRetriever`] with a query vector
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector` - Query vector, must have the same number of dimensions as the field
+    /// - `k` - Number of nearest neighbors to return
+    /// - `num_candidates` - Number of nearest neighbor candidates to consider per shard, can't
+    ///   be smaller than `k` or exceed `10000`
+    pub fn knn<T>(field: T, query_vector: Vec<f32>, k: u32, num_candidates: u32) -> KnnRetriever
+    where
+        T: ToString,
+    {
+        KnnRetriever {
+            field: field.to_string(),
+            query_vector: Some(query_vector),
+            query_vector_builder: None,
+            k,
+            num_candidates,
+            filter: Default::default(),
+            similarity: None,
+        }
+    }
+
+    /// Creates an instance of [`KnnRetriever`] with a query vector builder
+    ///
+    /// - `field` - The name of the vector field to search against
+    /// - `query_vector_builder` - Configuration of how to build the query vector
+    /// - `k` - Number of nearest neighbors to return
+    /// - `num_candidates` - Number of nearest neighbor candidates to consider per shard, can't
+    ///   be smaller than `k` or exceed `10000`
+    pub fn knn_query_vector_builder<T, U>(
+        field: T,
+        query_vector_builder: U,
+        k: u32,
+        num_candidates: u32,
+    ) -> KnnRetriever
+    where
+        T: ToString,
+        U: Into<QueryVectorBuilder>,
+    {
+        KnnRetriever {
+            field: field.to_string(),
+            query_vector: None,
+            query_vector_builder: Some(query_vector_builder.into()),
+            k,
+            num_candidates,
+            filter: Default::default(),
+            similarity: None,
+        }
+    }
+}
+
+impl KnnRetriever {
+    /// Queries the documents have to match, applied during the approximate kNN search
+    ///
+    /// Calling this method multiple times accumulates the filter queries.
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Option<Query>>,
+    {
+        self.filter.extend(filter);
+        self
+    }
+
+    /// The minimum similarity required for a document to be considered a match
+    pub fn similarity(mut self, similarity: f32) -> Self {
+        self.similarity = Some(similarity);
+        self
+    }
+
+    /// Mutable access to the filter queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.filter.as_mut_slice().iter_mut().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::from(
+                Retriever::knn("embedding", vec![0.5, 1.0], 10, 100)
+                    .filter([Query::term("category", "western")])
+                    .similarity(0.75),
+            ),
+            json!({
+                "knn": {
+                    "field": "embedding",
+                    "query_vector": [0.5, 1.0],
+                    "k": 10,
+                    "num_candidates": 100,
+                    "filter": [{ "term": { "category": { "value": "western" } } }],
+                    "similarity": 0.75
+                }
+            }),
+        );
+
+        assert_serialize(
+            Retriever::from(Retriever::knn_query_vector_builder(
+                "embedding",
+                TextEmbedding::new("my-model", "wild west"),
+                5,
+                50,
+            )),
+            json!({
+                "knn": {
+                    "field": "embedding",
+                    "query_vector_builder": {
+                        "text_embedding": {
+                            "model_id": "my-model",
+                            "model_text": "wild west"
+                        }
+                    },
+                    "k": 5,
+                    "num_candidates": 50
+                }
+            }),
+        );
+    }
+}
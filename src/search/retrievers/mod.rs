@@ -0,0 +1,79 @@
+//! Retrievers describe how the top documents of a search are retrieved, they can be nested to
+//! combine the results of multiple retrieval methods and rerank them.
+//!
+//! Retrievers are available since Elasticsearch `8.14` and replace the `query`, `knn` and `rank`
+//! sections of the request.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/retrievers-overview.html>
+
+mod knn_retriever;
+mod rrf_retriever;
+mod standard_retriever;
+mod text_similarity_reranker;
+
+use crate::search::Query;
+
+pub use self::knn_retriever::*;
+pub use self::rrf_retriever::*;
+pub use self::standard_retriever::*;
+pub use self::text_similarity_reranker::*;
+
+/// A container enum for supported Elasticsearch retriever types
+///
+/// To combine lexical and vector search with reciprocal rank fusion:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new().retriever(
+///     Retriever::rrf([
+///         Retriever::from(Retriever::standard().query(Query::r#match("title", "wild west"))),
+///         Retriever::from(Retriever::knn("embedding", vec![0.1, 0.2], 10, 100)),
+///     ])
+///     .rank_window_size(50),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum Retriever {
+    Standard(StandardRetriever),
+    Knn(KnnRetriever),
+    Rrf(RrfRetriever),
+    TextSimilarityReranker(TextSimilarityReranker),
+}
+
+impl From<StandardRetriever> for Retriever {
+    fn from(retriever: StandardRetriever) -> Self {
+        Self::Standard(retriever)
+    }
+}
+
+impl From<KnnRetriever> for Retriever {
+    fn from(retriever: KnnRetriever) -> Self {
+        Self::Knn(retriever)
+    }
+}
+
+impl From<RrfRetriever> for Retriever {
+    fn from(retriever: RrfRetriever) -> Self {
+        Self::Rrf(retriever)
+    }
+}
+
+impl From<TextSimilarityReranker> for Retriever {
+    fn from(retriever: TextSimilarityReranker) -> Self {
+        Self::TextSimilarityReranker(retriever)
+    }
+}
+
+impl Retriever {
+    /// Mutable access to the queries of the retriever and of its child retrievers
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        match self {
+            Self::Standard(retriever) => retriever.queries_mut(),
+            Self::Knn(retriever) => retriever.queries_mut(),
+            Self::Rrf(retriever) => retriever.queries_mut(),
+            Self::TextSimilarityReranker(retriever) => retriever.queries_mut(),
+        }
+    }
+}
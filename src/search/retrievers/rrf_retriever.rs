@@ -0,0 +1,116 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Combines the results of multiple child retrievers with reciprocal rank fusion (RRF), which
+/// scores the documents by their rank in each of the result sets.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#rrf-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RrfRetriever {
+    retrievers: Vec<Retriever>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_constant: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_window_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: QueryCollection,
+}
+
+impl Retriever {
+    /// Creates an instance of [`RrfRetriever`]
+    ///
+    /// - `retrievers` - child retrievers whose results are combined
+    pub fn rrf<T>(retrievers: T) -> RrfRetriever
+    where
+        T: IntoIterator,
+        T::Item: Into<Retriever>,
+    {
+        RrfRetriever {
+            retrievers: retrievers.into_iter().map(Into::into).collect(),
+            rank_constant: None,
+            rank_window_size: None,
+            filter: Default::default(),
+        }
+    }
+}
+
+impl RrfRetriever {
+    /// Determines how much influence the documents in the individual result sets have over the
+    /// final ranking, higher values give lower ranked documents more influence
+    ///
+    /// Default value is `60`
+    pub fn rank_constant(mut self, rank_constant: u32) -> Self {
+        self.rank_constant = Some(rank_constant);
+        self
+    }
+
+    /// The number of top documents of each child retriever to combine
+    ///
+    /// Default value is the `size` of the search
+    pub fn rank_window_size(mut self, rank_window_size: u32) -> Self {
+        self.rank_window_size = Some(rank_window_size);
+        self
+    }
+
+    /// Queries applied to all the child retrievers
+    ///
+    /// Calling this method multiple times accumulates the filter queries.
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Option<Query>>,
+    {
+        self.filter.extend(filter);
+        self
+    }
+
+    /// Mutable access to the filter queries and the queries of the child retrievers
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.filter
+            .as_mut_slice()
+            .iter_mut()
+            .chain(self.retrievers.iter_mut().flat_map(Retriever::queries_mut))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::from(
+                Retriever::rrf([
+                    Retriever::from(Retriever::standard().query(Query::term("title", "west"))),
+                    Retriever::from(Retriever::knn("embedding", vec![0.5], 10, 100)),
+                ])
+                .rank_constant(20)
+                .rank_window_size(100)
+                .filter([Query::term("category", "western")]),
+            ),
+            json!({
+                "rrf": {
+                    "retrievers": [
+                        { "standard": { "query": { "term": { "title": { "value": "west" } } } } },
+                        {
+                            "knn": {
+                                "field": "embedding",
+                                "query_vector": [0.5],
+                                "k": 10,
+                                "num_candidates": 100
+                            }
+                        }
+                    ],
+                    "rank_constant": 20,
+                    "rank_window_size": 100,
+                    "filter": [{ "term": { "category": { "value": "western" } } }]
+                }
+            }),
+        );
+    }
+}
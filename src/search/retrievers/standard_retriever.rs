@@ -0,0 +1,150 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Retrieves the top documents with a traditional query, the equivalent of the `query`,
+/// `sort`, `search_after` and `collapse` sections of the request.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#standard-retriever>
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct StandardRetriever {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: QueryCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_after: SearchAfter,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    terminate_after: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: SortCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_score: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collapse: Option<Collapse>,
+}
+
+impl Retriever {
+    /// Creates an instance of [`StandardRetriever`]
+    pub fn standard() -> StandardRetriever {
+        StandardRetriever::default()
+    }
+}
+
+impl StandardRetriever {
+    /// Query retrieving the documents
+    pub fn query<T>(mut self, query: T) -> Self
+    where
+        T: Into<Option<Query>>,
+    {
+        self.query = query.into();
+        self
+    }
+
+    /// Queries the retrieved documents have to match, without affecting their score
+    ///
+    /// Calling this method multiple times accumulates the filter queries.
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Option<Query>>,
+    {
+        self.filter.extend(filter);
+        self
+    }
+
+    /// Sort values of the last document of the previous page, see
+    /// [`Search::search_after`](crate::Search::search_after)
+    pub fn search_after<T>(mut self, sort_values: T) -> Self
+    where
+        T: Into<SearchAfter>,
+    {
+        self.search_after = sort_values.into();
+        self
+    }
+
+    /// Maximum number of documents to collect for each shard
+    pub fn terminate_after(mut self, terminate_after: u64) -> Self {
+        self.terminate_after = Some(terminate_after);
+        self
+    }
+
+    /// Sort order of the retrieved documents
+    pub fn sort<T>(mut self, sort: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Sort>,
+    {
+        self.sort.extend(sort);
+        self
+    }
+
+    /// Minimum score for the retrieved documents
+    pub fn min_score<T>(mut self, min_score: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.min_score = Some(min_score.as_float());
+        self
+    }
+
+    /// Collapses the retrieved documents by a field
+    pub fn collapse<T>(mut self, collapse: T) -> Self
+    where
+        T: Into<Collapse>,
+    {
+        self.collapse = Some(collapse.into());
+        self
+    }
+
+    /// Mutable access to the query, the filter queries and the inner hits highlight queries
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.query
+            .iter_mut()
+            .chain(self.filter.as_mut_slice())
+            .chain(self.collapse.iter_mut().flat_map(Collapse::queries_mut))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::from(Retriever::standard()),
+            json!({ "standard": {} }),
+        );
+
+        assert_serialize(
+            Retriever::from(
+                Retriever::standard()
+                    .query(Query::r#match("title", "wild west"))
+                    .filter([Query::term("category", "western")])
+                    .search_after([10])
+                    .terminate_after(100)
+                    .sort([Sort::from("year")])
+                    .min_score(0.5)
+                    .collapse(Collapse::new("author")),
+            ),
+            json!({
+                "standard": {
+                    "query": { "match": { "title": { "query": "wild west" } } },
+                    "filter": [{ "term": { "category": { "value": "western" } } }],
+                    "search_after": [10],
+                    "terminate_after": 100,
+                    "sort": ["year"],
+                    "min_score": 0.5,
+                    "collapse": { "field": "author" }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Reranks the top documents of a child retriever by their semantic similarity to a text, as
+/// scored by a `rerank` inference endpoint.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/retriever.html#text-similarity-reranker-retriever>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TextSimilarityReranker {
+    retriever: Box<Retriever>,
+
+    field: String,
+
+    inference_text: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inference_id: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    rank_window_size: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_score: Option<f32>,
+}
+
+impl Retriever {
+    /// Creates an instance of [`TextSimilarityReranker`]
+    ///
+    /// - `retriever` - child retriever whose top documents are reranked
+    /// - `field` - text field to compare to the `inference_text`
+    /// - `inference_text` - text the documents are compared to
+    pub fn text_similarity_reranker<T, U, V>(
+        retriever: T,
+        field: U,
+        inference_text: V,
+    ) -> TextSimilarityReranker
+    where
+        T: Into<Retriever>,
+        U: ToString,
+        V: ToString,
+    {
+        TextSimilarityReranker {
+            retriever: Box::new(retriever.into()),
+            field: field.to_string(),
+            inference_text: inference_text.to_string(),
+            inference_id: None,
+            rank_window_size: None,
+            min_score: None,
+        }
+    }
+}
+
+impl TextSimilarityReranker {
+    /// The `rerank` inference endpoint to score the documents with
+    pub fn inference_id<T>(mut self, inference_id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.inference_id = Some(inference_id.to_string());
+        self
+    }
+
+    /// The number of top documents of the child retriever to rerank
+    ///
+    /// Default value is `10`
+    pub fn rank_window_size(mut self, rank_window_size: u32) -> Self {
+        self.rank_window_size = Some(rank_window_size);
+        self
+    }
+
+    /// Minimum score of the reranked documents
+    pub fn min_score<T>(mut self, min_score: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.min_score = Some(min_score.as_float());
+        self
+    }
+
+    /// Mutable access to the queries of the child retriever
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.retriever.queries_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Retriever::from(
+                Retriever::text_similarity_reranker(
+                    Retriever::standard().query(Query::r#match("title", "wild west")),
+                    "description",
+                    "cowboys in the desert",
+                )
+                .inference_id("my-rerank-model")
+                .rank_window_size(100)
+                .min_score(0.5),
+            ),
+            json!({
+                "text_similarity_reranker": {
+                    "retriever": {
+                        "standard": {
+                            "query": { "match": { "title": { "query": "wild west" } } }
+                        }
+                    },
+                    "field": "description",
+                    "inference_text": "cowboys in the desert",
+                    "inference_id": "my-rerank-model",
+                    "rank_window_size": 100,
+                    "min_score": 0.5
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Searches rolled-up data and, when raw indices are targeted as well, merges the results with
+//! the ones of the live data.
+use crate::search::*;
+use crate::util::*;
+
+/// Request body of the [rollup search API](https://www.elastic.co/guide/en/elasticsearch/reference/current/rollup-search.html).
+///
+/// Rolled-up data only keeps the fields and metrics configured in the rollup job, hence the body
+/// only has `query` and `aggs`, and both are restricted to a subset of the Query DSL:
+/// - queries: `term`, `terms`, `range`, `match_all` and the `bool`, `boosting`,
+///   `constant_score` and `dis_max` compound queries made of them
+/// - aggregations: `date_histogram`, `histogram`, `terms`, `avg`, `max`, `min`, `sum` and
+///   `value_count`
+///
+/// The `try_*` builder methods reject anything else, the response is parsed as a regular
+/// [`SearchResponse`] with no hits.
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// let search = RollupSearch::new()
+///     .try_query(Query::bool().filter(Query::term("node", "a")))
+///     .unwrap()
+///     .try_aggregate("max_temperature", Aggregation::max("temperature"))
+///     .unwrap();
+///
+/// assert_eq!(
+///     RollupSearch::new()
+///         .try_query(Query::r#match("node", "a"))
+///         .unwrap_err(),
+///     ValidationError::RollupUnsupported("query"),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct RollupSearch {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+impl RollupSearch {
+    /// Creates a default rollup search instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Query restricting the rolled-up documents to aggregate over
+    pub fn query<Q>(mut self, query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Same as `query`, but fails if the query isn't supported by rollup search
+    pub fn try_query<Q>(self, query: Q) -> Result<Self, ValidationError>
+    where
+        Q: Into<Query>,
+    {
+        let query = query.into();
+
+        if !is_rollup_query(&query) {
+            return Err(ValidationError::RollupUnsupported("query"));
+        }
+
+        Ok(self.query(query))
+    }
+
+    add_aggregate!();
+
+    /// Same as `aggregate`, but fails if the aggregation or one of its sub-aggregations isn't
+    /// supported by rollup search
+    pub fn try_aggregate<N, A>(
+        self,
+        aggregation_name: N,
+        aggregation: A,
+    ) -> Result<Self, ValidationError>
+    where
+        N: Into<AggregationName>,
+        A: Into<Aggregation>,
+    {
+        let aggregation = aggregation.into();
+
+        if !is_rollup_aggregation(&aggregation) {
+            return Err(ValidationError::RollupUnsupported("aggregation"));
+        }
+
+        Ok(self.aggregate(aggregation_name, aggregation))
+    }
+
+    /// Fails if the query or any of the aggregations isn't supported by rollup search, for
+    /// requests built with the infallible builder methods
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if !self.query.as_ref().is_none_or(is_rollup_query) {
+            return Err(ValidationError::RollupUnsupported("query"));
+        }
+
+        if !self.aggs.values().all(is_rollup_aggregation) {
+            return Err(ValidationError::RollupUnsupported("aggregation"));
+        }
+
+        Ok(())
+    }
+}
+
+fn is_rollup_query(query: &Query) -> bool {
+    match query {
+        Query::Term(_) | Query::Terms(_) | Query::Range(_) | Query::MatchAll(_) => true,
+        Query::Bool(query) => query
+            .musts()
+            .iter()
+            .chain(query.filters())
+            .chain(query.shoulds())
+            .chain(query.must_nots())
+            .all(is_rollup_query),
+        Query::Boosting(query) => {
+            is_rollup_query(query.positive()) && is_rollup_query(query.negative())
+        }
+        Query::ConstantScore(query) => is_rollup_query(query.filter()),
+        Query::DisMax(query) => query.queries().iter().all(is_rollup_query),
+        _ => false,
+    }
+}
+
+fn is_rollup_aggregation(aggregation: &Aggregation) -> bool {
+    let supported = matches!(
+        aggregation,
+        Aggregation::DateHistogram(_)
+            | Aggregation::Histogram(_)
+            | Aggregation::Terms(_)
+            | Aggregation::Avg(_)
+            | Aggregation::Max(_)
+            | Aggregation::Min(_)
+            | Aggregation::Sum(_)
+            | Aggregation::ValueCount(_)
+    );
+
+    supported
+        && aggregation
+            .aggs()
+            .is_none_or(|aggs| aggs.values().all(is_rollup_aggregation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(RollupSearch::new(), json!({}));
+
+        assert_serialize(
+            RollupSearch::new()
+                .query(Query::term("node", "a"))
+                .aggregate("max_temperature", Aggregation::max("temperature")),
+            json!({
+                "query": { "term": { "node": { "value": "a" } } },
+                "aggs": { "max_temperature": { "max": { "field": "temperature" } } }
+            }),
+        );
+    }
+
+    #[test]
+    fn validates_queries() {
+        assert!(RollupSearch::new()
+            .try_query(
+                Query::bool()
+                    .must(Query::range("timestamp").gte("now-1d"))
+                    .filter(Query::terms("node", ["a", "b"]))
+                    .filter(Query::match_all())
+                    .should(Query::constant_score(Query::term("node", "c")))
+            )
+            .is_ok());
+
+        assert_eq!(
+            RollupSearch::new()
+                .try_query(Query::bool().filter(Query::exists("node")))
+                .unwrap_err(),
+            ValidationError::RollupUnsupported("query")
+        );
+
+        assert_eq!(
+            RollupSearch::new()
+                .query(Query::prefix("node", "a"))
+                .validate()
+                .unwrap_err(),
+            ValidationError::RollupUnsupported("query")
+        );
+    }
+
+    #[test]
+    fn validates_aggregations() {
+        let supported = Aggregation::date_histogram("timestamp")
+            .calendar_interval(CalendarInterval::Hour)
+            .aggregate("nodes", Aggregation::terms("node"));
+
+        assert!(RollupSearch::new()
+            .try_aggregate("timeline", supported.clone())
+            .is_ok());
+
+        assert_eq!(
+            RollupSearch::new()
+                .try_aggregate(
+                    "timeline",
+                    supported.aggregate("unique", Aggregation::cardinality("user"))
+                )
+                .unwrap_err(),
+            ValidationError::RollupUnsupported("aggregation")
+        );
+
+        assert_eq!(
+            RollupSearch::new()
+                .aggregate("unique", Aggregation::cardinality("user"))
+                .validate()
+                .unwrap_err(),
+            ValidationError::RollupUnsupported("aggregation")
+        );
+        assert!(RollupSearch::new().validate().is_ok());
+    }
+
+    #[test]
+    fn parses_response() {
+        let response: SearchResponse = serde_json::from_value(json!({
+            "took": 102,
+            "timed_out": false,
+            "terminated_early": false,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+            "hits": { "total": { "value": 0, "relation": "eq" }, "max_score": 0.0, "hits": [] },
+            "aggregations": { "max_temperature": { "value": 202.0 } }
+        }))
+        .unwrap();
+
+        assert!(response.hits.hits.is_empty());
+        assert_eq!(
+            response.aggregations,
+            Some(json!({ "max_temperature": { "value": 202.0 } }))
+        );
+    }
+}
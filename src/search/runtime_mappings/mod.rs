@@ -98,15 +98,57 @@ impl Serialize for RuntimeDataType {
     }
 }
 
-/// A runtime field that is used in a search request.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+impl<'de> serde::Deserialize<'de> for RuntimeDataType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        struct TypeAndFormat {
+            r#type: String,
+            format: Option<String>,
+        }
+
+        let TypeAndFormat { r#type, format } = TypeAndFormat::deserialize(deserializer)?;
+
+        match r#type.as_str() {
+            "boolean" => Ok(Self::Boolean),
+            "composite" => Ok(Self::Composite),
+            "date" => Ok(Self::Date(format)),
+            "double" => Ok(Self::Double),
+            "geo_point" => Ok(Self::GeoPoint),
+            "ip" => Ok(Self::Ip),
+            "keyword" => Ok(Self::Keyword),
+            "long" => Ok(Self::Long),
+            other => Err(D::Error::unknown_variant(
+                other,
+                &[
+                    "boolean",
+                    "composite",
+                    "date",
+                    "double",
+                    "geo_point",
+                    "ip",
+                    "keyword",
+                    "long",
+                ],
+            )),
+        }
+    }
+}
+
+/// A runtime field that is used in a search request or defined in the `runtime` section of the
+/// [index mappings](crate::Mappings::runtime).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct RuntimeMapping {
     #[serde(flatten)]
     r#type: RuntimeDataType,
     script: RuntimeScript,
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 struct RuntimeScript {
     source: String,
 }
@@ -204,6 +246,34 @@ mod tests {
     use super::*;
     use crate::util::*;
 
+    #[test]
+    fn deserialization() {
+        assert_eq!(
+            serde_json::from_value::<RuntimeMapping>(json!({
+                "type": "date",
+                "format": "yyyy-MM-dd",
+                "script": { "source": "emit(doc['timestamp'].value)", "lang": "painless" }
+            }))
+            .unwrap(),
+            RuntimeMapping::date_format("yyyy-MM-dd", "emit(doc['timestamp'].value)"),
+        );
+
+        assert_eq!(
+            serde_json::from_value::<RuntimeMapping>(json!({
+                "type": "keyword",
+                "script": { "source": "emit('a')" }
+            }))
+            .unwrap(),
+            RuntimeMapping::keyword("emit('a')"),
+        );
+
+        assert!(serde_json::from_value::<RuntimeMapping>(json!({
+            "type": "text",
+            "script": { "source": "emit('a')" }
+        }))
+        .is_err());
+    }
+
     #[test]
     fn serialization() {
         assert_serialize(
@@ -1,4 +1,5 @@
 use super::{FieldSort, GeoDistanceSort, ScriptSort, SortSpecialField};
+use crate::search::Field;
 use std::borrow::Cow;
 
 /// Sorting criterion
@@ -57,6 +58,12 @@ impl From<String> for Sort {
     }
 }
 
+impl From<Field> for Sort {
+    fn from(value: Field) -> Self {
+        Self::Field(value.into())
+    }
+}
+
 impl From<FieldSort> for Sort {
     fn from(value: FieldSort) -> Self {
         Self::FieldSort(value)
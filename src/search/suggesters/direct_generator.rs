@@ -0,0 +1,144 @@
+use super::SuggestMode;
+use crate::{util::ShouldSkip, AsFloat};
+
+/// A candidate generator of a [`PhraseSuggester`](super::PhraseSuggester), which produces a list
+/// of possible terms per term in the given text, similarly to a [`TermSuggester`](super::TermSuggester)
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html#_direct_generators>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectGenerator {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    suggest_mode: Option<SuggestMode>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_edits: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prefix_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_word_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_inspections: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_freq: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_term_freq: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pre_filter: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    post_filter: Option<String>,
+}
+
+impl DirectGenerator {
+    /// Creates a new instance of [DirectGenerator]
+    ///
+    /// - `field` - field to fetch the candidate suggestions from
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            size: None,
+            suggest_mode: None,
+            max_edits: None,
+            prefix_length: None,
+            min_word_length: None,
+            max_inspections: None,
+            min_doc_freq: None,
+            max_term_freq: None,
+            pre_filter: None,
+            post_filter: None,
+        }
+    }
+
+    /// The maximum corrections to be returned per suggest text token
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Controls what suggestions are included on the suggestions generated on each shard
+    pub fn suggest_mode(mut self, suggest_mode: SuggestMode) -> Self {
+        self.suggest_mode = Some(suggest_mode);
+        self
+    }
+
+    /// The maximum edit distance candidate suggestions can have in order to be considered as a
+    /// suggestion. Can only be a value between `1` and `2`, defaults to `2`
+    pub fn max_edits(mut self, max_edits: u8) -> Self {
+        self.max_edits = Some(max_edits);
+        self
+    }
+
+    /// The number of minimal prefix characters that must match in order be a candidate
+    /// suggestions, defaults to `1`
+    pub fn prefix_length(mut self, prefix_length: u64) -> Self {
+        self.prefix_length = Some(prefix_length);
+        self
+    }
+
+    /// The minimum length a suggest text term must have in order to be included, defaults to `4`
+    pub fn min_word_length(mut self, min_word_length: u64) -> Self {
+        self.min_word_length = Some(min_word_length);
+        self
+    }
+
+    /// A factor that is used to multiply with the `shard_size` in order to inspect more
+    /// candidate spelling corrections on the shard level, defaults to `5`
+    pub fn max_inspections(mut self, max_inspections: u64) -> Self {
+        self.max_inspections = Some(max_inspections);
+        self
+    }
+
+    /// The minimal threshold in number of documents a suggestion should appear in, either as an
+    /// absolute number of documents or relative to the number of documents when below `1`
+    pub fn min_doc_freq<T>(mut self, min_doc_freq: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.min_doc_freq = Some(min_doc_freq.as_float());
+        self
+    }
+
+    /// The maximum threshold in number of documents in which a suggest text token can exist in
+    /// order to be included, defaults to `0.01`
+    pub fn max_term_freq<T>(mut self, max_term_freq: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.max_term_freq = Some(max_term_freq.as_float());
+        self
+    }
+
+    /// Analyzer applied to each of the tokens passed to this candidate generator, before the
+    /// candidates are generated
+    pub fn pre_filter<T>(mut self, pre_filter: T) -> Self
+    where
+        T: ToString,
+    {
+        self.pre_filter = Some(pre_filter.to_string());
+        self
+    }
+
+    /// Analyzer applied to each of the generated tokens, before they are passed to the actual
+    /// phrase scorer
+    pub fn post_filter<T>(mut self, post_filter: T) -> Self
+    where
+        T: ToString,
+    {
+        self.post_filter = Some(post_filter.to_string());
+        self
+    }
+}
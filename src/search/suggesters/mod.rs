@@ -3,11 +3,23 @@
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html>
 
 mod completion_suggester;
+mod direct_generator;
+mod phrase_suggester;
+mod smoothing_model;
+mod suggest_collection;
 mod suggest_context_query;
 mod suggest_fuzziness;
+mod suggest_mode;
 mod suggester;
+mod term_suggester;
 
 pub use self::completion_suggester::*;
+pub use self::direct_generator::*;
+pub use self::phrase_suggester::*;
+pub use self::smoothing_model::*;
+pub use self::suggest_collection::*;
 pub use self::suggest_context_query::*;
 pub use self::suggest_fuzziness::*;
+pub use self::suggest_mode::*;
 pub use self::suggester::*;
+pub use self::term_suggester::*;
@@ -0,0 +1,418 @@
+use super::{DirectGenerator, SmoothingModel, Suggester};
+use crate::{util::ShouldSkip, AsFloat, Map, Query};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The `phrase` suggester adds additional logic on top of the `term` suggester to select entire
+/// corrected phrases instead of individual tokens weighted based on `ngram-language` models. In
+/// practice this suggester will be able to make better decisions about which tokens to pick based
+/// on co-occurrence and frequencies.
+///
+/// To create a phrase suggester:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new().suggest(
+///     "simple_phrase",
+///     Suggester::phrase("title.trigram")
+///         .text("noble prize")
+///         .direct_generator(DirectGenerator::new("title.trigram").suggest_mode(SuggestMode::Always))
+///         .highlight("<em>", "</em>"),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html#phrase-suggester>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PhraseSuggester {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    text: Option<String>,
+
+    phrase: PhraseSuggesterPhrase,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct PhraseSuggesterPhrase {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gram_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    real_word_error_likelihood: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    confidence: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_errors: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    separator: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    force_unigrams: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    token_limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    highlight: Option<PhraseSuggestHighlight>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collate: Option<PhraseSuggestCollate>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    smoothing: Option<SmoothingModel>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    direct_generator: Vec<DirectGenerator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct PhraseSuggestHighlight {
+    pre_tag: String,
+    post_tag: String,
+}
+
+/// Checks each suggestion of a [`PhraseSuggester`] against the specified query, to prune the
+/// suggestions for which no matching docs exist in the index
+///
+/// The query is rendered as a template, with the suggestion available as the `{{suggestion}}`
+/// variable, along with the specified [`param`](Self::param)s
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PhraseSuggestCollate {
+    query: PhraseSuggestCollateQuery,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    params: Map<String, Value>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prune: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct PhraseSuggestCollateQuery {
+    source: Query,
+}
+
+impl PhraseSuggestCollate {
+    /// Creates a new instance of [PhraseSuggestCollate]
+    ///
+    /// - `query` - query template to run for every suggestion
+    pub fn new<T>(query: T) -> Self
+    where
+        T: Into<Query>,
+    {
+        Self {
+            query: PhraseSuggestCollateQuery {
+                source: query.into(),
+            },
+            params: Default::default(),
+            prune: None,
+        }
+    }
+
+    /// Adds a parameter to the query template
+    pub fn param<T, U>(mut self, name: T, value: U) -> Self
+    where
+        T: ToString,
+        U: Serialize,
+    {
+        let _ = self.params.insert(
+            name.to_string(),
+            serde_json::to_value(value).unwrap_or_default(),
+        );
+        self
+    }
+
+    /// Returns all the suggestions with an additional `collate_match` option instead of only the
+    /// ones matching the query
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = Some(prune);
+        self
+    }
+}
+
+impl Suggester {
+    /// Creates an instance of [PhraseSuggester]
+    ///
+    /// - `field` - field to do n-gram lookups for the language model, the suggester will use
+    ///   this field to gain statistics to score corrections
+    pub fn phrase<T>(field: T) -> PhraseSuggester
+    where
+        T: ToString,
+    {
+        PhraseSuggester {
+            text: None,
+            phrase: PhraseSuggesterPhrase {
+                field: field.to_string(),
+                gram_size: None,
+                real_word_error_likelihood: None,
+                confidence: None,
+                max_errors: None,
+                separator: None,
+                size: None,
+                analyzer: None,
+                shard_size: None,
+                force_unigrams: None,
+                token_limit: None,
+                highlight: None,
+                collate: None,
+                smoothing: None,
+                direct_generator: Vec::new(),
+            },
+        }
+    }
+}
+
+impl PhraseSuggester {
+    /// The text to suggest corrections for, defaults to the global suggest text
+    pub fn text<T>(mut self, text: T) -> Self
+    where
+        T: ToString,
+    {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Sets max size of the n-grams (shingles) in the field, defaults to the `max_shingle_size`
+    /// of a `shingle` field or `1`
+    pub fn gram_size(mut self, gram_size: u64) -> Self {
+        self.phrase.gram_size = Some(gram_size);
+        self
+    }
+
+    /// The likelihood of a term being misspelled even if the term exists in the dictionary,
+    /// defaults to `0.95`
+    pub fn real_word_error_likelihood<T>(mut self, real_word_error_likelihood: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.phrase.real_word_error_likelihood = Some(real_word_error_likelihood.as_float());
+        self
+    }
+
+    /// Defines a factor applied to the input phrases score which is used as a threshold for other
+    /// suggest candidates, defaults to `1.0`
+    pub fn confidence<T>(mut self, confidence: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.phrase.confidence = Some(confidence.as_float());
+        self
+    }
+
+    /// The maximum percentage of the terms considered to be misspellings in order to form a
+    /// correction, or the absolute number of terms when `1` or above. Defaults to `1.0`
+    pub fn max_errors<T>(mut self, max_errors: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.phrase.max_errors = Some(max_errors.as_float());
+        self
+    }
+
+    /// The separator that is used to separate terms in the bigram field, defaults to the space
+    /// character
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: ToString,
+    {
+        self.phrase.separator = Some(separator.to_string());
+        self
+    }
+
+    /// The number of candidates that are generated for each individual query term, defaults to `5`
+    pub fn size(mut self, size: u64) -> Self {
+        self.phrase.size = Some(size);
+        self
+    }
+
+    /// Sets the analyzer to analyze the suggest text with, defaults to the search analyzer of the
+    /// field
+    pub fn analyzer<T>(mut self, analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.phrase.analyzer = Some(analyzer.to_string());
+        self
+    }
+
+    /// Sets the maximum number of suggested terms to be retrieved from each individual shard,
+    /// defaults to `5`
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.phrase.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Whether all shingles of the field are treated as unigrams (only relevant for `shingle`
+    /// fields), defaults to `true`
+    pub fn force_unigrams(mut self, force_unigrams: bool) -> Self {
+        self.phrase.force_unigrams = Some(force_unigrams);
+        self
+    }
+
+    /// Phrases with more tokens are not corrected individually, defaults to `10`
+    pub fn token_limit(mut self, token_limit: u64) -> Self {
+        self.phrase.token_limit = Some(token_limit);
+        self
+    }
+
+    /// Sets up suggestion highlighting, the tags surround the changed tokens of the suggestion
+    pub fn highlight<T, U>(mut self, pre_tag: T, post_tag: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        self.phrase.highlight = Some(PhraseSuggestHighlight {
+            pre_tag: pre_tag.to_string(),
+            post_tag: post_tag.to_string(),
+        });
+        self
+    }
+
+    /// Checks each suggestion against the specified query to prune suggestions for which no
+    /// matching docs exist in the index
+    pub fn collate(mut self, collate: PhraseSuggestCollate) -> Self {
+        self.phrase.collate = Some(collate);
+        self
+    }
+
+    /// The smoothing model to balance weight between infrequent grams and frequent grams,
+    /// defaults to [`SmoothingModel::StupidBackoff`]
+    pub fn smoothing(mut self, smoothing: SmoothingModel) -> Self {
+        self.phrase.smoothing = Some(smoothing);
+        self
+    }
+
+    /// Adds a candidate generator, candidates of all the generators are scored together
+    pub fn direct_generator(mut self, direct_generator: DirectGenerator) -> Self {
+        self.phrase.direct_generator.push(direct_generator);
+        self
+    }
+
+    /// Mutable access to the collate query
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.phrase
+            .collate
+            .iter_mut()
+            .map(|collate| &mut collate.query.source)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{util::assert_serialize, SuggestMode};
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            Suggester::phrase("title.trigram"),
+            json!({
+                "phrase": {
+                    "field": "title.trigram"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Suggester::phrase("title.trigram")
+                .text("noble prize")
+                .gram_size(3)
+                .real_word_error_likelihood(0.9)
+                .confidence(0.5)
+                .max_errors(2)
+                .separator("|")
+                .size(1)
+                .analyzer("trigram")
+                .shard_size(4)
+                .force_unigrams(false)
+                .token_limit(8)
+                .highlight("<em>", "</em>")
+                .collate(
+                    PhraseSuggestCollate::new(Query::r#match("{{field_name}}", "{{suggestion}}"))
+                        .param("field_name", "title")
+                        .prune(true),
+                )
+                .smoothing(SmoothingModel::laplace(0.7))
+                .direct_generator(
+                    DirectGenerator::new("title.trigram")
+                        .size(2)
+                        .suggest_mode(SuggestMode::Always)
+                        .max_edits(1)
+                        .prefix_length(2)
+                        .min_word_length(3)
+                        .max_inspections(4)
+                        .min_doc_freq(1)
+                        .max_term_freq(0.5),
+                )
+                .direct_generator(
+                    DirectGenerator::new("title.reverse")
+                        .pre_filter("reverse")
+                        .post_filter("reverse"),
+                ),
+            json!({
+                "text": "noble prize",
+                "phrase": {
+                    "field": "title.trigram",
+                    "gram_size": 3,
+                    "real_word_error_likelihood": 0.9,
+                    "confidence": 0.5,
+                    "max_errors": 2.0,
+                    "separator": "|",
+                    "size": 1,
+                    "analyzer": "trigram",
+                    "shard_size": 4,
+                    "force_unigrams": false,
+                    "token_limit": 8,
+                    "highlight": {
+                        "pre_tag": "<em>",
+                        "post_tag": "</em>"
+                    },
+                    "collate": {
+                        "query": {
+                            "source": {
+                                "match": {
+                                    "{{field_name}}": { "query": "{{suggestion}}" }
+                                }
+                            }
+                        },
+                        "params": { "field_name": "title" },
+                        "prune": true
+                    },
+                    "smoothing": {
+                        "laplace": { "alpha": 0.7 }
+                    },
+                    "direct_generator": [
+                        {
+                            "field": "title.trigram",
+                            "size": 2,
+                            "suggest_mode": "always",
+                            "max_edits": 1,
+                            "prefix_length": 2,
+                            "min_word_length": 3,
+                            "max_inspections": 4,
+                            "min_doc_freq": 1.0,
+                            "max_term_freq": 0.5
+                        },
+                        {
+                            "field": "title.reverse",
+                            "pre_filter": "reverse",
+                            "post_filter": "reverse"
+                        }
+                    ]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::AsFloat;
+
+/// Smoothing model of a [`PhraseSuggester`](super::PhraseSuggester), which balances weight
+/// between infrequent grams (grams (shingles) are not existing in the index) and frequent grams
+/// (appear at least once in the index)
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html#_smoothing_models>
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmoothingModel {
+    /// A simple backoff model that backs off to lower order n-gram models if the higher order
+    /// count is `0` and discounts the lower order n-gram model by a constant factor
+    StupidBackoff {
+        /// The constant factor, defaults to `0.4`
+        discount: f32,
+    },
+
+    /// A smoothing model that uses additive smoothing where a constant (typically `1.0` or
+    /// smaller) is added to all counts to balance weights
+    Laplace {
+        /// The constant added to all counts, defaults to `0.5`
+        alpha: f32,
+    },
+
+    /// A smoothing model that takes the weighted mean of the unigrams, bigrams, and trigrams
+    /// based on user supplied weights (lambdas). The sum of the lambdas must add up to `1`.
+    LinearInterpolation {
+        /// Weight of the trigrams
+        trigram_lambda: f32,
+
+        /// Weight of the bigrams
+        bigram_lambda: f32,
+
+        /// Weight of the unigrams
+        unigram_lambda: f32,
+    },
+}
+
+impl SmoothingModel {
+    /// Creates a [`SmoothingModel::StupidBackoff`] smoothing model
+    pub fn stupid_backoff<T>(discount: T) -> Self
+    where
+        T: AsFloat,
+    {
+        Self::StupidBackoff {
+            discount: discount.as_float(),
+        }
+    }
+
+    /// Creates a [`SmoothingModel::Laplace`] smoothing model
+    pub fn laplace<T>(alpha: T) -> Self
+    where
+        T: AsFloat,
+    {
+        Self::Laplace {
+            alpha: alpha.as_float(),
+        }
+    }
+
+    /// Creates a [`SmoothingModel::LinearInterpolation`] smoothing model
+    pub fn linear_interpolation<T, U, V>(
+        trigram_lambda: T,
+        bigram_lambda: U,
+        unigram_lambda: V,
+    ) -> Self
+    where
+        T: AsFloat,
+        U: AsFloat,
+        V: AsFloat,
+    {
+        Self::LinearInterpolation {
+            trigram_lambda: trigram_lambda.as_float(),
+            bigram_lambda: bigram_lambda.as_float(),
+            unigram_lambda: unigram_lambda.as_float(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            [
+                SmoothingModel::stupid_backoff(0.4),
+                SmoothingModel::laplace(0.7),
+                SmoothingModel::linear_interpolation(0.5, 0.25, 0.25),
+            ],
+            json!([
+                { "stupid_backoff": { "discount": 0.4 } },
+                { "laplace": { "alpha": 0.7 } },
+                {
+                    "linear_interpolation": {
+                        "trigram_lambda": 0.5,
+                        "bigram_lambda": 0.25,
+                        "unigram_lambda": 0.25
+                    }
+                }
+            ]),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use super::Suggester;
+use crate::search::Query;
+use crate::{util::ShouldSkip, Map};
+
+/// A collection of named suggesters along with the global suggest text shared between them
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct SuggestCollection {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    text: Option<String>,
+
+    #[serde(flatten)]
+    suggesters: Map<String, Suggester>,
+}
+
+impl ShouldSkip for SuggestCollection {
+    fn should_skip(&self) -> bool {
+        self.suggesters.should_skip()
+    }
+}
+
+impl SuggestCollection {
+    /// Creates a new instance of [SuggestCollection]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets global suggest text, used by suggesters that don't specify their own text
+    pub fn text<T>(&mut self, text: T)
+    where
+        T: ToString,
+    {
+        self.text = Some(text.to_string());
+    }
+
+    /// Adds a named suggester
+    pub fn insert<T, U>(&mut self, name: T, suggester: U)
+    where
+        T: ToString,
+        U: Into<Suggester>,
+    {
+        let _ = self.suggesters.insert(name.to_string(), suggester.into());
+    }
+
+    /// Overlays `other` on top of the collection, the text and suggesters of `other` take
+    /// precedence
+    pub(crate) fn merge(&mut self, other: SuggestCollection) {
+        if other.text.is_some() {
+            self.text = other.text;
+        }
+
+        self.suggesters.extend(other.suggesters);
+    }
+
+    /// Mutable access to the queries of the suggesters
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        self.suggesters
+            .values_mut()
+            .flat_map(Suggester::queries_mut)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        let mut subject = SuggestCollection::new();
+
+        assert!(subject.should_skip());
+
+        subject.text("query text");
+
+        assert!(subject.should_skip());
+
+        subject.insert("suggester", Suggester::completion("field", "prefix"));
+
+        assert_serialize(
+            subject,
+            json!({
+                "text": "query text",
+                "suggester": {
+                    "prefix": "prefix",
+                    "completion": { "field": "field" }
+                }
+            }),
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::util::ShouldSkip;
+use crate::AsFloat;
 
 /// The completion suggester considers all documents in the index, but it is often desirable to
 /// serve suggestions filtered and/or boosted by some criteria. For example, you want to suggest
@@ -38,9 +39,9 @@ impl SuggestContextQuery {
     /// multiplying the boost with the suggestion weight, defaults to `1`
     pub fn boost<T>(mut self, boost: T) -> Self
     where
-        T: num_traits::AsPrimitive<f32>,
+        T: AsFloat,
     {
-        self.boost = Some(boost.as_());
+        self.boost = Some(boost.as_float());
         self
     }
 
@@ -0,0 +1,14 @@
+/// Controls which suggestions are included, or for which suggest text terms suggestions are
+/// suggested
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestMode {
+    /// Only provide suggestions for suggest text terms that are not in the index (default)
+    Missing,
+
+    /// Only suggest suggestions that occur in more docs than the original suggest text term
+    Popular,
+
+    /// Suggest any matching suggestions based on terms in the suggest text
+    Always,
+}
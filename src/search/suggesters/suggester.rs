@@ -1,4 +1,5 @@
-use super::CompletionSuggester;
+use super::{CompletionSuggester, PhraseSuggester, TermSuggester};
+use crate::search::Query;
 
 /// Suggester variants
 #[derive(Clone, PartialEq, Serialize)]
@@ -6,12 +7,16 @@ use super::CompletionSuggester;
 #[allow(missing_docs)]
 pub enum Suggester {
     Completion(CompletionSuggester),
+    Term(TermSuggester),
+    Phrase(Box<PhraseSuggester>),
 }
 
 impl std::fmt::Debug for Suggester {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Completion(suggester) => suggester.fmt(f),
+            Self::Term(suggester) => suggester.fmt(f),
+            Self::Phrase(suggester) => suggester.fmt(f),
         }
     }
 }
@@ -21,3 +26,25 @@ impl From<CompletionSuggester> for Suggester {
         Self::Completion(value)
     }
 }
+
+impl From<TermSuggester> for Suggester {
+    fn from(value: TermSuggester) -> Self {
+        Self::Term(value)
+    }
+}
+
+impl From<PhraseSuggester> for Suggester {
+    fn from(value: PhraseSuggester) -> Self {
+        Self::Phrase(Box::new(value))
+    }
+}
+
+impl Suggester {
+    /// Mutable access to the queries of the suggester
+    pub(crate) fn queries_mut(&mut self) -> Vec<&mut Query> {
+        match self {
+            Self::Completion(_) | Self::Term(_) => Vec::new(),
+            Self::Phrase(suggester) => suggester.queries_mut(),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use super::{SuggestMode, Suggester};
+use crate::{util::ShouldSkip, AsFloat};
+
+/// The `term` suggester suggests terms based on edit distance. The provided suggest text is
+/// analyzed before terms are suggested. The suggested terms are provided per analyzed suggest
+/// text token. The `term` suggester doesn't take the query into account that is part of request.
+///
+/// To create a term suggester:
+/// ```
+/// # use elasticsearch_dsl::search::*;
+/// # let search =
+/// Search::new().suggest(
+///     "my-suggestion",
+///     Suggester::term("message")
+///         .text("tring out Elasticsearch")
+///         .suggest_mode(SuggestMode::Popular),
+/// );
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters.html#term-suggester>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TermSuggester {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    text: Option<String>,
+
+    term: TermSuggesterTerm,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TermSuggesterTerm {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sort: Option<TermSuggestSort>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    suggest_mode: Option<SuggestMode>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_edits: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    prefix_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_word_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_inspections: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_freq: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_term_freq: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    string_distance: Option<StringDistance>,
+}
+
+/// How the suggestions of a [`TermSuggester`] are sorted per suggest text term
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermSuggestSort {
+    /// Sort by score first, then document frequency and then the term itself (default)
+    Score,
+
+    /// Sort by document frequency first, then similarity score and then the term itself
+    Frequency,
+}
+
+/// String distance implementation used to compare how similar suggested terms are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StringDistance {
+    /// The default based on `damerau_levenshtein` but highly optimized for comparing string
+    /// distance for terms inside the index
+    Internal,
+
+    /// String distance algorithm based on Damerau-Levenshtein algorithm
+    DamerauLevenshtein,
+
+    /// String distance algorithm based on Levenshtein edit distance algorithm
+    Levenshtein,
+
+    /// String distance algorithm based on Jaro-Winkler algorithm
+    JaroWinkler,
+
+    /// String distance algorithm based on character n-grams
+    Ngram,
+}
+
+impl Suggester {
+    /// Creates an instance of [TermSuggester]
+    ///
+    /// - `field` - field to fetch the candidate suggestions from
+    pub fn term<T>(field: T) -> TermSuggester
+    where
+        T: ToString,
+    {
+        TermSuggester {
+            text: None,
+            term: TermSuggesterTerm {
+                field: field.to_string(),
+                analyzer: None,
+                size: None,
+                sort: None,
+                suggest_mode: None,
+                max_edits: None,
+                prefix_length: None,
+                min_word_length: None,
+                shard_size: None,
+                max_inspections: None,
+                min_doc_freq: None,
+                max_term_freq: None,
+                string_distance: None,
+            },
+        }
+    }
+}
+
+impl TermSuggester {
+    /// The text to suggest corrections for, defaults to the global suggest text
+    pub fn text<T>(mut self, text: T) -> Self
+    where
+        T: ToString,
+    {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Overrides search time analyzer, defaults to the search analyzer of the field
+    pub fn analyzer<T>(mut self, analyzer: T) -> Self
+    where
+        T: ToString,
+    {
+        self.term.analyzer = Some(analyzer.to_string());
+        self
+    }
+
+    /// The maximum corrections to be returned per suggest text token
+    pub fn size(mut self, size: u64) -> Self {
+        self.term.size = Some(size);
+        self
+    }
+
+    /// Defines how suggestions should be sorted per suggest text term
+    pub fn sort(mut self, sort: TermSuggestSort) -> Self {
+        self.term.sort = Some(sort);
+        self
+    }
+
+    /// Controls what suggestions are included or controls for what suggest text terms,
+    /// suggestions should be suggested
+    pub fn suggest_mode(mut self, suggest_mode: SuggestMode) -> Self {
+        self.term.suggest_mode = Some(suggest_mode);
+        self
+    }
+
+    /// The maximum edit distance candidate suggestions can have in order to be considered as a
+    /// suggestion. Can only be a value between `1` and `2`, defaults to `2`
+    pub fn max_edits(mut self, max_edits: u8) -> Self {
+        self.term.max_edits = Some(max_edits);
+        self
+    }
+
+    /// The number of minimal prefix characters that must match in order be a candidate for
+    /// suggestions, defaults to `1`
+    pub fn prefix_length(mut self, prefix_length: u64) -> Self {
+        self.term.prefix_length = Some(prefix_length);
+        self
+    }
+
+    /// The minimum length a suggest text term must have in order to be included, defaults to `4`
+    pub fn min_word_length(mut self, min_word_length: u64) -> Self {
+        self.term.min_word_length = Some(min_word_length);
+        self
+    }
+
+    /// The maximum number of suggestions that should be retrieved from each individual shard,
+    /// defaults to `size`
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.term.shard_size = Some(shard_size);
+        self
+    }
+
+    /// A factor that is used to multiply with the `shard_size` in order to inspect more
+    /// candidate spelling corrections on the shard level, defaults to `5`
+    pub fn max_inspections(mut self, max_inspections: u64) -> Self {
+        self.term.max_inspections = Some(max_inspections);
+        self
+    }
+
+    /// The minimal threshold in number of documents a suggestion should appear in, either as an
+    /// absolute number of documents or relative to the number of documents when below `1`
+    pub fn min_doc_freq<T>(mut self, min_doc_freq: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.term.min_doc_freq = Some(min_doc_freq.as_float());
+        self
+    }
+
+    /// The maximum threshold in number of documents in which a suggest text token can exist in
+    /// order to be included, either as an absolute number of documents or relative to the number
+    /// of documents when below `1`. Defaults to `0.01`
+    pub fn max_term_freq<T>(mut self, max_term_freq: T) -> Self
+    where
+        T: AsFloat,
+    {
+        self.term.max_term_freq = Some(max_term_freq.as_float());
+        self
+    }
+
+    /// Which string distance implementation to use for comparing how similar suggested terms are
+    pub fn string_distance(mut self, string_distance: StringDistance) -> Self {
+        self.term.string_distance = Some(string_distance);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            Suggester::term("message"),
+            json!({
+                "term": {
+                    "field": "message"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Suggester::term("message")
+                .text("tring out Elasticsearch")
+                .analyzer("standard")
+                .size(3)
+                .sort(TermSuggestSort::Frequency)
+                .suggest_mode(SuggestMode::Always)
+                .max_edits(1)
+                .prefix_length(2)
+                .min_word_length(3)
+                .shard_size(10)
+                .max_inspections(4)
+                .min_doc_freq(0.5)
+                .max_term_freq(0.02)
+                .string_distance(StringDistance::JaroWinkler),
+            json!({
+                "text": "tring out Elasticsearch",
+                "term": {
+                    "field": "message",
+                    "analyzer": "standard",
+                    "size": 3,
+                    "sort": "frequency",
+                    "suggest_mode": "always",
+                    "max_edits": 1,
+                    "prefix_length": 2,
+                    "min_word_length": 3,
+                    "shard_size": 10,
+                    "max_inspections": 4,
+                    "min_doc_freq": 0.5,
+                    "max_term_freq": 0.02,
+                    "string_distance": "jaro_winkler"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! The synonyms management API provides a convenient way to define and manage synonyms in an
+//! internal system index. Synonym sets can be referenced by `synonyms_set` in `synonym` and
+//! `synonym_graph` token filters.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/synonyms-apis.html>
+
+mod synonym_rule;
+mod synonyms_set;
+
+pub use self::synonym_rule::*;
+pub use self::synonyms_set::*;
@@ -0,0 +1,63 @@
+use crate::util::*;
+
+/// A synonym rule, used as an element of a [`SynonymsSet`](crate::SynonymsSet) or as the request
+/// body of the create or update synonym rule API
+///
+/// To create a synonym rule:
+/// ```
+/// # use elasticsearch_dsl::synonyms::*;
+/// # let rule =
+/// SynonymRule::new("hello, hi, howdy").id("greetings");
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-synonym-rule.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SynonymRule {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    id: Option<String>,
+
+    synonyms: String,
+}
+
+impl SynonymRule {
+    /// Creates an instance of [`SynonymRule`]
+    ///
+    /// - `synonyms` - The synonym rule definition, in
+    ///   [Solr format](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis-synonym-graph-tokenfilter.html#_solr_synonyms_2)
+    pub fn new<T>(synonyms: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            id: None,
+            synonyms: synonyms.to_string(),
+        }
+    }
+
+    /// The identifier for the synonym rule. If not specified, Elasticsearch will create an
+    /// identifier automatically. Ignored when the rule identifier is provided in the path.
+    pub fn id<T>(mut self, id: T) -> Self
+    where
+        T: ToString,
+    {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            SynonymRule::new("hello, hi"),
+            json!({ "synonyms": "hello, hi" }),
+        );
+
+        assert_serialize(
+            SynonymRule::new("i-pod, i pod => ipod").id("rule-1"),
+            json!({ "id": "rule-1", "synonyms": "i-pod, i pod => ipod" }),
+        );
+    }
+}
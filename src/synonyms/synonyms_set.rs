@@ -0,0 +1,66 @@
+use crate::synonyms::*;
+
+/// Request body of the create or update synonyms set API
+///
+/// To create a synonyms set:
+/// ```
+/// # use elasticsearch_dsl::synonyms::*;
+/// # let set =
+/// SynonymsSet::new()
+///     .rule(SynonymRule::new("hello, hi").id("greetings"))
+///     .rule(SynonymRule::new("bye, goodbye"));
+/// ```
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/put-synonyms-set.html>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct SynonymsSet {
+    synonyms_set: Vec<SynonymRule>,
+}
+
+impl SynonymsSet {
+    /// Creates an instance of [`SynonymsSet`]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a synonym rule to the set
+    pub fn rule(mut self, rule: SynonymRule) -> Self {
+        self.synonyms_set.push(rule);
+        self
+    }
+
+    /// Adds multiple synonym rules to the set
+    pub fn rules<T>(mut self, rules: T) -> Self
+    where
+        T: IntoIterator<Item = SynonymRule>,
+    {
+        self.synonyms_set.extend(rules);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(SynonymsSet::new(), json!({ "synonyms_set": [] }));
+
+        assert_serialize(
+            SynonymsSet::new()
+                .rule(SynonymRule::new("hello, hi").id("test-1"))
+                .rules([
+                    SynonymRule::new("bye, goodbye"),
+                    SynonymRule::new("i-pod, i pod => ipod").id("test-3"),
+                ]),
+            json!({
+                "synonyms_set": [
+                    { "id": "test-1", "synonyms": "hello, hi" },
+                    { "synonyms": "bye, goodbye" },
+                    { "id": "test-3", "synonyms": "i-pod, i pod => ipod" }
+                ]
+            }),
+        );
+    }
+}
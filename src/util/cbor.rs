@@ -0,0 +1,809 @@
+//! [CBOR](https://www.rfc-editor.org/rfc/rfc8949) encoding of request bodies and decoding of
+//! response bodies, sent with the `application/cbor` content type.
+//!
+//! Floats which fit into `f32` without losing precision are encoded in 5 bytes, which makes kNN
+//! query vectors considerably smaller than their JSON representation.
+//!
+//! SMILE, the other binary content type Elasticsearch accepts, isn't implemented.
+
+use super::RAW_VALUE_TOKEN;
+use serde::de::DeserializeOwned;
+use serde::ser::{self, Error as _, Serialize};
+use serde_json::{Error, Value};
+use std::convert::TryFrom;
+
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1 << 5;
+const BYTES: u8 = 2 << 5;
+const TEXT: u8 = 3 << 5;
+const ARRAY: u8 = 4 << 5;
+const MAP: u8 = 5 << 5;
+const SIMPLE: u8 = 7 << 5;
+
+const FALSE: u8 = SIMPLE | 20;
+const TRUE: u8 = SIMPLE | 21;
+const NULL: u8 = SIMPLE | 22;
+const F16: u8 = SIMPLE | 25;
+const F32: u8 = SIMPLE | 26;
+const F64: u8 = SIMPLE | 27;
+const INDEFINITE: u8 = 31;
+const BREAK: u8 = SIMPLE | INDEFINITE;
+
+/// Nesting depth of arrays, maps and tags the decoder accepts, same as the one of serde_json
+const MAX_DEPTH: usize = 128;
+
+/// Encodes the value as CBOR
+pub(crate) fn to_vec<T>(value: &T) -> Result<Vec<u8>, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let mut output = Vec::new();
+
+    value.serialize(&mut Serializer {
+        output: &mut output,
+    })?;
+
+    Ok(output)
+}
+
+/// Decodes a CBOR encoded value
+pub(crate) fn from_slice<T>(bytes: &[u8]) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut decoder = Decoder {
+        bytes,
+        position: 0,
+        depth: 0,
+    };
+    let value = decoder.value()?;
+
+    if decoder.position != bytes.len() {
+        return Err(Error::custom("trailing bytes after CBOR value"));
+    }
+
+    serde_json::from_value(value)
+}
+
+struct Serializer<'a> {
+    output: &'a mut Vec<u8>,
+}
+
+impl Serializer<'_> {
+    fn header(&mut self, major: u8, value: u64) {
+        match value {
+            0..=23 => self.output.push(major | value as u8),
+            24..=0xff => {
+                self.output.push(major | 24);
+                self.output.push(value as u8);
+            }
+            0x100..=0xffff => {
+                self.output.push(major | 25);
+                self.output.extend_from_slice(&(value as u16).to_be_bytes());
+            }
+            0x1_0000..=0xffff_ffff => {
+                self.output.push(major | 26);
+                self.output.extend_from_slice(&(value as u32).to_be_bytes());
+            }
+            _ => {
+                self.output.push(major | 27);
+                self.output.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+    }
+
+    fn length(&mut self, major: u8, len: Option<usize>) {
+        match len {
+            Some(len) => self.header(major, len as u64),
+            None => self.output.push(major | INDEFINITE),
+        }
+    }
+
+    fn text(&mut self, value: &str) {
+        self.header(TEXT, value.len() as u64);
+        self.output.extend_from_slice(value.as_bytes());
+    }
+
+    fn compound(&mut self, major: u8, len: Option<usize>) -> Compound<'_> {
+        self.length(major, len);
+
+        Compound {
+            serializer: Serializer {
+                output: self.output,
+            },
+            indefinite: len.is_none(),
+            raw: false,
+        }
+    }
+}
+
+impl<'a, 'b> ser::Serializer for &'a mut Serializer<'b> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.output.push(if v { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        if v < 0 {
+            self.header(NEGATIVE, !(v as u64));
+        } else {
+            self.header(UNSIGNED, v as u64);
+        }
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.header(UNSIGNED, v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        if !v.is_finite() {
+            // Same as serde_json, which has no representation for such floats either
+            return self.serialize_unit();
+        }
+
+        self.output.push(F32);
+        self.output.extend_from_slice(&v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        if f64::from(v as f32) == v {
+            return self.serialize_f32(v as f32);
+        }
+
+        if !v.is_finite() {
+            return self.serialize_unit();
+        }
+
+        self.output.push(F64);
+        self.output.extend_from_slice(&v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.text(v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.text(v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.header(BYTES, v.len() as u64);
+        self.output.extend_from_slice(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.output.push(NULL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.header(MAP, 1);
+        self.text(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(self.compound(ARRAY, len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.header(MAP, 1);
+        self.text(variant);
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(self.compound(MAP, len))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        if name == RAW_VALUE_TOKEN {
+            return Ok(Compound {
+                serializer: Serializer {
+                    output: self.output,
+                },
+                indefinite: false,
+                raw: true,
+            });
+        }
+
+        // Hand-written impls don't always report the number of fields they serialize, which
+        // doesn't matter for JSON
+        Ok(self.compound(MAP, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.header(MAP, 1);
+        self.text(variant);
+        Ok(self.compound(MAP, None))
+    }
+}
+
+struct Compound<'a> {
+    serializer: Serializer<'a>,
+    indefinite: bool,
+    raw: bool,
+}
+
+impl Compound<'_> {
+    fn element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut self.serializer)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        if self.indefinite {
+            self.serializer.output.push(BREAK);
+        }
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        // Map keys are strings in JSON, so the same conversions apply
+        match serde_json::to_value(key)? {
+            Value::String(key) => self.serializer.text(&key),
+            key @ (Value::Number(_) | Value::Bool(_)) => self.serializer.text(&key.to_string()),
+            _ => return Err(Error::custom("key must be a string")),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.raw {
+            // Raw JSON is serialized as a single string field, holding the JSON text
+            let json = serde_json::to_value(value)?;
+            let json = json
+                .as_str()
+                .ok_or_else(|| Error::custom("expected raw JSON text"))?;
+
+            return self.element(&serde_json::from_str::<Value>(json)?);
+        }
+
+        self.serializer.text(key);
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.serializer.text(key);
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| Error::custom("unexpected end of CBOR value"))?;
+        let bytes = &self.bytes[self.position..end];
+
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    /// Reads the argument of a header, `None` for indefinite lengths
+    fn argument(&mut self, info: u8) -> Result<Option<u64>, Error> {
+        let mut read = |len: usize| -> Result<u64, Error> {
+            Ok(self
+                .take(len)?
+                .iter()
+                .fold(0, |value, byte| value << 8 | u64::from(*byte)))
+        };
+
+        Ok(Some(match info {
+            0..=23 => info.into(),
+            24 => read(1)?,
+            25 => read(2)?,
+            26 => read(4)?,
+            27 => read(8)?,
+            INDEFINITE => return Ok(None),
+            _ => return Err(Error::custom("invalid CBOR header")),
+        }))
+    }
+
+    fn length(&mut self, info: u8) -> Result<Option<usize>, Error> {
+        self.argument(info)?
+            .map(|len| usize::try_from(len).map_err(Error::custom))
+            .transpose()
+    }
+
+    /// Whether the break marker of an indefinite length item is reached, consuming it
+    fn end(&mut self, len: Option<usize>, index: usize) -> bool {
+        match len {
+            Some(len) => index == len,
+            None if self.peek() == Some(BREAK) => {
+                self.position += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn chunks(&mut self, major: u8, info: u8) -> Result<Vec<u8>, Error> {
+        match self.length(info)? {
+            Some(len) => Ok(self.take(len)?.to_vec()),
+            None => {
+                let mut bytes = Vec::new();
+
+                while !self.end(None, 0) {
+                    let header = self.byte()?;
+
+                    if header & 0xe0 != major {
+                        return Err(Error::custom("invalid CBOR string chunk"));
+                    }
+
+                    // Chunks must have definite lengths, which also keeps this from recursing
+                    let len = self
+                        .length(header & 0x1f)?
+                        .ok_or_else(|| Error::custom("nested indefinite length CBOR string"))?;
+
+                    bytes.extend_from_slice(self.take(len)?);
+                }
+
+                Ok(bytes)
+            }
+        }
+    }
+
+    fn text(&mut self, info: u8) -> Result<String, Error> {
+        String::from_utf8(self.chunks(TEXT, info)?).map_err(Error::custom)
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(Error::custom("CBOR value nested too deeply"));
+        }
+
+        self.depth += 1;
+        let value = self.item();
+        self.depth -= 1;
+
+        value
+    }
+
+    fn item(&mut self) -> Result<Value, Error> {
+        let header = self.byte()?;
+        let (major, info) = (header & 0xe0, header & 0x1f);
+
+        Ok(match major {
+            UNSIGNED => match self.argument(info)? {
+                Some(value) => Value::from(value),
+                None => return Err(Error::custom("invalid CBOR integer")),
+            },
+            NEGATIVE => match self.argument(info)? {
+                Some(value) if value <= i64::MAX as u64 => Value::from(!(value as i64)),
+                Some(value) => Value::from(-1.0 - value as f64),
+                None => return Err(Error::custom("invalid CBOR integer")),
+            },
+            BYTES => Value::from(self.chunks(BYTES, info)?),
+            TEXT => Value::String(self.text(info)?),
+            ARRAY => {
+                let len = self.length(info)?;
+                let mut values = Vec::with_capacity(len.unwrap_or_default().min(1024));
+
+                while !self.end(len, values.len()) {
+                    values.push(self.value()?);
+                }
+
+                Value::Array(values)
+            }
+            MAP => {
+                let len = self.length(info)?;
+                let mut values = serde_json::Map::new();
+                let mut index = 0;
+
+                while !self.end(len, index) {
+                    let key = match self.value()? {
+                        Value::String(key) => key,
+                        key @ (Value::Number(_) | Value::Bool(_)) => key.to_string(),
+                        _ => return Err(Error::custom("unsupported CBOR map key")),
+                    };
+                    let _ = values.insert(key, self.value()?);
+
+                    index += 1;
+                }
+
+                Value::Object(values)
+            }
+            // Tags, such as dates and big numbers, are read as their tagged values
+            0xc0 => {
+                if self.argument(info)?.is_none() {
+                    return Err(Error::custom("invalid CBOR tag"));
+                }
+
+                self.value()?
+            }
+            _ => match header {
+                FALSE => Value::Bool(false),
+                TRUE => Value::Bool(true),
+                // `undefined` is read as `null`
+                NULL | 0xf7 => Value::Null,
+                F16 => {
+                    let bits = u16::from_be_bytes([self.byte()?, self.byte()?]);
+
+                    Value::from(f16_to_f64(bits))
+                }
+                F32 => {
+                    let bytes = self.take(4)?;
+
+                    Value::from(f64::from(f32::from_be_bytes([
+                        bytes[0], bytes[1], bytes[2], bytes[3],
+                    ])))
+                }
+                F64 => {
+                    let mut bytes = [0; 8];
+
+                    bytes.copy_from_slice(self.take(8)?);
+
+                    Value::from(f64::from_be_bytes(bytes))
+                }
+                _ => return Err(Error::custom("unsupported CBOR value")),
+            },
+        })
+    }
+}
+
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from(bits >> 10 & 0x1f);
+    let fraction = f64::from(bits & 0x3ff);
+
+    sign * match exponent {
+        0 => fraction * 2f64.powi(-24),
+        0x1f if fraction == 0.0 => f64::INFINITY,
+        0x1f => f64::NAN,
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::*;
+
+    #[test]
+    fn encodes_values() {
+        assert_eq!(to_vec(&0u8).unwrap(), [0x00]);
+        assert_eq!(to_vec(&24u8).unwrap(), [0x18, 24]);
+        assert_eq!(to_vec(&1000u16).unwrap(), [0x19, 0x03, 0xe8]);
+        assert_eq!(to_vec(&-1i8).unwrap(), [0x20]);
+        assert_eq!(to_vec(&-1000i32).unwrap(), [0x39, 0x03, 0xe7]);
+        assert_eq!(to_vec(&1.5f64).unwrap(), [0xfa, 0x3f, 0xc0, 0x00, 0x00]);
+        assert_eq!(
+            to_vec(&1.1f64).unwrap(),
+            [0xfb, 0x3f, 0xf1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a]
+        );
+        assert_eq!(to_vec("a").unwrap(), [0x61, b'a']);
+        assert_eq!(to_vec(&[true, false]).unwrap(), [0x82, 0xf5, 0xf4]);
+        assert_eq!(to_vec(&None::<u8>).unwrap(), [0xf6]);
+        assert_eq!(
+            to_vec(&json!({ "a": 1 })).unwrap(),
+            [0xa1, 0x61, b'a', 0x01]
+        );
+    }
+
+    #[test]
+    fn round_trips_search_requests() {
+        let search = Search::new()
+            .size(10)
+            .query(
+                Query::bool()
+                    .filter(Query::term("user_id", 1))
+                    .filter(Query::raw(Query::range("price").gte(-2.5)).unwrap())
+                    .should(Query::r#match("title", "shirt").boost(1.5)),
+            )
+            .knn(Knn::query_vector("embedding", vec![0.1, -0.2, 0.3]).k(5))
+            .extra([("terminate_after".to_string(), json!(100))].into());
+
+        let cbor = to_vec(&search).unwrap();
+
+        assert_eq!(
+            from_slice::<Value>(&cbor).unwrap(),
+            serde_json::to_value(&search).unwrap()
+        );
+        assert!(cbor.len() < serde_json::to_vec(&search).unwrap().len());
+    }
+
+    #[test]
+    fn decodes_search_responses() {
+        let response = json!({
+            "took": 1,
+            "timed_out": false,
+            "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+            "hits": {
+                "total": { "value": 1, "relation": "eq" },
+                "max_score": 1.0,
+                "hits": [{ "_index": "test", "_id": "1", "_score": 1.0, "_source": { "a": 1 } }]
+            }
+        });
+
+        assert_eq!(
+            SearchResponse::from_cbor(&to_vec(&response).unwrap()).unwrap(),
+            serde_json::from_value::<SearchResponse>(response).unwrap()
+        );
+    }
+
+    #[test]
+    fn decodes_values() {
+        // Indefinite length map and array, half precision float, tagged value
+        let cbor = [
+            0xbf, 0x61, b'a', 0x9f, 0x01, 0xf9, 0x3c, 0x00, 0xff, 0x61, b'b', 0xc1, 0x1a, 0x00,
+            0x00, 0x00, 0x01, 0xff,
+        ];
+
+        assert_eq!(
+            from_slice::<Value>(&cbor).unwrap(),
+            json!({ "a": [1, 1.0], "b": 1 })
+        );
+
+        assert!(from_slice::<Value>(&[0x82, 0x01]).is_err());
+        assert!(from_slice::<Value>(&[0x01, 0x01]).is_err());
+        assert!(from_slice::<Value>(&[0x1f]).is_err());
+        assert!(from_slice::<Value>(&[0xdf, 0x01]).is_err());
+    }
+
+    #[test]
+    fn decodes_chunked_strings() {
+        assert_eq!(
+            from_slice::<Value>(&[0x7f, 0x61, b'a', 0x62, b'b', b'c', 0xff]).unwrap(),
+            json!("abc")
+        );
+
+        let mut nested = vec![0x7f; 200_000];
+        nested.push(0xff);
+
+        assert!(SearchResponse::from_cbor(&nested).is_err());
+        assert!(from_slice::<Value>(&[0x7f, 0x7f, 0xff, 0xff]).is_err());
+    }
+
+    #[test]
+    fn limits_nesting_depth() {
+        let nested = |depth: usize| {
+            let mut cbor = vec![0x81; depth - 1];
+            cbor.push(0x80);
+            cbor
+        };
+
+        assert!(from_slice::<Value>(&nested(MAX_DEPTH)).is_ok());
+        assert!(from_slice::<Value>(&nested(MAX_DEPTH + 1)).is_err());
+        assert!(from_slice::<Value>(&[0xc1; 100_000]).is_err());
+    }
+}
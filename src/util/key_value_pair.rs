@@ -1,4 +1,6 @@
+use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::marker::PhantomData;
 
 #[derive(Clone, PartialEq, Eq)]
 pub(crate) struct KeyValuePair<K, V> {
@@ -40,6 +42,48 @@ where
     }
 }
 
+impl<'de, K, V> Deserialize<'de> for KeyValuePair<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyValuePairVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for KeyValuePairVisitor<K, V>
+        where
+            K: Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = KeyValuePair<K, V>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map with a single entry")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (key, value) = map
+                    .next_entry()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(2, &self));
+                }
+
+                Ok(KeyValuePair::new(key, value))
+            }
+        }
+
+        deserializer.deserialize_map(KeyValuePairVisitor(PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +93,17 @@ mod tests {
     fn serializes_as_key_value_pair() {
         assert_serialize(KeyValuePair::new("key", "value"), json!({ "key": "value" }));
     }
+
+    #[test]
+    fn deserializes_from_single_entry_map() {
+        let pair: KeyValuePair<String, u32> = serde_json::from_value(json!({ "key": 1 })).unwrap();
+
+        assert_eq!(pair.key, "key");
+        assert_eq!(pair.value, 1);
+
+        assert!(serde_json::from_value::<KeyValuePair<String, u32>>(json!({})).is_err());
+        assert!(
+            serde_json::from_value::<KeyValuePair<String, u32>>(json!({ "a": 1, "b": 2 })).is_err()
+        );
+    }
 }
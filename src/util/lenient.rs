@@ -0,0 +1,312 @@
+use serde::de::{
+    DeserializeSeed, Deserializer, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde_json::{Error, Map, Value};
+
+/// Deserializer over a JSON value which accepts the stringified values returned by the
+/// Elasticsearch settings and mapping APIs.
+///
+/// Numbers and booleans are parsed from strings, scalars are read as strings when one is expected
+/// and a single value is accepted where a list is expected. The coercions apply to all nested
+/// values as well.
+pub(crate) struct Lenient(Value);
+
+impl Lenient {
+    /// Creates an instance of [`Lenient`]
+    pub(crate) fn new(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                match self.0 {
+                    Value::String(value) => {
+                        let number: serde_json::Number = value
+                            .trim()
+                            .parse()
+                            .map_err(|_| Error::custom(format!("invalid number `{value}`")))?;
+
+                        Value::Number(number).$method(visitor)
+                    }
+                    value => value.$method(visitor),
+                }
+            }
+        )+
+    };
+}
+
+impl<'de> Deserializer<'de> for Lenient {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Array(values) => visitor.visit_seq(LenientSeq(values.into_iter())),
+            Value::Object(values) => visitor.visit_map(LenientMap::new(values)),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::String(value) if value == "true" => visitor.visit_bool(true),
+            Value::String(value) if value == "false" => visitor.visit_bool(false),
+            value => value.deserialize_bool(visitor),
+        }
+    }
+
+    deserialize_number!(
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+    );
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Number(value) => visitor.visit_string(value.to_string()),
+            Value::Bool(value) => visitor.visit_string(value.to_string()),
+            value => value.deserialize_string(visitor),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Self(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Array(values) => visitor.visit_seq(LenientSeq(values.into_iter())),
+            Value::Null => Value::Null.deserialize_seq(visitor),
+            value => visitor.visit_seq(LenientSeq(vec![value].into_iter())),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.0 {
+            Value::Object(values) => visitor.visit_map(LenientMap::new(values)),
+            value => value.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        char bytes byte_buf unit unit_struct identifier ignored_any
+    }
+}
+
+struct LenientSeq(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for LenientSeq {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(Lenient(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct LenientMap {
+    entries: serde_json::map::IntoIter,
+    value: Option<Value>,
+}
+
+impl LenientMap {
+    fn new(values: Map<String, Value>) -> Self {
+        Self {
+            entries: values.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for LenientMap {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Lenient(value)),
+            None => Err(Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Settings {
+        shards: u32,
+        ratio: f32,
+        enabled: bool,
+        name: String,
+        fields: Vec<String>,
+        nested: Option<Box<Settings>>,
+    }
+
+    #[test]
+    fn coerces_stringified_values() {
+        let value = json!({
+            "shards": "3",
+            "ratio": "0.5",
+            "enabled": "true",
+            "name": 1,
+            "fields": "date",
+            "nested": {
+                "shards": 1,
+                "ratio": 1.5,
+                "enabled": false,
+                "name": "nested",
+                "fields": ["a", "b"]
+            }
+        });
+
+        assert_eq!(
+            Settings::deserialize(Lenient::new(value)).unwrap(),
+            Settings {
+                shards: 3,
+                ratio: 0.5,
+                enabled: true,
+                name: "1".to_string(),
+                fields: vec!["date".to_string()],
+                nested: Some(Box::new(Settings {
+                    shards: 1,
+                    ratio: 1.5,
+                    enabled: false,
+                    name: "nested".to_string(),
+                    fields: vec!["a".to_string(), "b".to_string()],
+                    nested: None,
+                })),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(u32::deserialize(Lenient::new(json!("three"))).is_err());
+        assert!(bool::deserialize(Lenient::new(json!("yes"))).is_err());
+    }
+}
@@ -1,12 +1,21 @@
 //! Module containing helpers and util functions that are not specific to any DSL
 
 mod assert_serialize;
+#[cfg(feature = "cbor")]
+pub(crate) mod cbor;
 mod join_with_pipe;
 mod key_value_pair;
+mod lenient;
 mod should_skip;
+#[cfg(feature = "yaml")]
+pub(crate) mod yaml;
 
 #[cfg(test)]
 pub(crate) use self::assert_serialize::*;
 pub(crate) use self::join_with_pipe::*;
 pub(crate) use self::key_value_pair::*;
+pub(crate) use self::lenient::*;
 pub(crate) use self::should_skip::*;
+
+/// Name serde_json serializes and deserializes [`RawValue`](serde_json::value::RawValue)s with
+pub(crate) const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
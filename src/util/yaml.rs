@@ -0,0 +1,706 @@
+//! YAML rendering of request bodies for review and snapshot files, Elasticsearch accepts the
+//! output with the `application/yaml` content type.
+//!
+//! Values are serialized into a [`Node`] tree rather than a [`serde_json::Value`], which would
+//! sort object keys, so the output keeps the order the keys are serialized in.
+
+use super::RAW_VALUE_TOKEN;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Error as _, Serialize};
+use serde_json::{Error, Value};
+use std::fmt::Write;
+
+/// Renders the value as a YAML document
+pub(crate) fn to_string<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let mut output = String::new();
+
+    match value.serialize(Serializer)? {
+        Node::Object(entries) if !entries.is_empty() => write_map(&mut output, &entries, 0),
+        Node::Array(values) if !values.is_empty() => write_array(&mut output, &values, 0),
+        value => {
+            write_scalar(&mut output, &value);
+            output.push('\n');
+        }
+    }
+
+    Ok(output)
+}
+
+/// JSON value with object entries in serialization order
+enum Node {
+    Null,
+    Bool(bool),
+    /// Number formatted the same way as in JSON
+    Number(String),
+    String(String),
+    Array(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn float<T>(value: T) -> Result<Self, Error>
+    where
+        T: Serialize + Into<f64> + Copy,
+    {
+        if value.into().is_finite() {
+            serde_json::to_string(&value).map(Self::Number)
+        } else {
+            // Same as serde_json, which has no representation for such floats either
+            Ok(Self::Null)
+        }
+    }
+}
+
+fn indent(output: &mut String, level: usize) {
+    output.push_str(&"  ".repeat(level));
+}
+
+fn write_map(output: &mut String, entries: &[(String, Node)], level: usize) {
+    for (index, (key, value)) in entries.iter().enumerate() {
+        // The first entry of a map in a list goes on the same line as the dash
+        if index > 0 || !output.ends_with("- ") {
+            indent(output, level);
+        }
+
+        write_string(output, key);
+        output.push(':');
+        write_nested(output, value, level + 1);
+    }
+}
+
+fn write_array(output: &mut String, values: &[Node], level: usize) {
+    for value in values {
+        if !output.ends_with("- ") {
+            indent(output, level);
+        }
+
+        output.push_str("- ");
+
+        match value {
+            Node::Object(entries) if !entries.is_empty() => write_map(output, entries, level + 1),
+            Node::Array(values) if !values.is_empty() => write_array(output, values, level + 1),
+            value => {
+                write_scalar(output, value);
+                output.push('\n');
+            }
+        }
+    }
+}
+
+/// Writes a map value, nested collections start on the next line
+fn write_nested(output: &mut String, value: &Node, level: usize) {
+    match value {
+        Node::Object(entries) if !entries.is_empty() => {
+            output.push('\n');
+            write_map(output, entries, level);
+        }
+        // Lists are not indented under their keys, the same as most YAML tools format them
+        Node::Array(values) if !values.is_empty() => {
+            output.push('\n');
+            write_array(output, values, level - 1);
+        }
+        value => {
+            output.push(' ');
+            write_scalar(output, value);
+            output.push('\n');
+        }
+    }
+}
+
+fn write_scalar(output: &mut String, value: &Node) {
+    match value {
+        Node::Null => output.push_str("null"),
+        Node::Bool(value) => {
+            let _ = write!(output, "{value}");
+        }
+        Node::Number(value) => output.push_str(value),
+        Node::String(value) => write_string(output, value),
+        Node::Array(_) => output.push_str("[]"),
+        Node::Object(_) => output.push_str("{}"),
+    }
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Node;
+    type Error = Error;
+
+    type SerializeSeq = SeqBuilder;
+    type SerializeTuple = SeqBuilder;
+    type SerializeTupleStruct = SeqBuilder;
+    type SerializeTupleVariant = SeqBuilder;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = MapBuilder;
+    type SerializeStructVariant = MapBuilder;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, Error> {
+        Ok(Node::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, Error> {
+        Ok(Node::Number(v.to_string()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Node, Error> {
+        Ok(Node::Number(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, Error> {
+        Ok(Node::Number(v.to_string()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Node, Error> {
+        Ok(Node::Number(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, Error> {
+        Node::float(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, Error> {
+        Node::float(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, Error> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, Error> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, Error> {
+        Ok(Node::Array(
+            v.iter()
+                .map(|byte| Node::Number(byte.to_string()))
+                .collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Node, Error> {
+        Ok(Node::Null)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Node, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, Error> {
+        Ok(Node::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Node, Error> {
+        Ok(Node::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Node, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Node, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Ok(Node::Object(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder, Error> {
+        Ok(SeqBuilder {
+            values: Vec::with_capacity(len.unwrap_or_default()),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqBuilder, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqBuilder, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqBuilder, Error> {
+        Ok(SeqBuilder {
+            values: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapBuilder, Error> {
+        Ok(MapBuilder::default())
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<MapBuilder, Error> {
+        Ok(MapBuilder {
+            raw: name == RAW_VALUE_TOKEN,
+            ..Default::default()
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<MapBuilder, Error> {
+        Ok(MapBuilder {
+            variant: Some(variant),
+            ..Default::default()
+        })
+    }
+}
+
+/// Wraps the value into a single entry object keyed by the enum variant, if there's one
+fn wrap(variant: Option<&'static str>, value: Node) -> Node {
+    match variant {
+        Some(variant) => Node::Object(vec![(variant.to_string(), value)]),
+        None => value,
+    }
+}
+
+/// Later entries overwrite earlier ones with the same key, the same as in JSON objects
+fn insert(entries: &mut Vec<(String, Node)>, key: String, value: Node) {
+    match entries.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, existing)) => *existing = value,
+        None => entries.push((key, value)),
+    }
+}
+
+struct SeqBuilder {
+    values: Vec<Node>,
+    variant: Option<&'static str>,
+}
+
+impl SeqBuilder {
+    fn element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.values.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        Ok(wrap(self.variant, Node::Array(self.values)))
+    }
+}
+
+impl ser::SerializeSeq for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        SeqBuilder::end(self)
+    }
+}
+
+impl ser::SerializeTuple for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        SeqBuilder::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        SeqBuilder::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        SeqBuilder::end(self)
+    }
+}
+
+#[derive(Default)]
+struct MapBuilder {
+    entries: Vec<(String, Node)>,
+    key: Option<String>,
+    variant: Option<&'static str>,
+    raw: bool,
+    raw_node: Option<Node>,
+}
+
+impl MapBuilder {
+    fn entry<T>(&mut self, key: String, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        insert(&mut self.entries, key, value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        if let Some(node) = self.raw_node {
+            return Ok(node);
+        }
+
+        Ok(wrap(self.variant, Node::Object(self.entries)))
+    }
+}
+
+impl ser::SerializeMap for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        // Map keys are strings in JSON, so the same conversions apply
+        self.key = Some(match key.serialize(Serializer)? {
+            Node::String(key) | Node::Number(key) => key,
+            Node::Bool(key) => key.to_string(),
+            _ => return Err(Error::custom("key must be a string")),
+        });
+
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("map value without a key"))?;
+
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        MapBuilder::end(self)
+    }
+}
+
+impl ser::SerializeStruct for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.raw {
+            // Raw JSON is serialized as a single string field, holding the JSON text
+            let json = match value.serialize(Serializer)? {
+                Node::String(json) => json,
+                _ => return Err(Error::custom("expected raw JSON text")),
+            };
+
+            self.raw_node = Some(serde_json::from_str(&json)?);
+
+            return Ok(());
+        }
+
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        MapBuilder::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for MapBuilder {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Node, Error> {
+        MapBuilder::end(self)
+    }
+}
+
+/// Parses raw JSON, keeping the order of object keys
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NodeVisitor;
+
+        impl<'de> Visitor<'de> for NodeVisitor {
+            type Value = Node;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a JSON value")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Node, E> {
+                Ok(Node::Bool(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Node, E> {
+                Ok(Node::Number(v.to_string()))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Node, E> {
+                Ok(Node::Number(v.to_string()))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Node, E>
+            where
+                E: de::Error,
+            {
+                Node::float(v).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Node, E> {
+                Ok(Node::String(v.to_string()))
+            }
+
+            fn visit_unit<E>(self) -> Result<Node, E> {
+                Ok(Node::Null)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Node, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::new();
+
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+
+                Ok(Node::Array(values))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Node, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries: Vec<(String, Node)> = Vec::new();
+
+                while let Some((key, value)) = map.next_entry()? {
+                    insert(&mut entries, key, value);
+                }
+
+                Ok(Node::Object(entries))
+            }
+        }
+
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+/// Writes the string plain when it can't be mistaken for anything else, double quoted otherwise
+fn write_string(output: &mut String, value: &str) {
+    if is_plain(value) {
+        output.push_str(value);
+    } else {
+        // JSON strings are valid double quoted YAML scalars
+        let _ = write!(output, "{}", Value::from(value));
+    }
+}
+
+fn is_plain(value: &str) -> bool {
+    let first = match value.chars().next() {
+        Some(first) => first,
+        None => return false,
+    };
+
+    let reserved = matches!(
+        value.to_ascii_lowercase().as_str(),
+        "null" | "~" | "true" | "false" | "yes" | "no" | "on" | "off" | "y" | "n"
+    );
+
+    !reserved
+        && !value.starts_with(|c: char| c.is_ascii_digit() || "-+.?:,[]{}#&*!|>'\"%@`".contains(c))
+        && !first.is_whitespace()
+        && !value.ends_with(char::is_whitespace)
+        && !value.contains(": ")
+        && !value.contains(" #")
+        && !value.ends_with(':')
+        && !value.chars().any(char::is_control)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_values() {
+        #[derive(Serialize)]
+        struct Request {
+            size: u64,
+            query: Value,
+            _source: bool,
+            timeout: &'static str,
+            search_after: Value,
+            nested: Value,
+            runtime_mappings: Box<serde_json::value::RawValue>,
+            sort: Vec<std::collections::BTreeMap<&'static str, &'static str>>,
+            boost: f32,
+        }
+
+        let value = Request {
+            size: 10,
+            query: json!({
+                "bool": {
+                    "filter": [
+                        { "term": { "user": { "value": "kimchy" } } },
+                        { "terms": { "tags": ["a", "b"] } }
+                    ],
+                    "must": []
+                }
+            }),
+            _source: false,
+            timeout: "1s",
+            search_after: json!([1, "true", "", "a: b", " padded", "line\nbreak", null, -1.5]),
+            nested: json!([[1, 2], {}]),
+            runtime_mappings: serde_json::value::RawValue::from_string(
+                r#"{ "day": { "type": "keyword", "script": "emit('a')" }, "a": 1 }"#.into(),
+            )
+            .unwrap(),
+            sort: vec![vec![("price", "asc")].into_iter().collect()],
+            boost: 1.1,
+        };
+
+        assert_eq!(
+            to_string(&value).unwrap(),
+            r#"size: 10
+query:
+  bool:
+    filter:
+    - term:
+        user:
+          value: kimchy
+    - terms:
+        tags:
+        - a
+        - b
+    must: []
+_source: false
+timeout: "1s"
+search_after:
+- 1
+- "true"
+- ""
+- "a: b"
+- " padded"
+- "line\nbreak"
+- null
+- -1.5
+nested:
+- - 1
+  - 2
+- {}
+runtime_mappings:
+  day:
+    type: keyword
+    script: emit('a')
+  a: 1
+sort:
+- price: asc
+boost: 1.1
+"#
+        );
+
+        assert_eq!(to_string(&json!({})).unwrap(), "{}\n");
+        assert_eq!(to_string(&json!("text")).unwrap(), "text\n");
+    }
+}